@@ -0,0 +1,76 @@
+use std::sync::atomic::Ordering;
+use fluent::FluentArgs;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::discord::metrics::{CommandMetrics, LATENCY_BUCKETS_MS};
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+
+/// Most commands listed in the report, the most invoked first.
+static REPORT_SIZE: usize = 25;
+
+/// Shows how many commands ran since the bot started, how many failed and how long they took.
+///
+/// Only the owners listed in `OWNER_IDS` can run it.
+#[poise::command(slash_command, owners_only, rename = "botstats")]
+pub async fn botstats(ctx: Context<'_>) -> Result<(), Error> {
+    let mut commands = ctx.data().metrics.snapshot();
+    commands.sort_by_key(|(_, metrics)| std::cmp::Reverse(metrics.runs.load(Ordering::Relaxed)));
+
+    let (runs, failures) = commands.iter().fold((0, 0), |(runs, failures), (_, metrics)| {
+        (runs + metrics.runs.load(Ordering::Relaxed), failures + metrics.failures.load(Ordering::Relaxed))
+    });
+    let mut args = FluentArgs::new();
+    args.set("runs", runs);
+    args.set("failures", failures);
+    args.set("error_rate", error_rate(runs, failures));
+
+    let mut description = tr!(ctx, "botstats__report", @message; Some(&args));
+    for (name, metrics) in commands.iter().take(REPORT_SIZE) {
+        description.push('\n');
+        description.push_str(&command_line(ctx, name, metrics));
+    }
+    let embed = CreateEmbed::new()
+        .title(tr!(ctx, "botstats__report", @title))
+        .description(description)
+        .color(Color::from_rgb(112, 190, 255));
+    let Ok(_) = ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Line of the report about the command `name`.
+fn command_line(ctx: Context<'_>, name: &str, metrics: &CommandMetrics) -> String {
+    let runs = metrics.runs.load(Ordering::Relaxed);
+    let p95 = match metrics.p95() {
+        Some(p95) => tr!(ctx, "botstats__latency", ms: p95.as_millis() as u64),
+        // Only slower than the last bucket, since the command ran at least once
+        None => tr!(ctx, "botstats__latency_over", ms: LATENCY_BUCKETS_MS[LATENCY_BUCKETS_MS.len() - 1]),
+    };
+    tr!(ctx, "botstats__command",
+        command: name.to_string(),
+        runs: runs,
+        error_rate: error_rate(runs, metrics.failures.load(Ordering::Relaxed)),
+        p95: p95)
+}
+
+/// Share of failed invocations, in percent rounded to the nearest integer.
+pub fn error_rate(runs: u64, failures: u64) -> u64 {
+    if runs == 0 {
+        return 0;
+    }
+    (failures * 100 + runs / 2) / runs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_rate() {
+        assert_eq!(error_rate(0, 0), 0);
+        assert_eq!(error_rate(4, 1), 25);
+        assert_eq!(error_rate(3, 2), 67);
+        assert_eq!(error_rate(200, 1), 1);
+        assert_eq!(error_rate(5, 5), 100);
+    }
+}
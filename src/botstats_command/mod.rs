@@ -0,0 +1,30 @@
+//! A module for the owner-only 'botstats' command, reporting the command metrics of the bot.
+use std::collections::HashSet;
+use serenity::all::UserId;
+
+pub mod handler;
+
+/// Environment variable listing the ids of the owners of the bot, separated by commas.
+pub static OWNER_IDS_VAR: &str = "OWNER_IDS";
+
+/// Owners of the bot listed in `list`, the value of [`OWNER_IDS_VAR`]. Entries which are not
+/// user ids are skipped.
+pub fn owner_ids(list: &str) -> HashSet<UserId> {
+    list.split(',')
+        .filter_map(|id| id.trim().parse::<u64>().ok())
+        .filter(|id| *id != 0)
+        .map(UserId::new)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_owner_ids() {
+        assert_eq!(owner_ids("1, 2,2,"), HashSet::from([UserId::new(1), UserId::new(2)]));
+        assert_eq!(owner_ids("someone,0,3"), HashSet::from([UserId::new(3)]));
+        assert!(owner_ids("").is_empty());
+    }
+}
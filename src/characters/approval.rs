@@ -0,0 +1,17 @@
+use serenity::all::{ChannelId, CreateMessage, UserId};
+use serenity::http::CacheHttp;
+use crate::database::characters::Character;
+use crate::tr_locale;
+
+/// Tells the owner of `character` that `moderator` took the decision `key` on it, in `locale`.
+///
+/// The owner is told by direct message, or by a mention under their sheet when their direct
+/// messages are closed.
+pub async fn notify_owner(http: impl CacheHttp, character: &Character, locale: &str, key: &str, moderator: UserId) {
+    let notice = tr_locale!(locale, key, name: character.name.clone(), moderator: format!("<@{}>", moderator.get()));
+    let owner = UserId::new(character.user_id);
+    if owner.direct_message(&http, CreateMessage::new().content(notice.clone())).await.is_err()
+        && let Some(channel_id) = character.sheet_channel_id {
+        let _ = ChannelId::new(channel_id).send_message(&http, CreateMessage::new().content(format!("<@{}> {notice}", character.user_id))).await;
+    }
+}
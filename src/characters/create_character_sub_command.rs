@@ -12,10 +12,13 @@ use serenity::http::CacheHttp;
 use serenity::utils::CreateQuickModal;
 use crate::database::server::{get_server_by_id, Server};
 use crate::{tr, tr_locale};
-use crate::database::characters::Character;
-use crate::database::db_namespace::{CHARACTERS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
+use fluent::FluentArgs;
+use crate::characters::approval::notify_owner;
+use crate::database::characters::{ApprovalStatus, Character, CharacterStat};
+use crate::discord::audit::notify_moderation_of_press;
+use crate::database::db_namespace::VERSEENGINE_DB_NAME;
 use crate::database::places::{Place};
-use crate::database::stats::{Stat, StatValue};
+use crate::database::stats::{parse_stat_value, Stat};
 use crate::database::travel::{PlayerMove};
 use crate::database::universe::get_universe_by_id;
 
@@ -82,7 +85,7 @@ async fn verify_character_ownership(
 /// # Returns
 /// * `Ok(())` if the user has permission.
 /// * `Err` if the user is not a member or lacks permissions.
-async fn verify_moderator_permission(
+pub(crate) async fn verify_moderator_permission(
     _ctx: &SerenityContext,
     component_interaction: &ComponentInteraction,
     server: &Server,
@@ -104,6 +107,24 @@ async fn verify_moderator_permission(
     Ok(())
 }
 
+/// The character submitted with the sheet of `component_interaction`, still awaiting a decision.
+///
+/// # Errors
+/// - `"character_approval__not_found"`: no character was submitted with this sheet.
+/// - `"character_approval__already_decided"`: a moderator already accepted or refused it.
+/// - `"create_character__database_error"`: the query failed.
+async fn pending_character(component_interaction: &ComponentInteraction) -> Result<Character, Error> {
+    let character = match Character::get_character_by_sheet(component_interaction.message.id.get()).await {
+        Ok(Some(character)) => character,
+        Ok(None) => return Err("character_approval__not_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if character.approval_status != ApprovalStatus::Pending {
+        return Err("character_approval__already_decided".into());
+    }
+    Ok(character)
+}
+
 /// Slash command to initiate the character creation process.
 ///
 /// It delegates to `_create_character` and sends the result back to the user using the `reply` utility.
@@ -154,10 +175,6 @@ pub async fn _create_character(ctx: Context<'_>) -> Result<&'static str, Error>{
         return Err("create_character__wrong_channel".into())
     }
  
-    let Ok(player_result) = server.has_character(ctx.author().id.get()).await else { return Err("create_character__database_error".into()) };
-    if player_result.is_some() {
-        return Err("create_character__character_already_existing".into());
-    }
 
     let app_ctx = match ctx.clone() {
         Context::Application(app_ctx) => app_ctx,
@@ -227,11 +244,16 @@ pub async fn _create_character(ctx: Context<'_>) -> Result<&'static str, Error>{
     }
 }
 
-/// Deletes a character sheet draft from the channel.
+/// Deletes a character sheet draft from the channel, with the character refused with it if any.
 ///
 /// Only the owner of the character can perform this action.
 pub async fn delete_character(ctx: SerenityContext, component_interaction: ComponentInteraction) -> Result<&'static str, Error>{
     let Ok(_) = verify_character_ownership(&ctx, &component_interaction).await else { return Err("create_character__not_owner".into()) };
+    match Character::get_character_by_sheet(component_interaction.message.id.get()).await {
+        Ok(Some(character)) => { let Ok(_) = character.delete().await else { return Err("create_character__database_error".into()) }; }
+        Ok(None) => {}
+        Err(_) => return Err("create_character__database_error".into()),
+    }
     let Ok(_) = component_interaction.message.delete(ctx).await else { return Err("create_character__database_error".into()) };
     Ok("delete_character")
 }
@@ -240,11 +262,40 @@ pub async fn delete_character(ctx: SerenityContext, component_interaction: Compo
 ///
 /// This function:
 /// 1. Verifies ownership.
-/// 2. Replaces the action buttons with "Accept" and "Refuse" (visible to moderators).
-/// 3. Changes the embed color to green to indicate submission.
-/// 4. Sends a notification message to the server's log channel if configured.
+/// 2. Saves the character, awaiting approval, with the base values of the universe's stats. A
+///    sheet submitted again after a refusal replaces the refused character.
+/// 3. Replaces the action buttons with "Accept" and "Refuse" (visible to moderators).
+/// 4. Changes the embed color to green to indicate submission.
+/// 5. Sends a notification message to the server's log channel if configured.
 pub async fn submit_character(ctx: SerenityContext, component_interaction: ComponentInteraction) -> Result<&'static str, Error> {
     let Ok(_) = verify_character_ownership(&ctx, &component_interaction).await else { return Err("create_character__not_owner".into()) };
+    let server = match get_server_by_id(component_interaction.guild_id.unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+
+    let sheet = &component_interaction.message;
+    let Some(character_name) = sheet.embeds[0].title.clone() else { return Err("create_character__invalid_embed_title".into()) };
+    let description = sheet.embeds[0].fields.first().map(|field| field.value.clone()).unwrap_or_default();
+    let Ok(refused) = Character::get_character_by_sheet(sheet.id.get()).await else { return Err("create_character__database_error".into()) };
+    match Character::get_character_by_name(server.universe_id, &character_name).await {
+        Ok(Some(other)) if refused.as_ref().is_none_or(|refused| refused._id != other._id) => return Err("character_create__name_taken".into()),
+        Ok(_) => {}
+        Err(_) => return Err("create_character__database_error".into()),
+    }
+
+    let Ok(Some(universe)) = get_universe_by_id(server.universe_id).await else { return Err("create_character__database_error".into()) };
+    let Ok(stats_cursor) = universe.get_stats().await else { return Err("create_character__database_error".into()) };
+    let Ok(stats) = stats_cursor.try_collect::<Vec<Stat>>().await else { return Err("create_character__database_error".into()) };
+    if let Some(refused) = refused {
+        let Ok(_) = refused.delete().await else { return Err("create_character__database_error".into()) };
+    }
+    let mut character = Character::new(server.universe_id, server.server_id, component_interaction.user.id.get(), character_name, &stats);
+    character.description = description;
+    let Ok(_) = character.insert().await else { return Err("create_character__database_error".into()) };
+    // The submitted sheet is the sheet of the character, updated when its stats change once accepted
+    let Ok(_) = character.set_sheet(component_interaction.channel_id.get(), sheet.id.get()).await else { return Err("create_character__database_error".into()) };
 
     let buttons = vec![
         CreateActionRow::Buttons(
@@ -268,14 +319,12 @@ pub async fn submit_character(ctx: SerenityContext, component_interaction: Compo
 
     let message = tr_locale!(component_interaction.locale.as_str(), CREATE_CHARACTER_SUBMIT_NOTIFICATION) + " " + component_interaction.message.link().as_str();
 
-    if let Ok(Some(server)) = get_server_by_id(component_interaction.guild_id.unwrap().get()).await {
-        if let Some(log_channel) = server.log_channel_id {
-            let _ = ctx.http().send_message(
-                log_channel.id.into(),
-                vec![],
-                &CreateMessage::new().content(message),
-            ).await;
-        }
+    if let Some(log_channel) = server.log_channel_id {
+        let _ = ctx.http().send_message(
+            log_channel.id.into(),
+            vec![],
+            &CreateMessage::new().content(message),
+        ).await;
     }
 
     Ok("create_character__submitted")
@@ -396,13 +445,16 @@ pub async fn modify_character(ctx: SerenityContext, component_interaction: Compo
 /// This function:
 /// 1. Verifies moderator permissions.
 /// 2. Opens a modal to ask for a rejection reason.
-/// 3. Appends the reason to the embed, changes its color to red, and removes all buttons.
+/// 3. Appends the reason to the embed, changes its color to red, and gives the draft buttons back
+///    to its owner.
+/// 4. Marks the character as rejected, then tells its owner and the moderation channel.
 pub async fn refuse_character(ctx: SerenityContext, component_interaction: ComponentInteraction) -> Result<&'static str, Error> {
     let guild_id = component_interaction.guild_id.unwrap();
     let Ok(server) = get_server_by_id(guild_id.get()).await else { return Err("create_character__database_error".into()) };
     let Ok(server) = server.ok_or("create_character__no_universe_found") else { return Err("create_character__no_universe_found".into()) };
 
     let Ok(_) = verify_moderator_permission(&ctx, &component_interaction, &server).await else { return Err("create_character__no_permission".into()) };
+    let mut character = pending_character(&component_interaction).await?;
 
     let modal = CreateQuickModal::new(tr_locale!(component_interaction.locale.as_str(), CHARACTER_MODAL_TITLE))
         .field(
@@ -449,7 +501,7 @@ pub async fn refuse_character(ctx: SerenityContext, component_interaction: Compo
         .map(|field| (field.name.clone(), field.value.clone(), field.inline))
         .collect();
 
-    let Ok(_) = modal_response.interaction.channel_id.edit_message(ctx, message.id, EditMessage::new().embed(
+    let Ok(_) = modal_response.interaction.channel_id.edit_message(&ctx, message.id, EditMessage::new().embed(
         CreateEmbed::new()
             .footer(CreateEmbedFooter::new(message.embeds.get(0).unwrap().footer.clone().unwrap().text.as_str()))
             .title(inputs[0].clone())
@@ -460,34 +512,18 @@ pub async fn refuse_character(ctx: SerenityContext, component_interaction: Compo
         .components(buttons)
     ).await else { return Err("create_character__database_error".into()) };
 
+    let Ok(_) = character.set_approval_status(ApprovalStatus::Rejected).await else { return Err("create_character__database_error".into()) };
+    let mut notice_args = FluentArgs::new();
+    notice_args.set("name", character.name.clone());
+    notice_args.set("user", format!("<@{}>", character.user_id));
+    notify_moderation_of_press(&ctx, &component_interaction, &server, "character_approval__rejected_notice", notice_args).await;
+    let owner_locale = component_interaction.guild_locale.as_deref().unwrap_or(component_interaction.locale.as_str());
+    notify_owner(&ctx, &character, owner_locale, "character_approval__rejected", component_interaction.user.id).await;
+
     Ok("create_character__refused")
 }
 
-/// Parses a stat value from a string based on the expected `StatValue` variant.
-///
-/// Supported types: `i64`, `f64`, `String`, `bool`.
-fn parse_stat_value(value_str: &str, base_value: &StatValue) -> Option<StatValue> {
-    match base_value {
-        StatValue::I64(_) => value_str.parse::<i64>().ok().map(StatValue::I64),
-        StatValue::F64(_) => value_str.parse::<f64>().ok().map(StatValue::F64),
-        StatValue::String(_) => Some(StatValue::String(value_str.to_string())),
-        StatValue::Bool(_) => value_str.parse::<bool>().ok().map(StatValue::Bool),
-    }
-}
 
-/// Creates a new `Stat` instance with a new value while preserving other attributes.
-fn create_stat_with_value(stat: &Stat, value: StatValue) -> Stat {
-    Stat {
-        _id: Default::default(),
-        universe_id: Default::default(),
-        name: stat.name.clone(),
-        base_value: value,
-        formula: stat.formula.clone(),
-        min: stat.min.clone(),
-        max: stat.max.clone(),
-        modifiers: vec![],
-    }
-}
 
 
 
@@ -498,7 +534,7 @@ fn create_stat_with_value(stat: &Stat, value: StatValue) -> Stat {
 /// 2. Fetches the defined stats for the universe.
 /// 3. Opens a modal with a text area containing a template of all stats.
 /// 4. Parses the moderator's input to extract stat values.
-/// 5. Saves the character and its stats to the database.
+/// 5. Saves the stats of the submitted character and marks it as approved, then tells its owner.
 /// 6. Assigns the `player_role_id` to the user if configured.
 /// 7. Updates the message to indicate acceptance and removes all buttons.
 pub async fn accept_character(ctx: SerenityContext, component_interaction: ComponentInteraction) -> Result<&'static str, Error> {
@@ -522,6 +558,7 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
     if !has_admin_permission && !has_moderator_role && !has_admin_role {
         return Err("create_character__no_permission".into());
     }
+    let mut character = pending_character(&component_interaction).await?;
 
     let Ok(universe) = get_universe_by_id(ObjectId::from_str(server.universe_id.to_string().as_str())?).await else { return Err("create_character__database_error".into()) };
     let Ok(universe) = universe.ok_or("create_character__no_universe_found") else { return Err("create_character__no_universe_found".into()) };
@@ -537,7 +574,7 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
     quick_modal = quick_modal.field(CreateInputText::new(InputTextStyle::Paragraph, tr_locale!(component_interaction.locale.as_str(), CHARACTER_ACCEPT__STAT_INPUT) , "stats_input").value(text).required(false));
     let Ok(interaction) = component_interaction.quick_modal(&ctx, quick_modal).await else { return Err("create_character__timed_out".into()) };
 
-    let mut extracted_stats: Vec<Stat> = Vec::new();
+    let mut extracted_stats: Vec<CharacterStat> = Vec::new();
     match interaction {
         Some(interaction) => {
             let input = interaction.inputs[0].clone();
@@ -557,7 +594,7 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
                         };
 
                         if let Some(value) = parse_stat_value(value_str, &stat.base_value) {
-                            extracted_stats.push(create_stat_with_value(stat, value));
+                            extracted_stats.push(CharacterStat { name: stat.name.clone(), value, modifiers: vec![] });
                         } else {
                             return Err("create_character__type_mismatch".into());
                         }
@@ -569,7 +606,7 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
             // For any stats not found in the input, use their default values
             for stat in stats.iter() {
                 if !line_matched.contains(&stat.name) {
-                    extracted_stats.push(stat.clone());
+                    extracted_stats.push(CharacterStat::from(stat));
                 }
             }
 
@@ -580,45 +617,14 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
         }
     }
 
-    let character_user_id = match component_interaction.message.embeds[0]
-        .footer.as_ref()
-        .and_then(|f| f.text.parse::<u64>().ok()) {
-            Some(id) => id,
-            None => return Err("create_character__invalid_footer".into()),
-        };
-
-    let character_name = match component_interaction.message.embeds[0].title.as_ref() {
-        Some(title) => title.clone(),
-        None => return Err("create_character__invalid_embed_title".into()),
-    };
-
-    let embed_fields = &component_interaction.message.embeds[0].fields;
-    let _description = embed_fields.iter()
-        .find(|f| f.name == tr_locale!(component_interaction.locale.as_str(), CHARACTER_DESCRIPTION))
-        .map(|f| f.value.clone())
-        .unwrap_or_default();
-
-    let _story = embed_fields.iter()
-        .find(|f| f.name == tr_locale!(component_interaction.locale.as_str(), CHARACTER_STORY))
-        .map(|f| f.value.clone())
-        .unwrap_or_default();
-
-    let _special_request = embed_fields.iter()
-        .find(|f| f.name == tr_locale!(component_interaction.locale.as_str(), CHARACTER_SPECIAL_REQUEST))
-        .map(|f| f.value.clone())
-        .unwrap_or_default();
-
-
-
-    let character = Character {
-        _id: Default::default(), 
-        user_id: character_user_id,
-        universe_id: server.universe_id,
-        name: character_name,
-        stats: extracted_stats,
-    };
-
-    let Ok(character_result) = character.clone().update().await else { return Err("create_character__database_error".into()) };
+    let character_user_id = character.user_id;
+    let Ok(_) = character.set_stats(extracted_stats).await else { return Err("create_character__database_error".into()) };
+    // The moderator accepting the sheet is the approval
+    let Ok(_) = character.set_approval_status(ApprovalStatus::Approved).await else { return Err("create_character__database_error".into()) };
+    // The player plays the character they were just given, and no longer their others
+    let Ok(_) = character.activate().await else { return Err("create_character__database_error".into()) };
+    let owner_locale = component_interaction.guild_locale.as_deref().unwrap_or(component_interaction.locale.as_str());
+    notify_owner(&ctx, &character, owner_locale, "character_approval__approved", member.user.id).await;
 
     if let Some(player_role_id) = server.player_role_id {
         if let Ok(member) = ctx.http().get_member(guild_id, character_user_id.into()).await {
@@ -641,7 +647,7 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
     let original_embed: CreateEmbed = message.embeds[0].clone().into();
 
     let select_menu = serenity::all::CreateSelectMenu::new(
-        ACCEPT_CHARACTER_CHOOSE_PLACE,
+        format!("{ACCEPT_CHARACTER_CHOOSE_PLACE}:{}", character._id),
         serenity::all::CreateSelectMenuKind::Channel {
             channel_types: Some(vec![serenity::all::ChannelType::Category]),
             default_channels: None,
@@ -667,14 +673,18 @@ pub async fn accept_character(ctx: SerenityContext, component_interaction: Compo
 /// This function:
 /// 1. Verifies moderator permissions.
 /// 2. Validates that the selected category ID corresponds to a registered `Place`.
-/// 3. Updates the character sheet message to remove the select menu and set the final color.
-/// 4. If an invalid category is selected, sends an ephemeral message without acknowledging the interaction.
+/// 3. Puts the accepted character, whose id ends the custom id of the select menu, in this place.
+/// 4. Updates the character sheet message to remove the select menu and set the final color.
+/// 5. If an invalid category is selected, sends an ephemeral message without acknowledging the interaction.
 pub async fn choose_character_place(ctx: SerenityContext, component_interaction: ComponentInteraction) -> Result<&'static str, Error> {
     let guild_id = component_interaction.guild_id.ok_or("create_character__guild_only")?;
     let Ok(server) = get_server_by_id(guild_id.get()).await else { return Err("create_character__database_error".into()) };
     let Ok(server) = server.ok_or("create_character__no_universe_found") else { return Err("create_character__no_universe_found".into()) };
 
     let Ok(_) = verify_moderator_permission(&ctx, &component_interaction, &server).await else { return Err("create_character__no_permission".into()) };
+    // The select menu of the accepted character ends with its id
+    let Some(character_id) = component_interaction.data.custom_id.rsplit_once(':')
+        .and_then(|(_, id)| ObjectId::from_str(id).ok()) else { return Err("create_character__invalid_interaction".into()) };
 
     let selected_category_id = match &component_interaction.data.kind {
         serenity::all::ComponentInteractionDataKind::ChannelSelect { values } => {
@@ -712,11 +722,8 @@ pub async fn choose_character_place(ctx: SerenityContext, component_interaction:
     // Valid place selected
     let Ok(_) = component_interaction.create_response(&ctx, CreateInteractionResponse::Acknowledge).await else { return Err("create_character__database_error".into()) };
 
-    // Extract user ID from embed footer
-    let Ok(character_user_id) = component_interaction.message.embeds[0]
-        .footer.as_ref()
-        .and_then(|footer| footer.text.parse::<u64>().ok())
-        .ok_or_else(|| -> Error { "create_character__invalid_footer".into() }) else { return Err("create_character__invalid_footer".into()) };
+    let Ok(Some(mut character)) = Character::get_character_by_id(character_id).await else { return Err("create_character__database_error".into()) };
+    let character_user_id = character.user_id;
 
     if let Ok(member) = guild_id.member(&ctx, character_user_id).await {
         // Add place role
@@ -729,13 +736,7 @@ pub async fn choose_character_place(ctx: SerenityContext, component_interaction:
         }
     }
 
-    let player_id = component_interaction.message.embeds.get(0).unwrap();
-    let player_id = player_id.footer.clone().unwrap();
-    let player_id = player_id.text.as_str();
-    let character_filter = doc!{"user_id": player_id, "universe_id": server.universe_id};
-
-    let Ok(Some(character)) = db_client.database(VERSEENGINE_DB_NAME).collection::<Character>(CHARACTERS_COLLECTION_NAME)
-        .find_one(character_filter).await else {return Err("create_character__database_error".into())};
+    let Ok(_) = character.set_current_place(Some(place.category_id)).await else { return Err("create_character__database_error".into()) };
 
     let player_move = PlayerMove{
         _id: Default::default(),
@@ -758,6 +759,7 @@ pub async fn choose_character_place(ctx: SerenityContext, component_interaction:
         source_server_id: Some(place.server_id),
         modified_speed: 0.0,
         distance_traveled: 0.0,
+        departure_timestamp: None,
         user_id: character_user_id,
     };
 
@@ -0,0 +1,105 @@
+use std::time::Duration;
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, MessageId, RoleId};
+use crate::characters::{is_moderator, resolve_character};
+use crate::database::characters::{ApprovalStatus, Character};
+use crate::database::places::check_existing_place;
+use crate::database::server::{get_server_by_id, Server};
+use crate::discord::audit::notify_moderation;
+use crate::discord::poise_structs::{Context, Error};
+use crate::utility::confirm::{confirm, ConfirmOutcome};
+use crate::utility::reply::reply_with_args;
+
+/// Deletes a character, its sheet and the roles its travels gave.
+///
+/// Players can delete their own characters, moderators any of them.
+#[poise::command(slash_command, guild_only, rename = "character_delete")]
+pub async fn delete(
+    ctx: Context<'_>,
+    #[description = "character_delete.name"]
+    name: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _delete(&ctx, name.trim().to_string(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Asks for confirmation, then deletes the character named `name`. `args` receives its name.
+///
+/// When the deleted character is the one its player is playing, the place and road roles of its
+/// journey are taken back and the journey is forgotten. A player deleting their own approved
+/// character is reported in the moderation channel.
+///
+/// # Errors
+/// - `"character__not_found"`: no character has this name.
+/// - `"create_character__not_owner"`: a player tried to delete a character of someone else.
+/// - `"character_delete__timeout"`: the deletion was not confirmed in time.
+/// - `"create_character__database_error"`: the character could not be read or deleted.
+pub async fn _delete(ctx: &Context<'_>, name: String, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    args.set("name", name.clone());
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let character = resolve_character(server.universe_id, ctx.author().id.get(), Some(&name)).await?;
+    args.set("name", character.name.clone());
+    let by_owner = character.user_id == ctx.author().id.get();
+    if !by_owner && !is_moderator(ctx, &server).await {
+        return Err("create_character__not_owner".into());
+    }
+
+    let mut confirm_args = FluentArgs::new();
+    confirm_args.set("name", character.name.clone());
+    match confirm(*ctx, "character_delete__confirm", Some(confirm_args), Duration::from_secs(60)).await? {
+        ConfirmOutcome::Confirmed => {}
+        ConfirmOutcome::Cancelled => return Ok("character_delete__cancelled"),
+        ConfirmOutcome::TimedOut => return Err("character_delete__timeout".into()),
+    }
+
+    // Only the played character owns the journey and the roles it gave
+    let played = matches!(server.get_active_character(character.user_id).await, Ok(Some(active)) if active._id == character._id);
+    if character.delete().await.is_err() {
+        return Err("create_character__database_error".into());
+    }
+    if let (Some(channel_id), Some(message_id)) = (character.sheet_channel_id, character.sheet_message_id) {
+        let _ = ChannelId::new(channel_id).delete_message(ctx, MessageId::new(message_id)).await;
+    }
+    if played {
+        take_back_roles(ctx, &server, &character).await;
+    }
+    if by_owner && character.approval_status == ApprovalStatus::Approved {
+        let mut notice_args = FluentArgs::new();
+        notice_args.set("name", character.name.clone());
+        notify_moderation(ctx, &server, "character_delete__notice", notice_args).await;
+    }
+    Ok("character_delete__success")
+}
+
+/// Removes from the owner of `character` the roles of the place it is in and of its journey, then
+/// forgets the journey. Failures are logged, the character being already deleted.
+async fn take_back_roles(ctx: &Context<'_>, server: &Server, character: &Character) {
+    let guild_id = ctx.guild_id().unwrap();
+    let mut role_ids = vec![];
+    if let Some(place_id) = character.current_place_id
+        && let Ok(Some(place)) = check_existing_place(server.universe_id, place_id).await {
+        role_ids.push(place.role);
+    }
+    if let Ok(Some(player_move)) = server.clone().get_player_move(character.user_id).await {
+        role_ids.extend([player_move.source_role_id, player_move.road_role_id, player_move.destination_role_id].into_iter().flatten());
+        if let Err(e) = player_move.remove().await {
+            tracing::warn!(guild_id = guild_id.get(), user_id = character.user_id, "failed to forget the journey of a deleted character: {e:?}");
+        }
+    }
+    let Ok(member) = guild_id.member(ctx, character.user_id).await else { return };
+    let role_ids: Vec<RoleId> = role_ids.into_iter()
+        .map(RoleId::new)
+        .filter(|role_id| member.roles.contains(role_id))
+        .collect();
+    if let Err(e) = member.remove_roles(ctx, &role_ids).await {
+        tracing::warn!(guild_id = guild_id.get(), user_id = character.user_id, "failed to take back the roles of a deleted character: {e}");
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use fluent::FluentArgs;
+use serenity::all::{Color, CreateEmbed, User};
+use crate::characters::is_moderator;
+use crate::database::characters::Character;
+use crate::database::places::get_places_by_universe_id_sorted;
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::utility::pagination::{page_count, page_of, send_pages};
+use crate::utility::reply::reply;
+
+/// A character of the list, with the name of the place it is in.
+pub struct CharacterRow {
+    pub name: String,
+    /// Attribute of the `character_status` message naming its approval status.
+    pub status: &'static str,
+    pub place: Option<String>,
+}
+
+/// Lists your characters, or those of another player for moderators, ten per page.
+#[poise::command(slash_command, guild_only, rename = "character_list")]
+pub async fn list(
+    ctx: Context<'_>,
+    #[description = "character_list.user"]
+    user: Option<User>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let user = user.unwrap_or_else(|| ctx.author().clone());
+    let rows = match _list(&ctx, &user).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+            return Ok(());
+        }
+    };
+    if rows.is_empty() {
+        let Ok(_) = reply(ctx, Ok("character_list__empty")).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
+
+    send_pages(ctx, page_count(rows.len()), |page| page_embed(&ctx, &user, &rows, page)).await
+}
+
+/// Characters of `user` in the universe, sorted by name. Only moderators can list the characters
+/// of someone else.
+async fn _list(ctx: &Context<'_>, user: &User) -> Result<Vec<CharacterRow>, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if user.id != ctx.author().id && !is_moderator(ctx, &server).await {
+        return Err("create_character__no_permission".into());
+    }
+
+    let Ok(characters) = Character::get_characters_by_user(server.universe_id, user.id.get()).await else { return Err("create_character__database_error".into()) };
+    let Ok(places) = get_places_by_universe_id_sorted(server.universe_id).await else { return Err("create_character__database_error".into()) };
+    let names: HashMap<u64, String> = places.into_iter().map(|place| (place.category_id, place.name)).collect();
+    Ok(character_rows(characters, &names))
+}
+
+/// Rows of `characters`, with place names taken from `names`. A place missing from `names` is
+/// shown as a channel mention.
+pub fn character_rows(characters: Vec<Character>, names: &HashMap<u64, String>) -> Vec<CharacterRow> {
+    characters.into_iter()
+        .map(|character| CharacterRow {
+            name: character.name,
+            status: character.approval_status.label_key(),
+            place: character.current_place_id.map(|id| names.get(&id).cloned().unwrap_or_else(|| format!("<#{id}>"))),
+        })
+        .collect()
+}
+
+fn page_embed(ctx: &Context<'_>, user: &User, rows: &[CharacterRow], page: usize) -> CreateEmbed {
+    let lines = page_of(rows, page).iter()
+        .map(|row| {
+            let mut args = FluentArgs::new();
+            args.set("name", row.name.clone());
+            args.set("status", tr!(*ctx, "character_status", @(row.status)));
+            args.set("place", row.place.clone().unwrap_or_else(|| tr!(*ctx, "character_list__no_place", @message)));
+            tr!(*ctx, "character_list__row", @message; Some(&args))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut args = FluentArgs::new();
+    args.set("user", user.display_name().to_string());
+    args.set("page", page + 1);
+    args.set("pages", page_count(rows.len()));
+    args.set("count", rows.len());
+    CreateEmbed::new()
+        .title(tr!(*ctx, "character_list__page", @title; Some(&args)))
+        .description(lines)
+        .color(Color::from_rgb(112, 190, 255))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::characters::ApprovalStatus;
+
+    #[test]
+    fn test_character_rows() {
+        let mut alys = Character::new(ObjectId::new(), 1, 2, "Alys".to_string(), &[]);
+        alys.current_place_id = Some(10);
+        let mut bram = Character::new(ObjectId::new(), 1, 2, "Bram".to_string(), &[]);
+        bram.approval_status = ApprovalStatus::Approved;
+        bram.current_place_id = Some(11);
+        let cid = Character::new(ObjectId::new(), 1, 2, "Cid".to_string(), &[]);
+        let names = HashMap::from([(10, "Port".to_string())]);
+
+        let rows = character_rows(vec![alys, bram, cid], &names);
+        let shown: Vec<(&str, &str, Option<&str>)> = rows.iter().map(|row| (row.name.as_str(), row.status, row.place.as_deref())).collect();
+        assert_eq!(shown, vec![("Alys", "pending", Some("Port")), ("Bram", "approved", Some("<#11>")), ("Cid", "pending", None)]);
+    }
+}
@@ -1,9 +1,122 @@
+pub mod approval;
 pub mod create_character_sub_command;
+pub mod delete_sub_command;
+pub mod list_sub_command;
+pub mod sheet;
+pub mod show_sub_command;
+pub mod stat;
+pub mod switch_sub_command;
+pub mod teleport_sub_command;
+pub mod whereis_command;
 
+use std::collections::HashMap;
+use mongodb::bson::oid::ObjectId;
 use crate::characters::create_character_sub_command::create_character;
+use crate::characters::delete_sub_command::delete;
+use crate::characters::list_sub_command::list;
+use crate::characters::show_sub_command::show;
+use crate::characters::stat::stat;
+use crate::characters::switch_sub_command::switch;
+use crate::characters::teleport_sub_command::teleport;
+use crate::database::characters::{Character, CharacterStat};
+use crate::database::modifiers::Modifier;
+use crate::database::places::check_existing_place;
+use crate::database::server::{get_server_by_id, Id, Server};
+use crate::database::stats::{Stat, StatValue};
 use crate::discord::poise_structs::{Context, Error};
+use crate::stats::engine::compute_effective_value;
 
-#[poise::command(slash_command, subcommands("create_character"), subcommand_required, rename = "character")]
+#[poise::command(slash_command, subcommands("create_character", "list", "show", "stat", "switch", "delete", "teleport"), subcommand_required, rename = "character")]
 pub async fn character(_ctx: Context<'_>) -> Result<(), Error>{
     Ok(())
 }
+
+/// The character the author is playing in the universe of the server.
+///
+/// # Errors
+/// - `"create_character__no_universe_found"`: the server is not part of a universe.
+/// - `"character__no_active"`: the author has no character, or several and none chosen with `/character switch`.
+/// - `"create_character__database_error"`: a query failed.
+pub async fn get_active_character(ctx: &Context<'_>) -> Result<Character, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    match server.get_active_character(ctx.author().id.get()).await {
+        Ok(Some(character)) => Ok(character),
+        Ok(None) => Err("character__no_active".into()),
+        Err(_) => Err("create_character__database_error".into()),
+    }
+}
+
+/// Finds the character of the universe named `name`, or the character `user_id` is playing when
+/// no name is given.
+///
+/// # Errors
+/// - `"character__not_found"`: no character has this name.
+/// - `"character__no_active"`: the user is not playing any character.
+/// - `"create_character__database_error"`: the query failed.
+pub async fn resolve_character(universe_id: ObjectId, user_id: u64, name: Option<&str>) -> Result<Character, Error> {
+    let character = match name {
+        Some(name) => Character::get_character_by_name(universe_id, name.trim()).await,
+        None => Character::get_active_character(universe_id, user_id).await,
+    };
+    match (character, name) {
+        (Ok(Some(character)), _) => Ok(character),
+        (Ok(None), Some(_)) => Err("character__not_found".into()),
+        (Ok(None), None) => Err("character__no_active".into()),
+        (Err(_), _) => Err("create_character__database_error".into()),
+    }
+}
+
+/// Whether the author has the administrator permission or the moderator or admin role of `server`.
+pub async fn is_moderator(ctx: &Context<'_>, server: &Server) -> bool {
+    let Some(member) = ctx.author_member().await else { return false };
+    let has_role = |role: Option<Id>| role.is_some_and(|role| member.roles.contains(&role.id.into()));
+    member.permissions.is_some_and(|p| p.administrator())
+        || has_role(server.moderator_role_id)
+        || has_role(server.admin_role_id)
+}
+
+/// Active modifiers applying to `stat` for a character: those of `character_stat`, of the place
+/// the character is in and of the stat itself.
+pub fn active_modifiers(character_stat: Option<&CharacterStat>, stat: &Stat, place_modifiers: &[Modifier]) -> Vec<Modifier> {
+    character_stat.map(|character_stat| character_stat.modifiers.as_slice()).unwrap_or_default().iter()
+        .chain(place_modifiers)
+        .chain(&stat.modifiers)
+        .filter(|modifier| modifier.is_active())
+        .cloned()
+        .collect()
+}
+
+/// Values of the stats of `character`, by name, as read by stat formulas.
+pub fn stat_values(character: &Character) -> HashMap<String, StatValue> {
+    character.stats.iter().map(|stat| (stat.name.clone(), stat.value.clone())).collect()
+}
+
+/// The stat of the universe of `character` named `stat_name`, and its effective value for the
+/// character once its formula and the active modifiers are applied.
+///
+/// # Errors
+/// - `"character_stat__stat_not_found"`: the universe has no stat with this name.
+/// - `"stat_formula__*"`: the formula of the stat or of a modifier failed.
+/// - `"create_character__database_error"`: the stat could not be read.
+pub async fn effective_stat_value(character: &Character, stat_name: &str) -> Result<(Stat, StatValue), Error> {
+    let stat = match Stat::get_stat_by_name(character.universe_id, stat_name).await {
+        Ok(Some(stat)) => stat,
+        Ok(None) => return Err("character_stat__stat_not_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let place = match character.current_place_id {
+        Some(place_id) => check_existing_place(character.universe_id, place_id).await.ok().flatten(),
+        None => None,
+    };
+    let place_modifiers = place.as_ref().map(|place| place.modifiers.as_slice()).unwrap_or_default();
+    let character_stat = character.stats.iter().find(|character_stat| character_stat.name == stat.name);
+    let modifiers = active_modifiers(character_stat, &stat, place_modifiers);
+    match compute_effective_value(&stat, &stat_values(character), &modifiers) {
+        Ok(value) => Ok((stat, value)),
+        Err(e) => Err(e.key().into()),
+    }
+}
@@ -0,0 +1,80 @@
+use serenity::all::{ChannelId, Color, CreateEmbed, EditMessage, Http, MessageId};
+use crate::database::characters::{ApprovalStatus, Character, CharacterStat};
+use crate::place::info_place_sub_command::format_stat_value;
+use crate::translation::get_by_locale;
+
+/// Aligned table of the `(name, value)` rows of the stats of a character, in a code block so that
+/// columns line up.
+pub fn stat_table(rows: &[(String, String)]) -> Option<String> {
+    if rows.is_empty() {
+        return None;
+    }
+    let width = rows.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0);
+    let rows = rows.iter()
+        .map(|(name, value)| format!("{name:<width$}  {value}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(format!("```\n{rows}\n```"))
+}
+
+/// Rows of the stat table showing the values stored for the character.
+fn stored_rows(stats: &[CharacterStat]) -> Vec<(String, String)> {
+    stats.iter().map(|stat| (stat.name.clone(), format_stat_value(&stat.value))).collect()
+}
+
+/// Sheet of `character`: its name, owner, description and stats, labelled in `locale`.
+pub fn sheet_embed(locale: &str, character: &Character) -> CreateEmbed {
+    sheet_embed_with_stats(locale, character, &stored_rows(&character.stats))
+}
+
+/// Sheet of `character` with `stat_rows` as its stat table.
+pub fn sheet_embed_with_stats(locale: &str, character: &Character, stat_rows: &[(String, String)]) -> CreateEmbed {
+    let description = match character.description.trim() {
+        "" => get_by_locale(locale, "character_sheet__no_description", Some("message"), None),
+        description => description.to_string(),
+    };
+    let stats = stat_table(stat_rows)
+        .unwrap_or_else(|| get_by_locale(locale, "character_sheet__no_stats", Some("message"), None));
+
+    CreateEmbed::new()
+        .title(character.name.clone())
+        .color(Color::from_rgb(112, 190, 255))
+        .field(get_by_locale(locale, "character_sheet__fields", Some("owner"), None), format!("<@{}>", character.user_id), true)
+        .field(get_by_locale(locale, "character_sheet__fields", Some("description"), None), description, false)
+        .field(get_by_locale(locale, "character_sheet__fields", Some("stats"), None), stats, false)
+}
+
+/// Updates the posted sheet of `character` after its stats or description changed. Characters
+/// without a posted sheet are left as they are, and so are those awaiting a decision, whose sheet
+/// still holds the buttons of the moderators.
+pub async fn refresh_sheet(http: &Http, locale: &str, character: &Character) -> Result<(), &'static str> {
+    let (Some(channel_id), Some(message_id)) = (character.sheet_channel_id, character.sheet_message_id) else { return Ok(()) };
+    if character.approval_status != ApprovalStatus::Approved {
+        return Ok(());
+    }
+    let edit = EditMessage::new().embed(sheet_embed(locale, character));
+    if let Err(e) = ChannelId::new(channel_id).edit_message(http, MessageId::new(message_id), edit).await {
+        tracing::warn!(channel_id, message_id, "failed to update character sheet: {e}");
+        return Err("character_sheet__update_failed");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::stats::StatValue;
+
+    fn stat(name: &str, value: StatValue) -> CharacterStat {
+        CharacterStat { name: name.to_string(), value, modifiers: vec![] }
+    }
+
+    #[test]
+    fn test_stat_table() {
+        let rows = stored_rows(&[stat("speed", StatValue::F64(4.5)), stat("strength", StatValue::I64(10)), stat("cursed", StatValue::Bool(false))]);
+        let table = stat_table(&rows);
+
+        assert_eq!(table.as_deref(), Some("```\nspeed     4.5\nstrength  10\ncursed    false\n```"));
+        assert!(stat_table(&[]).is_none());
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use futures::TryStreamExt;
+use poise::CreateReply;
+use serenity::all::CreateEmbed;
+use crate::characters::{active_modifiers, resolve_character, stat_values};
+use crate::characters::sheet::sheet_embed_with_stats;
+use crate::database::characters::CharacterStat;
+use crate::database::modifiers::Modifier;
+use crate::database::places::check_existing_place;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::{Stat, StatValue};
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::info_place_sub_command::format_stat_value;
+use crate::stats::engine::compute_effective_value;
+use crate::tr;
+use crate::utility::reply::reply;
+
+/// Shows the sheet of a character, with its stats once modifiers are applied.
+#[poise::command(slash_command, guild_only, rename = "character_show")]
+pub async fn show(
+    ctx: Context<'_>,
+    #[description = "character_show.name"]
+    name: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let sent = match _show(&ctx, name).await {
+        Ok(embed) => ctx.send(CreateReply::default().embed(embed)).await.map(|_| "reply__reply_success").map_err(Error::from),
+        Err(e) => reply(ctx, Err(e)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Row of the stat table of `character_stat`: its effective value, given the other stats of the
+/// character and the active modifiers of the character, of the place it is in and of the universe,
+/// followed by its own value when they differ. The own value alone is shown when the formula fails.
+pub fn computed_row(character_stat: &CharacterStat, stat: Option<&Stat>, character_stats: &HashMap<String, StatValue>, place_modifiers: &[Modifier]) -> (String, String) {
+    let stored = format_stat_value(&character_stat.value);
+    let Some(stat) = stat else { return (character_stat.name.clone(), stored) };
+    let modifiers = active_modifiers(Some(character_stat), stat, place_modifiers);
+    let computed = match compute_effective_value(stat, character_stats, &modifiers) {
+        Ok(value) => format_stat_value(&value),
+        Err(e) => {
+            tracing::warn!(stat = stat.name, "failed to compute the effective value of a stat: {e}");
+            return (character_stat.name.clone(), stored);
+        }
+    };
+    let value = if computed == stored { computed } else { format!("{computed} ({stored})") };
+    (character_stat.name.clone(), value)
+}
+
+/// Builds the sheet of the character named `name`, with its approval status and place.
+async fn _show(ctx: &Context<'_>, name: String) -> Result<CreateEmbed, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let character = resolve_character(server.universe_id, ctx.author().id.get(), Some(&name)).await?;
+
+    let universe = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let Ok(cursor) = universe.get_stats().await else { return Err("create_character__database_error".into()) };
+    let Ok(stats) = cursor.try_collect::<Vec<Stat>>().await else { return Err("create_character__database_error".into()) };
+    let place = match character.current_place_id {
+        Some(place_id) => check_existing_place(server.universe_id, place_id).await.ok().flatten(),
+        None => None,
+    };
+    let place_modifiers = place.as_ref().map(|place| place.modifiers.as_slice()).unwrap_or_default();
+
+    let character_stats = stat_values(&character);
+    let rows: Vec<(String, String)> = character.stats.iter()
+        .map(|character_stat| computed_row(character_stat, stats.iter().find(|stat| stat.name == character_stat.name), &character_stats, place_modifiers))
+        .collect();
+    let place_name = match (&place, character.current_place_id) {
+        (Some(place), _) => place.name.clone(),
+        (None, Some(place_id)) => format!("<#{place_id}>"),
+        (None, None) => tr!(*ctx, "character_list__no_place", @message),
+    };
+
+    let locale = ctx.locale().unwrap_or("en-US");
+    Ok(sheet_embed_with_stats(locale, &character, &rows)
+        .field(tr!(*ctx, "character_sheet__fields", @status), tr!(*ctx, "character_status", @(character.approval_status.label_key())), true)
+        .field(tr!(*ctx, "character_sheet__fields", @place), place_name, true))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::modifiers::ModifierType;
+
+    #[test]
+    fn test_computed_row() {
+        let stat = Stat::named("speed", StatValue::F64(4.0));
+        let character_stat = CharacterStat { name: "speed".to_string(), value: StatValue::F64(5.0), modifiers: vec![] };
+        let mud = Modifier::on(stat._id, ModifierType::Multiplier, StatValue::F64(0.5));
+
+        let character_stats = HashMap::from([("speed".to_string(), StatValue::F64(5.0))]);
+
+        assert_eq!(computed_row(&character_stat, Some(&stat), &character_stats, &[mud]), ("speed".to_string(), "2.5 (5)".to_string()));
+        assert_eq!(computed_row(&character_stat, Some(&stat), &character_stats, &[]), ("speed".to_string(), "5".to_string()));
+        assert_eq!(computed_row(&character_stat, None, &character_stats, &[]), ("speed".to_string(), "5".to_string()));
+
+        let broken = Stat { formula: Some("speed /".to_string()), ..stat };
+        assert_eq!(computed_row(&character_stat, Some(&broken), &character_stats, &[]), ("speed".to_string(), "5".to_string()));
+    }
+}
@@ -0,0 +1,33 @@
+pub mod set_sub_command;
+
+use futures::TryStreamExt;
+use crate::characters::stat::set_sub_command::set;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::Stat;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+
+/// Groups the `/character stat` subcommands.
+///
+/// ### Subcommands:
+/// - **set**: Change the value of a stat of a character.
+#[poise::command(slash_command, subcommands("set"), subcommand_required, rename = "character_stat")]
+pub async fn stat(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Suggests the stats of the current universe whose name contains `partial`.
+pub async fn autocomplete_stat(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let Some(guild_id) = ctx.guild_id() else { return vec![] };
+    let Ok(Some(server)) = get_server_by_id(guild_id.get()).await else { return vec![] };
+    let Ok(Some(universe)) = get_universe_by_id(server.universe_id).await else { return vec![] };
+    let Ok(cursor) = universe.get_stats().await else { return vec![] };
+    let Ok(stats) = cursor.try_collect::<Vec<Stat>>().await else { return vec![] };
+
+    let partial = partial.to_lowercase();
+    stats.into_iter()
+        .map(|stat| stat.name)
+        .filter(|name| name.to_lowercase().contains(&partial))
+        .take(25)
+        .collect()
+}
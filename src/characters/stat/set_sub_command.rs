@@ -0,0 +1,120 @@
+use fluent::FluentArgs;
+use crate::characters::{is_moderator, resolve_character};
+use crate::characters::sheet::refresh_sheet;
+use crate::characters::stat::autocomplete_stat;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::{get_stat_by_name, parse_stat_value, Stat, StatValue};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::info_place_sub_command::format_stat_value;
+use crate::utility::reply::reply_with_args;
+
+/// Changes the value of a stat of a character, the one you are playing by default.
+///
+/// Moderators can change the stats of any character, players only those of their own.
+#[poise::command(slash_command, guild_only, rename = "character_stat_set")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[description = "character_stat_set.stat"]
+    #[autocomplete = "autocomplete_stat"]
+    stat: String,
+    #[description = "character_stat_set.value"]
+    value: String,
+    #[description = "character_stat_set.character"]
+    character: Option<String>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _set(&ctx, character, stat.trim().to_string(), value.trim().to_string(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// The bounds of `stat`, for error messages. A missing bound is shown as an infinity.
+fn bounds(stat: &Stat) -> (String, String) {
+    let min = stat.min.as_ref().map(format_stat_value).unwrap_or_else(|| "-∞".to_string());
+    let max = stat.max.as_ref().map(format_stat_value).unwrap_or_else(|| "∞".to_string());
+    (min, max)
+}
+
+/// Checks that `value_str` is a value of the type of `stat` within its bounds, and returns it.
+pub fn validate_stat_value(stat: &Stat, value_str: &str) -> Result<StatValue, &'static str> {
+    let Some(value) = parse_stat_value(value_str, &stat.base_value) else { return Err("character_stat__invalid_value") };
+    let candidate = Stat { base_value: value.clone(), ..stat.clone() };
+    if !candidate.is_within_bounds() {
+        return Err("character_stat__out_of_bounds");
+    }
+    Ok(value)
+}
+
+/// Sets the stat named `stat_name` of the character named `character_name`, or of the character
+/// the author is playing, to `value_str`, then updates its sheet. `args` receives the character, the stat,
+/// and its previous and new values.
+///
+/// # Errors
+/// - `"character__not_found"` / `"character__no_active"`: the character does not exist.
+/// - `"create_character__not_owner"`: a player tried to change a character of someone else.
+/// - `"character_stat__stat_not_found"`: the universe has no stat with this name.
+/// - `"character_stat__invalid_value"`: the value does not have the type of the stat.
+/// - `"character_stat__out_of_bounds"`: the value is below the minimum or above the maximum of the stat.
+/// - `"create_character__database_error"`: the character or the stat could not be read or saved.
+pub async fn _set(ctx: &Context<'_>, character_name: Option<String>, stat_name: String, value_str: String, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    args.set("stat", stat_name.clone());
+    args.set("value", value_str.clone());
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if let Some(name) = &character_name {
+        args.set("name", name.clone());
+    }
+    let mut character = resolve_character(server.universe_id, ctx.author().id.get(), character_name.as_deref()).await?;
+    args.set("character", character.name.clone());
+    if character.user_id != ctx.author().id.get() && !is_moderator(ctx, &server).await {
+        return Err("create_character__not_owner".into());
+    }
+
+    let stat = match get_stat_by_name(server.universe_id, &stat_name).await {
+        Ok(Some(stat)) => stat,
+        Ok(None) => return Err("character_stat__stat_not_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let (min, max) = bounds(&stat);
+    args.set("min", min);
+    args.set("max", max);
+    let value = validate_stat_value(&stat, &value_str)?;
+
+    let old_value = character.stats.iter()
+        .find(|character_stat| character_stat.name == stat.name)
+        .map(|character_stat| format_stat_value(&character_stat.value))
+        .unwrap_or_else(|| format_stat_value(&stat.base_value));
+    args.set("old_value", old_value);
+    args.set("value", format_stat_value(&value));
+    if character.set_stat_value(&stat.name, value).await.is_err() {
+        return Err("create_character__database_error".into());
+    }
+
+    let locale = ctx.locale().unwrap_or("en-US");
+    match refresh_sheet(ctx.http(), locale, &character).await {
+        Ok(()) => Ok("character_stat__set"),
+        Err(_) => Ok("character_stat__set_sheet_not_updated"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_stat_value() {
+        let stat = Stat { min: Some(StatValue::I64(0)), max: Some(StatValue::I64(20)), ..Stat::named("strength", StatValue::I64(10)) };
+
+        assert_eq!(validate_stat_value(&stat, "15"), Ok(StatValue::I64(15)));
+        assert_eq!(validate_stat_value(&stat, "20"), Ok(StatValue::I64(20)));
+        assert_eq!(validate_stat_value(&stat, "21"), Err("character_stat__out_of_bounds"));
+        assert_eq!(validate_stat_value(&stat, "-1"), Err("character_stat__out_of_bounds"));
+        assert_eq!(validate_stat_value(&stat, "strong"), Err("character_stat__invalid_value"));
+        assert_eq!(bounds(&Stat { min: None, ..stat }), ("-∞".to_string(), "20".to_string()));
+    }
+}
@@ -0,0 +1,55 @@
+use fluent::FluentArgs;
+use crate::characters::resolve_character;
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::utility::reply::reply_with_args;
+
+/// Chooses which of your characters you are playing.
+#[poise::command(slash_command, guild_only, rename = "character_switch")]
+pub async fn switch(
+    ctx: Context<'_>,
+    #[description = "character_switch.name"]
+    name: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _switch(&ctx, name.trim().to_string(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Makes the author's character named `name` the one they play. `args` receives its name.
+///
+/// # Errors
+/// - `"character__not_found"`: no character has this name.
+/// - `"create_character__not_owner"`: the character belongs to someone else.
+/// - `"character_switch__travelling"`: the author is on a road, the journey belongs to the current character.
+/// - `"create_character__database_error"`: a query failed.
+pub async fn _switch(ctx: &Context<'_>, name: String, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let user_id = ctx.author().id.get();
+    args.set("name", name.clone());
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let mut character = resolve_character(server.universe_id, user_id, Some(&name)).await?;
+    args.set("name", character.name.clone());
+    if character.user_id != user_id {
+        return Err("create_character__not_owner".into());
+    }
+    if character.active {
+        return Ok("character_switch__already_active");
+    }
+
+    match server.clone().get_player_move(user_id).await {
+        Ok(Some(player_move)) if player_move.is_in_move => return Err("character_switch__travelling".into()),
+        Ok(_) => {}
+        Err(_) => return Err("create_character__database_error".into()),
+    }
+    if character.activate().await.is_err() {
+        return Err("create_character__database_error".into());
+    }
+    Ok("character_switch__success")
+}
@@ -0,0 +1,91 @@
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, CreateMessage, User};
+use crate::characters::is_moderator;
+use crate::database::characters::Character;
+use crate::database::server::get_server_by_id;
+use crate::database::travel::PlayerMove;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::{autocomplete_place, resolve_place};
+use crate::tr;
+use crate::travel::logic::teleport as teleport_character;
+use crate::utility::reply::reply_with_args;
+
+/// Moves the character of a player to a place at once, without travelling.
+#[poise::command(slash_command, guild_only, rename = "character_teleport")]
+pub async fn teleport(
+    ctx: Context<'_>,
+    #[description = "character_teleport.user"]
+    user: User,
+    #[description = "character_teleport.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _teleport(&ctx, &user, place.trim(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Moves the approved character `user` plays to the place given by `place_input`, dropping their
+/// journey, then reports it in the moderation channel. `args` receives the user, the character
+/// and the place.
+///
+/// # Errors
+/// - `"create_character__no_universe_found"`: the server is not part of a universe.
+/// - `"create_character__no_permission"`: the author is not a moderator.
+/// - `"character_teleport__no_character"`: `user` plays no approved character.
+/// - `"travel__place_not_found"`: the place does not exist.
+/// - `"create_character__database_error"`: a query failed.
+async fn _teleport(ctx: &Context<'_>, user: &User, place_input: &str, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("user", format!("<@{}>", user.id));
+    args.set("destination", place_input.to_string());
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if !is_moderator(ctx, &server).await {
+        return Err("create_character__no_permission".into());
+    }
+    let mut character = match Character::get_active_character(server.universe_id, user.id.get()).await {
+        Ok(Some(character)) if character.check_playable().is_ok() => character,
+        Ok(_) => return Err("character_teleport__no_character".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    args.set("name", character.name.clone());
+    let destination = match resolve_place(server.universe_id, place_input).await {
+        Ok(Some(place)) => place,
+        Ok(None) => return Err("travel__place_not_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    args.set("destination", destination.name.clone());
+
+    let player_move = match server.clone().get_player_move(user.id.get()).await {
+        Ok(Some(player_move)) => player_move,
+        // Characters created before journeys were recorded have none yet
+        Ok(None) => PlayerMove {
+            universe_id: server.universe_id,
+            server_id: server.server_id,
+            user_id: user.id.get(),
+            actual_space_id: character.current_place_id.unwrap_or_default(),
+            ..Default::default()
+        },
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if teleport_character(ctx.serenity_context().http.clone(), player_move, &mut character, &destination).await.is_err() {
+        return Err("create_character__database_error".into());
+    }
+
+    if let Some(channel) = server.moderation_channel_id {
+        let notice = tr!(*ctx, "character_teleport__log",
+            moderator: format!("<@{}>", ctx.author().id),
+            user: format!("<@{}>", user.id),
+            name: character.name.clone(),
+            destination: destination.name.clone());
+        if let Err(e) = ChannelId::new(channel.id).send_message(ctx, CreateMessage::new().content(notice)).await {
+            tracing::warn!(guild_id = server.server_id, "failed to log a teleportation: {e}");
+        }
+    }
+    Ok("character_teleport__success")
+}
@@ -0,0 +1,78 @@
+use fluent::FluentArgs;
+use serenity::all::User;
+use crate::characters::is_moderator;
+use crate::database::places::check_existing_place;
+use crate::database::server::get_server_by_id;
+use crate::database::travel::SpaceType;
+use crate::discord::poise_structs::{Context, Error};
+use crate::utility::reply::reply_with_args;
+
+/// Shows the character a player is playing and where it is.
+#[poise::command(slash_command, guild_only, rename = "whereis")]
+pub async fn whereis(
+    ctx: Context<'_>,
+    #[description = "whereis.user"]
+    user: Option<User>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let user = user.unwrap_or_else(|| ctx.author().clone());
+    let mut args = FluentArgs::new();
+    let result = _whereis(&ctx, &user, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Tells which character `user` plays and the place it is in, or the road it travels on. Only
+/// moderators can look for someone else. `args` receives the user, the character and its
+/// whereabouts.
+///
+/// # Errors
+/// - `"create_character__no_universe_found"`: the server is not part of a universe.
+/// - `"create_character__no_permission"`: the author looks for someone else without being a moderator.
+/// - `"whereis__no_character"`: `user` plays no character.
+/// - `"create_character__database_error"`: a query failed.
+async fn _whereis(ctx: &Context<'_>, user: &User, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("user", format!("<@{}>", user.id));
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if user.id != ctx.author().id && !is_moderator(ctx, &server).await {
+        return Err("create_character__no_permission".into());
+    }
+    let character = match server.get_active_character(user.id.get()).await {
+        Ok(Some(character)) => character,
+        Ok(None) => return Err("whereis__no_character".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    args.set("name", character.name.clone());
+
+    let player_move = match server.clone().get_player_move(user.id.get()).await {
+        Ok(player_move) => player_move,
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    if let Some(player_move) = player_move
+        && player_move.actual_space_type == SpaceType::Road
+        && let Some(road_id) = player_move.road_id {
+        args.set("road", format!("<#{road_id}>"));
+        args.set("destination", place_name(server.universe_id, player_move.destination_id).await);
+        return Ok("whereis__travelling");
+    }
+    match character.current_place_id {
+        Some(place_id) => {
+            args.set("place", place_name(server.universe_id, Some(place_id)).await);
+            Ok("whereis__in_place")
+        }
+        None => Ok("whereis__nowhere"),
+    }
+}
+
+/// Name of the place `place_id`, or a mention of its category when it cannot be read.
+async fn place_name(universe_id: mongodb::bson::oid::ObjectId, place_id: Option<u64>) -> String {
+    let Some(place_id) = place_id else { return "-".to_string() };
+    match check_existing_place(universe_id, place_id).await {
+        Ok(Some(place)) => place.name,
+        _ => format!("<#{place_id}>"),
+    }
+}
@@ -1,28 +1,137 @@
+use futures::TryStreamExt;
 use mongodb::bson::doc;
 use serde_with::DisplayFromStr;
 use mongodb::bson::oid::ObjectId;
-use mongodb::results::InsertOneResult;
+use mongodb::options::{Collation, CollationStrength};
+use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use crate::database::db_client::{get_db_client};
 use crate::database::db_namespace::{CHARACTERS_COLLECTION_NAME, TRAVELS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
-use crate::database::stats::Stat;
+use crate::database::modifiers::Modifier;
+use crate::database::stats::{Stat, StatValue};
 use crate::database::travel::PlayerMove;
 
+/// Value of one of the universe's stats for a character, with the modifiers only this character has.
+///
+/// Sheets accepted before this type existed stored whole `Stat`s, hence the `base_value` alias.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharacterStat {
+    pub name: String,
+    #[serde(alias = "base_value")]
+    pub value: StatValue,
+    #[serde(default)]
+    pub modifiers: Vec<Modifier>,
+}
+
+impl From<&Stat> for CharacterStat {
+    /// Seeds the character's value of `stat` with the stat's `base_value`.
+    fn from(stat: &Stat) -> Self {
+        CharacterStat {
+            name: stat.name.clone(),
+            value: stat.base_value.clone(),
+            modifiers: vec![],
+        }
+    }
+}
+
+/// Decision of the moderators on a character. Characters created before approvals existed were
+/// all accepted through their sheet, hence the `Approved` default.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum ApprovalStatus {
+    Pending,
+    #[default]
+    Approved,
+    Rejected,
+}
+
+impl ApprovalStatus {
+    /// Attribute of the `character_status` message naming this status.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ApprovalStatus::Pending => "pending",
+            ApprovalStatus::Approved => "approved",
+            ApprovalStatus::Rejected => "rejected",
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Character {
     #[serde(rename = "_id")]
     pub _id: ObjectId,
+    pub universe_id: ObjectId,
     #[serde_as(as = "DisplayFromStr")]
     pub user_id: u64,
-    pub universe_id: ObjectId,
+    /// Server the character was created on.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub server_id: u64,
     pub name: String,
-    pub stats: Vec<Stat>,
+    #[serde(default)]
+    pub description: String,
+    /// Category of the place the character is in, if they have entered one.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub current_place_id: Option<u64>,
+    pub stats: Vec<CharacterStat>,
+    /// Unix timestamp of the creation, in seconds.
+    #[serde(default)]
+    pub created_at: u64,
+    /// Channel and message of the posted character sheet, if one was posted.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub sheet_channel_id: Option<u64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub sheet_message_id: Option<u64>,
+    #[serde(default)]
+    pub approval_status: ApprovalStatus,
+    /// Whether this is the character its player is playing, see [`Character::get_active_character`].
+    #[serde(default)]
+    pub active: bool,
+}
+
+/// Compares character names regardless of case.
+fn name_collation() -> Collation {
+    Collation::builder().locale("en").strength(CollationStrength::Secondary).build()
 }
 
 impl Character {
-    pub async fn update(self) -> mongodb::error::Result<InsertOneResult> {
+    /// A new character of `user_id`, whose stats are seeded with the base values of the universe's
+    /// `stats`. It waits for the approval of a moderator.
+    pub fn new(universe_id: ObjectId, server_id: u64, user_id: u64, name: String, stats: &[Stat]) -> Self {
+        Character {
+            _id: ObjectId::new(),
+            universe_id,
+            user_id,
+            server_id,
+            name,
+            description: String::new(),
+            current_place_id: None,
+            stats: stats.iter().map(CharacterStat::from).collect(),
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_secs(),
+            sheet_channel_id: None,
+            sheet_message_id: None,
+            approval_status: ApprovalStatus::Pending,
+            active: false,
+        }
+    }
+
+    /// Checks that the moderators approved the character, so that it can take part in the game.
+    pub fn check_playable(&self) -> Result<(), &'static str> {
+        match self.approval_status {
+            ApprovalStatus::Approved => Ok(()),
+            ApprovalStatus::Pending => Err("character__pending_approval"),
+            ApprovalStatus::Rejected => Err("character__rejected"),
+        }
+    }
+
+    pub async fn insert(&self) -> mongodb::error::Result<InsertOneResult> {
         let db_client = get_db_client().await;
         db_client
             .database(VERSEENGINE_DB_NAME)
@@ -31,6 +140,122 @@ impl Character {
             .await
     }
 
+    /// Saves where the sheet of the character was posted.
+    pub async fn set_sheet(&mut self, channel_id: u64, message_id: u64) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let result = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_one(
+                doc!{"_id": self._id},
+                doc!{"$set": {"sheet_channel_id": channel_id.to_string(), "sheet_message_id": message_id.to_string()}},
+            )
+            .await?;
+        self.sheet_channel_id = Some(channel_id);
+        self.sheet_message_id = Some(message_id);
+        Ok(result)
+    }
+
+    pub async fn set_approval_status(&mut self, status: ApprovalStatus) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let status_bson = mongodb::bson::to_bson(&status)?;
+        let result = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_one(doc!{"_id": self._id}, doc!{"$set": {"approval_status": status_bson}})
+            .await?;
+        self.approval_status = status;
+        Ok(result)
+    }
+
+    /// Replaces every stat of the character with `stats`.
+    pub async fn set_stats(&mut self, stats: Vec<CharacterStat>) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let stats_bson = mongodb::bson::to_bson(&stats)?;
+        let result = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_one(doc!{"_id": self._id}, doc!{"$set": {"stats": stats_bson}})
+            .await?;
+        self.stats = stats;
+        Ok(result)
+    }
+
+    /// Sets the value of the stat `name` of the character, adding the stat if the character
+    /// was created before it existed.
+    pub async fn set_stat_value(&mut self, name: &str, value: StatValue) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let collection = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME);
+        let value_bson = mongodb::bson::to_bson(&value)?;
+        let mut result = collection
+            .update_one(doc!{"_id": self._id, "stats.name": name}, doc!{"$set": {"stats.$.value": value_bson}})
+            .await?;
+        let stat = CharacterStat { name: name.to_string(), value: value.clone(), modifiers: vec![] };
+        if result.matched_count == 0 {
+            result = collection
+                .update_one(doc!{"_id": self._id}, doc!{"$push": {"stats": mongodb::bson::to_bson(&stat)?}})
+                .await?;
+        }
+        match self.stats.iter_mut().find(|stat| stat.name == name) {
+            Some(stat) => stat.value = value,
+            None => self.stats.push(stat),
+        }
+        Ok(result)
+    }
+
+    /// Renames the stat `old_name` to `new_name` in the stats of every character of the universe.
+    pub async fn rename_stat(universe_id: ObjectId, old_name: &str, new_name: &str) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_many(doc!{"universe_id": universe_id, "stats.name": old_name}, doc!{"$set": {"stats.$[stat].name": new_name}})
+            .array_filters(vec![doc!{"stat.name": old_name}])
+            .await
+    }
+
+    /// Saves the category of the place the character is now in.
+    pub async fn set_current_place(&mut self, place_id: Option<u64>) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let result = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_one(doc!{"_id": self._id}, doc!{"$set": {"current_place_id": place_id.map(|id| id.to_string())}})
+            .await?;
+        self.current_place_id = place_id;
+        Ok(result)
+    }
+
+    pub async fn delete(&self) -> mongodb::error::Result<DeleteResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .delete_one(doc!{"_id": self._id})
+            .await
+    }
+
+    pub async fn get_character_by_id(id: ObjectId) -> mongodb::error::Result<Option<Character>> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find_one(doc!{"_id": id})
+            .await
+    }
+
+    /// The character whose sheet is the message `message_id`.
+    pub async fn get_character_by_sheet(message_id: u64) -> mongodb::error::Result<Option<Character>> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find_one(doc!{"sheet_message_id": message_id.to_string()})
+            .await
+    }
+
     pub async fn get_player_move(self) -> mongodb::error::Result<Option<PlayerMove>> {
         let filter = doc!{"player_id": self._id, "universe_id": self.universe_id};
         let db_client = get_db_client().await;
@@ -50,4 +275,179 @@ impl Character {
             .find_one(filter)
             .await
     }
-}
\ No newline at end of file
+
+    /// The character `user_id` is playing in the universe. Players who only ever had one character,
+    /// from before characters could be switched, play it without having activated it.
+    pub async fn get_active_character(universe_id: ObjectId, user_id: u64) -> mongodb::error::Result<Option<Character>> {
+        let db_client = get_db_client().await;
+        let filter = doc!{"user_id": user_id.to_string(), "universe_id": universe_id, "active": true};
+        let active = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find_one(filter)
+            .await?;
+        if active.is_some() {
+            return Ok(active);
+        }
+        let mut characters = Character::get_characters_by_user(universe_id, user_id).await?;
+        Ok(match characters.len() {
+            1 => characters.pop(),
+            _ => None,
+        })
+    }
+
+    /// Makes this character the one its player is playing, and no longer any other, in a single update.
+    pub async fn activate(&mut self) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let filter = doc!{"user_id": self.user_id.to_string(), "universe_id": self.universe_id};
+        let result = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_many(filter, vec![doc!{"$set": {"active": {"$eq": ["$_id", self._id]}}}])
+            .await?;
+        self.active = true;
+        Ok(result)
+    }
+
+    /// Makes `user_id` play none of their characters in the universe anymore.
+    pub async fn deactivate_all(universe_id: ObjectId, user_id: u64) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        let filter = doc!{"user_id": user_id.to_string(), "universe_id": universe_id, "active": true};
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .update_many(filter, doc!{"$set": {"active": false}})
+            .await
+    }
+
+    /// Every character of the universe, sorted by player, then by name regardless of case.
+    pub async fn get_characters_by_universe(universe_id: ObjectId) -> mongodb::error::Result<Vec<Character>> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find(doc!{"universe_id": universe_id})
+            .sort(doc!{"user_id": 1, "name": 1})
+            .collation(name_collation())
+            .await?
+            .try_collect()
+            .await
+    }
+
+    /// Characters of `user_id` in the universe, sorted by name regardless of case.
+    pub async fn get_characters_by_user(universe_id: ObjectId, user_id: u64) -> mongodb::error::Result<Vec<Character>> {
+        let db_client = get_db_client().await;
+        let filter = doc!{"user_id": user_id.to_string(), "universe_id": universe_id};
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find(filter)
+            .sort(doc!{"name": 1})
+            .collation(name_collation())
+            .await?
+            .try_collect()
+            .await
+    }
+
+    /// Characters of the universe standing in the place `place_id`, sorted by player, then by
+    /// name regardless of case.
+    pub async fn get_characters_in_place(universe_id: ObjectId, place_id: u64) -> mongodb::error::Result<Vec<Character>> {
+        let db_client = get_db_client().await;
+        let filter = doc!{"universe_id": universe_id, "current_place_id": place_id.to_string()};
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find(filter)
+            .sort(doc!{"user_id": 1, "name": 1})
+            .collation(name_collation())
+            .await?
+            .try_collect()
+            .await
+    }
+
+    /// Finds the character of the universe named `name`, ignoring case.
+    pub async fn get_character_by_name(universe_id: ObjectId, name: &str) -> mongodb::error::Result<Option<Character>> {
+        let db_client = get_db_client().await;
+        let filter = doc!{"name": name, "universe_id": universe_id};
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .find_one(filter)
+            .collation(name_collation())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mongodb::bson::doc;
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::characters::{ApprovalStatus, Character};
+    use crate::database::db_client::get_db_client;
+    use crate::database::db_namespace::{CHARACTERS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
+    use crate::database::stats::{Stat, StatValue};
+
+    #[test]
+    fn test_new_character_seeds_stats() {
+        let stats = [Stat::named("speed", StatValue::F64(4.5)), Stat::named("strength", StatValue::I64(10))];
+        let character = Character::new(ObjectId::new(), 1, 2, "Alys".to_string(), &stats);
+
+        let seeded: Vec<(&str, &StatValue)> = character.stats.iter().map(|s| (s.name.as_str(), &s.value)).collect();
+        assert_eq!(seeded, vec![("speed", &StatValue::F64(4.5)), ("strength", &StatValue::I64(10))]);
+        assert!(character.current_place_id.is_none());
+        assert!(character.created_at > 0);
+        assert_eq!(character.check_playable(), Err("character__pending_approval"));
+    }
+
+    /// A character is found by its owner and by its name, in any case.
+    #[tokio::test]
+    async fn test_insert_and_get_character() {
+        let universe_id = ObjectId::new();
+        let character = Character::new(universe_id, 1, 42, "Alys Vane".to_string(), &[Stat::named("speed", StatValue::F64(4.5))]);
+        character.insert().await.unwrap();
+
+        let by_user = Character::get_character_by_user_id(universe_id, 42).await.unwrap().unwrap();
+        assert_eq!(by_user._id, character._id);
+        assert_eq!(by_user.stats[0].value, StatValue::F64(4.5));
+        let by_name = Character::get_character_by_name(universe_id, "alys VANE").await.unwrap().unwrap();
+        assert_eq!(by_name._id, character._id);
+        assert!(Character::get_character_by_name(universe_id, "Alys").await.unwrap().is_none());
+        assert!(Character::get_character_by_user_id(ObjectId::new(), 42).await.unwrap().is_none());
+
+        Character::new(universe_id, 1, 42, "bram".to_string(), &[]).insert().await.unwrap();
+        let names: Vec<String> = Character::get_characters_by_user(universe_id, 42).await.unwrap().into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["Alys Vane".to_string(), "bram".to_string()]);
+        // Two characters and none activated: none is played
+        assert!(Character::get_active_character(universe_id, 42).await.unwrap().is_none());
+        let mut bram = Character::get_character_by_name(universe_id, "Bram").await.unwrap().unwrap();
+        bram.activate().await.unwrap();
+        assert_eq!(Character::get_active_character(universe_id, 42).await.unwrap().map(|c| c.name), Some("bram".to_string()));
+        let mut alys = Character::get_character_by_name(universe_id, "alys vane").await.unwrap().unwrap();
+        alys.activate().await.unwrap();
+        assert_eq!(Character::get_active_character(universe_id, 42).await.unwrap().map(|c| c.name), Some("Alys Vane".to_string()));
+        assert!(!Character::get_character_by_name(universe_id, "bram").await.unwrap().unwrap().active);
+
+        let mut character = character;
+        character.set_approval_status(ApprovalStatus::Approved).await.unwrap();
+        let by_id = Character::get_character_by_id(character._id).await.unwrap().unwrap();
+        assert_eq!(by_id.approval_status, ApprovalStatus::Approved);
+
+        character.set_stat_value("speed", StatValue::F64(6.0)).await.unwrap();
+        character.set_stat_value("luck", StatValue::I64(3)).await.unwrap();
+        let stored = Character::get_character_by_id(character._id).await.unwrap().unwrap();
+        let values: Vec<(&str, &StatValue)> = stored.stats.iter().map(|s| (s.name.as_str(), &s.value)).collect();
+        assert_eq!(values, vec![("speed", &StatValue::F64(6.0)), ("luck", &StatValue::I64(3))]);
+
+        character.set_sheet(7, 8).await.unwrap();
+        assert_eq!(Character::get_character_by_sheet(8).await.unwrap().map(|c| c._id), Some(character._id));
+
+        assert_eq!(alys.delete().await.unwrap().deleted_count, 1);
+        assert!(Character::get_character_by_id(alys._id).await.unwrap().is_none());
+
+        let _ = get_db_client().await
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .delete_many(doc!{"universe_id": universe_id})
+            .await;
+    }
+}
@@ -1,13 +1,19 @@
 #![allow(unused_doc_comments)]
 use std::env;
+use std::time::{Duration, Instant};
 use mongodb::bson::doc;
 use mongodb::IndexModel;
 use mongodb::options::IndexOptions;
 use tokio::sync::OnceCell;
 use urlencoding::encode;
-use crate::database::db_namespace::{VERSEENGINE_DB_NAME, SERVERS_COLLECTION_NAME, ITEM_COLLECTION_NAME};
+use crate::database::characters::Character;
+use crate::database::db_namespace::{VERSEENGINE_DB_NAME, SERVERS_COLLECTION_NAME, CHARACTERS_COLLECTION_NAME, ITEM_COLLECTION_NAME, PLACES_COLLECTION_NAME, ROADS_COLLECTION_NAME, STATS_COLLECTION_NAME};
 use crate::database::items::Item;
+use crate::database::modifiers::ModifierHolder;
+use crate::database::places::{place_name_collation, Place};
+use crate::database::road::Road;
 use crate::database::server::Server;
+use crate::database::stats::Stat;
 
 /// Establishes an asynchronous connection to a MongoDB database.
 ///
@@ -61,19 +67,19 @@ pub async fn connect_db() -> Result<mongodb::Client, mongodb::error::Error>{
     let port = env::var("MONGODB_PORT").unwrap_or_else(|_| "27017".to_string());
     let auth_source = env::var("MONGODB_AUTH_SOURCE").unwrap_or_else(|_| "admin".to_string());
     let url = format!("mongodb://{user}:{password}@{host}:{port}/?authSource={auth_source}");
-    println!("Connecting to MongoDB at {host}:{port} with authSource={auth_source}...");
+    tracing::info!("connecting to MongoDB at {host}:{port} with authSource={auth_source}");
     match mongodb::Client::with_uri_str(&url).await {
         Ok(client) => {
             // Vérifier la connexion en listant les collections pour être sûr qu'on y accède vraiment
             if let Err(e) = client.database(VERSEENGINE_DB_NAME).list_collection_names().await {
-                eprintln!("Failed to ping MongoDB: {:?}", e);
+                tracing::error!("failed to ping MongoDB: {e}");
                 return Err(e);
             }
-            println!("Connected to MongoDB successfully!");
+            tracing::info!("connected to MongoDB");
             Ok(client)
         }
         Err(e) => {
-            eprintln!("Failed to create MongoDB client: {:?}", e);
+            tracing::error!("failed to create the MongoDB client: {e}");
             Err(e)
         }
     }
@@ -147,6 +153,85 @@ pub async fn constraint(){
         .collection::<Item>(ITEM_COLLECTION_NAME)
         .create_index(index_model)
         .await;
+
+    // Place names are unique per universe, ignoring case
+    let index_keys = doc! {
+        "universe_id": 1,
+        "name": 1,
+    };
+    let index_options = IndexOptions::builder()
+        .unique(true)
+        .collation(place_name_collation())
+        .build();
+    let index_model = IndexModel::builder()
+        .keys(index_keys)
+        .options(index_options)
+        .build();
+    let _ = db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .create_index(index_model)
+        .await;
+
+    // Stat names are unique per universe
+    let index_keys = doc! {
+        "universe_id": 1,
+        "name": 1,
+    };
+    let index_options = IndexOptions::builder()
+        .unique(true)
+        .build();
+    let index_model = IndexModel::builder()
+        .keys(index_keys)
+        .options(index_options)
+        .build();
+    let _ = db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Stat>(STATS_COLLECTION_NAME)
+        .create_index(index_model)
+        .await;
+
+    // Supports the scan of expired modifiers
+    for holder in [ModifierHolder::Place, ModifierHolder::Road, ModifierHolder::Character] {
+        let index_model = IndexModel::builder()
+            .keys(doc! {holder.end_timestamp_path(): 1})
+            .build();
+        let _ = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<mongodb::bson::Document>(holder.collection_name())
+            .create_index(index_model)
+            .await;
+    }
+
+    // Supports the lookup of the road between two places, each branch of the `$or` of
+    // `get_road` matching one order of the places
+    let index_keys = doc! {
+        "universe_id": 1,
+        "place_one_id": 1,
+        "place_two_id": 1,
+    };
+    let index_model = IndexModel::builder()
+        .keys(index_keys)
+        .build();
+    let _ = db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .create_index(index_model)
+        .await;
+
+    // Supports the listing of the characters standing in a place
+    let index_keys = doc! {
+        "universe_id": 1,
+        "current_place_id": 1,
+    };
+    let index_model = IndexModel::builder()
+        .keys(index_keys)
+        .build();
+    let _ = db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Character>(CHARACTERS_COLLECTION_NAME)
+        .create_index(index_model)
+        .await;
 }
 
 pub async fn get_db_client() -> mongodb::Client {
@@ -155,9 +240,28 @@ pub async fn get_db_client() -> mongodb::Client {
 
 pub static DB_CLIENT: OnceCell<mongodb::Client> = OnceCell::const_new();
 
+/// Time a `ping` command takes to reach the database and come back. `None` when the client is not
+/// connected yet, when the command fails or when it takes longer than `timeout`.
+pub async fn ping_db(timeout: Duration) -> Option<Duration> {
+    let database = DB_CLIENT.get()?.database(VERSEENGINE_DB_NAME);
+    let started = Instant::now();
+    match tokio::time::timeout(timeout, database.run_command(doc! {"ping": 1})).await {
+        Ok(Ok(_)) => Some(started.elapsed()),
+        Ok(Err(e)) => {
+            tracing::warn!("failed to ping MongoDB: {e}");
+            None
+        }
+        Err(_) => {
+            tracing::warn!(timeout_ms = timeout.as_millis() as u64, "MongoDB did not answer the ping in time");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::database::db_client::{DB_CLIENT, connect_db};
+    use std::time::Duration;
+    use crate::database::db_client::{DB_CLIENT, connect_db, get_db_client, ping_db};
     use crate::database::db_namespace::VERSEENGINE_DB_NAME;
 
     /// Ensures that the database connection initializes correctly.
@@ -169,4 +273,11 @@ mod test {
             Err(_) => {assert!(false)}
         };
     }
-}
\ No newline at end of file
+
+    /// A connected database answers the ping.
+    #[tokio::test]
+    async fn test_ping_db() {
+        get_db_client().await;
+        assert!(ping_db(Duration::from_secs(5)).await.is_some());
+    }
+}
@@ -8,4 +8,5 @@ pub mod stats;
 pub mod road;
 pub mod characters;
 pub mod travel;
-pub mod items;
\ No newline at end of file
+pub mod items;
+pub mod user_limits;
\ No newline at end of file
@@ -1,34 +1,185 @@
+use futures::TryStreamExt;
+use mongodb::bson::doc;
 use mongodb::bson::oid::ObjectId;
+use mongodb::results::UpdateResult;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use crate::database::db_client::get_db_client;
+use crate::database::db_namespace::{CHARACTERS_COLLECTION_NAME, PLACES_COLLECTION_NAME, ROADS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
 use crate::database::stats::StatValue;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(poise::ChoiceParameter, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum ModifierType{
+    #[name = "modifier_type_base"]
     Base,
+    #[name = "modifier_type_multiplier"]
     Multiplier,
+    #[name = "modifier_type_flats"]
     Flats
 }
 
+/// Change applied to a stat by a place, a road, an item, a stat or a character.
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Modifier{
+    /// Id of the modified stat.
     pub stat: ObjectId,
     pub value: StatValue,
     pub modifier_type: ModifierType,
+    /// Real timestamp, in seconds, at which the modifier ends; `None` for good. Unlike Discord
+    /// ids, it is stored as a number so that expired modifiers can be found with a range query.
     pub end_timestamp: Option<u64>,
-    pub source: ObjectId
+    /// Id of the place, road, item or character the modifier comes from.
+    pub source: ObjectId,
+    /// Modifiers apply from the lowest priority to the highest.
+    #[serde(default)]
+    pub priority: i32,
+    /// Rhai formula computing the new value from `value` and `modifier`, used instead of the
+    /// operation of `modifier_type`.
+    #[serde(default)]
+    pub formula: Option<String>
 }
 
 impl Modifier {
     pub fn is_active(&self) -> bool {
-        if let Some(end) = self.end_timestamp {
-            let now = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .expect("Time went backwards")
-                .as_secs();
-            return now < end;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+        !self.has_ended(now)
+    }
+
+    /// Whether the modifier has ended at the timestamp `now`, in seconds.
+    pub fn has_ended(&self, now: u64) -> bool {
+        self.end_timestamp.is_some_and(|end| end <= now)
+    }
+
+    /// Lasting modifier of `stat`, of priority 0 and without formula, for the tests to set the
+    /// fields they check with the struct update syntax.
+    #[cfg(test)]
+    pub fn on(stat: ObjectId, modifier_type: ModifierType, value: StatValue) -> Modifier {
+        Modifier {
+            stat,
+            value,
+            modifier_type,
+            end_timestamp: None,
+            source: ObjectId::new(),
+            priority: 0,
+            formula: None,
+        }
+    }
+}
+
+/// Kind of document holding modifiers, as scanned for expired modifiers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModifierHolder {
+    Place,
+    Road,
+    /// Modifiers of characters are kept per stat.
+    Character,
+}
+
+impl ModifierHolder {
+    pub fn collection_name(self) -> &'static str {
+        match self {
+            ModifierHolder::Place => PLACES_COLLECTION_NAME,
+            ModifierHolder::Road => ROADS_COLLECTION_NAME,
+            ModifierHolder::Character => CHARACTERS_COLLECTION_NAME,
+        }
+    }
+
+    /// Path of the modifier arrays in the documents.
+    fn modifiers_path(self) -> &'static str {
+        match self {
+            ModifierHolder::Place | ModifierHolder::Road => "modifiers",
+            ModifierHolder::Character => "stats.$[].modifiers",
+        }
+    }
+
+    /// Path of the end timestamps of the modifiers, indexed for the expiry scan.
+    pub fn end_timestamp_path(self) -> &'static str {
+        match self {
+            ModifierHolder::Place | ModifierHolder::Road => "modifiers.end_timestamp",
+            ModifierHolder::Character => "stats.modifiers.end_timestamp",
+        }
+    }
+}
+
+/// Documents of kind `holder` holding a modifier ended at `now`, at most `limit` of them.
+pub async fn find_expired_modifier_holders<T>(holder: ModifierHolder, now: u64, limit: i64) -> mongodb::error::Result<Vec<T>>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<T>(holder.collection_name())
+        .find(doc!{holder.end_timestamp_path(): {"$lte": now as i64}})
+        .limit(limit)
+        .await?
+        .try_collect()
+        .await
+}
+
+/// Removes the modifiers ended at `now` from the document `id` of kind `holder`.
+pub async fn pull_expired_modifiers(holder: ModifierHolder, id: ObjectId, now: u64) -> mongodb::error::Result<UpdateResult> {
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<mongodb::bson::Document>(holder.collection_name())
+        .update_one(doc!{"_id": id}, doc!{"$pull": {holder.modifiers_path(): {"end_timestamp": {"$lte": now as i64}}}})
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::{doc, from_document, to_document};
+    use crate::database::places::Place;
+
+    fn modifier() -> Modifier {
+        Modifier {
+            end_timestamp: Some(1_700_000_000),
+            priority: 2,
+            formula: Some("value * modifier".to_string()),
+            ..Modifier::on(ObjectId::new(), ModifierType::Multiplier, StatValue::F64(0.5))
         }
-        true
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_has_ended() {
+        let mut modifier = modifier();
+        assert!(!modifier.has_ended(1_699_999_999));
+        assert!(modifier.has_ended(1_700_000_000));
+        modifier.end_timestamp = None;
+        assert!(!modifier.has_ended(u64::MAX));
+    }
+
+    #[test]
+    fn test_modifier_bson_round_trip() {
+        let modifier = modifier();
+        let document = to_document(&modifier).unwrap();
+        assert_eq!(document.get_i64("end_timestamp"), Ok(1_700_000_000));
+        assert_eq!(from_document::<Modifier>(document).unwrap(), modifier);
+    }
+
+    /// Modifiers saved before priorities and formulas existed still load.
+    #[test]
+    fn test_legacy_modifier() {
+        let stat = ObjectId::new();
+        let source = ObjectId::new();
+        let document = doc! {"stat": stat, "value": {"I64": 2_i64}, "modifier_type": "Flats", "end_timestamp": null, "source": source};
+        let modifier = from_document::<Modifier>(document).unwrap();
+        assert_eq!(modifier.value, StatValue::I64(2));
+        assert_eq!(modifier.priority, 0);
+        assert_eq!(modifier.formula, None);
+    }
+
+    #[test]
+    fn test_place_with_modifiers_round_trip() {
+        let place = Place { modifiers: vec![modifier(), modifier()], ..Place::named("Swamp", 2) };
+        let loaded = from_document::<Place>(to_document(&place).unwrap()).unwrap();
+        assert_eq!(loaded.modifiers, place.modifiers);
+    }
+}
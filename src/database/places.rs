@@ -2,12 +2,13 @@ use futures::TryStreamExt;
 use serde_with::DisplayFromStr;
 use mongodb::bson::doc;
 use mongodb::bson::oid::ObjectId;
-use mongodb::results::InsertOneResult;
+use mongodb::options::{Collation, CollationStrength};
+use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use crate::database::db_client::{get_db_client};
 use crate::database::db_namespace::{PLACES_COLLECTION_NAME, ROADS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
-use crate::database::modifiers::Modifier;
+use crate::database::modifiers::{Modifier, ModifierType};
 use crate::database::road::Road;
 
 #[serde_as]
@@ -24,6 +25,18 @@ pub struct Place{
     pub role: u64,
     pub name: String,
     pub modifiers: Vec<Modifier>,
+    /// General text channel created inside the category with the place.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub text_channel_id: Option<u64>,
+    /// Voice channel created inside the category with the place.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub voice_channel_id: Option<u64>,
+    /// Wiki forum posts documenting the place, one per server of the universe with a wiki channel.
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default)]
+    pub wiki_post_ids: Vec<u64>,
 }
 
 impl Place{
@@ -54,6 +67,24 @@ impl Place{
             .await;
         cursor.expect("get_roads__collect_failed").try_collect().await
     }
+
+    /// Place `name` whose category and role both have the id `category_id`, without channels nor
+    /// modifiers, for the tests to set the fields they check with the struct update syntax.
+    #[cfg(test)]
+    pub fn named(name: &str, category_id: u64) -> Place {
+        Place {
+            _id: ObjectId::new(),
+            universe_id: ObjectId::new(),
+            server_id: 1,
+            category_id,
+            role: category_id,
+            name: name.to_string(),
+            modifiers: vec![],
+            text_channel_id: None,
+            voice_channel_id: None,
+            wiki_post_ids: vec![],
+        }
+    }
 }
 
 pub async fn get_places_by_universe_id(universe_id: ObjectId) -> mongodb::error::Result<mongodb::Cursor<Place>> {
@@ -67,6 +98,39 @@ pub async fn get_places_by_universe_id(universe_id: ObjectId) -> mongodb::error:
         .await
 }
 
+/// Returns every place of a universe, sorted by name.
+pub async fn get_places_by_universe_id_sorted(universe_id: ObjectId) -> mongodb::error::Result<Vec<Place>> {
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .find(doc!{"universe_id": universe_id})
+        .sort(doc!{"name": 1})
+        .await?
+        .try_collect()
+        .await
+}
+
+/// Collation comparing place names without case, shared by the name lookup and the unique
+/// `(universe_id, name)` index.
+pub fn place_name_collation() -> Collation {
+    Collation::builder()
+        .locale("en")
+        .strength(CollationStrength::Secondary)
+        .build()
+}
+
+/// Returns the place of the universe named `name`, ignoring case.
+pub async fn get_place_by_name(universe_id: ObjectId, name: &str) -> mongodb::error::Result<Option<Place>> {
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .find_one(doc!{"universe_id": universe_id, "name": name})
+        .collation(place_name_collation())
+        .await
+}
+
 pub async fn check_existing_place(universe_id: ObjectId, category_id: u64) -> mongodb::error::Result<Option<Place>> {
     let filter = doc!{"category_id": category_id.to_string(),
         "universe_id": universe_id,
@@ -97,4 +161,117 @@ pub async fn get_place_by_category_id(universe_id: ObjectId, category_id: u64) -
         .collection::<Place>(PLACES_COLLECTION_NAME)
         .find_one(filter)
         .await
-}
\ No newline at end of file
+}
+/// Renames the place stored for `category_id`.
+pub async fn set_place_name(universe_id: ObjectId, category_id: u64, name: &str) -> mongodb::error::Result<UpdateResult> {
+    let filter = doc!{"category_id": category_id.to_string(), "universe_id": universe_id,};
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .update_one(filter, doc!{"$set": {"name": name}})
+        .await
+}
+
+/// Appends `modifier` to the modifiers of the place stored for `category_id`.
+pub async fn push_place_modifier(universe_id: ObjectId, category_id: u64, modifier: &Modifier) -> mongodb::error::Result<UpdateResult> {
+    let filter = doc!{"category_id": category_id.to_string(), "universe_id": universe_id,};
+    let modifier = mongodb::bson::to_bson(modifier)?;
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .update_one(filter, doc!{"$push": {"modifiers": modifier}})
+        .await
+}
+
+/// Removes the modifiers of kind `modifier_type` on `stat` from the place stored for `category_id`.
+pub async fn pull_place_modifiers(universe_id: ObjectId, category_id: u64, stat: ObjectId, modifier_type: ModifierType) -> mongodb::error::Result<UpdateResult> {
+    let filter = doc!{"category_id": category_id.to_string(), "universe_id": universe_id,};
+    let modifier_type = mongodb::bson::to_bson(&modifier_type)?;
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .update_one(filter, doc!{"$pull": {"modifiers": {"stat": stat, "modifier_type": modifier_type}}})
+        .await
+}
+
+pub async fn delete_place_by_category_id(universe_id: ObjectId, category_id: u64) -> mongodb::error::Result<DeleteResult> {
+    let filter = doc!{"category_id": category_id.to_string(), "universe_id": universe_id,};
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .delete_one(filter)
+        .await
+}
+
+/// Removes the place whose category or role is `id`, returning it when there was one.
+pub async fn delete_place_by_discord_id(universe_id: ObjectId, id: u64) -> mongodb::error::Result<Option<Place>> {
+    let filter = doc! {
+        "$or": [
+            { "category_id": id.to_string(), "universe_id": universe_id },
+            { "role": id.to_string(), "universe_id": universe_id },
+        ]
+    };
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .find_one_and_delete(filter)
+        .await
+}
+
+pub async fn get_places_by_server_id(server_id: u64) -> mongodb::error::Result<Vec<Place>> {
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Place>(PLACES_COLLECTION_NAME)
+        .find(doc!{"server_id": server_id.to_string()})
+        .await?
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::db_client::constraint;
+    use crate::database::places::{delete_place_by_category_id, delete_place_by_discord_id, get_place_by_name, Place};
+
+    fn place(universe_id: ObjectId, category_id: u64, name: &str) -> Place {
+        Place { universe_id, ..Place::named(name, category_id) }
+    }
+
+    /// A second place with the same name, whatever its case, is found by the lookup done by
+    /// `_create_place` and rejected by the unique index.
+    #[tokio::test]
+    async fn test_duplicate_place_name() {
+        constraint().await;
+        let universe_id = ObjectId::new();
+        place(universe_id, 1, "Tavern").insert_place().await.unwrap();
+
+        let existing = get_place_by_name(universe_id, "tavern").await.unwrap();
+        assert_eq!(existing.map(|p| p.category_id), Some(1));
+        assert!(place(universe_id, 2, "TAVERN").insert_place().await.is_err());
+        // Another universe may use the same name
+        assert!(get_place_by_name(ObjectId::new(), "Tavern").await.unwrap().is_none());
+
+        let _ = delete_place_by_category_id(universe_id, 1).await;
+    }
+
+    /// A place is removed when either its category or its role is deleted.
+    #[tokio::test]
+    async fn test_delete_place_by_discord_id() {
+        let universe_id = ObjectId::new();
+        let mut by_role = place(universe_id, 3, "Harbor");
+        by_role.role = 4;
+        by_role.insert_place().await.unwrap();
+        place(universe_id, 5, "Market").insert_place().await.unwrap();
+
+        assert_eq!(delete_place_by_discord_id(universe_id, 4).await.unwrap().map(|p| p.category_id), Some(3));
+        assert_eq!(delete_place_by_discord_id(universe_id, 5).await.unwrap().map(|p| p.category_id), Some(5));
+        assert!(delete_place_by_discord_id(universe_id, 3).await.unwrap().is_none());
+    }
+}
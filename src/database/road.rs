@@ -1,8 +1,9 @@
+use futures::TryStreamExt;
 use mongodb::bson::doc;
 use serde_with::DisplayFromStr;
 use mongodb::bson::oid::ObjectId;
 use mongodb::Cursor;
-use mongodb::results::InsertOneResult;
+use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use crate::database::db_client::{get_db_client};
@@ -42,6 +43,51 @@ impl Road{
             .insert_one(self)
             .await
     }
+
+    /// Saves every field of the road over its stored document.
+    pub async fn update(&self) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Road>(ROADS_COLLECTION_NAME)
+            .replace_one(doc!{"_id": self._id}, self)
+            .await
+    }
+
+    /// Removes the road from the database.
+    pub async fn delete(&self) -> mongodb::error::Result<DeleteResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Road>(ROADS_COLLECTION_NAME)
+            .delete_one(doc!{"_id": self._id})
+            .await
+    }
+
+    /// Returns the place at the other end of the road from `place_id`.
+    pub fn other_end(&self, place_id: u64) -> u64 {
+        if self.place_one_id == place_id { self.place_two_id } else { self.place_one_id }
+    }
+
+    /// Road of length 1 between `place_one_id` and `place_two_id`, for the tests to set the
+    /// fields they check with the struct update syntax.
+    #[cfg(test)]
+    pub fn between(place_one_id: u64, place_two_id: u64) -> Road {
+        Road {
+            _id: ObjectId::new(),
+            universe_id: ObjectId::new(),
+            server_id: 1,
+            server_two_id: None,
+            road_name: String::new(),
+            role_id: 0,
+            channel_id: 0,
+            place_one_id,
+            place_two_id,
+            distance: 1,
+            secret: false,
+            modifiers: vec![],
+        }
+    }
 }
 
 pub async fn get_road_by_channel_id(universe_id: ObjectId, channel_id: u64) -> mongodb::error::Result<Option<Road>> {
@@ -54,6 +100,15 @@ pub async fn get_road_by_channel_id(universe_id: ObjectId, channel_id: u64) -> m
         .await
 }
 
+/// Returns every road of a universe, secret or not.
+pub async fn get_roads_by_universe_id(universe_id: ObjectId) -> mongodb::error::Result<Cursor<Road>> {
+    let db_client = get_db_client().await;
+    db_client.database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .find(doc! {"universe_id": universe_id})
+        .await
+}
+
 pub async fn get_road_by_source(universe_id: ObjectId, destination_id: u64) -> mongodb::error::Result<Cursor<Road>> {
     let db_client = get_db_client().await;
     let filter = doc! {
@@ -69,6 +124,23 @@ pub async fn get_road_by_source(universe_id: ObjectId, destination_id: u64) -> m
         .await
 }
 
+/// Returns every road, secret or not, with `category_id` at one of its ends.
+pub async fn get_roads_by_place(universe_id: ObjectId, category_id: u64) -> mongodb::error::Result<Vec<Road>> {
+    let db_client = get_db_client().await;
+    let filter = doc! {
+        "$or": [
+            { "place_one_id": category_id.to_string(), "universe_id": universe_id },
+            { "place_two_id": category_id.to_string(), "universe_id": universe_id },
+        ]
+    };
+    db_client.database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .find(filter)
+        .await?
+        .try_collect()
+        .await
+}
+
 pub async fn get_road(universe_id: ObjectId, place_one: u64, place_two: u64) -> mongodb::error::Result<Option<Road>> {
     let db_client = get_db_client().await;
     let filter = doc! {
@@ -104,4 +176,81 @@ pub async fn count_non_secret_roads_for_place(universe_id: ObjectId, place_id: u
         .collection::<Road>(ROADS_COLLECTION_NAME)
         .count_documents(filter)
         .await
-}
\ No newline at end of file
+}
+/// Renames the road whose channel is `channel_id`.
+pub async fn set_road_name(universe_id: ObjectId, channel_id: u64, name: &str) -> mongodb::error::Result<UpdateResult> {
+    let filter = doc!{"channel_id": channel_id.to_string(), "universe_id": universe_id};
+    let db_client = get_db_client().await;
+    db_client.database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .update_one(filter, doc!{"$set": {"road_name": name}})
+        .await
+}
+
+pub async fn delete_roads_by_discord_id(universe_id: ObjectId, id: u64) -> mongodb::error::Result<DeleteResult> {
+    let db_client = get_db_client().await;
+    let filter = doc! {
+        "$or": [
+            { "channel_id": id.to_string(), "universe_id": universe_id },
+            { "role_id": id.to_string(), "universe_id": universe_id },
+        ]
+    };
+    db_client.database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .delete_many(filter)
+        .await
+}
+
+/// Removes every road, secret or not, with `category_id` at one of its ends.
+pub async fn delete_roads_by_place(universe_id: ObjectId, category_id: u64) -> mongodb::error::Result<DeleteResult> {
+    let db_client = get_db_client().await;
+    let filter = doc! {
+        "$or": [
+            { "place_one_id": category_id.to_string(), "universe_id": universe_id },
+            { "place_two_id": category_id.to_string(), "universe_id": universe_id },
+        ]
+    };
+    db_client.database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .delete_many(filter)
+        .await
+}
+
+pub async fn get_roads_by_server_id(server_id: u64) -> mongodb::error::Result<Vec<Road>> {
+    let db_client = get_db_client().await;
+    db_client.database(VERSEENGINE_DB_NAME)
+        .collection::<Road>(ROADS_COLLECTION_NAME)
+        .find(doc! {"server_id": server_id.to_string()})
+        .await?
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::road::{get_road, get_road_by_channel_id, Road};
+
+    /// A road is found by its channel or by its ends, in any order, until it is deleted.
+    #[tokio::test]
+    async fn test_get_and_delete_road() {
+        let universe_id = ObjectId::new();
+        Road { universe_id, channel_id: 10, role_id: 10, ..Road::between(1, 2) }.insert().await.unwrap();
+
+        let found = get_road_by_channel_id(universe_id, 10).await.unwrap().unwrap();
+        assert_eq!((found.place_one_id, found.place_two_id), (1, 2));
+        // Either order of the places finds the road, so a duplicate is rejected both ways
+        assert_eq!(get_road(universe_id, 1, 2).await.unwrap().map(|r| r.channel_id), Some(10));
+        assert_eq!(get_road(universe_id, 2, 1).await.unwrap().map(|r| r.channel_id), Some(10));
+        assert!(get_road(universe_id, 1, 3).await.unwrap().is_none());
+
+        let mut found = found;
+        found.distance = 8;
+        assert_eq!(found.update().await.unwrap().modified_count, 1);
+        assert_eq!(get_road_by_channel_id(universe_id, 10).await.unwrap().map(|r| r.distance), Some(8));
+        assert!(get_road_by_channel_id(ObjectId::new(), 10).await.unwrap().is_none());
+
+        assert_eq!(found.delete().await.unwrap().deleted_count, 1);
+        assert!(get_road_by_channel_id(universe_id, 10).await.unwrap().is_none());
+    }
+}
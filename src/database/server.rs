@@ -1,10 +1,9 @@
 use futures::{TryStreamExt};
 use std::cmp::PartialEq;
-use log::{log, Level};
 use mongodb::bson::{doc, to_document};
 use mongodb::bson::oid::ObjectId;
 use mongodb::Cursor;
-use mongodb::results::{InsertOneResult, UpdateResult};
+use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
 use serde::{Deserialize, Serialize};
 use serde_with::{DisplayFromStr, serde_as};
 use crate::database::db_client::{get_db_client};
@@ -150,6 +149,27 @@ pub struct Server {
 
     pub universal_time_channel_id: Option<Id>,
     pub universal_invite_url: Option<String>,
+
+    /// README channel created by the bot when it joined the guild.
+    pub readme_channel_id: Option<Id>,
+
+    /// Unix timestamp (seconds) at which the bot was removed from the guild, if it was.
+    pub detached_at: Option<u64>,
+
+    /// Whether the server was restored from a backup and its roles and channels may no longer
+    /// exist, until the repair setup recreates them.
+    #[serde(default)]
+    pub needs_repair: bool,
+
+    /// Locale of the server, set with `/universe set_server_locale`, preferred over the default
+    /// one of its universe for replies without locale and for the names the setup gives.
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// Message of the NRP general channel whose buttons give and take the player and spectator
+    /// roles, posted by the setup.
+    #[serde(default)]
+    pub role_menu_message_id: Option<u64>,
 }
 
 impl Default for Server {
@@ -175,6 +195,11 @@ impl Default for Server {
             rp_character_channel_id: None,
             universal_time_channel_id: None,
             universal_invite_url: None,
+            readme_channel_id: None,
+            detached_at: None,
+            needs_repair: false,
+            locale: None,
+            role_menu_message_id: None,
         }
     }
 }
@@ -186,6 +211,20 @@ impl PartialEq for Id {
 }
 
 impl Server {
+    /// Whether a setup already gave the server some of its roles, categories or channels.
+    pub fn is_setup(&self) -> bool {
+        self.admin_role_id.is_some()
+            || self.moderator_role_id.is_some()
+            || self.spectator_role_id.is_some()
+            || self.player_role_id.is_some()
+            || self.road_category_id.is_some()
+            || self.rp_wiki_channel_id.is_some()
+            || self.admin_category_id.is_some()
+            || self.nrp_category_id.is_some()
+            || self.rp_category_id.is_some()
+            || self.rp_character_channel_id.is_some()
+    }
+
     /// Creates a deep clone of the server configuration.
     #[allow(unused)]
     pub fn clone(&self) -> Self {
@@ -210,6 +249,11 @@ impl Server {
             rp_character_channel_id: self.rp_character_channel_id.clone(),
             universal_time_channel_id: self.universal_time_channel_id.clone(),
             universal_invite_url: self.universal_invite_url.clone(),
+            readme_channel_id: self.readme_channel_id,
+            detached_at: self.detached_at,
+            needs_repair: self.needs_repair,
+            locale: self.locale.clone(),
+            role_menu_message_id: self.role_menu_message_id,
         }
     }
 
@@ -247,6 +291,19 @@ impl Server {
             .update_one(filter, update).await
     }
 
+    /// Deletes this server configuration from the database, using the `_id` field.
+    ///
+    /// # Errors
+    ///
+    /// Returns a MongoDB error if the delete operation fails.
+    pub async fn delete(&self) -> mongodb::error::Result<DeleteResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Server>(SERVERS_COLLECTION_NAME)
+            .delete_one(doc! {"_id": &self._id}).await
+    }
+
     /// Sets the universe ID. Returns `self` for method chaining.
     pub fn universe_id(&mut self, universe_id: impl Into<ObjectId>) -> &mut Self {self.universe_id = universe_id.into(); self}
 
@@ -326,193 +383,191 @@ impl Server {
     }
     /// Sets the RP character channel ID. Returns `self` for method chaining.
     pub fn rp_character_channel_id(&mut self, rp_character_channel_id: impl Into<Id>) -> &mut Self {self.rp_character_channel_id = Some(rp_character_channel_id.into()); self}
+    /// Sets the README channel ID. Returns `self` for method chaining.
+    pub fn readme_channel_id(&mut self, readme_channel_id: impl Into<Id>) -> &mut Self {
+        self.readme_channel_id = Some(readme_channel_id.into());
+        self
+    }
+
+    /// Lists every role, category and channel field managed by the bot, configured or not.
+    pub fn managed_ids(&self) -> Vec<(&'static str, Option<Id>)> {
+        vec![
+            ("admin_role_id", self.admin_role_id),
+            ("moderator_role_id", self.moderator_role_id),
+            ("spectator_role_id", self.spectator_role_id),
+            ("player_role_id", self.player_role_id),
+            ("everyone_role_id", self.everyone_role_id),
+            ("admin_category_id", self.admin_category_id),
+            ("nrp_category_id", self.nrp_category_id),
+            ("rp_category_id", self.rp_category_id),
+            ("road_category_id", self.road_category_id),
+            ("rp_wiki_channel_id", self.rp_wiki_channel_id),
+            ("log_channel_id", self.log_channel_id),
+            ("moderation_channel_id", self.moderation_channel_id),
+            ("commands_channel_id", self.commands_channel_id),
+            ("nrp_general_channel_id", self.nrp_general_channel_id),
+            ("rp_character_channel_id", self.rp_character_channel_id),
+            ("universal_time_channel_id", self.universal_time_channel_id),
+            ("readme_channel_id", self.readme_channel_id),
+        ]
+    }
+
+    /// Lists every role, category and channel ID stored on this server, with its field name.
+    pub fn stored_ids(&self) -> Vec<(&'static str, Id)> {
+        self.managed_ids()
+            .into_iter()
+            .filter_map(|(name, id)| id.map(|id| (name, id)))
+            .collect()
+    }
+
+    /// Mutable access to every role, category and channel field managed by the bot.
+    fn managed_ids_mut(&mut self) -> [(&'static str, &mut Option<Id>); 17] {
+        [
+            ("admin_role_id", &mut self.admin_role_id),
+            ("moderator_role_id", &mut self.moderator_role_id),
+            ("spectator_role_id", &mut self.spectator_role_id),
+            ("player_role_id", &mut self.player_role_id),
+            ("everyone_role_id", &mut self.everyone_role_id),
+            ("admin_category_id", &mut self.admin_category_id),
+            ("nrp_category_id", &mut self.nrp_category_id),
+            ("rp_category_id", &mut self.rp_category_id),
+            ("road_category_id", &mut self.road_category_id),
+            ("rp_wiki_channel_id", &mut self.rp_wiki_channel_id),
+            ("log_channel_id", &mut self.log_channel_id),
+            ("moderation_channel_id", &mut self.moderation_channel_id),
+            ("commands_channel_id", &mut self.commands_channel_id),
+            ("nrp_general_channel_id", &mut self.nrp_general_channel_id),
+            ("rp_character_channel_id", &mut self.rp_character_channel_id),
+            ("universal_time_channel_id", &mut self.universal_time_channel_id),
+            ("readme_channel_id", &mut self.readme_channel_id),
+        ]
+    }
+
+    /// Clears every stored role, category or channel ID equal to `id`.
+    ///
+    /// Used when a resource is deleted from Discord outside of the bot. Returns the names
+    /// of the cleared fields, empty when `id` was not referenced by this server.
+    pub fn clear_id(&mut self, id: u64) -> Vec<&'static str> {
+        let mut cleared = vec![];
+        for (name, field) in self.managed_ids_mut() {
+            if field.is_some_and(|field| field.id == id) {
+                *field = None;
+                cleared.push(name);
+            }
+        }
+        cleared
+    }
+
+    /// Lists the resources stored on this server that did not exist when `snapshot` was taken.
+    ///
+    /// A resource counts as pre-existing when its id is stored anywhere on the snapshot, so a
+    /// resource that was only moved from one field to another is never reported. The everyone
+    /// role is never reported, since it cannot be deleted.
+    pub fn created_since(&self, snapshot: &Server) -> Vec<(&'static str, Id)> {
+        let existing: Vec<u64> = snapshot.stored_ids().iter().map(|(_, id)| id.id).collect();
+        self.stored_ids()
+            .into_iter()
+            .filter(|(name, id)| *name != "everyone_role_id" && !existing.contains(&id.id))
+            .collect()
+    }
+
+    /// Restores every role, category and channel field to its value on `snapshot`.
+    pub fn restore_ids(&mut self, snapshot: &Server) {
+        let mut snapshot = snapshot.clone();
+        for ((_, field), (_, snapshot_field)) in self.managed_ids_mut().into_iter().zip(snapshot.managed_ids_mut()) {
+            *field = *snapshot_field;
+        }
+    }
 
     /// Rolls back the current server configuration to a previous snapshot state.
     ///
-    /// This asynchronous method compares the current server configuration with a provided
-    /// snapshot and deletes any roles, categories, or channels that differ between them.
-    /// All deletion operations are executed concurrently using `join_all`, and any errors
-    /// encountered during deletion are logged.
+    /// Only the resources created after the snapshot (see `created_since`) are deleted from
+    /// Discord: resources that existed before the setup started are never touched, even when
+    /// the setup stored them in a different field. The role, category and channel fields are
+    /// then restored to their snapshot values. The document itself is not saved.
     ///
     /// # Parameters
     ///
     /// - `ctx`: A reference to the Discord context, used for performing Discord API operations
     ///   such as deleting roles and channels.
-    /// - `snapshot`: A previous state of the `Server` instance to which the current state should
-    ///   be rolled back. Fields that differ from the snapshot will be deleted.
-    ///
-    /// # Behavior
-    ///
-    /// The method performs the following steps:
-    /// 1. Creates an array of mutable references to the current server's role/channel fields
-    ///    paired with their corresponding snapshot values.
-    /// 2. Filters the fields to identify those that differ between the current state and the snapshot.
-    /// 3. Collects futures for deleting the differing fields using the `IdExt::delete` method.
-    /// 4. Executes all deletion operations concurrently using `join_all`.
-    /// 5. Logs any errors that occur during deletion with error-level logging, including
-    ///    the universe ID, server ID, and error message.
-    ///
-    /// # Fields Rolled Back
-    ///
-    /// The following fields are checked and potentially rolled back:
-    /// - `admin_role_id`
-    /// - `moderator_role_id`
-    /// - `spectator_role_id`
-    /// - `player_role_id`
-    /// - `admin_category_id`
-    /// - `nrp_category_id`
-    /// - `rp_category_id`
-    /// - `road_category_id`
-    /// - `rp_wiki_channel_id`
-    /// - `log_channel_id`
-    /// - `moderation_channel_id`
-    /// - `commands_channel_id`
-    /// - `nrp_general_channel_id`
-    /// - `rp_character_channel_id`
+    /// - `snapshot`: The state returned by `snapshot` before the setup started.
     ///
     /// # Example
     ///
     /// ```rust
-    /// let mut server = Server::default();
-    /// let snapshot = server.clone();
+    /// let snapshot = server.clone().snapshot(&ctx).await;
     /// // ... modify server ...
     /// server.rollback(&ctx, snapshot).await;
     /// ```
     ///
     /// # Errors
     ///
-    /// Errors during deletion operations are logged but do not cause the function to return
-    /// an error. This allows the rollback to proceed even if some deletions fail.
-    ///
-    /// # Notes
-    ///
-    /// - This method requires the `futures` crate for `join_all`.
-    /// - Errors are logged using the `log` crate at the `Error` level.
+    /// Deletions run concurrently using `join_all`. Errors are logged but do not stop the
+    /// rollback, so one failed deletion does not leave the other resources behind.
     pub async fn rollback(&mut self, ctx: &Context<'_>, snapshot: Self) {
         use futures::future::join_all;
 
-        let mut fields = [
-            (&mut self.admin_role_id, snapshot.admin_role_id),
-            (&mut self.moderator_role_id, snapshot.moderator_role_id),
-            (&mut self.spectator_role_id, snapshot.spectator_role_id),
-            (&mut self.player_role_id, snapshot.player_role_id),
-            (&mut self.admin_category_id, snapshot.admin_category_id),
-            (&mut self.nrp_category_id, snapshot.nrp_category_id),
-            (&mut self.rp_category_id, snapshot.rp_category_id),
-            (&mut self.road_category_id, snapshot.road_category_id),
-            (&mut self.rp_wiki_channel_id, snapshot.rp_wiki_channel_id),
-            (&mut self.log_channel_id, snapshot.log_channel_id),
-            (&mut self.moderation_channel_id, snapshot.moderation_channel_id),
-            (&mut self.commands_channel_id, snapshot.commands_channel_id),
-            (&mut self.nrp_general_channel_id, snapshot.nrp_general_channel_id),
-            (&mut self.rp_character_channel_id, snapshot.rp_character_channel_id),
-            (&mut self.universal_time_channel_id, snapshot.universal_time_channel_id),
-        ];
-
-        let delete_futures: Vec<_> = fields
-            .iter_mut()
-            .filter_map(|(field, snapshot_field)| {
-                if **field != *snapshot_field {
-                    Some(field.delete(ctx))
-                } else {
-                    None
-                }
-            })
+        let mut created: Vec<Option<Id>> = self.created_since(&snapshot)
+            .into_iter()
+            .map(|(_, id)| Some(id))
             .collect();
+        let delete_futures: Vec<_> = created.iter_mut().map(|id| id.delete(ctx)).collect();
 
         let results = join_all(delete_futures).await;
         results.iter().for_each(|r| {
             if let Err(err) = r {
-                log!(
-                    Level::Error,
-                    "Error during setup and rollback.\nuniverse_id: {}\nserver_id: {}\n error: {}",
-                    self.universe_id,
-                    self.server_id,
-                    err
-                );
+                tracing::error!(universe_id = %self.universe_id, server_id = self.server_id, "failed to roll back a setup: {err}");
             }
         });
+
+        self.restore_ids(&snapshot);
     }
 
     /// Creates a validated snapshot of the current server configuration.
     ///
     /// Verifies that all role and channel IDs still exist in the Discord guild.
-    /// Any IDs that no longer exist are set to `None` in the returned snapshot.
+    /// Any IDs that no longer exist are set to `None` in the returned snapshot, so the
+    /// snapshot lists exactly the resources that existed before the setup.
     ///
-    /// # Panics
-    ///
-    /// Panics if the guild ID cannot be retrieved or if the HTTP requests to
-    /// retrieve guild roles or channels fail.
-    pub async fn snaphot(self, ctx: &Context<'_>) -> Self {
+    /// If the roles or channels of the guild cannot be retrieved, the snapshot is returned
+    /// unvalidated: every stored resource is then considered pre-existing, which keeps a
+    /// later rollback from deleting it.
+    pub async fn snapshot(self, ctx: &Context<'_>) -> Self {
         let mut snapshot = self.clone();
-        let guild_id = ctx.guild_id().unwrap();
-        let roles = ctx.http().get_guild_roles(guild_id.into()).await.unwrap();
-        let channels = ctx.http().get_channels(guild_id.into()).await.unwrap();
-
-
-        let role_exists = |id: u64| roles.iter().any(|r| r.id.get() == id);
-
-        if snapshot.admin_role_id.map(|x| role_exists(x.id)) == Some(false) {
-            snapshot.admin_role_id = None;
-        }
-        if snapshot.moderator_role_id.map(|x| role_exists(x.id)) == Some(false) {
-            snapshot.moderator_role_id = None;
-        }
-        if snapshot.spectator_role_id.map(|x| role_exists(x.id)) == Some(false) {
-            snapshot.spectator_role_id = None;
-        }
-        if snapshot.player_role_id.map(|x| role_exists(x.id)) == Some(false) {
-            snapshot.player_role_id = None;
-        }
-        if snapshot.everyone_role_id.map(|x| role_exists(x.id)) == Some(false) {
-            snapshot.everyone_role_id = None;
-        }
-
-        let channel_exists = |id: u64| channels.iter().any(|r| r.id.get() == id);
-
-        // ---- Channels/categories: check via get_channel ----
+        let Some(guild_id) = ctx.guild_id() else { return snapshot };
+        let (Ok(roles), Ok(channels)) = (
+            ctx.http().get_guild_roles(guild_id).await,
+            ctx.http().get_channels(guild_id).await,
+        ) else { return snapshot };
+
+        let role_ids: Vec<u64> = roles.iter().map(|r| r.id.get()).collect();
+        let channel_ids: Vec<u64> = channels.iter().map(|c| c.id.get()).collect();
+        snapshot.forget_missing(&role_ids, &channel_ids);
+        snapshot
+    }
 
-        if snapshot.road_category_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.road_category_id = None;
-        }
-        if snapshot.admin_category_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.admin_category_id = None;
-        }
-        if snapshot.nrp_category_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.nrp_category_id = None;
-        }
-        if snapshot.rp_category_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.rp_category_id = None;
-        }
-        if snapshot.log_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.log_channel_id = None;
-        }
-        if snapshot.commands_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.commands_channel_id = None;
-        }
-        if snapshot.moderation_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.moderation_channel_id = None;
-        }
-        if snapshot.nrp_general_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.nrp_general_channel_id = None;
-        }
-        if snapshot.rp_character_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.rp_character_channel_id = None;
-        }
-        if snapshot.rp_wiki_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.rp_wiki_channel_id = None;
-        }
-        if snapshot.universal_time_channel_id.map(|x| channel_exists(x.id)) == Some(false) {
-            snapshot.universal_time_channel_id = None;
+    /// Clears every field whose role or channel is not in `role_ids` or `channel_ids`.
+    fn forget_missing(&mut self, role_ids: &[u64], channel_ids: &[u64]) {
+        for (_, field) in self.managed_ids_mut() {
+            let exists = field.is_some_and(|id| match id.id_type {
+                IdType::Role => role_ids.contains(&id.id),
+                IdType::Channel | IdType::Category => channel_ids.contains(&id.id),
+            });
+            if !exists {
+                *field = None;
+            }
         }
-
-        snapshot
     }
 
     pub async fn get_character_by_user_id(self, user_id: u64) -> mongodb::error::Result<Option<Character>> {
         Character::get_character_by_user_id(self.universe_id, user_id).await
     }
 
+    /// The character `user_id` is playing in the universe of the server.
+    pub async fn get_active_character(&self, user_id: u64) -> mongodb::error::Result<Option<Character>> {
+        Character::get_active_character(self.universe_id, user_id).await
+    }
+
     pub async fn has_character(self, user_id: u64) -> mongodb::error::Result<Option<Character>> {
         let player_result = self.get_character_by_user_id(user_id).await;
         match player_result {
@@ -552,11 +607,40 @@ impl Server {
         get_road(self.universe_id, place_one, place_two).await
     }
     
+    /// Returns the id of the road category, which only exists once the server is set up.
+    pub fn road_category(&self) -> Result<u64, &'static str> {
+        self.road_category_id.as_ref().map(|id| id.id).ok_or("create_road__setup_required")
+    }
+
+    /// Checks that the roles places are built upon were created by the setup.
+    pub fn check_place_roles(&self) -> Result<(), &'static str> {
+        match (&self.player_role_id, &self.everyone_role_id) {
+            (Some(_), Some(_)) => Ok(()),
+            _ => Err("create_place__setup_required"),
+        }
+    }
+
     pub async fn get_other_servers(&self) -> mongodb::error::Result<Cursor<Server>> {
         get_servers_from_universe(&self.universe_id).await
     }
 }
 
+/// Retrieves every server configuration, attached or not.
+///
+/// # Errors
+///
+/// Returns a MongoDB error if the query fails.
+pub async fn get_all_servers() -> mongodb::error::Result<Vec<Server>> {
+    let db_client = get_db_client().await;
+    db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<Server>(SERVERS_COLLECTION_NAME)
+        .find(doc! {})
+        .await?
+        .try_collect()
+        .await
+}
+
 /// Retrieves a server configuration by Discord guild ID.
 ///
 /// # Errors
@@ -588,6 +672,21 @@ mod test {
         pub static ref UNIVERSE_ID: ObjectId = ObjectId::new();
     }
 
+    #[test]
+    fn test_setup_guards() {
+        let mut server = Server::default();
+        assert_eq!(server.road_category(), Err("create_road__setup_required"));
+        assert_eq!(server.check_place_roles(), Err("create_place__setup_required"));
+
+        server.road_category_id(Id { id: 5, id_type: IdType::Category });
+        server.player_role_id(Id { id: 6, id_type: IdType::Role });
+        assert_eq!(server.road_category(), Ok(5));
+        // The everyone role is still missing
+        assert_eq!(server.check_place_roles(), Err("create_place__setup_required"));
+        server.everyone_role_id(Id { id: 7, id_type: IdType::Role });
+        assert_eq!(server.check_place_roles(), Ok(()));
+    }
+
     async fn insert_universe() -> Result<InsertOneResult, String> {
         let _ = get_db_client().await;
         let universe = Universe {
@@ -603,6 +702,13 @@ mod test {
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .unwrap()
                 .as_millis(),
+            name_overrides: Default::default(),
+            road_slowmode_per_distance: 0,
+            contest_die: None,
+            starting_place_id: None,
+            join_requires_approval: false,
+            admins: vec![],
+            default_locale: None,
         };
         match universe.insert_universe().await {
             Ok(universe) => Ok(universe),
@@ -613,6 +719,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_clear_id() {
+        let mut server = Server::default();
+        server.admin_role_id((10, IdType::Role))
+            .road_category_id((20, IdType::Category))
+            .log_channel_id((20, IdType::Channel));
+
+        assert_eq!(server.clear_id(20), vec!["road_category_id", "log_channel_id"]);
+        assert!(server.road_category_id.is_none() && server.log_channel_id.is_none());
+        assert_eq!(server.admin_role_id.map(|id| id.id), Some(10));
+        assert!(server.clear_id(30).is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let mut server = Server::default();
+        server.admin_role_id((1, IdType::Role))
+            .everyone_role_id((2, IdType::Role))
+            .log_channel_id((3, IdType::Channel))
+            .commands_channel_id((4, IdType::Channel));
+
+        // The commands channel was deleted from Discord before the setup started
+        let mut snapshot = server.clone();
+        snapshot.forget_missing(&[1, 2], &[3]);
+        assert!(snapshot.commands_channel_id.is_none());
+        assert_eq!(snapshot.log_channel_id.map(|id| id.id), Some(3));
+
+        // The setup recreates the commands channel, creates a road category and reuses the
+        // pre-existing log channel as moderation channel
+        server.commands_channel_id((5, IdType::Channel))
+            .road_category_id((6, IdType::Category))
+            .moderation_channel_id((3, IdType::Channel));
+
+        let created: Vec<u64> = server.created_since(&snapshot).iter().map(|(_, id)| id.id).collect();
+        assert_eq!(created, vec![6, 5]);
+
+        server.restore_ids(&snapshot);
+        assert!(server.road_category_id.is_none() && server.moderation_channel_id.is_none());
+        assert!(server.commands_channel_id.is_none());
+        assert_eq!(server.admin_role_id.map(|id| id.id), Some(1));
+        assert!(server.created_since(&snapshot).is_empty());
+    }
+
     #[tokio::test]
     async fn test_insert_server() {
         insert_universe().await.unwrap();
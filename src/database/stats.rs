@@ -13,7 +13,7 @@
 //!  # Examples
 //! 
 //!  ```rust
-//!  let universe_id = "sample_universe_id";
+//!  let universe_id = universe.universe_id;
 //!  let stat_name = "Health";
 //!  
 //!  let stat = Stat::get_stat_by_name(universe_id, stat_name).await;
@@ -28,25 +28,29 @@
 //! 
 //!  - This function assumes that the `connect_db()` function is properly implemented and
 //!    that the `DB_CLIENT` singleton is functional.
-//!  - The query uses `name` as the matching parameter to retrieve a specific `Stat`. If 
-//!    multiple documents with the same name exist, only one will be returned, as specified
-//!    by the database driver's behavior.
+//!  - The query matches both `universe_id` and `name`. The unique `(universe_id, name)` index
+//!    guarantees at most one matching document.
 //! 
 //!  # Dependencies
 //! 
 //!  This function relies on the following:
 //!  - A global `DB_CLIENT` to establish and manage database connections.
 //!  - `STATS_COLLECTION_NAME`, which specifies the target collection.
+use std::collections::HashMap;
+use futures::TryStreamExt;
 use mongodb::bson::doc;
 use mongodb::bson::oid::ObjectId;
+use mongodb::error::{ErrorKind, WriteFailure};
+use mongodb::results::{DeleteResult, UpdateResult};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use crate::database::db_client::{get_db_client};
-use crate::database::db_namespace::{CHARACTERS_COLLECTION_NAME, ROADS_COLLECTION_NAME, STATS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
-use crate::database::modifiers::{Modifier, ModifierType};
+use crate::database::db_namespace::{CHARACTERS_COLLECTION_NAME, ITEM_COLLECTION_NAME, PLACES_COLLECTION_NAME, ROADS_COLLECTION_NAME, STATS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
+use crate::database::modifiers::Modifier;
 use crate::database::characters::Character;
 use crate::database::road::Road;
 use crate::discord::poise_structs::Error;
+use crate::stats::engine::compute_effective_value;
 
 pub static SPEED_STAT: &str = "speed";
 
@@ -97,6 +101,67 @@ impl StatValue {
             StatValue::Bool(b) => if *b { 1.0 } else { 0.0 },
         }
     }
+
+    /// Value as a rhai `Dynamic`, for the variables of stat formulas.
+    pub fn to_dynamic(&self) -> rhai::Dynamic {
+        match self {
+            StatValue::I64(v) => rhai::Dynamic::from_int(*v),
+            StatValue::F64(v) => rhai::Dynamic::from_float(*v),
+            StatValue::String(v) => rhai::Dynamic::from(v.clone()),
+            StatValue::Bool(v) => rhai::Dynamic::from_bool(*v),
+        }
+    }
+}
+
+/// Kind of value a stat holds, chosen when the stat is defined.
+#[derive(poise::ChoiceParameter, Debug, Clone, Copy, PartialEq)]
+pub enum StatType {
+    #[name = "stat_type_int"]
+    Int,
+    #[name = "stat_type_float"]
+    Float,
+    #[name = "stat_type_text"]
+    Text,
+    #[name = "stat_type_bool"]
+    Bool
+}
+
+impl StatType {
+    /// Kind of `value`.
+    pub fn of(value: &StatValue) -> StatType {
+        match value {
+            StatValue::I64(_) => StatType::Int,
+            StatValue::F64(_) => StatType::Float,
+            StatValue::String(_) => StatType::Text,
+            StatValue::Bool(_) => StatType::Bool,
+        }
+    }
+
+    /// Parses `value` as a value of this kind.
+    pub fn parse(self, value: &str) -> Option<StatValue> {
+        let template = match self {
+            StatType::Int => StatValue::I64(0),
+            StatType::Float => StatValue::F64(0.0),
+            StatType::Text => StatValue::String(String::new()),
+            StatType::Bool => StatValue::Bool(false),
+        };
+        parse_stat_value(value.trim(), &template)
+    }
+
+    /// Attribute of the `stat_type` message naming this kind.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            StatType::Int => "int",
+            StatType::Float => "float",
+            StatType::Text => "text",
+            StatType::Bool => "bool",
+        }
+    }
+
+    /// Whether values of this kind can be bounded and modified.
+    pub fn is_numeric(self) -> bool {
+        matches!(self, StatType::Int | StatType::Float)
+    }
 }
 
 impl PartialOrd for StatValue {
@@ -203,6 +268,7 @@ impl Stat {
     ///
     /// This function will return an error in the following scenarios:
     /// - Unable to establish a connection to the database using `connect_db()`.
+    /// - The universe already has a stat with the same name, resulting in `"stat_insert__duplicate_name"`.
     /// - The insertion operation fails with the database client, resulting in `"stat_insert__failed"`.
     ///
     /// # Examples
@@ -251,6 +317,8 @@ impl Stat {
         match result {
             Ok(_) => {
                 Ok(self.clone()) }
+            // The unique `(universe_id, name)` index rejects a second stat with the same name
+            Err(e) if is_duplicate_key(&e) => { Err("stat_insert__duplicate_name".into()) }
             Err(_) => { Err("stat_insert__failed".into()) }
         }
     }
@@ -258,7 +326,7 @@ impl Stat {
     /// Retrieves a specific statistic by its name from the database.
     ///
     /// # Parameters
-    /// - `universe_id`: A string slice representing the ID of the database (or namespace) to query.
+    /// - `universe_id`: The ID of the universe the statistic belongs to.
     /// - `name`: A string slice representing the name of the statistic to retrieve.
     ///
     /// # Returns
@@ -269,12 +337,12 @@ impl Stat {
     ///
     /// # Behavior
     /// - Establishes a connection to the database using a cached `DB_CLIENT`.
-    /// - Queries the specified collection (`STATS_COLLECTION_NAME`) within the database identified
-    ///   by `universe_id` for the document where the "name" field matches the given `name`.
+    /// - Queries the specified collection (`STATS_COLLECTION_NAME`) for the document of the universe
+    ///   `universe_id` where the "name" field matches the given `name`.
     ///
     /// # Example
     /// ```rust
-    /// let stat = get_stat_by_name("game_universe", "player_kills").await?;
+    /// let stat = Stat::get_stat_by_name(universe_id, "player_kills").await?;
     /// match stat {
     ///     Some(stat) => println!("Found stat: {:?}", stat),
     ///     None => println!("No statistic found with the given name."),
@@ -291,7 +359,7 @@ impl Stat {
     /// This function may return a `mongodb::error::Error` if:
     /// - The database connection cannot be established.
     /// - The query execution fails.
-    pub async fn get_stat_by_name(universe_id: &str, name: &str) -> mongodb::error::Result<Option<Stat>> {
+    pub async fn get_stat_by_name(universe_id: ObjectId, name: &str) -> mongodb::error::Result<Option<Stat>> {
         let db_client = get_db_client().await;
         let filter = doc! { "name": name, "universe_id": universe_id };
         db_client
@@ -344,149 +412,268 @@ impl Stat {
         true
     }
 
+    /// Effective value of the stat for the character `user_id` plays, standing in the place or on
+    /// the road `category_id`, computed by the stat engine with the active modifiers of the
+    /// character, of the road and of the stat. Also returns the first of these modifiers to end,
+    /// after which the value changes.
+    ///
+    /// # Errors
+    /// - `"resolve_stat__database_error"`: the character could not be read.
+    /// - `"resolve_stat__character_not_found"`: the user plays no character.
+    /// - `"stat_formula__*"`: the formula of the stat or of a modifier failed.
     pub async fn resolve(self, category_id: u64, user_id: u64) -> Result<(StatValue, Option<Modifier>), Error> {
         let db_client = get_db_client().await;
         let db = db_client.database(VERSEENGINE_DB_NAME);
 
         // 1. Recover stat in the universe (global)
-        let mut universe_stat = db.collection::<Stat>(STATS_COLLECTION_NAME)
+        let universe_stat = db.collection::<Stat>(STATS_COLLECTION_NAME)
             .find_one(doc! { "name": &self.name, "universe_id":  self.universe_id })
-            .await.unwrap_or_else(|_| None);
+            .await.ok().flatten();
 
         // 2. Recover location stat/modifiers
-        let mut road = db.collection::<Road>(ROADS_COLLECTION_NAME)
+        let road = db.collection::<Road>(ROADS_COLLECTION_NAME)
             .find_one(doc! { "$or": [
                 { "channel_id": category_id.to_string(), "universe_id":  self.universe_id},
                 { "place_one_id": category_id.to_string(), "universe_id":  self.universe_id },
                 { "place_two_id": category_id.to_string(), "universe_id":  self.universe_id }
             ] })
-            .await.unwrap_or_else(|_| None);
+            .await.ok().flatten();
 
         // 3. Recover player stat/modifiers
-        let mut character = match db.collection::<Character>(CHARACTERS_COLLECTION_NAME)
-            .find_one(doc! { "user_id": user_id.to_string(), "universe_id":  self.universe_id })
-            .await {
-                Ok(res) => res,
-                Err(_) => return Err("resolve_stat__database_error".into())
-            };
-
-        let stat_id = self._id;
-
-
-
-        // Cleanup expired modifiers
-        if let Some(ref mut _character) = character {
-            let mut changed = false;
-            for stat in &mut _character.stats {
-                let initial_len = stat.modifiers.len();
-                stat.modifiers.retain(|m| m.is_active());
-                if stat.modifiers.len() != initial_len {
-                    changed = true;
-                }
-            }
-            if changed {
-                let _ = db.collection::<Character>(CHARACTERS_COLLECTION_NAME)
-                    .replace_one(doc! { "_id": _character._id }, _character.clone())
-                    .await;
-            }
-        } else {return Err("resolve_stat__character_not_found".into())}
-
-        if let Some(ref mut road) = road {
-            let initial_len = road.modifiers.len();
-            road.modifiers.retain(|m| m.is_active());
-            if road.modifiers.len() != initial_len {
-                let _ = db.collection::<Road>(ROADS_COLLECTION_NAME)
-                    .replace_one(doc! { "_id": road._id }, road.clone())
-                    .await;
-            }
-        }
-
-        if let Some(ref mut _universe_stat) = universe_stat {
-            let initial_len = _universe_stat.modifiers.len();
-            _universe_stat.modifiers.retain(|m| m.is_active());
-            if _universe_stat.modifiers.len() != initial_len {
-                let _ = db.collection::<Stat>(STATS_COLLECTION_NAME)
-                    .replace_one(doc! { "_id": _universe_stat._id }, _universe_stat.clone())
-                    .await;
-            }
-        }
-
-
-
-        let mut grouped_modifiers: Vec<Modifier> = vec![];
-
-        let mut apply_modifiers = |modifiers: &Vec<Modifier>, multipliers: &mut f64, bases: &mut f64, flats: &mut f64| {
-            grouped_modifiers.append(modifiers.clone().as_mut());
-            for modifier in modifiers {
-                if modifier.stat == stat_id {
-                    match modifier.modifier_type {
-                        ModifierType::Multiplier => *multipliers *= modifier.value.as_f64(),
-                        ModifierType::Base => *bases += modifier.value.as_f64(),
-                        ModifierType::Flats => *flats += modifier.value.as_f64(),
-                    }
-                }
-            }
+        let character = match Character::get_active_character(self.universe_id, user_id).await {
+            Ok(Some(character)) => character,
+            Ok(None) => return Err("resolve_stat__character_not_found".into()),
+            Err(_) => return Err("resolve_stat__database_error".into()),
         };
 
-        let mut value = self.base_value.as_f64();
-        let mut multipliers = 1.0;
-        let mut bases = 0.0;
-        let mut flats = 0.0;
+        // Expired modifiers are skipped here and removed by the expiry task
+        let stat = universe_stat.unwrap_or(self);
+        let character_stat = character.stats.iter().find(|character_stat| character_stat.name == stat.name);
+        let modifiers: Vec<Modifier> = character_stat.map(|character_stat| character_stat.modifiers.as_slice()).unwrap_or_default().iter()
+            .chain(road.as_ref().map(|road| road.modifiers.as_slice()).unwrap_or_default())
+            .chain(&stat.modifiers)
+            .filter(|modifier| modifier.is_active())
+            .cloned()
+            .collect();
+        let character_stats: HashMap<String, StatValue> = character.stats.iter().map(|stat| (stat.name.clone(), stat.value.clone())).collect();
+        let value = compute_effective_value(&stat, &character_stats, &modifiers).map_err(|e| Error::from(e.key()))?;
+
+        let next_change = modifiers.into_iter()
+            .filter(|modifier| modifier.stat == stat._id && modifier.end_timestamp.is_some())
+            .min_by_key(|modifier| modifier.end_timestamp);
+        Ok((value, next_change))
+    }
+}
 
+impl Stat {
+    /// Stats of the universe `universe_id`, sorted by name.
+    pub async fn get_stats_by_universe(universe_id: ObjectId) -> mongodb::error::Result<Vec<Stat>> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Stat>(STATS_COLLECTION_NAME)
+            .find(doc!{"universe_id": universe_id})
+            .sort(doc!{"name": 1})
+            .await?
+            .try_collect()
+            .await
+    }
 
-        // 2. Extract modifiers from Character's stat
-        if let Some(_character) = character {
-            if let Some(c_stat) = _character.stats.iter().find(|s| s.name == self.name) {
-                apply_modifiers(&c_stat.modifiers, &mut multipliers, &mut bases, &mut flats);
-                value = multipliers * (c_stat.base_value.as_f64() + bases) + flats;
+    /// Saves the name, values, formula and modifiers of the stat.
+    pub async fn update(&self) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Stat>(STATS_COLLECTION_NAME)
+            .replace_one(doc!{"_id": self._id}, self)
+            .await
+    }
 
-                multipliers = 1.0;
-                bases = 0.0;
-                flats = 0.0;
-            }
-        }
-        else { return Err("resolve_stat__character_not_found".into()) }
+    /// Deletes the stat. Use [`Stat::is_referenced`] first, characters and modifiers are not updated.
+    pub async fn delete(&self) -> mongodb::error::Result<DeleteResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Stat>(STATS_COLLECTION_NAME)
+            .delete_one(doc!{"_id": self._id})
+            .await
+    }
 
-        // 3. Extract modifiers from Place
-        if let Some(_place) = road {
-            apply_modifiers(&_place.modifiers, &mut multipliers, &mut bases, &mut flats);
-            value = multipliers * (value + bases) + flats;
+    /// Whether a character of the universe has a value for the stat, or a modifier of a
+    /// character, place, road, item or other stat applies to it.
+    pub async fn is_referenced(&self) -> mongodb::error::Result<bool> {
+        let db = get_db_client().await.database(VERSEENGINE_DB_NAME);
+        let characters = db.collection::<Character>(CHARACTERS_COLLECTION_NAME)
+            .count_documents(doc!{"universe_id": self.universe_id, "$or": [{"stats.name": &self.name}, {"stats.modifiers.stat": self._id}]})
+            .await?;
+        let places = db.collection::<Stat>(PLACES_COLLECTION_NAME)
+            .count_documents(doc!{"universe_id": self.universe_id, "modifiers.stat": self._id})
+            .await?;
+        let roads = db.collection::<Stat>(ROADS_COLLECTION_NAME)
+            .count_documents(doc!{"universe_id": self.universe_id, "modifiers.stat": self._id})
+            .await?;
+        let items = db.collection::<Stat>(ITEM_COLLECTION_NAME)
+            .count_documents(doc!{"universe_id": self.universe_id, "effects.stat": self._id})
+            .await?;
+        let stats = db.collection::<Stat>(STATS_COLLECTION_NAME)
+            .count_documents(doc!{"universe_id": self.universe_id, "_id": {"$ne": self._id}, "modifiers.stat": self._id})
+            .await?;
+        Ok(characters + places + roads + items + stats > 0)
+    }
 
-            multipliers = 1.0;
-            bases = 0.0;
-            flats = 0.0;
+    /// Checks that the bounds of the stat suit its kind and that `min <= base_value <= max`.
+    ///
+    /// # Errors
+    /// - `"stat__bounds_not_numeric"`: a text or boolean stat has bounds.
+    /// - `"stat__invalid_bounds"`: `min` is greater than `max`.
+    /// - `"stat__out_of_bounds"`: the base value is not within the bounds.
+    pub fn check_definition(&self) -> Result<(), &'static str> {
+        if !StatType::of(&self.base_value).is_numeric() && (self.min.is_some() || self.max.is_some()) {
+            return Err("stat__bounds_not_numeric");
         }
-
-        // 4. Extract modifiers from Universe stat
-        if let Some(u_stat) = universe_stat {
-            apply_modifiers(&u_stat.modifiers, &mut multipliers, &mut bases, &mut flats);
-            value = multipliers * (value + bases) + flats;
+        if let (Some(min), Some(max)) = (&self.min, &self.max) && min > max {
+            return Err("stat__invalid_bounds");
+        }
+        if !self.is_within_bounds() {
+            return Err("stat__out_of_bounds");
         }
+        Ok(())
+    }
 
-        if let Some(max) = self.max { match max{
-            StatValue::I64(max) => {if (max as f64) < value {value = max as f64}}
-            StatValue::F64(max) => {if max < value {value = max}}
-            _ => {}
-        }}
-        if let Some(min) = self.min { match min{
-            StatValue::I64(min) => {if min as f64 > value {value = min as f64}}
-            StatValue::F64(min) => {if min > value {value = min}}
-            _ => {}
-        }}
-
-        let shortest_modifier_end_timestamp = grouped_modifiers.clone().into_iter().min_by_key(|modifier| modifier.end_timestamp);
-
-        match self.base_value {
-            StatValue::I64(_) => Ok((StatValue::I64(value.round() as i64), shortest_modifier_end_timestamp)),
-            _ => Ok((StatValue::F64(value), shortest_modifier_end_timestamp)),
+    /// Stat `name` of a new universe, without formula, bounds nor modifiers, for the tests to set
+    /// the fields they check with the struct update syntax.
+    #[cfg(test)]
+    pub fn named(name: &str, base_value: StatValue) -> Stat {
+        Stat {
+            _id: ObjectId::new(),
+            universe_id: ObjectId::new(),
+            name: name.to_string(),
+            base_value,
+            formula: None,
+            min: None,
+            max: None,
+            modifiers: vec![],
         }
     }
 }
 
+/// Parses a stat value from a string based on the expected `StatValue` variant.
+///
+/// Supported types: `i64`, `f64`, `String`, `bool`.
+pub fn parse_stat_value(value_str: &str, base_value: &StatValue) -> Option<StatValue> {
+    match base_value {
+        StatValue::I64(_) => value_str.parse::<i64>().ok().map(StatValue::I64),
+        StatValue::F64(_) => value_str.parse::<f64>().ok().map(StatValue::F64),
+        StatValue::String(_) => Some(StatValue::String(value_str.to_string())),
+        StatValue::Bool(_) => value_str.parse::<bool>().ok().map(StatValue::Bool),
+    }
+}
+
 pub async fn get_stat_by_name(universe_id: ObjectId, name: &str) -> mongodb::error::Result<Option<Stat>> {
+    Stat::get_stat_by_name(universe_id, name).await
+}
+
+/// Whether `error` comes from a unique index rejecting a write.
+fn is_duplicate_key(error: &mongodb::error::Error) -> bool {
+    matches!(&*error.kind, ErrorKind::Write(WriteFailure::WriteError(e)) if e.code == 11000)
+}
+
+pub async fn get_stat_by_id(stat_id: ObjectId) -> mongodb::error::Result<Option<Stat>> {
     let db_client = get_db_client().await;
     db_client.database(VERSEENGINE_DB_NAME)
         .collection::<Stat>(STATS_COLLECTION_NAME)
-        .find_one(doc! { "name": name, "universe_id":  universe_id })
+        .find_one(doc! { "_id": stat_id })
         .await
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::db_client::constraint;
+
+    #[test]
+    fn test_parse_stat_value() {
+        assert_eq!(parse_stat_value("12", &StatValue::I64(0)), Some(StatValue::I64(12)));
+        assert_eq!(parse_stat_value("1.5", &StatValue::I64(0)), None);
+        assert_eq!(parse_stat_value("1.5", &StatValue::F64(0.0)), Some(StatValue::F64(1.5)));
+        assert_eq!(parse_stat_value("true", &StatValue::Bool(false)), Some(StatValue::Bool(true)));
+        assert_eq!(parse_stat_value("yes", &StatValue::Bool(false)), None);
+        assert_eq!(parse_stat_value("elf", &StatValue::String(String::new())), Some(StatValue::String("elf".to_string())));
+    }
+
+    fn bounded(base_value: StatValue, min: Option<StatValue>, max: Option<StatValue>) -> Stat {
+        Stat { min, max, ..Stat::named("health", base_value) }
+    }
+
+    #[test]
+    fn test_stat_type() {
+        assert_eq!(StatType::Int.parse(" 12 "), Some(StatValue::I64(12)));
+        assert_eq!(StatType::Float.parse("0.5"), Some(StatValue::F64(0.5)));
+        assert_eq!(StatType::Bool.parse("maybe"), None);
+        assert_eq!(StatType::of(&StatValue::String("elf".to_string())), StatType::Text);
+        assert!(StatType::Float.is_numeric());
+        assert!(!StatType::Bool.is_numeric());
+    }
+
+    #[test]
+    fn test_check_definition() {
+        assert_eq!(bounded(StatValue::I64(10), Some(StatValue::I64(0)), Some(StatValue::I64(10))).check_definition(), Ok(()));
+        assert_eq!(bounded(StatValue::F64(1.0), None, None).check_definition(), Ok(()));
+        assert_eq!(bounded(StatValue::I64(11), Some(StatValue::I64(0)), Some(StatValue::I64(10))).check_definition(), Err("stat__out_of_bounds"));
+        assert_eq!(bounded(StatValue::I64(-1), Some(StatValue::I64(0)), None).check_definition(), Err("stat__out_of_bounds"));
+        assert_eq!(bounded(StatValue::I64(5), Some(StatValue::I64(10)), Some(StatValue::I64(0))).check_definition(), Err("stat__invalid_bounds"));
+        assert_eq!(bounded(StatValue::Bool(true), Some(StatValue::Bool(false)), None).check_definition(), Err("stat__bounds_not_numeric"));
+    }
+
+    /// Stats are listed by name, saved after an edit and deleted.
+    #[tokio::test]
+    async fn test_stat_crud() {
+        let universe_id = ObjectId::new();
+        let mut speed = bounded(StatValue::I64(4), None, None);
+        speed.universe_id = universe_id;
+        speed.name = "speed".to_string();
+        let mut agility = bounded(StatValue::I64(2), None, None);
+        agility.universe_id = universe_id;
+        agility.name = "agility".to_string();
+        speed.insert_stat().await.unwrap();
+        agility.insert_stat().await.unwrap();
+
+        let names: Vec<String> = Stat::get_stats_by_universe(universe_id).await.unwrap().into_iter().map(|stat| stat.name).collect();
+        assert_eq!(names, vec!["agility".to_string(), "speed".to_string()]);
+        assert!(!speed.is_referenced().await.unwrap());
+
+        speed.max = Some(StatValue::I64(8));
+        speed.update().await.unwrap();
+        assert_eq!(get_stat_by_id(speed._id).await.unwrap().unwrap().max, Some(StatValue::I64(8)));
+
+        speed.delete().await.unwrap();
+        agility.delete().await.unwrap();
+        assert!(Stat::get_stats_by_universe(universe_id).await.unwrap().is_empty());
+    }
+
+    /// A second stat with the same name is rejected in its universe, but another universe may use the name.
+    #[tokio::test]
+    async fn test_duplicate_stat_name() {
+        constraint().await;
+        let universe_id = ObjectId::new();
+        let mut speed = bounded(StatValue::I64(4), None, None);
+        speed.universe_id = universe_id;
+        speed.name = "speed".to_string();
+        speed.insert_stat().await.unwrap();
+
+        let mut duplicate = speed.clone();
+        duplicate._id = ObjectId::new();
+        assert_eq!(duplicate.insert_stat().await.unwrap_err().to_string(), "stat_insert__duplicate_name");
+
+        let mut elsewhere = speed.clone();
+        elsewhere._id = ObjectId::new();
+        elsewhere.universe_id = ObjectId::new();
+        elsewhere.insert_stat().await.unwrap();
+        let found = Stat::get_stat_by_name(elsewhere.universe_id, "speed").await.unwrap();
+        assert_eq!(found.map(|stat| stat._id), Some(elsewhere._id));
+        assert_eq!(Stat::get_stat_by_name(universe_id, "speed").await.unwrap().map(|stat| stat._id), Some(speed._id));
+
+        let _ = speed.delete().await;
+        let _ = elsewhere.delete().await;
+    }
+}
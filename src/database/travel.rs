@@ -1,4 +1,3 @@
-use log::log;
 use mongodb::bson::{doc, to_document};
 use mongodb::bson::oid::ObjectId;
 use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
@@ -47,6 +46,9 @@ pub struct PlayerMove{
 
     pub modified_speed: f64,
     pub distance_traveled: f64,
+    /// Real timestamp, in seconds, at which the current journey started.
+    #[serde(default)]
+    pub departure_timestamp: Option<u64>,
     #[serde_as(as = "DisplayFromStr")]
     pub user_id: u64,
     #[serde_as(as = "DisplayFromStr")]
@@ -105,7 +107,7 @@ impl PlayerMove {
     pub async fn next_step(self) -> Option<(StatValue, Option<Modifier>)>{
         if !self.is_end{
             let Ok(Some(stat)) = get_stat_by_name(self.universe_id, SPEED_STAT).await else {
-                log!(log::Level::Error, "Failed to get stat. [{:?}]", self);
+                tracing::error!(user_id = self.user_id, "failed to get the speed stat of a move: {self:?}");
                 return None;
             };
             let Ok((speed, shortest_modifier)) = stat.resolve(self.actual_space_id, self.user_id).await else { todo!() };
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use futures::TryStreamExt;
 use crate::database::db_client::{get_db_client};
 use crate::database::db_namespace::{
@@ -9,7 +10,7 @@ use mongodb::bson::{doc, from_document};
 use mongodb::bson::oid::ObjectId;
 use mongodb::{Cursor, IndexModel};
 use mongodb::options::{IndexOptions};
-use mongodb::results::{CreateIndexResult, InsertOneResult};
+use mongodb::results::{CreateIndexResult, InsertOneResult, UpdateResult};
 use serde::{Deserialize, Serialize};
 use serde_with::{DisplayFromStr, serde_as};
 use tokio::join;
@@ -19,6 +20,7 @@ use crate::database::road::Road;
 use crate::database::server::{Server};
 use crate::database::stats::Stat;
 use crate::database::travel::PlayerMove;
+use crate::database::user_limits::get_limits_for_user;
 use crate::discord::poise_structs::Error;
 
 pub static FREE_LIMIT_UNIVERSE: usize = 2;
@@ -47,6 +49,30 @@ pub static FREE_LIMIT_SERVERS_PER_UNIVERSE: usize = 2;
 ///   The timestamp of when the universe was created.
 ///   Serialized as a string using the `DisplayFromStr` attribute.
 ///
+/// * `name_overrides` (`HashMap<String, String>`):
+///   Custom names of the setup-created roles and channels, keyed by the translation key of
+///   their default name (e.g. `admin_role_name`). Missing from older documents.
+///
+/// * `road_slowmode_per_distance` (`u64`):
+///   Seconds of slowmode given to a road channel per kilometer of the road. `0`, the default,
+///   gives every road the same fixed slowmode.
+///
+/// * `contest_die` (`Option<String>`):
+///   Dice expression each side of a `/contest` rolls, [`DEFAULT_CONTEST_DIE`] when unset.
+///
+/// * `starting_place_id` (`Option<u64>`):
+///   Category of the place whose role `/join` gives to new players.
+///
+/// * `join_requires_approval` (`bool`):
+///   Whether `/join` is reserved to players whose character was approved.
+///
+/// * `admins` (`Vec<u64>`):
+///   Users who run the universe with its creator: they can change its settings and add servers to it.
+///
+/// * `default_locale` (`Option<String>`):
+///   Locale of the messages the bot posts on its own, such as travel announcements. When unset,
+///   each server uses its own preferred locale.
+///
 /// # Serde Attributes
 ///
 /// * `#[serde_as]`:
@@ -73,8 +99,35 @@ pub struct Universe {
 
     #[serde_as(as = "DisplayFromStr")]
     pub creation_timestamp: u128,
+
+    #[serde(default)]
+    pub name_overrides: HashMap<String, String>,
+
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub road_slowmode_per_distance: u64,
+
+    #[serde(default)]
+    pub contest_die: Option<String>,
+
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub starting_place_id: Option<u64>,
+
+    #[serde(default)]
+    pub join_requires_approval: bool,
+
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default)]
+    pub admins: Vec<u64>,
+
+    #[serde(default)]
+    pub default_locale: Option<String>,
 }
 
+/// Die each side of a contest rolls when the universe does not choose another one.
+pub static DEFAULT_CONTEST_DIE: &str = "1d20";
+
 impl Universe {
     /// Inserts the current `Universe` instance into the MongoDB collection.
     ///
@@ -116,10 +169,10 @@ impl Universe {
             .await
     }
 
-    /// Asynchronously retrieves a list of universes created by a specific user.
+    /// Asynchronously retrieves a list of universes a specific user created or administrates.
     ///
     /// This function connects to the database and queries the `Universe` collection
-    /// to retrieve all universes associated with the provided `user_id`.
+    /// to retrieve all universes whose creator or one of whose admins is the provided `user_id`.
     /// The function relies on initializing a shared database client if not already initialized.
     ///
     /// # Arguments
@@ -128,7 +181,7 @@ impl Universe {
     ///
     /// # Returns
     ///
-    /// Returns a `Vec<Universe>` containing the universes created by the specified user.
+    /// Returns a `Vec<Universe>` containing the universes the specified user created or administrates.
     ///
     /// # Panics
     ///
@@ -140,7 +193,7 @@ impl Universe {
     ///
     /// ```rust
     /// let user_id = 12345;
-    /// let universes = get_creator_universes(user_id).await;
+    /// let universes = get_administrable_universes(user_id).await;
     /// println!("{:?}", universes);
     /// ```
     ///
@@ -155,9 +208,9 @@ impl Universe {
     ///
     /// Make sure the database is properly configured and accessible, and that `connect_db()`
     /// is implemented to initialize the database connection.
-    pub async fn get_creator_universes(user_id: u64) -> Vec<Universe> {
+    pub async fn get_administrable_universes(user_id: u64) -> Vec<Universe> {
         let db_client = get_db_client().await;
-        let filter = doc! { "creator_id": user_id.to_string() };
+        let filter = doc! { "$or": [{"creator_id": user_id.to_string()}, {"admins": user_id.to_string()}] };
         db_client
             .database(VERSEENGINE_DB_NAME)
             .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
@@ -173,14 +226,15 @@ impl Universe {
     ///
     /// # Returns
     /// - `Result<bool, Error>`:
-    ///    - `Ok(true)`: If the number of universes created by the user is within the allowed free limit.
-    ///    - `Ok(false)`: If the number of universes created by the user exceeds the allowed free limit.
+    ///    - `Ok(true)`: If the number of universes created by the user is within the user's limit.
+    ///    - `Ok(false)`: If the number of universes created by the user reached the user's limit.
     ///    - `Err(Error)`: If there is an error during the database interaction.
     ///
     /// # Behavior
     /// - Establishes a connection to the database using the globally initialized `DB_CLIENT`.
     /// - Constructs a MongoDB filter to count documents in the `UNIVERSE_COLLECTION_NAME` where `creator_id` matches the given `user_id`.
-    /// - Compares the retrieved document count with `FREE_LIMIT_UNIVERSE`.
+    /// - Compares the retrieved document count with the limit given by `get_limits_for_user`,
+    ///   `FREE_LIMIT_UNIVERSE` unless the user was granted more.
     ///
     /// # Panics
     /// - This function will panic if the `DB_CLIENT` initialization fails. The `.unwrap()` call during the `connect_db` process assumes successful database connection.
@@ -216,18 +270,34 @@ impl Universe {
             .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
             .count_documents(filter)
             .await;
-
-        println!("{:?}", result); //got Ok(0)
+        let result = match result {
+            Ok(count) => get_limits_for_user(user_id).await.map(|limits| limits.allows_universe(count)),
+            Err(e) => Err(e),
+        };
 
         match result {
-            Ok(count) => Ok(count < FREE_LIMIT_UNIVERSE as u64),
+            Ok(allowed) => Ok(allowed),
             Err(e) => {
-                log::error!("Error counting universes for user {}: {}", user_id, e);
+                tracing::error!(user_id, "failed to check the universe limit of a user: {e}");
                 Err(e.into())
             }
         }
     }
 
+    /// Tells whether `creator_id` already created a universe called `name`, ignoring case.
+    pub async fn creator_has_universe_named(creator_id: u64, name: &str) -> mongodb::error::Result<bool> {
+        let db_client = get_db_client().await;
+        let universes: Vec<Universe> = db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .find(doc! { "creator_id": creator_id.to_string() })
+            .await?
+            .try_collect()
+            .await?;
+        let name = name.to_lowercase();
+        Ok(universes.iter().any(|universe| universe.name.to_lowercase() == name))
+    }
+
     /// Asynchronously adds a server to the universe in the database.
     ///
     /// # Parameters
@@ -312,9 +382,121 @@ impl Universe {
             global_time_modifier: self.global_time_modifier.clone(),
             time_origin_timestamp: self.time_origin_timestamp.clone(),
             creation_timestamp: self.creation_timestamp.clone(),
+            name_overrides: self.name_overrides.clone(),
+            road_slowmode_per_distance: self.road_slowmode_per_distance,
+            contest_die: self.contest_die.clone(),
+            starting_place_id: self.starting_place_id,
+            join_requires_approval: self.join_requires_approval,
+            admins: self.admins.clone(),
+            default_locale: self.default_locale.clone(),
+        }
+    }
+
+    /// Sets the custom name used by the setup for the resource named after `key`.
+    pub async fn set_name_override(&self, key: &str, value: &str) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$set": {format!("name_overrides.{key}"): value}})
+            .await
+    }
+
+    /// Sets the seconds of slowmode given to road channels per kilometer.
+    pub async fn set_road_slowmode_per_distance(&self, factor: u64) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$set": {"road_slowmode_per_distance": factor.to_string()}})
+            .await
+    }
+
+    /// Dice expression each side of a contest rolls.
+    pub fn contest_die(&self) -> &str {
+        self.contest_die.as_deref().unwrap_or(DEFAULT_CONTEST_DIE)
+    }
+
+    /// Universe `name` of `creator_id` running at the real pace, with the default settings, for the
+    /// tests to set the fields they check with the struct update syntax.
+    #[cfg(test)]
+    pub fn named(name: &str, creator_id: u64) -> Universe {
+        Universe {
+            universe_id: ObjectId::new(),
+            name: name.to_string(),
+            creator_id,
+            global_time_modifier: 100,
+            time_origin_timestamp: 0,
+            creation_timestamp: 0,
+            name_overrides: HashMap::new(),
+            road_slowmode_per_distance: 0,
+            contest_die: None,
+            starting_place_id: None,
+            join_requires_approval: false,
+            admins: vec![],
+            default_locale: None,
         }
     }
 
+    /// Sets the dice expression each side of a contest rolls.
+    pub async fn set_contest_die(&self, expression: &str) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$set": {"contest_die": expression}})
+            .await
+    }
+
+    /// Sets the place new players start in and whether joining needs an approved character.
+    pub async fn set_join_settings(&self, starting_place_id: Option<u64>, requires_approval: bool) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$set": {
+                "starting_place_id": starting_place_id.map(|id| id.to_string()),
+                "join_requires_approval": requires_approval,
+            }})
+            .await
+    }
+
+    /// Sets the default locale and the time modifier of the universe. `time_origin_timestamp`, in
+    /// milliseconds, is moved along with the modifier so that the time of the universe goes on
+    /// from where it was.
+    pub async fn update_settings(&self, default_locale: Option<&str>, global_time_modifier: u32, time_origin_timestamp: u128) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$set": {
+                "default_locale": default_locale,
+                "global_time_modifier": global_time_modifier.to_string(),
+                "time_origin_timestamp": time_origin_timestamp.to_string(),
+            }})
+            .await
+    }
+
+    /// Adds `user_id` to the admins of the universe, once.
+    pub async fn add_admin(&self, user_id: u64) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$addToSet": {"admins": user_id.to_string()}})
+            .await
+    }
+
+    /// Removes `user_id` from the admins of the universe.
+    pub async fn remove_admin(&self, user_id: u64) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<Universe>(UNIVERSES_COLLECTION_NAME)
+            .update_one(doc! {"_id": self.universe_id}, doc! {"$pull": {"admins": user_id.to_string()}})
+            .await
+    }
+
     /// Asynchronously checks if a given user owns the universe associated with the provided server ID.
     ///
     /// # Parameters
@@ -496,8 +678,8 @@ impl Universe {
     /// - `self`: The instance of the struct containing the `universe_id` to be checked.
     ///
     /// # Returns
-    /// - `Ok(true)`: If the number of servers is below the specified free limit.
-    /// - `Ok(false)`: If the number of servers has reached or exceeded the specified free limit.
+    /// - `Ok(true)`: If the number of servers is below the limit of the creator of the universe.
+    /// - `Ok(false)`: If the number of servers has reached or exceeded that limit.
     /// - `Err(&'static str)`: If there is a failure in querying the database, an error message is returned.
     ///
     /// # Errors
@@ -510,7 +692,8 @@ impl Universe {
     ///   `SERVER_COLLECTION_NAME`.
     ///
     /// # Notes
-    /// - The limit for the number of servers per universe is defined by the constant `FREE_LIMIT_UNIVERSE`.
+    /// - The limit for the number of servers per universe is the one `get_limits_for_user` gives the
+    ///   creator of the universe, `FREE_LIMIT_SERVERS_PER_UNIVERSE` unless they were granted more.
     /// - This function clones the database client for use in querying.
     ///
     /// # Example
@@ -537,12 +720,17 @@ impl Universe {
             .collection::<Server>(SERVERS_COLLECTION_NAME)
             .count_documents(filter)
             .await;
+        let servers_result_request = match servers_result_request {
+            Ok(server_count) => get_limits_for_user(self.creator_id).await.map(|limits| limits.allows_server(server_count)),
+            Err(e) => Err(e),
+        };
 
         match servers_result_request {
-            Ok(server_count) => {
-                Ok(server_count < FREE_LIMIT_SERVERS_PER_UNIVERSE as u64)
+            Ok(allowed) => Ok(allowed),
+            Err(e) => {
+                tracing::error!("failed to count the servers of a universe: {e}");
+                Err("universe__check_server_limit_failed".into())
             }
-            Err(e) => { println!("{:?}", e); return Err("universe__check_server_limit_failed".into()) }
         }
     }
 
@@ -771,6 +959,13 @@ mod test {
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .unwrap()
                 .as_millis(),
+            name_overrides: Default::default(),
+            road_slowmode_per_distance: 0,
+            contest_die: None,
+            starting_place_id: None,
+            join_requires_approval: false,
+            admins: vec![],
+            default_locale: None,
         };
         match universe.insert_universe().await {
             Ok(universe) => Ok(universe),
@@ -836,7 +1031,7 @@ mod test {
     #[tokio::test]
     async fn test_recover_universe_by_creator_id() {
         let _ = insert_universe().await;
-        let result = Universe::get_creator_universes(0).await;
+        let result = Universe::get_administrable_universes(0).await;
         delete_previously_setup().await;
         if result.is_empty(){
             println!("no universes found");
@@ -871,7 +1066,7 @@ mod test {
     #[tokio::test]
     async fn test_recover_unexisting_universe_by_id() {
         let _ = insert_universe().await;
-        let result = Universe::get_creator_universes(1).await;
+        let result = Universe::get_administrable_universes(1).await;
         if !result.is_empty(){
             println!("universes found {:?}", result);
             assert!(false)
@@ -0,0 +1,150 @@
+use mongodb::bson::doc;
+use mongodb::results::UpdateResult;
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use crate::database::db_client::get_db_client;
+use crate::database::db_namespace::{USER_LIMITS_COLLECTION_NAME, VERSEENGINE_DB_NAME};
+use crate::database::universe::{FREE_LIMIT_SERVERS_PER_UNIVERSE, FREE_LIMIT_UNIVERSE};
+
+/// Higher limits granted to a user, such as an early tester or a patron, by an owner of the bot.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UserLimits {
+    #[serde(rename = "_id")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub user_id: u64,
+
+    /// Most universes the user can create.
+    pub max_universes: u64,
+
+    /// Most servers each universe the user created can span.
+    pub max_servers_per_universe: u64,
+
+    /// Owner of the bot who granted the limits.
+    #[serde_as(as = "DisplayFromStr")]
+    pub granted_by: u64,
+
+    /// Unix timestamp (seconds) at which the user falls back to the free limits; `None` for good.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+/// Limits that apply to a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub max_universes: u64,
+    pub max_servers_per_universe: u64,
+}
+
+impl Limits {
+    /// Limits of users who were granted nothing.
+    pub fn free() -> Limits {
+        Limits {
+            max_universes: FREE_LIMIT_UNIVERSE as u64,
+            max_servers_per_universe: FREE_LIMIT_SERVERS_PER_UNIVERSE as u64,
+        }
+    }
+
+    /// Limits given by `granted` at the timestamp `now`, in seconds: the free ones when nothing
+    /// was granted or the grant expired.
+    pub fn effective(granted: Option<&UserLimits>, now: u64) -> Limits {
+        match granted {
+            Some(granted) if granted.expires_at.is_none_or(|end| now < end) => Limits {
+                max_universes: granted.max_universes,
+                max_servers_per_universe: granted.max_servers_per_universe,
+            },
+            _ => Limits::free(),
+        }
+    }
+
+    /// Whether a user who already has `universes` universes can create another one.
+    pub fn allows_universe(&self, universes: u64) -> bool {
+        universes < self.max_universes
+    }
+
+    /// Whether a universe already spanning `servers` servers can be added another one.
+    pub fn allows_server(&self, servers: u64) -> bool {
+        servers < self.max_servers_per_universe
+    }
+}
+
+impl UserLimits {
+    /// Stores the limits, replacing those the user was granted before.
+    pub async fn save(&self) -> mongodb::error::Result<UpdateResult> {
+        let db_client = get_db_client().await;
+        db_client
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<UserLimits>(USER_LIMITS_COLLECTION_NAME)
+            .replace_one(doc! {"_id": self.user_id.to_string()}, self)
+            .upsert(true)
+            .await
+    }
+}
+
+/// Limits that currently apply to `user_id`, the free ones unless higher ones were granted and
+/// have not expired.
+pub async fn get_limits_for_user(user_id: u64) -> mongodb::error::Result<Limits> {
+    let db_client = get_db_client().await;
+    let granted = db_client
+        .database(VERSEENGINE_DB_NAME)
+        .collection::<UserLimits>(USER_LIMITS_COLLECTION_NAME)
+        .find_one(doc! {"_id": user_id.to_string()})
+        .await?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    Ok(Limits::effective(granted.as_ref(), now))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn granted(expires_at: Option<u64>) -> UserLimits {
+        UserLimits { user_id: 1, max_universes: 10, max_servers_per_universe: 5, granted_by: 2, expires_at }
+    }
+
+    #[test]
+    fn test_effective_limits() {
+        assert_eq!(Limits::effective(None, 100), Limits::free());
+        let higher = Limits { max_universes: 10, max_servers_per_universe: 5 };
+        assert_eq!(Limits::effective(Some(&granted(None)), 100), higher);
+        assert_eq!(Limits::effective(Some(&granted(Some(101))), 100), higher);
+        // The grant no longer applies from its expiry on
+        assert_eq!(Limits::effective(Some(&granted(Some(100))), 100), Limits::free());
+        assert_eq!(Limits::effective(Some(&granted(Some(50))), 100), Limits::free());
+    }
+
+    #[test]
+    fn test_limit_boundaries() {
+        let limits = Limits { max_universes: 2, max_servers_per_universe: 3 };
+        assert!(limits.allows_universe(1));
+        assert!(!limits.allows_universe(2));
+        assert!(limits.allows_server(2));
+        assert!(!limits.allows_server(3));
+        let none = Limits { max_universes: 0, max_servers_per_universe: 0 };
+        assert!(!none.allows_universe(0));
+        assert!(!none.allows_server(0));
+    }
+
+    /// Limits are read back once granted and replaced by a later grant.
+    #[tokio::test]
+    async fn test_grant_and_get_limits() {
+        let user_id = rand::random::<u64>() >> 1;
+        assert_eq!(get_limits_for_user(user_id).await.unwrap(), Limits::free());
+
+        let mut limits = UserLimits { user_id, ..granted(None) };
+        limits.save().await.unwrap();
+        assert_eq!(get_limits_for_user(user_id).await.unwrap().max_universes, 10);
+        limits.expires_at = Some(1);
+        limits.save().await.unwrap();
+        assert_eq!(get_limits_for_user(user_id).await.unwrap(), Limits::free());
+
+        let _ = get_db_client().await
+            .database(VERSEENGINE_DB_NAME)
+            .collection::<UserLimits>(USER_LIMITS_COLLECTION_NAME)
+            .delete_one(doc! {"_id": user_id.to_string()})
+            .await;
+    }
+}
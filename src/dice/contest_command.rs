@@ -0,0 +1,210 @@
+use fluent::FluentArgs;
+use poise::CreateReply;
+use serenity::all::{ChannelId, Color, CreateEmbed, CreateMessage, User};
+use crate::characters::{effective_stat_value, get_active_character};
+use crate::characters::stat::autocomplete_stat;
+use crate::database::characters::Character;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::{StatType, StatValue};
+use crate::database::universe::get_universe_by_id;
+use crate::dice::roll_command::{add_bonus, group_line};
+use crate::dice::{DiceExpression, RollResult};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::info_place_sub_command::format_stat_value;
+use crate::tr;
+use crate::utility::reply::reply_with_args;
+
+/// Opposes a stat of your character to a stat of the character of another player.
+#[poise::command(slash_command, guild_only, rename = "contest")]
+pub async fn contest(
+    ctx: Context<'_>,
+    #[description = "contest.stat_a"]
+    #[autocomplete = "autocomplete_stat"]
+    stat_a: String,
+    #[description = "contest.user_b"]
+    user_b: User,
+    #[description = "contest.stat_b"]
+    #[autocomplete = "autocomplete_stat"]
+    stat_b: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let sent = match _contest(&ctx, stat_a.trim(), &user_b, stat_b.trim(), &mut args).await {
+        Ok(embed) => ctx.send(CreateReply::default().embed(embed)).await.map(|_| "reply__reply_success").map_err(Error::from),
+        Err(e) => reply_with_args(ctx, Err(e), Some(args)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// One side of a contest once rolled.
+pub struct Side {
+    pub character: String,
+    pub stat: String,
+    pub bonus: StatValue,
+    pub roll: RollResult,
+}
+
+impl Side {
+    pub fn total(&self) -> StatValue {
+        add_bonus(self.roll.total(), &self.bonus)
+    }
+}
+
+/// Who won a contest, and by how much.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// The first side won with this margin.
+    First(StatValue),
+    /// The second side won with this margin.
+    Second(StatValue),
+    Draw,
+}
+
+/// Outcome of a contest whose sides reached `first` and `second`.
+pub fn outcome(first: &StatValue, second: &StatValue) -> Outcome {
+    let margin = |winner: &StatValue, loser: &StatValue| match (winner, loser) {
+        (StatValue::I64(winner), StatValue::I64(loser)) => StatValue::I64(winner - loser),
+        (winner, loser) => StatValue::F64(winner.as_f64() - loser.as_f64()),
+    };
+    match first.as_f64().partial_cmp(&second.as_f64()) {
+        Some(std::cmp::Ordering::Greater) => Outcome::First(margin(first, second)),
+        Some(std::cmp::Ordering::Less) => Outcome::Second(margin(second, first)),
+        _ => Outcome::Draw,
+    }
+}
+
+/// Checks that `character` has the stat named `stat_name` and returns its effective value.
+/// `args` receives the character and the stat for error messages.
+///
+/// # Errors
+/// - `"contest__missing_stat"`: the character has no value for this stat.
+/// - `"character_stat__stat_not_found"`: the universe has no stat with this name.
+/// - `"roll__stat_not_numeric"`: the stat is not a number.
+/// - `"stat_formula__*"` / `"create_character__database_error"`: see [`effective_stat_value`].
+async fn side_bonus(character: &Character, stat_name: &str, args: &mut FluentArgs<'_>) -> Result<(String, StatValue), Error> {
+    args.set("character", character.name.clone());
+    args.set("stat", stat_name.to_string());
+    let (stat, value) = effective_stat_value(character, stat_name).await?;
+    if !character.stats.iter().any(|character_stat| character_stat.name == stat.name) {
+        return Err("contest__missing_stat".into());
+    }
+    if !StatType::of(&value).is_numeric() {
+        return Err("roll__stat_not_numeric".into());
+    }
+    Ok((stat.name, value))
+}
+
+/// Resolves the characters of the author and of `user_b`, rolls the contest die of the universe
+/// for each of them, and describes the result. The result is also posted in the log channel.
+///
+/// # Errors
+/// - `"create_character__no_universe_found"`: the server is not part of a universe.
+/// - `"character__no_active"` / `"character__pending_approval"` / `"character__rejected"`: the
+///   author plays no character able to take part.
+/// - `"contest__opponent_no_active"`: `user_b` plays no character.
+/// - `"contest__opponent_not_playable"`: the character of `user_b` is not approved.
+/// - `"contest__invalid_die"`: the contest die of the universe is not a valid expression.
+/// - See [`side_bonus`] for the stats.
+async fn _contest(ctx: &Context<'_>, stat_a: &str, user_b: &User, stat_b: &str, args: &mut FluentArgs<'_>) -> Result<CreateEmbed, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    let universe = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("create_character__no_universe_found".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+
+    let character_a = get_active_character(ctx).await?;
+    character_a.check_playable()?;
+    args.set("user", format!("<@{}>", user_b.id));
+    let character_b = match Character::get_active_character(server.universe_id, user_b.id.get()).await {
+        Ok(Some(character)) => character,
+        Ok(None) => return Err("contest__opponent_no_active".into()),
+        Err(_) => return Err("create_character__database_error".into()),
+    };
+    args.set("name", character_b.name.clone());
+    if character_b.check_playable().is_err() {
+        return Err("contest__opponent_not_playable".into());
+    }
+
+    args.set("expression", universe.contest_die().to_string());
+    let Ok(die) = DiceExpression::parse(universe.contest_die()) else { return Err("contest__invalid_die".into()) };
+    let (stat_a, bonus_a) = side_bonus(&character_a, stat_a, args).await?;
+    let (stat_b, bonus_b) = side_bonus(&character_b, stat_b, args).await?;
+
+    let (roll_a, roll_b) = {
+        let mut rng = rand::rng();
+        (die.roll(&mut rng), die.roll(&mut rng))
+    };
+    let first = Side { character: character_a.name, stat: stat_a, bonus: bonus_a, roll: roll_a };
+    let second = Side { character: character_b.name, stat: stat_b, bonus: bonus_b, roll: roll_b };
+    let embed = contest_embed(ctx, &first, &second);
+
+    if let Some(log_channel) = server.log_channel_id
+        && let Err(e) = ChannelId::new(log_channel.id).send_message(ctx, CreateMessage::new().embed(embed.clone())).await {
+        tracing::warn!(guild_id = server.server_id, "failed to log the contest: {e}");
+    }
+    Ok(embed)
+}
+
+/// Embed showing the rolls of both sides and the winner.
+fn contest_embed(ctx: &Context<'_>, first: &Side, second: &Side) -> CreateEmbed {
+    let side_field = |side: &Side| {
+        let mut lines: Vec<String> = side.roll.groups.iter().map(group_line).collect();
+        if side.roll.constant != 0 {
+            lines.push(format!("{:+}", side.roll.constant));
+        }
+        let mut args = FluentArgs::new();
+        args.set("stat", side.stat.clone());
+        args.set("value", format_stat_value(&side.bonus));
+        args.set("total", format_stat_value(&side.total()));
+        lines.push(tr!(*ctx, "contest__side", @message; Some(&args)));
+        (side.character.clone(), lines.join("\n"), true)
+    };
+
+    let mut args = FluentArgs::new();
+    args.set("first", first.character.clone());
+    args.set("second", second.character.clone());
+    let winner = match outcome(&first.total(), &second.total()) {
+        Outcome::First(margin) => Some((first, margin)),
+        Outcome::Second(margin) => Some((second, margin)),
+        Outcome::Draw => None,
+    };
+    let description = match winner {
+        Some((winner, margin)) => {
+            args.set("winner", winner.character.clone());
+            args.set("margin", format_stat_value(&margin));
+            tr!(*ctx, "contest__result", @winner; Some(&args))
+        }
+        None => tr!(*ctx, "contest__result", @draw; Some(&args)),
+    };
+    CreateEmbed::new()
+        .title(tr!(*ctx, "contest__result", @title; Some(&args)))
+        .description(description)
+        .color(Color::from_rgb(0xf5, 0xa6, 0x23))
+        .fields([side_field(first), side_field(second)])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(outcome(&StatValue::I64(15), &StatValue::I64(12)), Outcome::First(StatValue::I64(3)));
+        assert_eq!(outcome(&StatValue::I64(8), &StatValue::F64(10.5)), Outcome::Second(StatValue::F64(2.5)));
+        assert_eq!(outcome(&StatValue::I64(9), &StatValue::F64(9.0)), Outcome::Draw);
+    }
+
+    #[test]
+    fn test_side_total() {
+        let roll = DiceExpression::parse("1d20").unwrap().roll(&mut rand::rng());
+        let rolled = roll.total();
+        let side = Side { character: "Aria".to_string(), stat: "stealth".to_string(), bonus: StatValue::I64(4), roll };
+        assert_eq!(side.total(), StatValue::I64(rolled + 4));
+    }
+}
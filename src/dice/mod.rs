@@ -0,0 +1,316 @@
+//! Dice expressions such as `2d6+3` or `4d6kh3`, shared by `/roll` and `/contest`.
+use std::fmt;
+use rand::Rng;
+
+pub mod contest_command;
+pub mod roll_command;
+
+/// Most dice one expression may roll.
+pub static MAX_DICE: u32 = 100;
+/// Most sides a die may have.
+pub static MAX_SIDES: u32 = 999;
+
+/// Why a dice expression was rejected. Each variant names a translation key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiceError {
+    Invalid,
+    TooManyDice,
+    TooManySides,
+    InvalidKeep,
+}
+
+impl DiceError {
+    /// Translation key describing the error.
+    pub fn key(self) -> &'static str {
+        match self {
+            DiceError::Invalid => "dice__invalid_expression",
+            DiceError::TooManyDice => "dice__too_many_dice",
+            DiceError::TooManySides => "dice__too_many_sides",
+            DiceError::InvalidKeep => "dice__invalid_keep",
+        }
+    }
+}
+
+/// Dice kept from a group once rolled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Keep {
+    Highest(u32),
+    Lowest(u32),
+}
+
+/// A term of an expression, added or subtracted according to `negative`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    Dice { count: u32, sides: u32, keep: Option<Keep>, negative: bool },
+    Constant(i64),
+}
+
+/// A sum of dice groups and constants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiceExpression {
+    pub terms: Vec<Term>,
+}
+
+/// The dice rolled for one group of an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiceGroup {
+    /// The group as written, such as `4d6kh3`.
+    pub label: String,
+    pub rolls: Vec<u32>,
+    /// Whether each roll counts, following the keep rule.
+    pub kept: Vec<bool>,
+    pub negative: bool,
+}
+
+impl DiceGroup {
+    /// Sum of the kept rolls, negative for a subtracted group.
+    pub fn subtotal(&self) -> i64 {
+        let sum: i64 = self.rolls.iter().zip(&self.kept).filter(|(_, kept)| **kept).map(|(roll, _)| *roll as i64).sum();
+        if self.negative { -sum } else { sum }
+    }
+}
+
+/// Outcome of rolling an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollResult {
+    pub groups: Vec<DiceGroup>,
+    /// Sum of the constants of the expression.
+    pub constant: i64,
+}
+
+impl RollResult {
+    pub fn total(&self) -> i64 {
+        self.groups.iter().map(DiceGroup::subtotal).sum::<i64>() + self.constant
+    }
+}
+
+impl fmt::Display for Keep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Keep::Highest(n) => write!(f, "kh{n}"),
+            Keep::Lowest(n) => write!(f, "kl{n}"),
+        }
+    }
+}
+
+/// Reads the number at the start of `input`, if any, and the rest.
+fn take_number(input: &str) -> (Option<u64>, &str) {
+    let end = input.find(|c: char| !c.is_ascii_digit()).unwrap_or(input.len());
+    let (digits, rest) = input.split_at(end);
+    // Too long numbers are as invalid as missing ones
+    (digits.parse().ok().filter(|_| !digits.is_empty()), rest)
+}
+
+/// Parses one term without its sign: `NdM`, `dM`, `NdMkhK`, `NdMklK` or a constant.
+fn parse_term(input: &str, negative: bool) -> Result<Term, DiceError> {
+    let (count, rest) = take_number(input);
+    let Some(rest) = rest.strip_prefix('d') else {
+        return match (count, rest.is_empty()) {
+            (Some(value), true) => {
+                let value = i64::try_from(value).map_err(|_| DiceError::Invalid)?;
+                Ok(Term::Constant(if negative { -value } else { value }))
+            }
+            _ => Err(DiceError::Invalid),
+        };
+    };
+    // `d20` rolls one die
+    let count = count.unwrap_or(1);
+    let (sides, rest) = take_number(rest);
+    let Some(sides) = sides else { return Err(DiceError::Invalid) };
+    if count == 0 || sides == 0 {
+        return Err(DiceError::Invalid);
+    }
+    if count > MAX_DICE as u64 {
+        return Err(DiceError::TooManyDice);
+    }
+    if sides > MAX_SIDES as u64 {
+        return Err(DiceError::TooManySides);
+    }
+    let (count, sides) = (count as u32, sides as u32);
+
+    let keep = if rest.is_empty() {
+        None
+    } else {
+        let (highest, rest) = if let Some(rest) = rest.strip_prefix("kh") {
+            (true, rest)
+        } else if let Some(rest) = rest.strip_prefix("kl") {
+            (false, rest)
+        } else if let Some(rest) = rest.strip_prefix('k') {
+            (true, rest)
+        } else {
+            return Err(DiceError::Invalid);
+        };
+        let (kept, rest) = take_number(rest);
+        let Some(kept) = kept.filter(|_| rest.is_empty()) else { return Err(DiceError::Invalid) };
+        if kept == 0 || kept > count as u64 {
+            return Err(DiceError::InvalidKeep);
+        }
+        Some(if highest { Keep::Highest(kept as u32) } else { Keep::Lowest(kept as u32) })
+    };
+    Ok(Term::Dice { count, sides, keep, negative })
+}
+
+impl DiceExpression {
+    /// Parses `input`: terms such as `2d6`, `d20`, `4d6kh3`, `2d20kl1` or `5`, joined by `+` or
+    /// `-`. Case and spaces are ignored.
+    pub fn parse(input: &str) -> Result<DiceExpression, DiceError> {
+        let input: String = input.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+        if input.is_empty() {
+            return Err(DiceError::Invalid);
+        }
+        let mut terms = vec![];
+        let mut rest = input.as_str();
+        let mut negative = false;
+        if let Some(stripped) = rest.strip_prefix('-') {
+            negative = true;
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix('+') {
+            rest = stripped;
+        }
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            terms.push(parse_term(&rest[..end], negative)?);
+            if end == rest.len() {
+                break;
+            }
+            negative = rest[end..].starts_with('-');
+            rest = &rest[end + 1..];
+        }
+
+        let dice: u32 = terms.iter().map(|term| match term {
+            Term::Dice { count, .. } => *count,
+            Term::Constant(_) => 0,
+        }).sum();
+        if dice > MAX_DICE {
+            return Err(DiceError::TooManyDice);
+        }
+        Ok(DiceExpression { terms })
+    }
+
+    /// Rolls every die of the expression with `rng`.
+    pub fn roll(&self, rng: &mut impl Rng) -> RollResult {
+        let mut groups = vec![];
+        let mut constant = 0;
+        for term in &self.terms {
+            match term {
+                Term::Constant(value) => constant += value,
+                Term::Dice { count, sides, keep, negative } => {
+                    let rolls: Vec<u32> = (0..*count).map(|_| rng.random_range(1..=*sides)).collect();
+                    let kept = kept_rolls(&rolls, *keep);
+                    let keep_label = keep.map(|keep| keep.to_string()).unwrap_or_default();
+                    groups.push(DiceGroup { label: format!("{count}d{sides}{keep_label}"), rolls, kept, negative: *negative });
+                }
+            }
+        }
+        RollResult { groups, constant }
+    }
+}
+
+/// Which of `rolls` count under `keep`. Among equal rolls, the first ones are kept.
+fn kept_rolls(rolls: &[u32], keep: Option<Keep>) -> Vec<bool> {
+    let Some(keep) = keep else { return vec![true; rolls.len()] };
+    let mut order: Vec<usize> = (0..rolls.len()).collect();
+    let count = match keep {
+        Keep::Highest(count) => {
+            order.sort_by(|a, b| rolls[*b].cmp(&rolls[*a]));
+            count
+        }
+        Keep::Lowest(count) => {
+            order.sort_by_key(|index| rolls[*index]);
+            count
+        }
+    };
+    let mut kept = vec![false; rolls.len()];
+    for index in order.into_iter().take(count as usize) {
+        kept[index] = true;
+    }
+    kept
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn dice(count: u32, sides: u32, keep: Option<Keep>, negative: bool) -> Term {
+        Term::Dice { count, sides, keep, negative }
+    }
+
+    #[test]
+    fn test_parse_simple_expressions() {
+        assert_eq!(DiceExpression::parse("2d6").unwrap().terms, vec![dice(2, 6, None, false)]);
+        assert_eq!(DiceExpression::parse("d20").unwrap().terms, vec![dice(1, 20, None, false)]);
+        assert_eq!(DiceExpression::parse(" 2D6 + 3 ").unwrap().terms, vec![dice(2, 6, None, false), Term::Constant(3)]);
+        assert_eq!(DiceExpression::parse("1d8-1").unwrap().terms, vec![dice(1, 8, None, false), Term::Constant(-1)]);
+        assert_eq!(DiceExpression::parse("7").unwrap().terms, vec![Term::Constant(7)]);
+        assert_eq!(DiceExpression::parse("-1d4+2").unwrap().terms, vec![dice(1, 4, None, true), Term::Constant(2)]);
+    }
+
+    #[test]
+    fn test_parse_several_groups() {
+        assert_eq!(
+            DiceExpression::parse("1d20+2d4-1d6+5").unwrap().terms,
+            vec![dice(1, 20, None, false), dice(2, 4, None, false), dice(1, 6, None, true), Term::Constant(5)]
+        );
+    }
+
+    #[test]
+    fn test_parse_keep() {
+        assert_eq!(DiceExpression::parse("4d6kh3").unwrap().terms, vec![dice(4, 6, Some(Keep::Highest(3)), false)]);
+        assert_eq!(DiceExpression::parse("2d20kl1").unwrap().terms, vec![dice(2, 20, Some(Keep::Lowest(1)), false)]);
+        assert_eq!(DiceExpression::parse("2d20k1").unwrap().terms, vec![dice(2, 20, Some(Keep::Highest(1)), false)]);
+        assert_eq!(DiceExpression::parse("4d6kh5"), Err(DiceError::InvalidKeep));
+        assert_eq!(DiceExpression::parse("4d6kh0"), Err(DiceError::InvalidKeep));
+        assert_eq!(DiceExpression::parse("4d6kh"), Err(DiceError::Invalid));
+        assert_eq!(DiceExpression::parse("4d6kx2"), Err(DiceError::Invalid));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for input in ["", "   ", "d", "2d", "2x6", "2d6+", "+", "2d6++1", "abc", "0d6", "2d0", "3.5", "2d6 kh3 x", "99999999999999999999"] {
+            assert_eq!(DiceExpression::parse(input), Err(DiceError::Invalid), "`{input}` should be invalid");
+        }
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        assert!(DiceExpression::parse("100d6").is_ok());
+        assert_eq!(DiceExpression::parse("101d6"), Err(DiceError::TooManyDice));
+        assert_eq!(DiceExpression::parse("60d6+41d4"), Err(DiceError::TooManyDice));
+        assert!(DiceExpression::parse("1d999").is_ok());
+        assert_eq!(DiceExpression::parse("1d1000"), Err(DiceError::TooManySides));
+        assert_eq!(DiceExpression::parse("99999999999d6"), Err(DiceError::TooManyDice));
+    }
+
+    #[test]
+    fn test_roll() {
+        let expression = DiceExpression::parse("4d6kh3+2").unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let result = expression.roll(&mut rng);
+            assert_eq!(result.groups.len(), 1);
+            let group = &result.groups[0];
+            assert_eq!(group.label, "4d6kh3");
+            assert!(group.rolls.iter().all(|roll| (1..=6).contains(roll)));
+            assert_eq!(group.kept.iter().filter(|kept| **kept).count(), 3);
+            let mut sorted = group.rolls.clone();
+            sorted.sort();
+            assert_eq!(result.total(), sorted[1..].iter().map(|roll| *roll as i64).sum::<i64>() + 2);
+        }
+    }
+
+    #[test]
+    fn test_kept_rolls() {
+        assert_eq!(kept_rolls(&[3, 6, 1, 6], Some(Keep::Highest(2))), vec![false, true, false, true]);
+        assert_eq!(kept_rolls(&[3, 6, 1, 1], Some(Keep::Lowest(1))), vec![false, false, true, false]);
+        assert_eq!(kept_rolls(&[3, 6], None), vec![true, true]);
+    }
+
+    #[test]
+    fn test_negative_group() {
+        let group = DiceGroup { label: "1d6".to_string(), rolls: vec![4], kept: vec![true], negative: true };
+        let result = RollResult { groups: vec![group], constant: 10 };
+        assert_eq!(result.total(), 6);
+    }
+}
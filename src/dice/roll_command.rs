@@ -0,0 +1,124 @@
+use fluent::FluentArgs;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::characters::{effective_stat_value, get_active_character};
+use crate::characters::stat::autocomplete_stat;
+use crate::database::stats::{StatType, StatValue};
+use crate::dice::{DiceExpression, DiceGroup};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::info_place_sub_command::format_stat_value;
+use crate::tr;
+use crate::utility::reply::reply_with_args;
+
+/// Rolls dice such as `2d6+3` or `4d6kh3`, adding a stat of the played character when one is given.
+#[poise::command(slash_command, guild_only, rename = "roll")]
+pub async fn roll(
+    ctx: Context<'_>,
+    #[description = "roll.expression"]
+    expression: String,
+    #[description = "roll.stat"]
+    #[autocomplete = "autocomplete_stat"]
+    stat: Option<String>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let sent = match _roll(&ctx, expression.trim(), stat.as_deref().map(str::trim), &mut args).await {
+        Ok(embed) => ctx.send(CreateReply::default().embed(embed)).await.map(|_| "reply__reply_success").map_err(Error::from),
+        Err(e) => reply_with_args(ctx, Err(e), Some(args)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// The dice of `group`, dropped ones struck through, followed by its subtotal.
+pub fn group_line(group: &DiceGroup) -> String {
+    let dice: Vec<String> = group.rolls.iter().zip(&group.kept)
+        .map(|(roll, kept)| if *kept { roll.to_string() } else { format!("~~{roll}~~") })
+        .collect();
+    let sign = if group.negative { "-" } else { "" };
+    format!("{sign}`{}`: [{}] = {}", group.label, dice.join(", "), group.subtotal())
+}
+
+/// `total` increased by the stat value `bonus`, keeping decimals for float stats.
+pub fn add_bonus(total: i64, bonus: &StatValue) -> StatValue {
+    match bonus {
+        StatValue::F64(value) => StatValue::F64(total as f64 + value),
+        other => StatValue::I64(total + other.as_f64() as i64),
+    }
+}
+
+/// Rolls `expression` and describes the result. With `stat_name`, the effective value of this
+/// stat for the character the author plays is added to the total. `args` receives the expression
+/// and the stat for error messages.
+///
+/// # Errors
+/// - `"dice__*"`: the expression is invalid or rolls too many dice.
+/// - `"character__no_active"` / `"character__pending_approval"` / `"character__rejected"`: the
+///   author plays no character able to roll.
+/// - `"character_stat__stat_not_found"`: the universe has no stat with this name.
+/// - `"roll__stat_not_numeric"`: the stat is not a number.
+/// - `"stat_formula__*"`: the effective value of the stat could not be computed.
+async fn _roll(ctx: &Context<'_>, expression: &str, stat_name: Option<&str>, args: &mut FluentArgs<'_>) -> Result<CreateEmbed, Error> {
+    args.set("expression", expression.to_string());
+    let parsed = DiceExpression::parse(expression).map_err(|e| e.key())?;
+
+    let bonus = match stat_name {
+        Some(stat_name) => {
+            args.set("stat", stat_name.to_string());
+            let character = get_active_character(ctx).await?;
+            character.check_playable()?;
+            let (stat, value) = effective_stat_value(&character, stat_name).await?;
+            if !StatType::of(&value).is_numeric() {
+                return Err("roll__stat_not_numeric".into());
+            }
+            Some((character.name, stat.name, value))
+        }
+        None => None,
+    };
+
+    let result = parsed.roll(&mut rand::rng());
+    let mut lines: Vec<String> = result.groups.iter().map(group_line).collect();
+    if result.constant != 0 {
+        lines.push(format!("{:+}", result.constant));
+    }
+    let total = match &bonus {
+        Some((_, _, value)) => add_bonus(result.total(), value),
+        None => StatValue::I64(result.total()),
+    };
+
+    let mut title_args = FluentArgs::new();
+    title_args.set("expression", expression.to_string());
+    let mut embed = CreateEmbed::new()
+        .title(tr!(*ctx, "roll__result", @title; Some(&title_args)))
+        .color(Color::from_rgb(0xf5, 0xa6, 0x23));
+    if !lines.is_empty() {
+        embed = embed.field(tr!(*ctx, "roll__fields", @dice), lines.join("\n"), false);
+    }
+    if let Some((character, stat, value)) = &bonus {
+        let mut bonus_args = FluentArgs::new();
+        bonus_args.set("character", character.clone());
+        bonus_args.set("stat", stat.clone());
+        bonus_args.set("value", format_stat_value(value));
+        embed = embed.field(tr!(*ctx, "roll__fields", @stat), tr!(*ctx, "roll__stat_bonus", @message; Some(&bonus_args)), false);
+    }
+    Ok(embed.field(tr!(*ctx, "roll__fields", @total), format!("**{}**", format_stat_value(&total)), false))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_group_line() {
+        let group = DiceGroup { label: "4d6kh3".to_string(), rolls: vec![5, 1, 6, 3], kept: vec![true, false, true, true], negative: false };
+        assert_eq!(group_line(&group), "`4d6kh3`: [5, ~~1~~, 6, 3] = 14");
+        let group = DiceGroup { label: "1d4".to_string(), rolls: vec![2], kept: vec![true], negative: true };
+        assert_eq!(group_line(&group), "-`1d4`: [2] = -2");
+    }
+
+    #[test]
+    fn test_add_bonus() {
+        assert_eq!(add_bonus(10, &StatValue::I64(-3)), StatValue::I64(7));
+        assert_eq!(add_bonus(10, &StatValue::F64(1.5)), StatValue::F64(11.5));
+    }
+}
@@ -0,0 +1,87 @@
+use fluent::FluentArgs;
+use serenity::all::{CacheHttp, ChannelId, Color, ComponentInteraction, CreateEmbed, CreateEmbedFooter, CreateMessage, MessageFlags, Timestamp, UserId};
+use crate::database::server::{Id, Server};
+use crate::discord::poise_structs::Context;
+use crate::translation::{get_by_locale, guild_locale_or_main};
+
+/// Who made a change, and where, as shown at the bottom of an audit entry.
+struct Origin {
+    actor: UserId,
+    /// Command or interaction that made the change.
+    source: String,
+    /// Link to the message of the interaction, when others can open it.
+    link: Option<String>,
+}
+
+/// Posts the `title` and `message` of `key` in the log channel of `server`, in the language of
+/// the guild, so that moderators can follow what changed. `args` receive the author as `actor`;
+/// the embed names the command and when it ran.
+///
+/// Nothing is posted when the server has no log channel. A failure to post is only logged: it
+/// never fails the command that changed the server.
+pub async fn log_action(ctx: &Context<'_>, server: &Server, key: &str, args: FluentArgs<'_>) {
+    let origin = command_origin(ctx, false).await;
+    post(ctx, server, server.log_channel_id, LOG_COLOR, &origin, key, args).await;
+}
+
+/// Posts the `title` and `message` of `key` in the moderation channel of `server`, apart from the
+/// busy log channel, for the changes that delete something. Like [`log_action`], with a link to
+/// the reply of the command when it is not ephemeral.
+pub async fn notify_moderation(ctx: &Context<'_>, server: &Server, key: &str, args: FluentArgs<'_>) {
+    let origin = command_origin(ctx, true).await;
+    post(ctx, server, server.moderation_channel_id, MODERATION_COLOR, &origin, key, args).await;
+}
+
+/// [`log_action`] for a change made by pressing a button of `interaction`.
+pub async fn log_press(http: impl CacheHttp, interaction: &ComponentInteraction, server: &Server, key: &str, args: FluentArgs<'_>) {
+    let origin = press_origin(interaction, false);
+    post(http, server, server.log_channel_id, LOG_COLOR, &origin, key, args).await;
+}
+
+/// [`notify_moderation`] for a change made by pressing a button of `interaction`, linking to its message.
+pub async fn notify_moderation_of_press(http: impl CacheHttp, interaction: &ComponentInteraction, server: &Server, key: &str, args: FluentArgs<'_>) {
+    let origin = press_origin(interaction, true);
+    post(http, server, server.moderation_channel_id, MODERATION_COLOR, &origin, key, args).await;
+}
+
+static LOG_COLOR: Color = Color::from_rgb(112, 190, 255);
+
+static MODERATION_COLOR: Color = Color::from_rgb(0xff, 0x98, 0);
+
+fn press_origin(interaction: &ComponentInteraction, with_link: bool) -> Origin {
+    Origin {
+        actor: interaction.user.id,
+        source: interaction.data.custom_id.split(':').next().unwrap_or_default().to_string(),
+        link: with_link.then(|| interaction.message.link()),
+    }
+}
+
+async fn command_origin(ctx: &Context<'_>, with_link: bool) -> Origin {
+    let link = match ctx {
+        _ if !with_link => None,
+        Context::Prefix(prefix) => Some(prefix.msg.link()),
+        Context::Application(app) => app.interaction.get_response(ctx.http()).await.ok()
+            .filter(|message| !message.flags.is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL)))
+            .map(|message| message.link()),
+    };
+    Origin { actor: ctx.author().id, source: format!("/{}", ctx.command().qualified_name), link }
+}
+
+async fn post(http: impl CacheHttp, server: &Server, channel: Option<Id>, color: Color, origin: &Origin, key: &str, mut args: FluentArgs<'_>) {
+    let Some(channel) = channel else { return };
+    let locale = guild_locale_or_main(server.server_id).await;
+    args.set("actor", format!("<@{}>", origin.actor));
+    let mut description = get_by_locale(&locale, key, Some("message"), Some(&args));
+    if let Some(link) = &origin.link {
+        description = format!("{description}\n{link}");
+    }
+    let embed = CreateEmbed::new()
+        .title(get_by_locale(&locale, key, Some("title"), Some(&args)))
+        .description(description)
+        .footer(CreateEmbedFooter::new(&origin.source))
+        .timestamp(Timestamp::now())
+        .color(color);
+    if let Err(e) = ChannelId::new(channel.id).send_message(http, CreateMessage::new().embed(embed)).await {
+        tracing::warn!(guild_id = server.server_id, key, "failed to post an audit entry: {e}");
+    }
+}
@@ -1,5 +1,7 @@
-use serenity::all::{ChannelType, CreateChannel, GuildChannel, PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+use serenity::all::{AutoArchiveDuration, ChannelType, CreateChannel, GuildChannel, PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
 use poise::serenity_prelude::Builder;
+use crate::database::server::Id;
+use crate::utility::retry::retry_on_rate_limit;
 use crate::discord::poise_structs::Context;
 
 pub static SPACE_TAG: &str = "space";
@@ -212,16 +214,113 @@ pub fn get_universal_time_permission_set(everyone_role_id: RoleId) -> Vec<Permis
 /// println!("Created channel ID: {:?}", channel.id);
 /// ```
 pub async fn create_channel(ctx: &Context<'_>, channel_name: String, channel_type: ChannelType, position: u16, permissions: Vec<PermissionOverwrite>, category: Option<u64>) -> serenity::Result<GuildChannel> {
-    let mut channel = CreateChannel::new(channel_name)
-        .kind(channel_type)
-        .position(position)
-        .permissions(permissions);
-
-    if channel_type != ChannelType::Category {
-        if let Some(cat) = category {
-            channel = channel.category(cat);
+    let spec = ChannelSpec { position, permissions, parent: category, ..ChannelSpec::new(channel_name, channel_type) };
+    create_channel_from_spec(ctx, spec).await
+}
+
+/// Creates a channel in the current guild from a [`ChannelSpec`].
+///
+/// The parent is ignored for categories, and options Discord only accepts on some channel
+/// types are left to Discord to validate.
+pub async fn create_channel_from_spec(ctx: &Context<'_>, spec: ChannelSpec) -> serenity::Result<GuildChannel> {
+    let mut channel = CreateChannel::new(spec.name.clone())
+        .kind(spec.kind)
+        .position(spec.position)
+        .permissions(spec.permissions)
+        .nsfw(spec.nsfw);
+
+    if spec.kind != ChannelType::Category && let Some(parent) = spec.parent {
+        channel = channel.category(parent);
+    }
+    if let Some(topic) = spec.topic {
+        channel = channel.topic(topic);
+    }
+    if let Some(seconds) = spec.rate_limit_per_user {
+        channel = channel.rate_limit_per_user(seconds);
+    }
+    if let Some(duration) = spec.default_auto_archive_duration {
+        channel = channel.default_auto_archive_duration(duration);
+    }
+
+    retry_on_rate_limit(&spec.name, || channel.clone().execute(ctx.http(), ctx.guild_id().unwrap())).await
+}
+
+/// Description of a channel or category to create, passed to [`ensure_channel`] and
+/// [`create_channel_from_spec`].
+#[derive(Debug, Clone, Default)]
+pub struct ChannelSpec {
+    pub name: String,
+    pub kind: ChannelType,
+    pub position: u16,
+    pub permissions: Vec<PermissionOverwrite>,
+    pub parent: Option<u64>,
+    pub topic: Option<String>,
+    /// Slowmode, in seconds.
+    pub rate_limit_per_user: Option<u16>,
+    pub nsfw: bool,
+    /// Default archive duration of the threads and forum posts of the channel.
+    pub default_auto_archive_duration: Option<AutoArchiveDuration>,
+}
+
+impl ChannelSpec {
+    pub fn new(name: String, kind: ChannelType) -> Self {
+        ChannelSpec { name, kind, ..Default::default() }
+    }
+
+    pub fn position(mut self, position: u16) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn permissions(mut self, permissions: Vec<PermissionOverwrite>) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    pub fn parent(mut self, parent: u64) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    pub fn topic(mut self, topic: String) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    pub fn rate_limit_per_user(mut self, seconds: u16) -> Self {
+        self.rate_limit_per_user = Some(seconds);
+        self
+    }
+
+    pub fn default_auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.default_auto_archive_duration = Some(duration);
+        self
+    }
+}
+
+/// Returns the channel stored in `existing` if it still exists, or creates it from `spec` otherwise.
+///
+/// Newly created channels are pushed to `created`; a channel found on Discord is returned as is.
+/// `error_key` is returned when the creation fails. The caller stores the id of the returned
+/// channel on the server.
+pub async fn ensure_channel(
+    ctx: &Context<'_>,
+    existing: Option<Id>,
+    spec: ChannelSpec,
+    created: &mut Vec<GuildChannel>,
+    error_key: &'static str,
+) -> Result<GuildChannel, &'static str> {
+    if let Some(id) = existing
+        && let Ok(channel) = ctx.http().get_channel(id.id.into()).await
+        && let Some(channel) = channel.guild() {
+        return Ok(channel);
+    }
+
+    match create_channel_from_spec(ctx, spec).await {
+        Ok(channel) => {
+            created.push(channel.clone());
+            Ok(channel)
         }
+        Err(_) => Err(error_key),
     }
-    
-    channel.execute(ctx.http(), ctx.guild_id().unwrap()).await
 }
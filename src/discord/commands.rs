@@ -0,0 +1,82 @@
+use crate::botstats_command::handler::botstats;
+use crate::characters::character;
+use crate::dice::contest_command::contest;
+use crate::dice::roll_command::roll;
+use crate::grant_limits_command::handler::grant_limits;
+use crate::reload_translations_command::handler::reload_translations;
+use crate::discord::cooldowns::apply_cooldowns;
+use crate::discord::poise_structs::{Data, Error};
+use crate::item::item;
+use crate::characters::whereis_command::whereis;
+use crate::membership::join_command::join;
+use crate::membership::leave_command::leave;
+use crate::membership::spectate_command::spectate;
+use crate::ping_command::handler::ping;
+use crate::place::place;
+use crate::roads::road;
+use crate::start_command::handler::start;
+use crate::stats::stat;
+use crate::tip::support_command::support_command;
+use crate::travel::navigate_command::navigate;
+use crate::travel::travel__sub_command::travel;
+use crate::universe::universe;
+
+/// Returns the complete list of top-level commands exposed by the bot.
+///
+/// Command trees (`/universe`, `/place`, `/road`, ...) are registered through their parent
+/// command only; poise takes care of their subcommands. This is the single place where a new
+/// command has to be added for it to be localized by `apply_translations` and registered
+/// with Discord. The cooldowns of [`crate::discord::cooldowns::COOLDOWNS`] are set here too.
+pub fn commands() -> Vec<poise::Command<Data, Error>> {
+    let mut commands = vec![
+        ping(),
+        start(),
+        universe(),
+        place(),
+        road(),
+        stat(),
+        character(),
+        travel(),
+        navigate(),
+        roll(),
+        contest(),
+        item(),
+        join(),
+        spectate(),
+        leave(),
+        whereis(),
+        support_command(),
+        botstats(),
+        grant_limits(),
+        reload_translations(),
+    ];
+    apply_cooldowns(&mut commands);
+    commands
+}
+
+#[cfg(test)]
+mod test {
+    use crate::discord::commands::commands;
+    use crate::translation;
+
+    #[test]
+    fn test_command_trees_registered() {
+        let names: Vec<String> = commands().into_iter().map(|c| c.name).collect();
+        for name in ["ping", "start", "universe", "place", "road"] {
+            assert!(names.contains(&name.to_string()), "missing command `{name}`");
+        }
+    }
+
+    #[test]
+    fn test_subcommands_are_translated() {
+        let translations = translation::read_ftl().expect("failed to read translation files");
+        let mut commands = commands();
+        translation::apply_translations(&translations, &mut commands);
+
+        let universe = commands.iter().find(|c| c.name == "universe").unwrap();
+        assert!(!universe.subcommands.is_empty());
+        for subcommand in &universe.subcommands {
+            assert!(subcommand.description.is_some(), "`{}` has no description", subcommand.name);
+        }
+    }
+}
@@ -10,21 +10,26 @@ use std::{env};
 use poise::serenity_prelude::ClientBuilder;
 use serenity::Client;
 use poise::serenity_prelude::GatewayIntents;
-use crate::{translation};
-use crate::characters::character;
-use crate::place::place;
-use crate::roads::road;
+use crate::{translation, translation_keys};
+use crate::botstats_command::{owner_ids, OWNER_IDS_VAR};
+use crate::translation::TranslationsKey;
+use crate::discord::commands::commands;
 use crate::discord::handler::Handler;
-use crate::ping_command::handler::ping;
-use crate::start_command::handler::start;
+use crate::discord::on_error::on_error;
 use crate::discord::poise_structs::Data;
-use crate::item::item;
-use crate::tip::support_command::support_command;
-use crate::universe::universe;
-use crate::travel::travel__sub_command::travel;
+use crate::discord::telemetry::{init_tracing, post_command, pre_command};
+use crate::utility::guild_locales::GUILD_LOCALES;
+#[cfg(not(test))]
+use crate::utility::tasks::TASKS;
+
+/// Time given to the background tasks to stop, then to the work in flight to finish, once the
+/// client has stopped.
+#[cfg(not(test))]
+static TASK_SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
 
+/// Name of the environment variable holding the number of shards to start.
 #[cfg(not(test))]
-static SHARD_NUMBER: u32 = 1;
+static SHARD_COUNT_VAR: &str = "SHARD_COUNT";
 
 #[cfg(test)]
 pub(crate) static TEST_PASSED: Mutex<VecDeque<bool>> = Mutex::new(VecDeque::new());
@@ -40,14 +45,19 @@ pub(crate) static TEST_PASSED: Mutex<VecDeque<bool>> = Mutex::new(VecDeque::new(
 ///
 /// # Steps
 /// 1. Initializes logging using `tracing_subscriber`.
-/// 2. Prepares a list of commands using `ping()`, `universe()`, and `start()`.
+/// 2. Prepares the list of commands returned by [`commands`].
 /// 3. Reads and applies translation files to the commands.
 /// 4. Retrieves the Discord bot token from the `DISCORD_TOKEN` environment variable.
 /// 5. Builds `FrameworkOptions` for the bot framework, registering global commands and setting up app data.
 /// 6. Handles two build modes:
 ///     - **Production**:
 ///       - Creates a `Client` with the specified token, event handler, intents, and framework.
-///       - Starts the Discord client's shards.
+///       - Starts the background tasks of [`TASKS`](crate::utility::tasks::TASKS), such as the
+///         expiry of modifiers.
+///       - Starts `SHARD_COUNT` shards, or lets Discord decide the shard count when it is not set.
+///       - Stops every shard once `shutdown` resolves.
+///       - Stops the background tasks once the shards have stopped, and waits for the commands
+///         and database writes in flight.
 ///       - Returns the configured client.
 ///     - **Test**:
 ///       - Creates and locks a `Client` wrapped in an `Arc<Mutex<>>` for asynchronous use.
@@ -61,14 +71,20 @@ pub(crate) static TEST_PASSED: Mutex<VecDeque<bool>> = Mutex::new(VecDeque::new(
 /// - If the framework or client creation fails.
 ///
 /// # Configuration
-/// - `GatewayIntents` are configured to include `GUILD_MESSAGES`, `DIRECT_MESSAGES`, and `MESSAGE_CONTENT`.
-/// - Translations are applied using the `apply_translations` function with the data read by `read_ftl`.
+/// - `GatewayIntents` are configured to include `GUILDS`, `GUILD_MESSAGES`, `DIRECT_MESSAGES`, `MESSAGE_CONTENT` and `GUILD_MEMBERS`.
+/// - Translations are applied using the `apply_translations` function with the data read by `read_ftl`,
+///   and how much of the commands each locale localizes is logged.
+/// - The same translations are stored in the client's `TypeMap` under `TranslationsKey` for the event handler.
 ///
 /// # Environment Variables
 /// - **DISCORD_TOKEN**: The bot token required to connect to Discord.
+/// - **SHARD_COUNT** (optional): The number of shards to start. Autosharding is used when absent or invalid.
+/// - **MODIFIER_EXPIRY_INTERVAL_SECS** / **MODIFIER_EXPIRY_BATCH_SIZE** (optional): How often expired
+///   modifiers are removed, and how many documents of each kind are cleaned each time.
 ///
 /// # Framework Options
 /// - Commands are registered globally during setup.
+/// - Command errors are reported to the user through [`on_error`].
 ///
 /// # Platforms
 /// - Includes both testing and production configurations under relevant `cfg` attributes.
@@ -86,18 +102,22 @@ pub(crate) static TEST_PASSED: Mutex<VecDeque<bool>> = Mutex::new(VecDeque::new(
 ///     }
 /// }
 /// ```
-pub async fn connect_bot() -> Result<Client, ()>{
-    tracing_subscriber::fmt::init();
+pub async fn connect_bot(shutdown: impl Future<Output = ()> + Send + 'static) -> Result<Client, ()>{
+    init_tracing();
     
     
-    let mut commands= vec![ping(), universe(), start(), place(), road(), character(), travel(), support_command(), item()];
+    let mut commands = commands();
     
     
-    let translations = translation::read_ftl().expect("failed to read translation files");
-    translation::apply_translations(&translations, &mut commands);
+    let translations = translation::TRANSLATIONS.clone();
+    translation_keys::check_keys(&translations.load());
+    let coverage = translation::apply_translations(&translations.load(), &mut commands);
+    translation::log_coverage(&coverage);
+    let handler_translations = translations.clone();
     
     let token = env::var("DISCORD_TOKEN").expect("Expected a token in the environment");
-    let intents = GatewayIntents::GUILD_MESSAGES
+    let intents = GatewayIntents::GUILDS
+        | GatewayIntents::GUILD_MESSAGES
         | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT
         | GatewayIntents::GUILD_MEMBERS;
@@ -105,12 +125,17 @@ pub async fn connect_bot() -> Result<Client, ()>{
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
             commands,
+            on_error: |error| Box::pin(on_error(error)),
+            pre_command,
+            post_command,
+            owners: owner_ids(&env::var(OWNER_IDS_VAR).unwrap_or_default()),
+            initialize_owners: false,
             ..Default::default()
         })
         .setup(|ctx, _ready, framework| {
             Box::pin(async move {
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
-                Ok(Data {translations})
+                Ok(Data {translations, guild_locks: Default::default(), metrics: Default::default(), guild_locales: GUILD_LOCALES.clone(), unresolved_variable_policy: translation::UnresolvedVariablePolicy::from_env()})
             })
         })
         .build();
@@ -119,23 +144,25 @@ pub async fn connect_bot() -> Result<Client, ()>{
     #[cfg(test)]
     #[allow(unused_results)]
     {
+        drop(shutdown);
         let client = Arc::new(Mut::new(
             ClientBuilder::new(token, intents)
                 .framework(framework)
                 .event_handler(Handler)
+                .type_map_insert::<TranslationsKey>(handler_translations)
                 .await
                 .expect("Err creating client"),
         ));
 
         TEST_PASSED.lock().unwrap().push_back(false);
-        println!("start shards");
+        tracing::info!("start shards");
 
         let client_clone = Arc::clone(&client);
 
         tokio::spawn(async move {
             let client = client_clone.lock().await.start_shard(0, 1).await;
             if let Err(why) = client {
-                println!("Client error: {why:?}");
+                tracing::error!("client error: {why}");
             }
         });
 
@@ -148,6 +175,7 @@ pub async fn connect_bot() -> Result<Client, ()>{
         let mut client = ClientBuilder::new(token, intents)
                 .framework(framework)
                 .event_handler(Handler)
+                .type_map_insert::<TranslationsKey>(handler_translations)
                 .await
                 .expect("Err creating client");
         
@@ -155,21 +183,50 @@ pub async fn connect_bot() -> Result<Client, ()>{
             let mut http_client = crate::travel::logic::HTTP_CLIENT.lock().await;
             *http_client = Some(client.http.clone());
         }
+        crate::stats::expiry::setup_modifier_expiry(client.http.clone()).await;
+
+        let shard_manager = client.shard_manager.clone();
+        tokio::spawn(async move {
+            shutdown.await;
+            tracing::info!("shutdown requested, stopping the shards");
+            shard_manager.shutdown_all().await;
+        });
 
-        if let Err(why) = client.start_shards(SHARD_NUMBER).await {
-            println!("Client error: {why:?}");
+        let result = match shard_count() {
+            Some(shard_count) => {
+                tracing::info!(shard_count, "starting shards");
+                client.start_shards(shard_count).await
+            }
+            None => {
+                tracing::info!("{SHARD_COUNT_VAR} not set, starting with autosharding");
+                client.start_autosharded().await
+            }
+        };
+        if let Err(why) = result {
+            tracing::error!("Client error: {why:?}");
         }
+        TASKS.shutdown(TASK_SHUTDOWN_GRACE).await;
         return Ok(client)
     }
 }
 
+/// Reads the shard count from the `SHARD_COUNT` environment variable.
+///
+/// Returns `None` when the variable is missing, not a number or zero.
+#[cfg(not(test))]
+fn shard_count() -> Option<u32> {
+    env::var(SHARD_COUNT_VAR).ok()
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .filter(|count| *count > 0)
+}
+
 #[cfg(test)]
 mod test {
     use crate::discord::connect_bot::{connect_bot, TEST_PASSED};
 
     #[tokio::test]
     async fn test_discord_bot_connection(){
-        let _ = connect_bot().await;
+        let _ = connect_bot(std::future::pending()).await;
         assert_eq!(TEST_PASSED.try_lock().unwrap().pop_front().unwrap(), true);
     }
 }
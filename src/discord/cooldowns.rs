@@ -0,0 +1,88 @@
+use std::time::Duration;
+use crate::discord::poise_structs::{Data, Error};
+
+/// How often a command can be used by the same user and on the same guild.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cooldown {
+    pub user: Option<Duration>,
+    pub guild: Option<Duration>,
+}
+
+/// Setup and teardown create or delete every role and channel of a server.
+static SERVER_REBUILD_COOLDOWN: Cooldown = Cooldown {
+    user: Some(Duration::from_secs(60)),
+    guild: Some(Duration::from_secs(30)),
+};
+
+/// The road map reads every place and road of the universe.
+static MAP_COOLDOWN: Cooldown = Cooldown {
+    user: Some(Duration::from_secs(30)),
+    guild: Some(Duration::from_secs(10)),
+};
+
+/// The export and the import read or write every document of the universe.
+static BACKUP_COOLDOWN: Cooldown = Cooldown {
+    user: Some(Duration::from_secs(300)),
+    guild: Some(Duration::from_secs(60)),
+};
+
+/// Dice rolls are cheap but post in the channel.
+static DICE_COOLDOWN: Cooldown = Cooldown {
+    user: Some(Duration::from_secs(3)),
+    guild: None,
+};
+
+/// Cooldown of each rate-limited command, by the name it is declared with. This is the single
+/// place where they are tuned.
+pub static COOLDOWNS: [(&str, Cooldown); 8] = [
+    ("universe_setup", SERVER_REBUILD_COOLDOWN),
+    ("universe_teardown", SERVER_REBUILD_COOLDOWN),
+    ("universe_verify", MAP_COOLDOWN),
+    ("universe_export", BACKUP_COOLDOWN),
+    ("universe_import", BACKUP_COOLDOWN),
+    ("road_map", MAP_COOLDOWN),
+    ("roll", DICE_COOLDOWN),
+    ("contest", DICE_COOLDOWN),
+];
+
+/// Sets the cooldowns of [`COOLDOWNS`] on `commands` and their subcommands. Must run before the
+/// commands are translated, while they still have their declared names.
+pub fn apply_cooldowns(commands: &mut [poise::Command<Data, Error>]) {
+    for command in commands {
+        if let Some((_, cooldown)) = COOLDOWNS.iter().find(|(name, _)| *name == command.name) {
+            let mut config = command.cooldown_config.write().unwrap();
+            config.user = cooldown.user;
+            config.guild = cooldown.guild;
+        }
+        apply_cooldowns(&mut command.subcommands);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::discord::commands::commands;
+
+    /// Names of `commands` and of their subcommands that have a user cooldown.
+    fn cooled_down(commands: &[poise::Command<Data, Error>]) -> Vec<String> {
+        commands.iter()
+            .flat_map(|command| {
+                let mut names = cooled_down(&command.subcommands);
+                if command.cooldown_config.read().unwrap().user.is_some() {
+                    names.push(command.name.clone());
+                }
+                names
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_cooldowns() {
+        let commands = commands();
+        let mut names = cooled_down(&commands);
+        names.sort();
+        let mut expected: Vec<String> = COOLDOWNS.iter().map(|(name, _)| name.to_string()).collect();
+        expected.sort();
+        assert_eq!(names, expected);
+    }
+}
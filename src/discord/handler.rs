@@ -9,15 +9,28 @@ use poise::serenity_prelude::{EventHandler};
 
 #[allow(unused_imports)]
 #[cfg(not(test))] use serenity::all::ActivityData;
-use serenity::all::{CreateInteractionResponse, CreateInteractionResponseMessage, Interaction, Member};
-use crate::characters::create_character_sub_command::{accept_character, choose_character_place, delete_character, modify_character, refuse_character, submit_character};
+#[cfg(not(test))] use std::sync::atomic::{AtomicBool, Ordering};
+use serenity::all::{Color, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, ChannelId, GuildChannel, GuildId, Interaction, Member, Message, Role, RoleId, UnavailableGuild, UserId};
+use chrono::Utc;
+use fluent::FluentArgs;
+use crate::characters::create_character_sub_command::{accept_character, choose_character_place, ACCEPT_CHARACTER_CHOOSE_PLACE, delete_character, modify_character, refuse_character, submit_character};
 #[allow(unused_imports)]
 use crate::translation::{apply_translations, tr};
 use crate::tr_locale;
+use crate::translation::{tr_raw, TranslationsKey};
 use crate::travel::travel__sub_command::{travel_from_handler};
-use crate::database::server::get_server_by_id;
+use crate::database::server::{get_server_by_id, IdType};
+use crate::database::universe::get_universe_by_id;
+use crate::database::places::delete_place_by_discord_id;
+use crate::database::road::{delete_roads_by_discord_id, delete_roads_by_place};
 use crate::database::travel::SpaceType;
 use crate::travel::logic::manage_roles;
+use crate::membership::role_menu::{toggle_menu_role, ROLE_MENU_PREFIX};
+
+/// Set by the first `ready`, so that the `ready` following a reconnection does not start the
+/// background tasks a second time.
+#[cfg(not(test))]
+static BACKGROUND_TASKS_STARTED: AtomicBool = AtomicBool::new(false);
 
 /// The `Handler` struct serves as a placeholder or marker in this context.
 ///
@@ -74,18 +87,24 @@ impl EventHandler for Handler {
     ///    console; they are not propagated further.
     #[cfg(test)]
     async fn ready(&self, _ctx: Context, ready: Ready) {
-        println!("{} is connected!", ready.user.name);
+        tracing::info!("{} is connected", ready.user.name);
         match TEST_PASSED.lock(){
             Ok(mut mutex) => {mutex.push_front(true)}
-            Err(e) => {println!("{:?}", e)}
+            Err(e) => {tracing::error!("{e}")}
         }
     }
 
     #[cfg(not(test))]
-    async fn ready(&self, _ctx: Context, ready: Ready) {
-        println!("{} is connected!", ready.user.name);
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        tracing::info!(shard = ?ready.shard, "{} is connected!", ready.user.name);
+        // Background tasks are global: only start them once, from the first shard, and not again
+        // when it reconnects
+        if ctx.shard_id.0 != 0 || BACKGROUND_TASKS_STARTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
         let _ = crate::travel::logic::setup().await;
         let _ = crate::universe::time::setup_universal_time().await;
+        crate::universe::verify::setup_consistency_checker().await;
     }
 
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
@@ -99,8 +118,9 @@ impl EventHandler for Handler {
                     "create_character__refuse_character" => refuse_character(ctx.clone(), modal.clone()).await,
                     "create_character__accept_character" => accept_character(ctx.clone(), modal.clone()).await,
                     "create_character__modify_character" => modify_character(ctx.clone(), modal.clone()).await,
-                    "create_character__choose_place" => choose_character_place(ctx.clone(), modal.clone()).await,
                     "select__menu__chose_destination" => travel_from_handler(ctx.clone(), modal.clone()).await,
+                    id if id.starts_with(ACCEPT_CHARACTER_CHOOSE_PLACE) => choose_character_place(ctx.clone(), modal.clone()).await,
+                    id if id.starts_with(ROLE_MENU_PREFIX) => toggle_menu_role(ctx.clone(), modal.clone()).await,
                     _ => return,
                 };
 
@@ -117,6 +137,132 @@ impl EventHandler for Handler {
         }
     }
 
+    /// Creates a README channel with the startup instructions when the bot joins a new guild.
+    ///
+    /// The message is written in the guild's preferred locale.
+    /// Only guilds the bot has just been added to are handled (`is_new == Some(true)`), so
+    /// reconnections and resumes do nothing. The guild is skipped if the bot lacks the
+    /// `MANAGE_CHANNELS` permission or if it already has a README channel: the one tracked by its
+    /// server document, or before a universe is linked, a text channel with the README name.
+    /// Errors are logged and never propagated.
+    async fn guild_create(&self, ctx: Context, guild: Guild, is_new: Option<bool>) {
+        if is_new != Some(true) {
+            return;
+        }
+
+        let server = match get_server_by_id(guild.id.get()).await {
+            Ok(server) => server,
+            Err(e) => {
+                tracing::error!(guild_id = %guild.id, "failed to fetch server on guild_create: {e:?}");
+                return;
+            }
+        };
+        if server.as_ref().is_some_and(|server| server.readme_channel_id.is_some()) {
+            return;
+        }
+
+        let Some(translations) = ctx.data.read().await.get::<TranslationsKey>().map(|translations| translations.load_full()) else {
+            tracing::error!(guild_id = %guild.id, "translations missing from the client data");
+            return;
+        };
+        let locale = guild.preferred_locale.as_str();
+        let channel_name = tr_raw(&translations, locale, "readme_channel_name", None, None);
+        // Without a server document, the README of a previous add is only known by its name
+        if server.is_none() && guild.channels.values().any(|channel| channel.kind == ChannelType::Text && channel.name == channel_name) {
+            return;
+        }
+
+        let bot_id = ctx.cache.current_user().id;
+        let Ok(bot_member) = guild.member(&ctx, bot_id).await else {
+            tracing::warn!(guild_id = %guild.id, "bot member not found, skipping README creation");
+            return;
+        };
+        if !guild.member_permissions(&bot_member).manage_channels() {
+            tracing::warn!(guild_id = %guild.id, "missing MANAGE_CHANNELS permission, skipping README creation");
+            return;
+        }
+
+        let channel = match guild.create_channel(&ctx, CreateChannel::new(channel_name).kind(ChannelType::Text)).await {
+            Ok(channel) => channel,
+            Err(e) => {
+                tracing::error!(guild_id = %guild.id, "failed to create README channel: {e:?}");
+                return;
+            }
+        };
+
+        let embed = CreateEmbed::new()
+            .title(tr_raw(&translations, locale, "start_message", Some("title"), None))
+            .description(tr_raw(&translations, locale, "start_message", Some("description"), None))
+            .footer(CreateEmbedFooter::new("start_message"))
+            .color(Color::from_rgb(0x6f, 0x00, 0xff));
+        if let Err(e) = channel.send_message(&ctx, CreateMessage::new().embed(embed)).await {
+            tracing::error!(guild_id = %guild.id, "failed to send welcome message: {e:?}");
+        }
+
+        if let Some(mut server) = server {
+            server.readme_channel_id((channel.id.get(), IdType::Channel));
+            if let Err(e) = server.update().await {
+                tracing::error!(guild_id = %guild.id, "failed to save README channel: {e:?}");
+            }
+        }
+    }
+
+    /// Marks the guild's `Server` document as detached when the bot is removed from a guild.
+    ///
+    /// Guild outages (`incomplete.unavailable`) are ignored. The universe creator is notified by
+    /// DM when possible; the document is kept so that a later `/universe add_server` can reattach it.
+    async fn guild_delete(&self, ctx: Context, incomplete: UnavailableGuild, full: Option<Guild>) {
+        if incomplete.unavailable {
+            return;
+        }
+
+        let mut server = match get_server_by_id(incomplete.id.get()).await {
+            Ok(Some(server)) => server,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!(guild_id = %incomplete.id, "failed to fetch server on guild_delete: {e:?}");
+                return;
+            }
+        };
+
+        server.detached_at = Some(Utc::now().timestamp() as u64);
+        if let Err(e) = server.update().await {
+            tracing::error!(guild_id = %incomplete.id, "failed to mark server as detached: {e:?}");
+            return;
+        }
+        tracing::info!(guild_id = %incomplete.id, universe_id = %server.universe_id, "server detached");
+
+        let Ok(Some(universe)) = get_universe_by_id(server.universe_id).await else { return };
+        let Some(translations) = ctx.data.read().await.get::<TranslationsKey>().map(|translations| translations.load_full()) else { return };
+
+        let (locale, guild_name) = match &full {
+            Some(guild) => (guild.preferred_locale.clone(), guild.name.clone()),
+            None => ("en-US".to_string(), incomplete.id.to_string()),
+        };
+        let mut args = FluentArgs::new();
+        args.set("server", guild_name);
+        args.set("universe", universe.name.clone());
+
+        let embed = CreateEmbed::new()
+            .title(tr_raw(&translations, &locale, "guild_delete__server_detached", Some("title"), Some(&args)))
+            .description(tr_raw(&translations, &locale, "guild_delete__server_detached", Some("message"), Some(&args)))
+            .color(Color::from_rgb(0xff, 0x98, 0));
+        if let Ok(creator) = UserId::new(universe.creator_id).to_user(&ctx).await
+            && let Err(e) = creator.direct_message(&ctx, CreateMessage::new().embed(embed)).await {
+            tracing::warn!(user_id = universe.creator_id, "failed to notify universe creator: {e:?}");
+        }
+    }
+
+    /// Forgets a channel or category that was deleted outside of the bot.
+    async fn channel_delete(&self, ctx: Context, channel: GuildChannel, _messages: Option<Vec<Message>>) {
+        reconcile_deleted_resource(&ctx, channel.guild_id, channel.id.get()).await;
+    }
+
+    /// Forgets a role that was deleted outside of the bot.
+    async fn guild_role_delete(&self, ctx: Context, guild_id: GuildId, removed_role_id: RoleId, _removed_role: Option<Role>) {
+        reconcile_deleted_resource(&ctx, guild_id, removed_role_id.get()).await;
+    }
+
     async fn guild_member_addition(&self, ctx: Context, new_member: Member) {
         let guild_id = new_member.guild_id.get();
         let user_id = new_member.user.id.get();
@@ -172,4 +318,76 @@ impl EventHandler for Handler {
             }
         }
     }
-}
\ No newline at end of file
+}
+/// Removes every reference to a deleted Discord resource from the database.
+///
+/// Matching ids are cleared on the guild's `Server` document, while places whose category or
+/// role and roads whose channel or role was deleted are removed. A removed place takes its roads
+/// with it, and the role of a deleted category is deleted too; the category of a deleted role is
+/// left to the admins, since its channels may still hold roleplay. When something changed, a
+/// summary is posted to the server's log channel if one is still configured.
+async fn reconcile_deleted_resource(ctx: &Context, guild_id: GuildId, deleted_id: u64) {
+    let mut server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::error!(guild_id = %guild_id, "failed to fetch server during reconciliation: {e:?}");
+            return;
+        }
+    };
+
+    let cleared = server.clear_id(deleted_id);
+    if !cleared.is_empty() && let Err(e) = server.update().await {
+        tracing::error!(guild_id = %guild_id, "failed to clear deleted id {deleted_id}: {e:?}");
+    }
+
+    let place = match delete_place_by_discord_id(server.universe_id, deleted_id).await {
+        Ok(place) => place,
+        Err(e) => {
+            tracing::error!(guild_id = %guild_id, "failed to remove place {deleted_id}: {e:?}");
+            None
+        }
+    };
+    let mut roads = match delete_roads_by_discord_id(server.universe_id, deleted_id).await {
+        Ok(result) => result.deleted_count,
+        Err(e) => {
+            tracing::error!(guild_id = %guild_id, "failed to remove roads of {deleted_id}: {e:?}");
+            0
+        }
+    };
+    if let Some(place) = &place {
+        match delete_roads_by_place(server.universe_id, place.category_id).await {
+            Ok(result) => roads += result.deleted_count,
+            Err(e) => tracing::error!(guild_id = %guild_id, "failed to remove roads of place {}: {e:?}", place.category_id),
+        }
+        if place.role != deleted_id && let Err(e) = guild_id.delete_role(ctx, place.role).await {
+            tracing::warn!(guild_id = %guild_id, role_id = place.role, "failed to delete the role of a deleted place: {e:?}");
+        }
+    }
+    let places = u64::from(place.is_some());
+
+    if cleared.is_empty() && places == 0 && roads == 0 {
+        return;
+    }
+    tracing::info!(guild_id = %guild_id, deleted_id, ?cleared, places, roads, "reconciled deleted resource");
+
+    let Some(log_channel) = server.log_channel_id else { return };
+    let Some(translations) = ctx.data.read().await.get::<TranslationsKey>().map(|translations| translations.load_full()) else { return };
+    let locale = ctx.cache.guild(guild_id)
+        .map(|guild| guild.preferred_locale.clone())
+        .unwrap_or_else(|| "en-US".to_string());
+
+    let mut args = FluentArgs::new();
+    args.set("id", deleted_id.to_string());
+    args.set("fields", if cleared.is_empty() { "-".to_string() } else { cleared.join(", ") });
+    args.set("places", places);
+    args.set("roads", roads);
+
+    let embed = CreateEmbed::new()
+        .title(tr_raw(&translations, &locale, "reconcile__deleted_resource", Some("title"), Some(&args)))
+        .description(tr_raw(&translations, &locale, "reconcile__deleted_resource", Some("message"), Some(&args)))
+        .color(Color::from_rgb(0xff, 0x98, 0));
+    if let Err(e) = ChannelId::new(log_channel.id).send_message(ctx, CreateMessage::new().embed(embed)).await {
+        tracing::warn!(guild_id = %guild_id, "failed to log reconciliation: {e:?}");
+    }
+}
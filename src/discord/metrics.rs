@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Upper bounds, in milliseconds, of the buckets of the latency histograms. A last bucket
+/// counts the slower invocations.
+pub static LATENCY_BUCKETS_MS: [u64; 10] = [50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 30_000, 60_000];
+
+/// Invocations of one command since the start of the process.
+#[derive(Debug, Default)]
+pub struct CommandMetrics {
+    pub runs: AtomicU64,
+    pub failures: AtomicU64,
+    latencies: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl CommandMetrics {
+    fn record(&self, latency: Duration, failed: bool) {
+        self.runs.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+        let ms = latency.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS.iter().position(|bound| ms <= *bound).unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.latencies[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Upper bound of the latency of 95% of the invocations, or `None` before the first one or
+    /// when it exceeds the last bucket.
+    pub fn p95(&self) -> Option<Duration> {
+        let counts: Vec<u64> = self.latencies.iter().map(|count| count.load(Ordering::Relaxed)).collect();
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let target = (total * 95).div_ceil(100);
+        let mut seen = 0;
+        for (bucket, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return LATENCY_BUCKETS_MS.get(bucket).map(|ms| Duration::from_millis(*ms));
+            }
+        }
+        None
+    }
+}
+
+/// Counters and latency histograms of the commands, keyed by qualified command name, kept for
+/// the lifetime of the process.
+///
+/// Shared through the framework `Data` and updated by the pre and post command hooks.
+#[derive(Debug, Default, Clone)]
+pub struct Metrics(Arc<RwLock<BTreeMap<String, Arc<CommandMetrics>>>>);
+
+impl Metrics {
+    /// Counts an invocation of `command` that took `latency`.
+    pub fn record(&self, command: &str, latency: Duration, failed: bool) {
+        let known = self.0.read().unwrap_or_else(|poisoned| poisoned.into_inner()).get(command).cloned();
+        let metrics = match known {
+            Some(metrics) => metrics,
+            None => self.0.write().unwrap_or_else(|poisoned| poisoned.into_inner()).entry(command.to_string()).or_default().clone(),
+        };
+        metrics.record(latency, failed);
+    }
+
+    /// Metrics of every command invoked so far, sorted by name.
+    pub fn snapshot(&self) -> Vec<(String, Arc<CommandMetrics>)> {
+        self.0.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|(name, metrics)| (name.clone(), metrics.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let metrics = Metrics::default();
+        metrics.record("road_map", Duration::from_millis(20), false);
+        metrics.record("road_map", Duration::from_millis(20), true);
+        metrics.record("ping", Duration::from_millis(20), false);
+
+        let snapshot = metrics.snapshot();
+        let names: Vec<&str> = snapshot.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["ping", "road_map"]);
+        assert_eq!(snapshot[1].1.runs.load(Ordering::Relaxed), 2);
+        assert_eq!(snapshot[1].1.failures.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_p95() {
+        let metrics = CommandMetrics::default();
+        assert_eq!(metrics.p95(), None);
+        for _ in 0..95 {
+            metrics.record(Duration::from_millis(80), false);
+        }
+        assert_eq!(metrics.p95(), Some(Duration::from_millis(100)));
+        for _ in 0..10 {
+            metrics.record(Duration::from_millis(3_000), false);
+        }
+        assert_eq!(metrics.p95(), Some(Duration::from_millis(5_000)));
+        metrics.record(Duration::from_secs(120), false);
+        assert_eq!(metrics.p95(), Some(Duration::from_millis(5_000)));
+    }
+}
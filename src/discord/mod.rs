@@ -1,6 +1,12 @@
 pub mod connect_bot;
+pub mod commands;
+pub mod on_error;
 pub mod poise_structs;
 pub mod handler;
 pub mod channels;
 pub mod roles;
+pub mod cooldowns;
+pub mod audit;
+pub mod telemetry;
+pub mod metrics;
 pub mod lib_tuning;
\ No newline at end of file
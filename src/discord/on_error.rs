@@ -0,0 +1,75 @@
+use std::time::Duration;
+use fluent::FluentArgs;
+use poise::FrameworkError;
+use crate::discord::poise_structs::{Data, Error};
+use crate::discord::telemetry::{command_span, record_command};
+use crate::utility::reply::{reply, reply_with_args};
+
+/// Global error handler of the poise framework.
+///
+/// Command errors are translation keys, so they are sent back to the user as a red ephemeral
+/// embed through the `reply` utility. Argument parsing failures, cooldowns, owner-only commands
+/// run by others and panics get their own generic message. Every case is logged in the span of
+/// the invocation (see `command_span`).
+/// Anything else is forwarded to poise's default handler.
+pub async fn on_error(error: FrameworkError<'_, Data, Error>) {
+    match error {
+        FrameworkError::Command { error, ctx, .. } => {
+            command_span(ctx).await.in_scope(|| tracing::error!("command failed: {error}"));
+            record_command(ctx, true).await;
+            let _ = reply(ctx, Err(error)).await;
+        }
+        FrameworkError::ArgumentParse { error, input, ctx, .. } => {
+            command_span(ctx).await.in_scope(|| tracing::warn!(input = ?input, "failed to parse argument: {error}"));
+            let _ = reply(ctx, Err("error__argument_parse".into())).await;
+        }
+        FrameworkError::CooldownHit { remaining_cooldown, ctx, .. } => {
+            command_span(ctx).await.in_scope(|| tracing::debug!("cooldown hit, {}s remaining", remaining_cooldown.as_secs()));
+            let mut args = FluentArgs::new();
+            args.set("seconds", cooldown_seconds(remaining_cooldown));
+            let _ = reply_with_args(ctx, Err("error__cooldown_hit".into()), Some(args)).await;
+        }
+        FrameworkError::NotAnOwner { ctx, .. } => {
+            command_span(ctx).await.in_scope(|| tracing::warn!("owner-only command run by someone else"));
+            let _ = reply(ctx, Err("error__not_an_owner".into())).await;
+        }
+        FrameworkError::CommandPanic { payload, ctx, .. } => {
+            command_span(ctx).await.in_scope(|| tracing::error!("command panicked: {}", payload.as_deref().unwrap_or("<no payload>")));
+            record_command(ctx, true).await;
+            let _ = reply(ctx, Err("error__internal_error".into())).await;
+        }
+        other => {
+            if let Err(e) = poise::builtins::on_error(other).await {
+                tracing::error!("error while handling error: {e}");
+            }
+        }
+    }
+}
+
+/// Whole seconds left of `remaining`, rounded up so that a user never retries too early.
+pub fn cooldown_seconds(remaining: Duration) -> u64 {
+    remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::translation::get_by_locale;
+
+    #[test]
+    fn test_cooldown_seconds() {
+        assert_eq!(cooldown_seconds(Duration::from_secs(5)), 5);
+        assert_eq!(cooldown_seconds(Duration::from_millis(2100)), 3);
+        assert_eq!(cooldown_seconds(Duration::from_millis(1)), 1);
+    }
+
+    #[test]
+    fn test_cooldown_message() {
+        let mut args = FluentArgs::new();
+        args.set("seconds", cooldown_seconds(Duration::from_millis(41_500)));
+        for locale in ["en-US", "fr"] {
+            let message = get_by_locale(locale, "error__cooldown_hit", Some("message"), Some(&args));
+            assert!(message.contains("42"), "{locale}: {message}");
+        }
+    }
+}
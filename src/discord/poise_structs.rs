@@ -1,4 +1,7 @@
 use crate::translation;
+use crate::discord::metrics::Metrics;
+use crate::utility::guild_lock::GuildLocks;
+use crate::utility::guild_locales::GuildLocales;
 
 /// Represents a data structure holding translation information.
 ///
@@ -7,8 +10,17 @@ use crate::translation;
 /// # Fields
 ///
 /// * `translations` - Contains translation-related data, sourced from the `translation::Translations` module.
+///   It is shared with the serenity event handler (see `translation::TranslationsKey`) and swapped
+///   as a whole by `/reload_translations`.
 ///   This field is marked with `#[allow(dead_code)]` to suppress warnings about unused code, as it may
 ///   serve internal purposes within the crate.
+/// * `guild_locks` - Guilds on which a setup, place or road creation is running, to prevent two
+///   of them from running concurrently.
+/// * `metrics` - Invocations, failures and latencies of the commands since the bot started.
+/// * `guild_locales` - Locale of each guild and default locale of its universe, for interactions
+///   without locale and for the names the setup gives.
+/// * `unresolved_variable_policy` - What `tr!` returns for a message with a variable it cannot
+///   resolve, chosen with `UNRESOLVED_VARIABLE_POLICY`.
 ///
 /// # Visibility
 ///
@@ -16,7 +28,11 @@ use crate::translation;
 /// making it inaccessible from outside the crate to ensure encapsulation and control of its usage.
 pub struct Data {
     #[allow(dead_code)]
-    pub(crate) translations: translation::Translations,
+    pub(crate) translations: translation::SharedTranslations,
+    pub(crate) guild_locks: GuildLocks,
+    pub(crate) metrics: Metrics,
+    pub(crate) guild_locales: GuildLocales,
+    pub(crate) unresolved_variable_policy: translation::UnresolvedVariablePolicy,
 }
 
 /// A type alias for a boxed error that implements the `std::error::Error`
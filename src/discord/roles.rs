@@ -1,9 +1,13 @@
 use crate::discord::lib_tuning::guildid::RolePositions;
+use fluent::FluentArgs;
 use lazy_static::lazy_static;
 use poise::serenity_prelude::Builder;
 use serenity::all::{EditRole, GuildId, Permissions, Role, RoleId};
 use serenity::model::permissions::{PRESET_GENERAL};
-use crate::discord::poise_structs::Context;
+use crate::database::server::Id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::utility::retry::retry_on_rate_limit;
 
 lazy_static!(
     pub static ref AdminRolePermissions: Permissions =
@@ -40,14 +44,53 @@ lazy_static!(
     pub static ref EveryoneRolePermissions: Permissions = PRESET_GENERAL;
 );
 
-/// Asynchronously creates a new role in the guild with the specified name and permissions.
+/// Permissions and appearance of a role to create.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RoleOptions {
+    pub permissions: Permissions,
+    /// RGB color, the default grey when `None`.
+    pub color: Option<u32>,
+    /// Whether members of the role are displayed separately in the member list.
+    pub hoist: bool,
+    pub mentionable: bool,
+}
+
+impl RoleOptions {
+    pub fn new(permissions: Permissions) -> Self {
+        RoleOptions { permissions, ..Default::default() }
+    }
+
+    pub fn color(mut self, color: u32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = hoist;
+        self
+    }
+}
+
+/// Default options of the role the setup names after `name_key`: each role gets its own color,
+/// and the admin and moderator roles are displayed separately in the member list.
+pub fn setup_role_options(name_key: &str) -> RoleOptions {
+    match name_key {
+        "admin_role_name" => RoleOptions::new(*AdminRolePermissions).color(0xe74c3c).hoist(true),
+        "moderator_role_name" => RoleOptions::new(*ModeratorRolePermissions).color(0xe67e22).hoist(true),
+        "spectator_role_name" => RoleOptions::new(*SpectatorRolePermissions).color(0x3498db),
+        "player_role_name" => RoleOptions::new(*PlayerRolePermissions).color(0x2ecc71),
+        _ => RoleOptions::default(),
+    }
+}
+
+/// Asynchronously creates a new role in the guild with the specified name, permissions and appearance.
 ///
 /// # Arguments
 ///
 /// * `ctx` - The context within which the command is executed. This includes information such as the current guild
 ///   and available API resources.
 /// * `role_name` - A `String` representing the desired name for the new role.
-/// * `role_permissions` - A `Permissions` object specifying the permissions to be assigned to the new role.
+/// * `options` - The permissions, color, hoist and mentionable flags of the new role.
 ///
 /// # Returns
 ///
@@ -68,9 +111,9 @@ lazy_static!(
 ///
 /// async fn example(ctx: &Context<'_>) -> serenity::Result<()> {
 ///     let role_name = "Moderator".to_string();
-///     let permissions = Permissions::ADMINISTRATOR;
+///     let options = RoleOptions::new(Permissions::ADMINISTRATOR).color(0xe74c3c);
 ///
-///     let new_role = create_role(ctx, role_name, permissions).await?;
+///     let new_role = create_role(ctx, role_name, options).await?;
 ///     println!("Created role: {}", new_role.name);
 ///
 ///     Ok(())
@@ -80,11 +123,18 @@ lazy_static!(
 /// # Notes
 /// - The guild ID is retrieved from the context, so this function assumes the context is tied to a specific guild.
 /// - Ensure the bot has sufficient permissions, such as the `MANAGE_ROLES` permission, to create roles in the guild.
-pub async fn create_role(ctx: &Context<'_>, role_name: String, role_permissions: Permissions) -> serenity::Result<Role> {
-    EditRole::new()
-        .name(role_name)
-        .permissions(role_permissions)
-        .execute(ctx, (ctx.guild_id().unwrap(), None)).await
+/// - A creation refused because of a Discord rate limit is retried (see `retry_on_rate_limit`).
+pub async fn create_role(ctx: &Context<'_>, role_name: String, options: RoleOptions) -> serenity::Result<Role> {
+    let mut role = EditRole::new()
+        .name(role_name.clone())
+        .permissions(options.permissions)
+        .hoist(options.hoist)
+        .mentionable(options.mentionable);
+    if let Some(color) = options.color {
+        role = role.colour(color);
+    }
+
+    retry_on_rate_limit(&role_name, || role.clone().execute(ctx, (ctx.guild_id().unwrap(), None))).await
 }
 
 /// Edits the positions of roles in a guild.
@@ -152,4 +202,251 @@ pub async fn edit_role_positions(
     positions: Vec<(RoleId, Option<u64>)>,
 ) -> serenity::Result<Vec<Role>> {
     guild_id.reorder_roles(ctx, positions).await
-}
\ No newline at end of file
+}
+
+/// Computes the positions placing the `managed` roles directly beneath the highest role of the
+/// bot, keeping the order of `managed` (the first one ends up highest).
+///
+/// Only the managed roles are moved: Discord shifts the roles in between, so every other role
+/// keeps its order. Returns `setup__bot_role_too_low` when the bot has no role above the
+/// managed ones, since Discord refuses to move roles at or above the bot's highest role.
+pub fn managed_role_positions(guild_roles: &[Role], bot_roles: &[RoleId], managed: &[RoleId]) -> Result<Vec<(RoleId, Option<u64>)>, &'static str> {
+    let Some(bot_position) = guild_roles.iter()
+        .filter(|role| bot_roles.contains(&role.id))
+        .map(|role| role.position as u64)
+        .max() else { return Err("setup__bot_role_too_low") };
+
+    let above_bot = guild_roles.iter()
+        .any(|role| managed.contains(&role.id) && role.position as u64 >= bot_position);
+    if above_bot || bot_position <= managed.len() as u64 {
+        return Err("setup__bot_role_too_low");
+    }
+
+    Ok(managed.iter()
+        .enumerate()
+        .map(|(index, id)| (*id, Some(bot_position - 1 - index as u64)))
+        .collect())
+}
+
+/// Returns the first of the `targets` roles the bot cannot give or take, that is one at or above
+/// its highest role, or any of them when the bot has no role at all.
+pub fn unmanageable_role(guild_roles: &[Role], bot_roles: &[RoleId], targets: &[RoleId]) -> Option<RoleId> {
+    let bot_position = guild_roles.iter()
+        .filter(|role| bot_roles.contains(&role.id))
+        .map(|role| role.position)
+        .max();
+    targets.iter().copied().find(|target| {
+        let position = guild_roles.iter().find(|role| role.id == *target).map(|role| role.position);
+        match (bot_position, position) {
+            (Some(bot_position), Some(position)) => position >= bot_position,
+            (None, _) => true,
+            // Unknown roles are left for Discord to refuse
+            (Some(_), None) => false,
+        }
+    })
+}
+
+/// Permissions the bot needs to create the roles and channels of a universe, each with the key
+/// naming it when it is missing.
+pub static PREFLIGHT_PERMISSIONS: [(Permissions, &str); 2] = [
+    (Permissions::MANAGE_ROLES, "preflight__missing_manage_roles"),
+    (Permissions::MANAGE_CHANNELS, "preflight__missing_manage_channels"),
+];
+
+/// Server-wide permissions of a member with `member_roles`: those of the `everyone` role and of
+/// each of their roles.
+pub fn guild_permissions(guild_roles: &[Role], everyone: RoleId, member_roles: &[RoleId]) -> Permissions {
+    guild_roles.iter()
+        .filter(|role| role.id == everyone || member_roles.contains(&role.id))
+        .fold(Permissions::empty(), |permissions, role| permissions | role.permissions)
+}
+
+/// Keys of everything the bot with `bot_roles` lacks to set up a universe: each missing
+/// permission of [`PREFLIGHT_PERMISSIONS`], and `preflight__role_too_low` when none of its roles
+/// is above the `everyone` role, since it could then give no role at all.
+pub fn missing_permissions(guild_roles: &[Role], everyone: RoleId, bot_roles: &[RoleId]) -> Vec<&'static str> {
+    let permissions = guild_permissions(guild_roles, everyone, bot_roles);
+    let mut missing: Vec<&'static str> = PREFLIGHT_PERMISSIONS.iter()
+        .filter(|(required, _)| !permissions.administrator() && !permissions.contains(*required))
+        .map(|(_, key)| *key)
+        .collect();
+    let above_everyone = guild_roles.iter().any(|role| bot_roles.contains(&role.id) && role.id != everyone && role.position > 0);
+    if !above_everyone {
+        missing.push("preflight__role_too_low");
+    }
+    missing
+}
+
+/// Checks that the bot can create and give the roles and channels of a universe on the server,
+/// before anything is changed. `args` receives the list of everything missing, so that it can be
+/// fixed at once.
+///
+/// The check is skipped when the bot or the roles cannot be read: Discord then refuses the
+/// operations themselves.
+///
+/// # Errors
+/// - `"preflight__missing_permissions"`: the bot lacks a permission or a high enough role.
+pub async fn preflight(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let bot_id = ctx.cache().current_user().id;
+    let (Ok(bot_member), Ok(guild_roles)) = (guild_id.member(ctx, bot_id).await, guild_id.roles(ctx).await) else {
+        tracing::warn!(guild_id = guild_id.get(), "preflight skipped: the bot or the roles could not be read");
+        return Ok(());
+    };
+    let guild_roles: Vec<Role> = guild_roles.into_values().collect();
+    let missing = missing_permissions(&guild_roles, guild_id.everyone_role(), &bot_member.roles);
+    if missing.is_empty() {
+        return Ok(());
+    }
+    let list = missing.iter().map(|key| format!("- {}", tr!(*ctx, key))).collect::<Vec<_>>().join("\n");
+    args.set("permissions", list);
+    Err("preflight__missing_permissions".into())
+}
+
+/// Returns the role stored in `existing` if it is still part of `guild_roles`.
+pub fn find_role(guild_roles: &[Role], existing: Option<Id>) -> Option<Role> {
+    let id = existing?;
+    guild_roles.iter().find(|role| role.id.get() == id.id).cloned()
+}
+
+/// Returns the role stored in `existing` if it still exists, or creates it otherwise.
+///
+/// `guild_roles` are the roles currently on the guild. Newly created roles are pushed to
+/// `created`; a role found on Discord is returned as is, so it never ends up in a rollback
+/// list. `error_key` is returned when the creation fails.
+pub async fn ensure_role(
+    ctx: &Context<'_>,
+    guild_roles: &[Role],
+    existing: Option<Id>,
+    name: String,
+    options: RoleOptions,
+    created: &mut Vec<Role>,
+    error_key: &'static str,
+) -> Result<Role, &'static str> {
+    if let Some(role) = find_role(guild_roles, existing) {
+        return Ok(role);
+    }
+
+    match create_role(ctx, name, options).await {
+        Ok(role) => {
+            created.push(role.clone());
+            Ok(role)
+        }
+        Err(_) => Err(error_key),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::server::IdType;
+
+    fn role(id: u64, name: &str) -> Role {
+        role_at(id, name, 1)
+    }
+
+    fn role_at(id: u64, name: &str, position: u16) -> Role {
+        serde_json::from_value(serde_json::json!({
+            "id": id.to_string(),
+            "name": name,
+            "color": 0,
+            "colors": {"primary_color": 0},
+            "hoist": false,
+            "position": position,
+            "permissions": "0",
+            "managed": false,
+            "mentionable": false,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_find_role() {
+        let guild_roles = vec![role(1, "Admin"), role(2, "Player")];
+
+        // Stored and present on Discord: reused
+        let found = find_role(&guild_roles, Some(Id { id: 2, id_type: IdType::Role }));
+        assert_eq!(found.unwrap().name, "Player");
+
+        // Stored but deleted on Discord, or never stored: to be created
+        assert!(find_role(&guild_roles, Some(Id { id: 3, id_type: IdType::Role })).is_none());
+        assert!(find_role(&guild_roles, None).is_none());
+    }
+
+    #[test]
+    fn test_managed_role_positions() {
+        // Freshly created managed roles at the bottom, a user role and the bot's two roles above
+        let guild_roles = vec![
+            role_at(1, "@everyone", 0),
+            role_at(10, "Admin", 4),
+            role_at(11, "Moderator", 3),
+            role_at(12, "Spectator", 2),
+            role_at(13, "Player", 1),
+            role_at(20, "Members", 5),
+            role_at(30, "Bot", 6),
+            role_at(31, "Bot extra", 7),
+            role_at(40, "Owner", 8),
+        ];
+        let managed = [RoleId::new(10), RoleId::new(11), RoleId::new(12), RoleId::new(13)];
+        let bot_roles = [RoleId::new(30), RoleId::new(31)];
+
+        let positions: Vec<(u64, Option<u64>)> = managed_role_positions(&guild_roles, &bot_roles, &managed).unwrap()
+            .iter().map(|(id, position)| (id.get(), *position)).collect();
+        assert_eq!(positions, vec![(10, Some(6)), (11, Some(5)), (12, Some(4)), (13, Some(3))]);
+
+        // A reused managed role sitting above the bot cannot be moved
+        let mut above = guild_roles.clone();
+        above[1] = role_at(10, "Admin", 9);
+        assert_eq!(managed_role_positions(&above, &bot_roles, &managed), Err("setup__bot_role_too_low"));
+        assert_eq!(managed_role_positions(&guild_roles, &[], &managed), Err("setup__bot_role_too_low"));
+    }
+
+    fn role_with(id: u64, position: u16, permissions: Permissions) -> Role {
+        let mut role = role_at(id, "role", position);
+        role.permissions = permissions;
+        role
+    }
+
+    #[test]
+    fn test_missing_permissions() {
+        let everyone = RoleId::new(1);
+        let bot = [RoleId::new(30)];
+        let guild_roles = |bot_permissions| vec![
+            role_with(1, 0, Permissions::SEND_MESSAGES),
+            role_with(20, 2, Permissions::MANAGE_CHANNELS),
+            role_with(30, 1, bot_permissions),
+        ];
+
+        assert!(missing_permissions(&guild_roles(Permissions::MANAGE_ROLES | Permissions::MANAGE_CHANNELS), everyone, &bot).is_empty());
+        assert!(missing_permissions(&guild_roles(Permissions::ADMINISTRATOR), everyone, &bot).is_empty());
+        assert_eq!(missing_permissions(&guild_roles(Permissions::MANAGE_ROLES), everyone, &bot), vec!["preflight__missing_manage_channels"]);
+        assert_eq!(
+            missing_permissions(&guild_roles(Permissions::empty()), everyone, &bot),
+            vec!["preflight__missing_manage_roles", "preflight__missing_manage_channels"]
+        );
+        // Permissions of roles the bot does not have do not count, those of everyone do
+        let roles = vec![role_with(1, 0, Permissions::MANAGE_CHANNELS), role_with(30, 1, Permissions::MANAGE_ROLES)];
+        assert!(missing_permissions(&roles, everyone, &bot).is_empty());
+        assert_eq!(
+            missing_permissions(&roles, everyone, &[]),
+            vec!["preflight__missing_manage_roles", "preflight__role_too_low"]
+        );
+    }
+
+    #[test]
+    fn test_unmanageable_role() {
+        let guild_roles = vec![
+            role_at(1, "@everyone", 0),
+            role_at(12, "Spectator", 2),
+            role_at(13, "Player", 1),
+            role_at(30, "Bot", 3),
+            role_at(40, "Owner", 4),
+        ];
+        let bot_roles = [RoleId::new(30)];
+        let targets = [RoleId::new(13), RoleId::new(12)];
+        assert_eq!(unmanageable_role(&guild_roles, &bot_roles, &targets), None);
+        assert_eq!(unmanageable_role(&guild_roles, &bot_roles, &[RoleId::new(13), RoleId::new(40)]), Some(RoleId::new(40)));
+        assert_eq!(unmanageable_role(&guild_roles, &bot_roles, &[RoleId::new(30)]), Some(RoleId::new(30)));
+        assert_eq!(unmanageable_role(&guild_roles, &[], &targets), Some(RoleId::new(13)));
+        assert_eq!(unmanageable_role(&guild_roles, &[], &[]), None);
+    }
+}
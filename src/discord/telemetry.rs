@@ -0,0 +1,122 @@
+use std::str::FromStr;
+use std::time::Instant;
+use poise::BoxFuture;
+use tracing::field::Empty;
+use tracing::Span;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::Context;
+use crate::utility::tasks::{InFlight, TASKS};
+use crate::utility::guild_locales::guild_locale;
+
+/// Environment variable holding the verbosity of the logs, as comma separated
+/// `target=level` directives, e.g. `info,RpBot_reborn::travel=debug,serenity=warn`.
+pub static LOG_FILTER_VAR: &str = "RUST_LOG";
+static DEFAULT_LOG_FILTER: &str = "info";
+
+/// Filter of the logs described by `directives`, or the default `info` level when they are
+/// missing or invalid.
+pub fn log_filter(directives: Option<&str>) -> Targets {
+    directives
+        .and_then(|directives| Targets::from_str(directives).ok())
+        .unwrap_or_else(|| Targets::from_str(DEFAULT_LOG_FILTER).expect("the default filter is valid"))
+}
+
+/// Prints the logs on the standard output, filtered by [`LOG_FILTER_VAR`]. Events of the `log`
+/// crate, used by some dependencies, are forwarded to `tracing`.
+pub fn init_tracing() {
+    let directives = std::env::var(LOG_FILTER_VAR).ok();
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(log_filter(directives.as_deref()))
+        .init();
+    if let Some(directives) = directives && Targets::from_str(&directives).is_err() {
+        tracing::warn!("invalid {LOG_FILTER_VAR} \"{directives}\", falling back to \"{DEFAULT_LOG_FILTER}\"");
+    }
+}
+
+/// Span and timing of one command invocation, kept in the invocation data of its context.
+struct Invocation {
+    span: Span,
+    started: Instant,
+    /// Whether the command replied with an error, even though it returned `Ok`.
+    failed: bool,
+    /// Keeps the shutdown waiting until the invocation is over.
+    _in_flight: InFlight<'static>,
+}
+
+/// Span recording the guild, the author, the command and the universe of the invocation `ctx`.
+/// Created by [`pre_command`]; the checks running before it, such as cooldowns, get a span
+/// without universe.
+pub async fn command_span(ctx: Context<'_>) -> Span {
+    if let Some(invocation) = ctx.invocation_data::<Invocation>().await {
+        return invocation.span.clone();
+    }
+    tracing::info_span!(
+        "command",
+        guild_id = ctx.guild_id().map(|id| id.get()),
+        user_id = ctx.author().id.get(),
+        command = %ctx.command().qualified_name,
+        universe_id = Empty,
+    )
+}
+
+/// Opens the span of the invocation, starts its timer and logs it. The locales of the guild are
+/// cached for the translations to fall back on and for the names the setup gives.
+pub fn pre_command(ctx: Context<'_>) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+        let started = Instant::now();
+        guild_locale(ctx).await;
+        let span = command_span(ctx).await;
+        if let Some(guild_id) = ctx.guild_id()
+            && let Ok(Some(server)) = get_server_by_id(guild_id.get()).await {
+            span.record("universe_id", server.universe_id.to_hex());
+        }
+        span.in_scope(|| tracing::info!("command invoked"));
+        ctx.set_invocation_data(Invocation { span, started, failed: false, _in_flight: TASKS.track() }).await;
+    })
+}
+
+/// Logs the end of an invocation which returned `Ok` in its span, and counts it in the metrics.
+pub fn post_command(ctx: Context<'_>) -> BoxFuture<'_, ()> {
+    Box::pin(async move {
+        let failed = ctx.invocation_data::<Invocation>().await.is_some_and(|invocation| invocation.failed);
+        command_span(ctx).await.in_scope(|| tracing::info!(failed, "command completed"));
+        record_command(ctx, failed).await;
+    })
+}
+
+/// Marks the invocation `ctx` as failed: it replied with an error but will return `Ok`.
+pub async fn mark_failed(ctx: Context<'_>) {
+    if let Some(mut invocation) = ctx.invocation_data::<Invocation>().await {
+        invocation.failed = true;
+    }
+}
+
+/// Counts the invocation `ctx` in the metrics of the bot, with the time elapsed since
+/// [`pre_command`]. Invocations stopped before it, by a check or a cooldown, are not counted.
+pub async fn record_command(ctx: Context<'_>, failed: bool) {
+    let Some(started) = ctx.invocation_data::<Invocation>().await.map(|invocation| invocation.started) else { return };
+    ctx.data().metrics.record(&ctx.command().qualified_name, started.elapsed(), failed);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tracing::Level;
+
+    #[test]
+    fn test_log_filter() {
+        let filter = log_filter(Some("warn,RpBot_reborn::travel=debug"));
+        assert!(filter.would_enable("RpBot_reborn::travel::logic", &Level::DEBUG));
+        assert!(!filter.would_enable("serenity::gateway", &Level::INFO));
+        assert!(filter.would_enable("serenity::gateway", &Level::WARN));
+
+        for default in [log_filter(None), log_filter(Some("=!not a filter"))] {
+            assert!(default.would_enable("RpBot_reborn", &Level::INFO));
+            assert!(!default.would_enable("RpBot_reborn", &Level::DEBUG));
+        }
+    }
+}
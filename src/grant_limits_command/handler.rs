@@ -0,0 +1,55 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use fluent::FluentArgs;
+use serenity::all::User;
+use crate::database::user_limits::UserLimits;
+use crate::discord::poise_structs::{Context, Error};
+use crate::utility::reply::reply_with_args;
+
+/// Seconds in a day, the unit of the duration of a grant.
+static DAY_SECS: u64 = 24 * 60 * 60;
+
+/// Sets how many universes a user can create and how many servers each of them can span.
+///
+/// Only the owners listed in `OWNER_IDS` can run it.
+#[poise::command(slash_command, owners_only, rename = "grant_limits")]
+pub async fn grant_limits(
+    ctx: Context<'_>,
+    #[description = "grant_limits.user"]
+    user: User,
+    #[description = "grant_limits.max_universes"]
+    max_universes: u64,
+    #[description = "grant_limits.max_servers_per_universe"]
+    max_servers_per_universe: u64,
+    #[description = "grant_limits.days"]
+    #[min = 1]
+    days: Option<u64>,
+) -> Result<(), Error> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs();
+    let limits = UserLimits {
+        user_id: user.id.get(),
+        max_universes,
+        max_servers_per_universe,
+        granted_by: ctx.author().id.get(),
+        expires_at: days.map(|days| now + days * DAY_SECS),
+    };
+
+    let mut args = FluentArgs::new();
+    args.set("user", format!("<@{}>", user.id.get()));
+    args.set("max_universes", max_universes);
+    args.set("max_servers_per_universe", max_servers_per_universe);
+    let result = match limits.save().await {
+        Ok(_) => match limits.expires_at {
+            Some(expires_at) => {
+                args.set("expires_at", format!("<t:{expires_at}:f>"));
+                Ok("grant_limits__success_until")
+            }
+            None => Ok("grant_limits__success"),
+        },
+        Err(e) => {
+            tracing::error!(user_id = user.id.get(), "failed to grant limits: {e}");
+            Err("grant_limits__failed".into())
+        }
+    };
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
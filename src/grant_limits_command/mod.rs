@@ -0,0 +1,3 @@
+//! A module for the owner-only 'grant_limits' command, raising the limits of a user.
+
+pub mod handler;
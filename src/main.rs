@@ -1,5 +1,9 @@
 mod ping_command;
+mod botstats_command;
+mod grant_limits_command;
+mod reload_translations_command;
 mod translation;
+mod translation_keys;
 mod database;
 mod discord;
 mod bson_modifiers;
@@ -8,10 +12,13 @@ pub mod universe;
 mod utility;
 mod place;
 mod roads;
+mod stats;
 mod characters;
+mod dice;
 mod travel;
 mod tip;
 mod item;
+mod membership;
 
 use discord::poise_structs::{Context, Data, Error};
 use crate::database::db_client::constraint;
@@ -29,6 +36,7 @@ use dotenv::dotenv;
 ///      - This appears to perform some constraints or precondition checks. Implementation details are contained in the respective function definition.
 ///    - `connect_bot()`
 ///      - This function is presumably responsible for establishing a connection to a bot or initializing bot functionality.
+///      - It stops the bot once [`shutdown_signal`] resolves, letting the work in flight finish.
 ///
 /// ## Notes
 /// - The `#[tokio::main(flavor = "multi_thread")]` attribute indicates that the Tokio runtime is configured with a multi-threaded flavor, allowing concurrent execution of tasks.
@@ -52,5 +60,32 @@ async fn main() {
     let _ = database::db_client::get_db_client().await;
 
     constraint().await;
-    let _ = connect_bot().await;
+    let _ = connect_bot(shutdown_signal()).await;
+}
+
+/// Resolves on ctrl-c or, on Unix, on SIGTERM as sent by `docker stop` and systemd.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::warn!("cannot listen for ctrl-c: {e}");
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut terminate) => { terminate.recv().await; }
+            Err(e) => {
+                tracing::warn!("cannot listen for SIGTERM: {e}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+use fluent::FluentArgs;
+use serenity::all::{CacheHttp, Member, RoleId};
+use crate::characters::get_active_character;
+use crate::database::places::check_existing_place;
+use crate::database::server::{get_server_by_id, Server};
+use crate::database::universe::{get_universe_by_id, Universe};
+use crate::discord::poise_structs::{Context, Error};
+use crate::discord::audit::log_action;
+use crate::membership::check_manageable;
+use crate::utility::reply::reply_with_args;
+
+/// Joins the game: gives you the player role and the role of the starting place.
+#[poise::command(slash_command, guild_only, rename = "join")]
+pub async fn join(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _join(&ctx, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Gives the author the player role of the server with [`join_member`]. When the universe
+/// requires it, the author must play an approved character. `args` receives the starting place.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"character__no_active"` / `"character__pending_approval"` / `"character__rejected"`: the
+///   universe requires an approved character and the author has none.
+/// - `"membership__role_failed"`: the member could not be fetched.
+/// - `"verify__database_error"`: a query failed.
+/// - The errors of [`join_member`].
+async fn _join(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    let universe = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    if universe.join_requires_approval {
+        get_active_character(ctx).await?.check_playable()?;
+    }
+
+    let Some(member) = ctx.author_member().await else { return Err("membership__role_failed".into()) };
+    let key = join_member(ctx, &member, &server, &universe, args).await?;
+
+    let mut log_args = FluentArgs::new();
+    log_args.set("user", format!("<@{}>", ctx.author().id));
+    log_action(ctx, &server, "join__log", log_args).await;
+    Ok(key)
+}
+
+/// Gives `member` the player role of `server` in place of the spectator role, and the role of
+/// the starting place of `universe` when it has one on this server. Shared by `/join` and the
+/// role menu, which check beforehand whether the universe requires an approved character.
+/// `args` receives the starting place.
+///
+/// # Errors
+/// - `"membership__setup_required"`: the server has no player role.
+/// - `"join__already_player"`: the member already has the player role.
+/// - `"membership__bot_role_too_low"`: a role to change is out of reach of the bot.
+/// - `"membership__role_failed"`: the roles could not be changed.
+pub async fn join_member(http: impl CacheHttp, member: &Member, server: &Server, universe: &Universe, args: &mut FluentArgs<'_>) -> Result<&'static str, &'static str> {
+    let Some(player_role) = server.player_role_id else { return Err("membership__setup_required") };
+    if member.roles.contains(&player_role.id.into()) {
+        return Err("join__already_player");
+    }
+    let starting_place = match universe.starting_place_id {
+        Some(place_id) => check_existing_place(server.universe_id, place_id).await.ok().flatten()
+            .filter(|place| place.server_id == member.guild_id.get()),
+        None => None,
+    };
+    args.set("place", starting_place.as_ref().map(|place| place.name.clone()).unwrap_or_else(|| "-".to_string()));
+    let spectator_role = server.spectator_role_id
+        .map(|role| RoleId::new(role.id))
+        .filter(|role_id| member.roles.contains(role_id));
+    let role_ids: Vec<RoleId> = [Some(RoleId::new(player_role.id)), spectator_role, starting_place.as_ref().map(|place| RoleId::new(place.role))]
+        .into_iter().flatten().collect();
+    check_manageable(&http, member.guild_id, &role_ids, args).await?;
+
+    let Ok(_) = member.add_role(http.http(), player_role.id).await else { return Err("membership__role_failed") };
+    if let Some(spectator_role) = spectator_role {
+        let Ok(_) = member.remove_role(http.http(), spectator_role).await else { return Err("membership__role_failed") };
+    }
+    if let Some(place) = &starting_place {
+        let Ok(_) = member.add_role(http.http(), place.role).await else { return Err("membership__role_failed") };
+    }
+    Ok(if starting_place.is_some() { "join__success_with_place" } else { "join__success" })
+}
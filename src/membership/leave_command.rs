@@ -0,0 +1,85 @@
+use fluent::FluentArgs;
+use futures::TryStreamExt;
+use serenity::all::{CacheHttp, Member, RoleId};
+use crate::database::characters::Character;
+use crate::database::places::get_places_by_universe_id;
+use crate::database::road::get_roads_by_universe_id;
+use crate::database::server::{get_server_by_id, Server};
+use crate::discord::poise_structs::{Context, Error};
+use crate::discord::audit::log_action;
+use crate::membership::check_manageable;
+use crate::travel::logic::stop_travel;
+use crate::utility::reply::reply_with_args;
+
+/// Leaves the game: takes back your player, spectator, place and road roles.
+#[poise::command(slash_command, guild_only, rename = "leave")]
+pub async fn leave(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _leave(&ctx, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Takes back from the author their roles with [`leave_member`].
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"membership__role_failed"`: the member could not be fetched.
+/// - `"verify__database_error"`: the server could not be fetched.
+/// - The errors of [`leave_member`].
+async fn _leave(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    let Some(member) = ctx.author_member().await else { return Err("membership__role_failed".into()) };
+    let key = leave_member(ctx, &member, &server, args).await?;
+
+    let mut log_args = FluentArgs::new();
+    log_args.set("user", format!("<@{}>", ctx.author().id));
+    log_action(ctx, &server, "leave__log", log_args).await;
+    Ok(key)
+}
+
+/// Takes back from `member` the player and spectator roles of `server` and the roles of the
+/// places and roads of its universe, stops their journey and makes them play no character.
+/// Shared by `/leave` and the role menu.
+///
+/// # Errors
+/// - `"membership__setup_required"`: the server has no player or spectator role.
+/// - `"leave__not_in_game"`: the member has none of these roles.
+/// - `"membership__bot_role_too_low"`: a role to take back is out of reach of the bot.
+/// - `"membership__role_failed"`: the roles could not be taken back.
+/// - `"verify__database_error"`: a query failed.
+pub async fn leave_member(http: impl CacheHttp, member: &Member, server: &Server, args: &mut FluentArgs<'_>) -> Result<&'static str, &'static str> {
+    let (Some(player_role), Some(spectator_role)) = (server.player_role_id, server.spectator_role_id) else {
+        return Err("membership__setup_required")
+    };
+
+    let mut role_ids = vec![RoleId::new(player_role.id), RoleId::new(spectator_role.id)];
+    let Ok(mut places) = get_places_by_universe_id(server.universe_id).await else { return Err("verify__database_error") };
+    while let Some(place) = places.try_next().await.map_err(|_| "verify__database_error")? {
+        role_ids.push(RoleId::new(place.role));
+    }
+    let Ok(mut roads) = get_roads_by_universe_id(server.universe_id).await else { return Err("verify__database_error") };
+    while let Some(road) = roads.try_next().await.map_err(|_| "verify__database_error")? {
+        role_ids.push(RoleId::new(road.role_id));
+    }
+
+    role_ids.retain(|role_id| member.roles.contains(role_id));
+    if role_ids.is_empty() {
+        return Err("leave__not_in_game");
+    }
+    check_manageable(&http, member.guild_id, &role_ids, args).await?;
+    let Ok(_) = member.remove_roles(http.http(), &role_ids).await else { return Err("membership__role_failed") };
+
+    let user_id = member.user.id.get();
+    // Players who are not traveling have no journey to stop
+    let _ = stop_travel(user_id).await;
+    if Character::deactivate_all(server.universe_id, user_id).await.is_err() {
+        return Err("verify__database_error");
+    }
+    Ok("leave__success")
+}
@@ -0,0 +1,28 @@
+//! Self-service commands giving players the roles of the universe.
+use fluent::FluentArgs;
+use serenity::all::{CacheHttp, GuildId, RoleId};
+use crate::discord::roles::unmanageable_role;
+
+pub mod join_command;
+pub mod leave_command;
+pub mod role_menu;
+pub mod spectate_command;
+
+/// Checks that the bot can give and take every role of `role_ids` in `guild_id`, Discord refusing
+/// to change roles at or above the highest role of the bot. `args` receives the first role out of
+/// reach.
+///
+/// # Errors
+/// - `"membership__bot_role_too_low"`: a role is at or above the highest role of the bot.
+/// - `"membership__role_failed"`: the roles of the guild or of the bot could not be fetched.
+pub async fn check_manageable(http: impl CacheHttp, guild_id: GuildId, role_ids: &[RoleId], args: &mut FluentArgs<'_>) -> Result<(), &'static str> {
+    let Some(bot_id) = http.cache().map(|cache| cache.current_user().id) else { return Err("membership__role_failed") };
+    let Ok(bot_member) = guild_id.member(&http, bot_id).await else { return Err("membership__role_failed") };
+    let Ok(guild_roles) = guild_id.roles(http.http()).await else { return Err("membership__role_failed") };
+    let guild_roles: Vec<_> = guild_roles.into_values().collect();
+    if let Some(role_id) = unmanageable_role(&guild_roles, &bot_member.roles, role_ids) {
+        args.set("role", format!("<@&{role_id}>"));
+        return Err("membership__bot_role_too_low");
+    }
+    Ok(())
+}
@@ -0,0 +1,228 @@
+use fluent::FluentArgs;
+use serenity::all::{ButtonStyle, ChannelId, Color, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, Http, MessageId, RoleId};
+use serenity::client::Context as SerenityContext;
+use crate::database::server::{get_server_by_id, Server};
+use crate::database::universe::get_universe_by_id;
+use crate::discord::audit::log_press;
+use crate::discord::poise_structs::Error;
+use crate::membership::check_manageable;
+use crate::membership::join_command::join_member;
+use crate::membership::leave_command::leave_member;
+use crate::translation::get_by_locale;
+use crate::tr_locale;
+
+/// Prefix of the custom ids of the role menu buttons, followed by the name of the role.
+pub static ROLE_MENU_PREFIX: &str = "role_menu__";
+
+/// Role given and taken by a button of the role menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuRole {
+    Player,
+    Spectator,
+}
+
+impl MenuRole {
+    /// Every role of the menu, in the order of its buttons.
+    pub const ALL: [MenuRole; 2] = [MenuRole::Player, MenuRole::Spectator];
+
+    /// Name of the role in the custom id and in the translation keys.
+    fn name(self) -> &'static str {
+        match self {
+            MenuRole::Player => "player",
+            MenuRole::Spectator => "spectator",
+        }
+    }
+
+    /// Custom id of the button of this role.
+    fn custom_id(self) -> String {
+        format!("{ROLE_MENU_PREFIX}{}", self.name())
+    }
+
+    /// Role of a role menu button custom id.
+    pub fn parse(custom_id: &str) -> Option<Self> {
+        let name = custom_id.strip_prefix(ROLE_MENU_PREFIX)?;
+        Self::ALL.into_iter().find(|role| role.name() == name)
+    }
+
+    /// Id of this role on `server`, `None` before the setup created it.
+    fn role_id(self, server: &Server) -> Option<RoleId> {
+        let id = match self {
+            MenuRole::Player => server.player_role_id,
+            MenuRole::Spectator => server.spectator_role_id,
+        };
+        id.map(|id| RoleId::new(id.id))
+    }
+}
+
+/// Change of the roles of a member pressing a button of the role menu.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RoleToggle {
+    /// Gives the role the member does not hold yet.
+    Add { role: RoleId },
+    /// Takes back the role the member already holds.
+    Remove { role: RoleId },
+}
+
+/// What pressing the button of `role` does to a member of `server` holding `member_roles`.
+///
+/// Like `/spectate`, players cannot become spectators without leaving the game first.
+///
+/// # Errors
+/// - `"membership__setup_required"`: the server has no such role.
+/// - `"spectate__is_player"`: a player asked for the spectator role.
+pub fn toggle_of(role: MenuRole, server: &Server, member_roles: &[RoleId]) -> Result<RoleToggle, &'static str> {
+    let Some(role_id) = role.role_id(server) else { return Err("membership__setup_required") };
+    if member_roles.contains(&role_id) {
+        return Ok(RoleToggle::Remove { role: role_id });
+    }
+    let is_player = MenuRole::Player.role_id(server).is_some_and(|id| member_roles.contains(&id));
+    if role == MenuRole::Spectator && is_player {
+        return Err("spectate__is_player");
+    }
+    Ok(RoleToggle::Add { role: role_id })
+}
+
+/// Posts the role menu of `server` in its NRP general channel, in `locale`, and returns the id of
+/// the message. The menu already posted is kept when it still exists, so that running the setup
+/// again does not post it twice.
+///
+/// # Errors
+/// - `"role_menu__no_channel"`: the server has no NRP general channel.
+/// - `"role_menu__post_failed"`: the message could not be sent.
+pub async fn post_role_menu(http: &Http, server: &Server, locale: &str) -> Result<MessageId, &'static str> {
+    let Some(channel) = &server.nrp_general_channel_id else { return Err("role_menu__no_channel") };
+    let channel = ChannelId::new(channel.id);
+    if let Some(message_id) = server.role_menu_message_id
+        && channel.message(http, message_id).await.is_ok() {
+        return Ok(MessageId::new(message_id));
+    }
+
+    let buttons = MenuRole::ALL.into_iter()
+        .map(|role| CreateButton::new(role.custom_id())
+            .label(get_by_locale(locale, "role_menu__button", Some(role.name()), None))
+            .style(if role == MenuRole::Player { ButtonStyle::Primary } else { ButtonStyle::Secondary }))
+        .collect();
+    let embed = CreateEmbed::new()
+        .title(get_by_locale(locale, "role_menu__menu", Some("title"), None))
+        .description(get_by_locale(locale, "role_menu__menu", Some("message"), None))
+        .color(Color::from_rgb(0x6f, 0x00, 0xff));
+    let message = CreateMessage::new().embed(embed).components(vec![CreateActionRow::Buttons(buttons)]);
+    match channel.send_message(http, message).await {
+        Ok(message) => Ok(message.id),
+        Err(e) => {
+            tracing::warn!(guild_id = server.server_id, channel_id = channel.get(), "failed to post the role menu: {e}");
+            Err("role_menu__post_failed")
+        }
+    }
+}
+
+/// Gives or takes back the role of the pressed role menu button, then tells the member which in
+/// an ephemeral reply, in their language. Refusals are replied the same way.
+pub async fn toggle_menu_role(ctx: SerenityContext, component_interaction: ComponentInteraction) -> Result<&'static str, Error> {
+    let mut args = FluentArgs::new();
+    let (key, color) = match _toggle_menu_role(&ctx, &component_interaction, &mut args).await {
+        Ok(key) => (key, Color::from_rgb(0, 255, 0)),
+        Err(key) => (key, Color::from_rgb(255, 0, 0)),
+    };
+    let locale = component_interaction.locale.as_str();
+    let embed = CreateEmbed::new()
+        .title(tr_locale!(locale, key))
+        .description(get_by_locale(locale, key, Some("message"), Some(&args)))
+        .color(color);
+    let response = CreateInteractionResponse::Message(CreateInteractionResponseMessage::new().embed(embed).ephemeral(true));
+    let Ok(_) = component_interaction.create_response(&ctx, response).await else { return Err("reply__reply_failed".into()) };
+    Ok(key)
+}
+
+/// Becoming or no longer being a player goes through [`join_member`] and [`leave_member`], as
+/// `/join` and `/leave` do; the spectator role is given and taken back like `/spectate` does.
+/// Each change is written in the log channel of the server.
+///
+/// # Errors
+/// - `"role_menu__unknown_button"`: the custom id is not one of the role menu.
+/// - `"role_menu__server_not_found"`: the server is not part of a universe.
+/// - `"role_menu__database_error"`: a query failed.
+/// - `"role_menu__approval_required"`: the universe approves players, who must use `/join`.
+/// - `"membership__bot_role_too_low"`: the spectator role is out of reach of the bot.
+/// - `"membership__role_failed"`: the roles could not be changed.
+/// - The errors of [`toggle_of`], [`join_member`] and [`leave_member`].
+async fn _toggle_menu_role(ctx: &SerenityContext, component_interaction: &ComponentInteraction, args: &mut FluentArgs<'_>) -> Result<&'static str, &'static str> {
+    let Some(role) = MenuRole::parse(&component_interaction.data.custom_id) else { return Err("role_menu__unknown_button") };
+    let (Some(guild_id), Some(member)) = (component_interaction.guild_id, &component_interaction.member) else { return Err("membership__role_failed") };
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("role_menu__server_not_found"),
+        Err(_) => return Err("role_menu__database_error"),
+    };
+
+    let (key, log_key) = match (role, toggle_of(role, &server, &member.roles)?) {
+        (MenuRole::Player, RoleToggle::Add { .. }) => {
+            let universe = match get_universe_by_id(server.universe_id).await {
+                Ok(Some(universe)) => universe,
+                Ok(None) => return Err("role_menu__server_not_found"),
+                Err(_) => return Err("role_menu__database_error"),
+            };
+            if universe.join_requires_approval {
+                return Err("role_menu__approval_required");
+            }
+            (join_member(ctx, member, &server, &universe, args).await?, "join__log")
+        }
+        (MenuRole::Player, RoleToggle::Remove { .. }) => {
+            let key = leave_member(ctx, member, &server, args).await
+                .map_err(|key| if key == "verify__database_error" { "role_menu__database_error" } else { key })?;
+            (key, "leave__log")
+        }
+        (MenuRole::Spectator, RoleToggle::Add { role: role_id }) => {
+            check_manageable(ctx, guild_id, &[role_id], args).await?;
+            let Ok(_) = member.add_role(ctx, role_id).await else { return Err("membership__role_failed") };
+            ("role_menu__spectator_added", "spectate__log")
+        }
+        (MenuRole::Spectator, RoleToggle::Remove { role: role_id }) => {
+            check_manageable(ctx, guild_id, &[role_id], args).await?;
+            let Ok(_) = member.remove_role(ctx, role_id).await else { return Err("membership__role_failed") };
+            ("role_menu__spectator_removed", "leave__log")
+        }
+    };
+
+    let mut log_args = FluentArgs::new();
+    log_args.set("user", format!("<@{}>", member.user.id));
+    log_press(ctx, component_interaction, &server, log_key, log_args).await;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::database::server::IdType;
+    use super::*;
+
+    fn server() -> Server {
+        Server {
+            player_role_id: Some((1, IdType::Role).into()),
+            spectator_role_id: Some((2, IdType::Role).into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_role_menu_ids() {
+        for role in MenuRole::ALL {
+            assert_eq!(MenuRole::parse(&role.custom_id()), Some(role));
+        }
+        assert_eq!(MenuRole::parse(&format!("{ROLE_MENU_PREFIX}moderator")), None);
+        assert_eq!(MenuRole::parse("create_character__accept_character"), None);
+    }
+
+    #[test]
+    fn test_toggle_of() {
+        let (player, spectator) = (RoleId::new(1), RoleId::new(2));
+        let server = server();
+
+        assert_eq!(toggle_of(MenuRole::Player, &server, &[]), Ok(RoleToggle::Add { role: player }));
+        assert_eq!(toggle_of(MenuRole::Player, &server, &[spectator]), Ok(RoleToggle::Add { role: player }));
+        assert_eq!(toggle_of(MenuRole::Player, &server, &[player]), Ok(RoleToggle::Remove { role: player }));
+        assert_eq!(toggle_of(MenuRole::Spectator, &server, &[]), Ok(RoleToggle::Add { role: spectator }));
+        assert_eq!(toggle_of(MenuRole::Spectator, &server, &[spectator]), Ok(RoleToggle::Remove { role: spectator }));
+        assert_eq!(toggle_of(MenuRole::Spectator, &server, &[player]), Err("spectate__is_player"));
+        assert_eq!(toggle_of(MenuRole::Spectator, &Server::default(), &[]), Err("membership__setup_required"));
+    }
+}
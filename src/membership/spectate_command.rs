@@ -0,0 +1,54 @@
+use fluent::FluentArgs;
+use serenity::all::RoleId;
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::discord::audit::log_action;
+use crate::membership::check_manageable;
+use crate::utility::reply::reply_with_args;
+
+/// Watches the game: gives you the spectator role, which can read but not play.
+#[poise::command(slash_command, guild_only, rename = "spectate")]
+pub async fn spectate(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _spectate(&ctx, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Gives the author the spectator role of the server. Players must leave the game first, the
+/// player role granting more than the spectator one.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"membership__setup_required"`: the server has no spectator role.
+/// - `"spectate__already"`: the author already has the spectator role.
+/// - `"spectate__is_player"`: the author has the player role.
+/// - `"membership__bot_role_too_low"`: the spectator role is out of reach of the bot.
+/// - `"membership__role_failed"`: the role could not be given.
+/// - `"verify__database_error"`: a query failed.
+async fn _spectate(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    let Some(spectator_role) = server.spectator_role_id else { return Err("membership__setup_required".into()) };
+    let spectator_role = RoleId::new(spectator_role.id);
+
+    let Some(member) = ctx.author_member().await else { return Err("membership__role_failed".into()) };
+    if member.roles.contains(&spectator_role) {
+        return Err("spectate__already".into());
+    }
+    if let Some(player_role) = server.player_role_id
+        && member.roles.contains(&RoleId::new(player_role.id)) {
+        return Err("spectate__is_player".into());
+    }
+    check_manageable(ctx, member.guild_id, &[spectator_role], args).await?;
+    let Ok(_) = member.add_role(ctx, spectator_role).await else { return Err("membership__role_failed".into()) };
+
+    let mut log_args = FluentArgs::new();
+    log_args.set("user", format!("<@{}>", ctx.author().id));
+    log_action(ctx, &server, "spectate__log", log_args).await;
+    Ok("spectate__success")
+}
@@ -1,7 +1,15 @@
+use std::time::Duration;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::db_client::ping_db;
 use crate::ping_command::ping_data;
 use crate::discord::poise_structs::*;
+use crate::tr;
 
-/// Responds to a "ping" command with "Pong!" and the latency in milliseconds.
+/// Longest the database may take to answer before it is shown as unavailable.
+static DATABASE_PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Responds to a "ping" command with the latencies of the bot in milliseconds.
 ///
 /// # Arguments
 /// * `ctx` - The command context, which provides access to information about the command execution, such as the message and invoking user.
@@ -11,23 +19,42 @@ use crate::discord::poise_structs::*;
 ///
 /// # Behavior
 /// * Calculates the latency between the command's creation time and the current time in milliseconds.
-/// * Sends a message in the channel where the command was invoked, containing "Pong!" followed by the computed latency.
+/// * Reads the id of the shard handling the command and its gateway heartbeat latency.
+/// * Times a `ping` command sent to the database, shown as unavailable when it does not answer.
+/// * Sends an embed in the channel where the command was invoked with the three latencies.
 /// * Logs an error to the console if there is a failure in sending the message.
 ///
 /// # Example
 /// User sends a `/ping` command:
 /// ```
 /// User: /ping
-/// Bot: Pong! 123ms
+/// Bot: Pong!
+///      Interaction: 123 ms | Gateway (shard 0): 42 ms | Database: unavailable
 /// ```
 #[poise::command(slash_command, rename = "ping")]
 pub async fn ping(
     ctx: Context<'_>
 ) -> Result<(), Error> {
-    let ping = ping_data::PingCommandData::new(ctx.created_at().timestamp_millis() as u128).ping;
+    let created_at = ctx.created_at().timestamp_millis() as u128;
+    let shard_id = ctx.serenity_context().shard_id;
+    let gateway = ctx.framework().shard_manager().runners.lock().await
+        .get(&shard_id)
+        .and_then(|runner| runner.latency);
+    let database = ping_db(DATABASE_PING_TIMEOUT).await;
+    let data = ping_data::PingCommandData::new(created_at, gateway, database);
 
-    if let Err(why) = ctx.say(format!("Pong! {}ms", ping)).await {
-        println!("Error sending message: {why:?}");
+    let latency = |ms: Option<u64>| match ms {
+        Some(ms) => tr!(ctx, "ping__latency", ms: ms),
+        None => tr!(ctx, "ping__report", @unavailable),
+    };
+    let embed = CreateEmbed::new()
+        .title(tr!(ctx, "ping__report", @title))
+        .field(tr!(ctx, "ping__report", @interaction), latency(Some(data.ping as u64)), true)
+        .field(tr!(ctx, "ping__report", @gateway, shard: shard_id.0), latency(data.gateway_ms()), true)
+        .field(tr!(ctx, "ping__report", @database), latency(data.database_ms()), true)
+        .color(Color::from_rgb(112, 190, 255));
+    if let Err(why) = ctx.send(CreateReply::default().embed(embed)).await {
+        tracing::error!(guild_id = ?ctx.guild_id(), "failed to send the ping reply: {why}");
     }
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 /// A structure representing the data associated with a "Ping" command.
 ///
@@ -9,19 +9,27 @@ use std::time::SystemTime;
 /// # Fields
 ///
 /// * `timestamp` (private):
-///     A 128-bit unsigned integer representing the time at which the 
-///     ping command was issued. This field is private and not directly 
-///     accessible from outside the struct. It is marked with 
-///     `#[allow(dead_code)]` as it may not currently be used.
+///   A 128-bit unsigned integer representing the time at which the 
+///   ping command was issued. This field is private and not directly 
+///   accessible from outside the struct. It is marked with 
+///   `#[allow(dead_code)]` as it may not currently be used.
 ///
 /// * `ping` (crate-level visibility):
-///     A 128-bit unsigned integer representing the measured ping value 
-///     (e.g., latency). This field is visible within the current crate.
+///   A 128-bit unsigned integer representing the measured ping value 
+///   (e.g., latency). This field is visible within the current crate.
+///
+/// * `gateway` (crate-level visibility):
+///   The heartbeat latency of the shard handling the command, `None`
+///   until the gateway acknowledged a heartbeat.
+///
+/// * `database` (crate-level visibility):
+///   The time a `ping` command took to reach the database and come back,
+///   `None` when the database could not be reached.
 ///
 /// # Visibility
 /// The `PingCommandData` structure is public (`pub`), but the visibility 
 /// of its fields is limited:
-/// - `ping` is accessible within the same crate (`pub(crate)`).
+/// - `ping`, `gateway` and `database` are accessible within the same crate (`pub(crate)`).
 /// - `timestamp` is private and not accessible outside of this struct.
 ///
 /// # Example
@@ -29,8 +37,10 @@ use std::time::SystemTime;
 /// use your_crate::PingCommandData;
 ///
 /// let ping_data = PingCommandData {
-///     timestamp: 1632995812356,
-///     ping: 42,
+///   timestamp: 1632995812356,
+///   ping: 42,
+///   gateway: Some(Duration::from_millis(35)),
+///   database: None,
 /// };
 ///
 /// // The `ping` value can be accessed within the same crate.
@@ -39,13 +49,27 @@ use std::time::SystemTime;
 pub struct PingCommandData{
     #[allow(dead_code)]
     timestamp: u128,
-    pub(crate) ping: u128
+    pub(crate) ping: u128,
+    pub(crate) gateway: Option<Duration>,
+    pub(crate) database: Option<Duration>,
 }
 
 impl PingCommandData {
-    pub(crate) fn new(timestamp: u128) -> Self {
-        let ping = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis() - timestamp;
-        Self {timestamp, ping}
+    /// Measurements of a command created at `timestamp`, in milliseconds. A command seemingly
+    /// created after now, as the clocks of Discord and of the bot differ, has a ping of 0.
+    pub(crate) fn new(timestamp: u128, gateway: Option<Duration>, database: Option<Duration>) -> Self {
+        let ping = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis().saturating_sub(timestamp);
+        Self {timestamp, ping, gateway, database}
+    }
+
+    /// Heartbeat latency of the shard in milliseconds, if known.
+    pub(crate) fn gateway_ms(&self) -> Option<u64> {
+        self.gateway.map(|latency| latency.as_millis() as u64)
+    }
+
+    /// Round trip to the database in milliseconds, if it answered.
+    pub(crate) fn database_ms(&self) -> Option<u64> {
+        self.database.map(|latency| latency.as_millis() as u64)
     }
 }
 
@@ -55,6 +79,21 @@ mod test {
     #[test]
     fn test_ping() {
         let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis();
-        assert_eq!(PingCommandData::new(now).ping, 0);
+        assert_eq!(PingCommandData::new(now, None, None).ping, 0);
+        // Clock skew must not make the ping underflow
+        assert_eq!(PingCommandData::new(now + 60_000, None, None).ping, 0);
+    }
+
+    #[test]
+    fn test_gateway_and_database_latencies() {
+        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis();
+        let data = PingCommandData::new(now, Some(Duration::from_micros(42_700)), Some(Duration::from_millis(3)));
+        assert_eq!(data.gateway_ms(), Some(42));
+        assert_eq!(data.database_ms(), Some(3));
+
+        // A database which could not be reached, and a shard without heartbeat yet
+        let data = PingCommandData::new(now, None, None);
+        assert_eq!(data.gateway_ms(), None);
+        assert_eq!(data.database_ms(), None);
     }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use chrono::Utc;
+use fluent::FluentArgs;
+use crate::database::modifiers::{Modifier, ModifierType};
+use crate::database::places::{check_existing_place, push_place_modifier};
+use crate::database::server::get_server_by_id;
+use crate::database::stats::{get_stat_by_name, StatValue};
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::place::info_place_sub_command::modifier_lines;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::reply::reply_with_args;
+
+/// Adds a modifier on a stat to a place, for good or for a number of in-universe hours.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "place_add_modifier")]
+pub async fn add_modifier(
+    ctx: Context<'_>,
+    #[description = "place_add_modifier.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String,
+    #[description = "place_add_modifier.stat"]
+    stat: String,
+    #[description = "place_add_modifier.kind"]
+    kind: ModifierType,
+    #[description = "place_add_modifier.value"]
+    value: f64,
+    #[description = "place_add_modifier.duration"]
+    #[min = 0]
+    duration: Option<f64>,
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _add_modifier(&ctx, place, stat.trim().to_string(), kind, value, duration, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Real timestamp, in seconds, at which a modifier lasting `hours` in-universe hours and added at
+/// `now` ends, for a universe whose time runs at `global_time_modifier` percent of real time.
+///
+/// Returns `None` when the time of the universe is stopped, since the modifier would never end.
+pub fn modifier_end_timestamp(now: u64, hours: f64, global_time_modifier: u32) -> Option<u64> {
+    if global_time_modifier == 0 {
+        return None;
+    }
+    let real_secs = hours * 3600.0 * 100.0 / global_time_modifier as f64;
+    Some(now + real_secs.round() as u64)
+}
+
+/// Adds a modifier of kind `kind` and value `value` on the stat named `stat_name` to the place
+/// whose category is `place_str`. `args` receives the updated list of modifiers of the place.
+///
+/// # Errors
+/// - `"delete_place__invalid_place"` / `"delete_place__not_found"`: the place cannot be resolved.
+/// - `"place_modifier__stat_not_found"`: the universe has no stat with this name.
+/// - `"place_modifier__time_stopped"`: a duration was given but the time of the universe is stopped.
+/// - `"create_place__database_not_found"`: a database request failed.
+pub async fn _add_modifier(ctx: &Context<'_>, place_str: String, stat_name: String, kind: ModifierType, value: f64, duration: Option<f64>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(category_id) = parse_channel_id(place_str.trim()) else { return Err("delete_place__invalid_place".into()) };
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+
+    let Ok(place_opt) = check_existing_place(server.universe_id, category_id).await else { return Err("create_place__database_not_found".into()) };
+    let Some(mut place) = place_opt else { return Err("delete_place__not_found".into()) };
+    args.set("place", place.name.clone());
+    args.set("stat", stat_name.clone());
+
+    let Ok(stat_opt) = get_stat_by_name(server.universe_id, &stat_name).await else { return Err("create_place__database_not_found".into()) };
+    let Some(stat) = stat_opt else { return Err("place_modifier__stat_not_found".into()) };
+
+    let end_timestamp = match duration {
+        None => None,
+        Some(hours) => {
+            let Ok(Some(universe)) = get_universe_by_id(server.universe_id).await else { return Err("create_place__database_not_found".into()) };
+            let Some(end) = modifier_end_timestamp(Utc::now().timestamp() as u64, hours, universe.global_time_modifier) else { return Err("place_modifier__time_stopped".into()) };
+            Some(end)
+        }
+    };
+
+    let modifier = Modifier {
+        stat: stat._id,
+        value: StatValue::F64(value),
+        modifier_type: kind,
+        end_timestamp,
+        source: place._id,
+        priority: 0,
+        formula: None,
+    };
+    if push_place_modifier(server.universe_id, place.category_id, &modifier).await.is_err() {
+        return Err("create_place__database_not_found".into());
+    }
+    place.modifiers.push(modifier);
+    args.set("modifiers", modifier_lines(ctx, &place.modifiers).await.join("\n"));
+
+    Ok("place_modifier__added")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_modifier_end_timestamp() {
+        // Real time: one in-universe hour lasts one hour
+        assert_eq!(modifier_end_timestamp(1_000, 2.0, 100), Some(1_000 + 7_200));
+        // Time runs twice as fast: two in-universe hours last one hour
+        assert_eq!(modifier_end_timestamp(1_000, 2.0, 200), Some(1_000 + 3_600));
+        assert_eq!(modifier_end_timestamp(1_000, 2.0, 0), None);
+    }
+}
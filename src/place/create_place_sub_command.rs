@@ -1,22 +1,30 @@
+use fluent::FluentArgs;
 use futures::TryStreamExt;
-use serenity::all::{CreateChannel, CreateEmbed, CreateForumPost, CreateMessage, EditRole, PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+use serenity::all::{ChannelType, CreateChannel, CreateEmbed, CreateForumPost, CreateMessage, EditRole, GuildChannel, PermissionOverwrite, PermissionOverwriteType, Permissions, Role, RoleId};
 use serenity::all::ChannelType::Category;
-use crate::database::places::Place;
+use crate::database::places::{get_place_by_name, Place};
 use crate::database::server::{get_server_by_id, Server};
+use crate::discord::audit::log_action;
 use crate::discord::channels::PLACE_TAG;
 use crate::discord::poise_structs::{Context, Error};
+use crate::discord::roles::preflight;
 use crate::tr;
-use crate::utility::reply::reply;
+use crate::utility::reply::reply_with_args;
 
 #[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "place_create_place")]
 pub async fn create_place(
     ctx: Context<'_>,
     #[description = "place_create_place.name"]
-    name: String
+    name: String,
+    #[description = "place_create_place.with_text"]
+    with_text: Option<bool>,
+    #[description = "place_create_place.with_voice"]
+    with_voice: Option<bool>,
 ) -> Result<(), Error>{
     let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
-    let result = _create_place(&ctx, name).await;
-    let Ok(_) = reply(ctx, result).await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _create_place(&ctx, name, with_text.unwrap_or(true), with_voice.unwrap_or(false), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
     Ok(())
 }
 
@@ -26,21 +34,32 @@ pub async fn create_place(
 /// - Creating a new server role.
 /// - Creating a new channel category associated with the role.
 /// - Ensuring proper permissions and relationships between the role and channel.
-/// - Persisting the "place" data in the database.
+/// - Creating the general text channel and the voice channel of the place inside the category,
+///   when `with_text` / `with_voice` are set, with the same permissions as the category.
+/// - Posting a page for the place in the wiki forum of every server of the universe that has one.
+///   A failed post is only reported as a warning and never rolls the place back.
+/// - Persisting the "place" data in the database, with the ids of the wiki posts.
 ///
 /// If any step fails, the function attempts to roll back changes to leave the server in a consistent state.
 ///
 /// # Arguments
 /// - `ctx`: The context of the current operation, used to interact with the server and manage permissions.
 /// - `name`: The desired name for the "place" (role and channel).
+/// - `with_text`: Whether to create a general text channel in the category.
+/// - `with_voice`: Whether to create a voice channel in the category.
+/// - `args`: Receives the place name and, when posted, the mention of the local wiki post.
 ///
 /// # Returns
-/// - `Ok(&'static str)`: A success message indicating that the "place" was created successfully.
+/// - `Ok(&'static str)`: A success message indicating that the "place" was created successfully,
+///   linking the wiki post of this server or warning that it could not be created.
 /// - `Err(Error)`: An error message/code describing why the operation failed.
 ///
 /// # Errors
+/// - `"preflight__missing_permissions"`: The bot cannot manage roles or channels, `args` lists why.
 /// - `"create_place__server_not_found"`: The server was not found in the database.
 /// - `"create_place__database_not_found"`: A database issue occurred while fetching the server.
+/// - `"create_place__setup_required"`: The player or everyone role is missing, the setup was not run.
+/// - `"create_place__name_already_exists"`: The universe already has a place with this name, ignoring case.
 /// - `"create_place__role_not_created"`: The role creation failed in the server.
 /// - `"create_place__rollback_complete"`: Rollback successfully completed after a failure.
 /// - `"create_role__rollback_failed"`: Rollback of either the role or channel failed.
@@ -52,14 +71,16 @@ pub async fn create_place(
 ///
 /// # Example
 /// ```rust
-/// let result = _create_place(&ctx, "My New Place".to_string()).await;
+/// let result = _create_place(&ctx, "My New Place".to_string(), true, false, &mut args).await;
 /// match result {
 ///     Ok(success_message) => println!("Success: {}", success_message),
 ///     Err(error_message) => eprintln!("Error: {}", error_message),
 /// }
 /// ```
-pub async fn _create_place(ctx: &Context<'_>, name: String) -> Result<&'static str, Error>{
+pub async fn _create_place(ctx: &Context<'_>, name: String, with_text: bool, with_voice: bool, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
     let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+    preflight(ctx, args).await?;
     let result = get_server_by_id(guild_id.get()).await;
     let server = match result {
         Ok(universe_result) => {
@@ -70,6 +91,13 @@ pub async fn _create_place(ctx: &Context<'_>, name: String) -> Result<&'static s
         }
         Err(_) => {return Err("create_place__database_not_found".into())}
     };
+    server.check_place_roles()?;
+
+    match get_place_by_name(server.universe_id, &name).await {
+        Ok(None) => {}
+        Ok(Some(_)) => {return Err("create_place__name_already_exists".into())}
+        Err(_) => {return Err("create_place__database_not_found".into())}
+    }
 
     let new_role = EditRole::new()
         .name(name.clone())
@@ -96,7 +124,7 @@ pub async fn _create_place(ctx: &Context<'_>, name: String) -> Result<&'static s
 
     let new_channel = CreateChannel::new(name.clone())
             .kind(Category)
-            .permissions(permissions);
+            .permissions(permissions.clone());
 
     let new_place = match guild_id.create_channel(ctx, new_channel).await {
         Ok(channel) => {channel}
@@ -108,22 +136,52 @@ pub async fn _create_place(ctx: &Context<'_>, name: String) -> Result<&'static s
         }
     };
 
+    let mut children = vec![];
+    let child_channels = [
+        (with_text, ChannelType::Text, "place_general_channel_name"),
+        (with_voice, ChannelType::Voice, "place_voice_channel_name"),
+    ];
+    for (wanted, kind, name_key) in child_channels {
+        if !wanted {
+            continue;
+        }
+        let child = CreateChannel::new(tr!(*ctx, name_key, place_name: name.clone()))
+            .kind(kind)
+            .category(new_place.id)
+            .permissions(permissions.clone());
+        match guild_id.create_channel(ctx, child).await {
+            Ok(channel) => children.push(channel),
+            Err(_) => {return Err(rollback_place(ctx, &mut role, &new_place, &children).await.into())}
+        }
+    }
+    let text_channel_id = children.iter().find(|c| c.kind == ChannelType::Text).map(|c| c.id.get());
+    let voice_channel_id = children.iter().find(|c| c.kind == ChannelType::Voice).map(|c| c.id.get());
+
     let embed = CreateEmbed::new()
         .title(name.clone().to_string())
         .field(tr!(ctx.clone(), "create_place__channel_id"), "`".to_string() + new_place.clone().id.get().to_string().as_str() + "`", true);
 
-    let Ok(servers_cursor) = server.get_other_servers().await else {return Err("create_place__servers_not_found".into())};
-    let Ok(servers) = servers_cursor.try_collect::<Vec<Server>>().await else {return Err("create_place__server_collect_failed".into())};
-    for server in servers {
-        if let Some(wiki_channel_id) = server.rp_wiki_channel_id{
-            let Ok(wiki_channel) = ctx.http().get_channel(wiki_channel_id.id.into()).await else {continue};
-            let channel = wiki_channel.guild().unwrap();
-            let place_tag = channel.available_tags.iter().find(|tag| tag.name == PLACE_TAG);
-            let mut post = CreateForumPost::new(tr!(ctx.clone(), "create_place__new_place_title", place_name: name.clone()).to_string(), CreateMessage::new().embed(embed.clone()));
-            if let Some(tag) = place_tag {
-                post = post.add_applied_tag(tag.id);
+    let mut wiki_posts = vec![];
+    let mut local_wiki_post = None;
+    let mut wiki_failed = false;
+    let servers = match server.get_other_servers().await {
+        Ok(cursor) => cursor.try_collect::<Vec<Server>>().await.unwrap_or_else(|_| vec![server.clone()]),
+        Err(_) => vec![server.clone()],
+    };
+    for wiki_server in servers {
+        let Some(wiki_channel_id) = wiki_server.rp_wiki_channel_id else { continue };
+        let is_local = wiki_server.server_id == server.server_id;
+        match create_wiki_post(ctx, wiki_channel_id.id, &name, &embed).await {
+            Some(post) => {
+                if is_local {
+                    local_wiki_post = Some(post.id);
+                }
+                wiki_posts.push(post);
+            }
+            None => {
+                tracing::warn!(guild_id = wiki_server.server_id, place = name.as_str(), "failed to create the wiki post of a place");
+                wiki_failed |= is_local;
             }
-            let _ = channel.create_forum_post(ctx, post).await;
         }
     }
 
@@ -135,20 +193,54 @@ pub async fn _create_place(ctx: &Context<'_>, name: String) -> Result<&'static s
         role: role.id.get(),
         name: new_place.name.clone(),
         modifiers: vec![],
+        text_channel_id,
+        voice_channel_id,
+        wiki_post_ids: wiki_posts.iter().map(|post| post.id.get()).collect(),
     };
 
-    match place.insert_place().await{
-        Ok(_) => {Ok("create_place__success")}
-        Err(_) => {
-            match role.delete(ctx).await {
-                Ok(_) => {}
-                Err(_) => {return Err("create_role__rollback_failed".into())}
-            };
+    if place.insert_place().await.is_err() {
+        let created = [children.as_slice(), wiki_posts.as_slice()].concat();
+        return Err(rollback_place(ctx, &mut role, &new_place, &created).await.into());
+    }
 
-            match new_place.delete(ctx).await {
-                Ok(_) => {Err("create_place__rollback_complete".into())}
-                Err(_) => {Err("create_role__rollback_failed".into())}
-            }
+    let mut log_args = FluentArgs::new();
+    log_args.set("place", name.clone());
+    log_action(ctx, &server, "create_place__log", log_args).await;
+
+    args.set("place", name);
+    match (local_wiki_post, wiki_failed) {
+        (Some(post), _) => {
+            args.set("wiki_post", format!("<#{post}>"));
+            Ok("create_place__success_with_wiki")
         }
+        (None, true) => Ok("create_place__success_wiki_failed"),
+        (None, false) => Ok("create_place__success"),
+    }
+}
+
+/// Creates the wiki forum post of the place `name` in the forum `wiki_channel_id`, with the
+/// place embed and the localized page template. Returns `None` if the post could not be created.
+async fn create_wiki_post(ctx: &Context<'_>, wiki_channel_id: u64, name: &str, embed: &CreateEmbed) -> Option<GuildChannel> {
+    let channel = ctx.http().get_channel(wiki_channel_id.into()).await.ok()?.guild()?;
+    let place_tag = channel.available_tags.iter().find(|tag| tag.name == PLACE_TAG);
+    let message = CreateMessage::new()
+        .content(tr!(*ctx, "create_place__wiki_template", place_name: name.to_string()))
+        .embed(embed.clone());
+    let mut post = CreateForumPost::new(tr!(*ctx, "create_place__new_place_title", place_name: name.to_string()), message);
+    if let Some(tag) = place_tag {
+        post = post.add_applied_tag(tag.id);
+    }
+    channel.create_forum_post(ctx, post).await.ok()
+}
+
+/// Deletes the child channels, the category and the role of a place being created, and returns
+/// the error key describing the outcome of the rollback.
+async fn rollback_place(ctx: &Context<'_>, role: &mut Role, category: &GuildChannel, children: &[GuildChannel]) -> &'static str {
+    let mut rollback_ok = true;
+    for channel in children {
+        rollback_ok &= channel.delete(ctx).await.is_ok();
     }
+    rollback_ok &= category.delete(ctx).await.is_ok();
+    rollback_ok &= role.delete(ctx).await.is_ok();
+    if rollback_ok { "create_place__rollback_complete" } else { "create_role__rollback_failed" }
 }
\ No newline at end of file
@@ -0,0 +1,96 @@
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, RoleId};
+use crate::database::places::{check_existing_place, delete_place_by_category_id};
+use crate::database::server::get_server_by_id;
+use crate::discord::audit::{log_action, notify_moderation};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::reply::reply_with_args;
+
+/// Deletes a place: its role, its category and the channels inside, its wiki posts, then its record.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "place_delete_place")]
+pub async fn delete_place(
+    ctx: Context<'_>,
+    #[description = "place_delete_place.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _delete_place(&ctx, place, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Deletes the place whose category is `place_str` (an id or a mention).
+///
+/// The place is kept when roads still lead to it: the roads are listed in `args` and must be
+/// deleted first. Discord deletions cannot be undone, so the database record is only removed
+/// once the role and channels are gone; if that removal fails, the place is left in the database
+/// without its Discord resources and a distinct error is returned.
+///
+/// # Errors
+/// - `"delete_place__invalid_place"` / `"delete_place__not_found"`: the place cannot be resolved.
+/// - `"delete_place__other_server"`: the place belongs to another server of the universe.
+/// - `"delete_place__roads_remaining"`: roads still reference the place.
+/// - `"delete_place__discord_delete_failed"`: a role or channel could not be deleted.
+/// - `"delete_place__database_delete_failed"`: the Discord resources are deleted but not the record.
+pub async fn _delete_place(ctx: &Context<'_>, place_str: String, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+
+    let Some(category_id) = parse_channel_id(place_str.trim()) else { return Err("delete_place__invalid_place".into()) };
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+
+    let Ok(place_opt) = check_existing_place(server.universe_id, category_id).await else { return Err("create_place__database_not_found".into()) };
+    let Some(place) = place_opt else { return Err("delete_place__not_found".into()) };
+    args.set("place", place.name.clone());
+    if place.server_id != server.server_id {
+        return Err("delete_place__other_server".into());
+    }
+
+    let Ok(roads) = place.clone().get_roads().await else { return Err("create_place__database_not_found".into()) };
+    if !roads.is_empty() {
+        let names = roads.iter()
+            .map(|road| format!("- <#{}>", road.channel_id))
+            .collect::<Vec<_>>()
+            .join("\n");
+        args.set("roads", names);
+        return Err("delete_place__roads_remaining".into());
+    }
+
+    let Ok(channels) = guild_id.channels(ctx).await else { return Err("delete_place__discord_delete_failed".into()) };
+    let category = ChannelId::new(place.category_id);
+    for channel in channels.values().filter(|channel| channel.parent_id == Some(category)) {
+        if channel.delete(ctx).await.is_err() {
+            return Err("delete_place__discord_delete_failed".into());
+        }
+    }
+    if let Some(category) = channels.get(&category)
+        && category.delete(ctx).await.is_err() {
+        return Err("delete_place__discord_delete_failed".into());
+    }
+    if let Ok(role) = guild_id.role(ctx, RoleId::new(place.role)).await
+        && let Err(e) = guild_id.delete_role(ctx, role.id).await {
+        tracing::warn!(guild_id = guild_id.get(), role_id = place.role, "failed to delete place role: {e}");
+        return Err("delete_place__discord_delete_failed".into());
+    }
+    // A wiki post left behind is only documentation, it does not prevent the deletion
+    for post_id in &place.wiki_post_ids {
+        if let Err(e) = ChannelId::new(*post_id).delete(ctx).await {
+            tracing::warn!(guild_id = guild_id.get(), post_id, "failed to delete the wiki post of a place: {e}");
+        }
+    }
+
+    if let Err(e) = delete_place_by_category_id(server.universe_id, place.category_id).await {
+        tracing::error!(guild_id = guild_id.get(), category_id = place.category_id, "place deleted on Discord but not in the database: {e:?}");
+        return Err("delete_place__database_delete_failed".into());
+    }
+
+    let audit_args = || FluentArgs::from_iter([("place", place.name.clone())]);
+    notify_moderation(ctx, &server, "delete_place__notice", audit_args()).await;
+    log_action(ctx, &server, "delete_place__log", audit_args()).await;
+    Ok("delete_place__success")
+}
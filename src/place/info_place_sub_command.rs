@@ -0,0 +1,127 @@
+use fluent::FluentArgs;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::modifiers::{Modifier, ModifierType};
+use crate::database::places::check_existing_place;
+use crate::database::road::get_roads_by_place;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::{get_stat_by_id, StatValue};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::tr;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::reply::reply;
+
+/// Shows a place with the places it leads to and its active modifiers.
+#[poise::command(slash_command, guild_only, rename = "place_info_place")]
+pub async fn info_place(
+    ctx: Context<'_>,
+    #[description = "place_info_place.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let sent = match _info_place(&ctx, place).await {
+        Ok(embed) => ctx.send(CreateReply::default().embed(embed)).await.map(|_| "reply__reply_success").map_err(Error::from),
+        Err(e) => reply(ctx, Err(e)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Builds the embed describing the place whose category is `place_str`.
+///
+/// Secret roads are only listed to administrators, so players do not discover them this way.
+async fn _info_place(ctx: &Context<'_>, place_str: String) -> Result<CreateEmbed, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(category_id) = parse_channel_id(place_str.trim()) else { return Err("delete_place__invalid_place".into()) };
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+    let Ok(place_opt) = check_existing_place(server.universe_id, category_id).await else { return Err("create_place__database_not_found".into()) };
+    let Some(place) = place_opt else { return Err("delete_place__not_found".into()) };
+
+    let show_secret = ctx.author_member().await
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.administrator());
+    let Ok(roads) = get_roads_by_place(server.universe_id, place.category_id).await else { return Err("create_place__database_not_found".into()) };
+
+    let mut road_lines = vec![];
+    for road in roads.iter().filter(|road| show_secret || !road.secret) {
+        let other_id = road.other_end(place.category_id);
+        let other_name = match check_existing_place(server.universe_id, other_id).await {
+            Ok(Some(other)) => other.name,
+            _ => format!("<#{other_id}>"),
+        };
+        let mut args = FluentArgs::new();
+        args.set("place", other_name);
+        args.set("distance", road.distance);
+        args.set("channel", format!("<#{}>", road.channel_id));
+        road_lines.push(tr!(*ctx, "place_info__road", @message; Some(&args)));
+    }
+    if road_lines.is_empty() {
+        road_lines.push(tr!(*ctx, "place_info__no_roads", @message));
+    }
+
+    let modifier_lines = modifier_lines(ctx, &place.modifiers).await;
+
+    Ok(CreateEmbed::new()
+        .title(place.name.clone())
+        .color(Color::from_rgb(0x6f, 0x00, 0xff))
+        .field(tr!(*ctx, "place_info__fields", @role), format!("<@&{}>", place.role), true)
+        .field(tr!(*ctx, "place_info__fields", @category), format!("<#{}>", place.category_id), true)
+        .field(tr!(*ctx, "place_info__fields", @roads), road_lines.join("\n"), false)
+        .field(tr!(*ctx, "place_info__fields", @modifiers), modifier_lines.join("\n"), false))
+}
+
+/// One localized line per active modifier of `modifiers`, or a single line saying there are none.
+pub async fn modifier_lines(ctx: &Context<'_>, modifiers: &[Modifier]) -> Vec<String> {
+    let mut lines = vec![];
+    for modifier in modifiers.iter().filter(|modifier| modifier.is_active()) {
+        let stat = get_stat_by_id(modifier.stat).await.ok().flatten();
+        lines.push(modifier_line(ctx, modifier, stat.as_ref().map(|s| s.name.as_str()), stat.as_ref().and_then(|s| s.formula.as_deref())));
+    }
+    if lines.is_empty() {
+        lines.push(tr!(*ctx, "place_info__no_modifiers", @message));
+    }
+    lines
+}
+
+/// Short textual form of a stat value.
+pub fn format_stat_value(value: &StatValue) -> String {
+    match value {
+        StatValue::I64(v) => v.to_string(),
+        StatValue::F64(v) => v.to_string(),
+        StatValue::String(v) => v.clone(),
+        StatValue::Bool(v) => v.to_string(),
+    }
+}
+
+fn modifier_line(ctx: &Context<'_>, modifier: &Modifier, stat: Option<&str>, formula: Option<&str>) -> String {
+    let kind = match modifier.modifier_type {
+        ModifierType::Base => "base",
+        ModifierType::Multiplier => "multiplier",
+        ModifierType::Flats => "flat",
+    };
+    let mut args = FluentArgs::new();
+    args.set("stat", stat.map(str::to_string).unwrap_or_else(|| modifier.stat.to_hex()));
+    args.set("kind", tr!(*ctx, "place_info__modifier_kind", @(kind)));
+    args.set("value", format_stat_value(&modifier.value));
+    args.set("formula", formula.unwrap_or("-").to_string());
+    args.set("expiry", match modifier.end_timestamp {
+        Some(end) => format!("<t:{end}:R>"),
+        None => tr!(*ctx, "place_info__permanent", @message),
+    });
+    tr!(*ctx, "place_info__modifier", @message; Some(&args))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_stat_value() {
+        assert_eq!(format_stat_value(&StatValue::I64(3)), "3");
+        assert_eq!(format_stat_value(&StatValue::F64(1.5)), "1.5");
+        assert_eq!(format_stat_value(&StatValue::Bool(true)), "true");
+    }
+}
@@ -0,0 +1,58 @@
+use fluent::FluentArgs;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::places::{get_places_by_universe_id_sorted, Place};
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::utility::pagination::{page_count, page_of, send_pages};
+use crate::utility::reply::reply;
+
+/// Lists the places of the universe, ten per page.
+#[poise::command(slash_command, guild_only, rename = "place_list_places")]
+pub async fn list_places(ctx: Context<'_>) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let places = match _list_places(&ctx).await {
+        Ok(places) => places,
+        Err(e) => {
+            let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+            return Ok(());
+        }
+    };
+    if places.is_empty() {
+        let Ok(_) = reply(ctx, Ok("place_list__empty")).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
+
+    send_pages(ctx, page_count(places.len()), |page| page_embed(&ctx, &places, page)).await
+}
+
+async fn _list_places(ctx: &Context<'_>) -> Result<Vec<Place>, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+    let Ok(places) = get_places_by_universe_id_sorted(server.universe_id).await else { return Err("create_place__database_not_found".into()) };
+    Ok(places)
+}
+
+fn page_embed(ctx: &Context<'_>, places: &[Place], page: usize) -> CreateEmbed {
+    let lines = page_of(places, page).iter()
+        .map(|place| {
+            let mut args = FluentArgs::new();
+            args.set("name", place.name.clone());
+            args.set("category", format!("<#{}>", place.category_id));
+            args.set("role", format!("<@&{}>", place.role));
+            args.set("modifiers", place.modifiers.len());
+            tr!(*ctx, "place_list__row", @message; Some(&args))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut args = FluentArgs::new();
+    args.set("page", page + 1);
+    args.set("pages", page_count(places.len()));
+    args.set("count", places.len());
+    CreateEmbed::new()
+        .title(tr!(*ctx, "place_list__page", @title; Some(&args)))
+        .description(lines)
+        .color(Color::from_rgb(0x6f, 0x00, 0xff))
+}
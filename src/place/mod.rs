@@ -1,9 +1,61 @@
+use futures::TryStreamExt;
+use serenity::all::AutocompleteChoice;
+use mongodb::bson::oid::ObjectId;
+use crate::database::places::{check_existing_place, get_place_by_name, get_places_by_universe_id, Place};
+use crate::utility::mention::parse_channel_id;
+use crate::database::server::get_server_by_id;
+use crate::place::add_modifier_sub_command::add_modifier;
 use crate::place::create_place_sub_command::create_place;
+use crate::place::delete_place_sub_command::delete_place;
+use crate::place::info_place_sub_command::info_place;
+use crate::place::list_places_sub_command::list_places;
+use crate::place::occupants_place_sub_command::occupants;
+use crate::place::remove_modifier_sub_command::remove_modifier;
+use crate::place::rename_place_sub_command::rename_place;
 use crate::discord::poise_structs::{Context, Error};
 
+pub mod add_modifier_sub_command;
 pub mod create_place_sub_command;
+pub mod delete_place_sub_command;
+pub mod info_place_sub_command;
+pub mod list_places_sub_command;
+pub mod occupants_place_sub_command;
+pub mod remove_modifier_sub_command;
+pub mod rename_place_sub_command;
 
-#[poise::command(slash_command, subcommands("create_place"), subcommand_required, rename = "place")]
+#[poise::command(slash_command, subcommands("create_place", "delete_place", "list_places", "rename_place", "info_place", "occupants", "add_modifier", "remove_modifier"), subcommand_required, rename = "place")]
 pub async fn place(_ctx: Context<'_>) -> Result<(), Error>{
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Suggests the places of the current universe whose name contains `partial`, those starting
+/// with it first. The value of a
+/// choice is the id of the place's category, so it is parsed like a channel id.
+pub async fn autocomplete_place(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let Some(guild_id) = ctx.guild_id() else { return vec![] };
+    let Ok(Some(server)) = get_server_by_id(guild_id.get()).await else { return vec![] };
+    let Ok(cursor) = get_places_by_universe_id(server.universe_id).await else { return vec![] };
+    let Ok(places) = cursor.try_collect::<Vec<Place>>().await else { return vec![] };
+
+    let partial = partial.to_lowercase();
+    let mut places: Vec<Place> = places.into_iter()
+        .filter(|place| place.name.to_lowercase().contains(&partial))
+        .collect();
+    // Names starting with the input come first
+    places.sort_by_key(|place| !place.name.to_lowercase().starts_with(&partial));
+    places.into_iter()
+        .take(25)
+        .map(|place| AutocompleteChoice::new(place.name, place.category_id.to_string()))
+        .collect()
+}
+
+/// Finds the place of the universe given by `input`: the category id sent by
+/// [`autocomplete_place`], a category mention, or else the name of the place, ignoring case.
+pub async fn resolve_place(universe_id: ObjectId, input: &str) -> mongodb::error::Result<Option<Place>> {
+    let input = input.trim();
+    if let Some(category_id) = parse_channel_id(input)
+        && let Some(place) = check_existing_place(universe_id, category_id).await? {
+        return Ok(Some(place));
+    }
+    get_place_by_name(universe_id, input).await
+}
@@ -0,0 +1,128 @@
+use fluent::FluentArgs;
+use serenity::all::{Color, CreateEmbed};
+use crate::characters::is_moderator;
+use crate::database::characters::Character;
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::{autocomplete_place, resolve_place};
+use crate::tr;
+use crate::utility::pagination::{page_count_by, page_of_by, send_pages};
+use crate::utility::reply::{reply, reply_with_args};
+
+/// Number of characters shown on a page of occupants.
+static OCCUPANTS_PAGE_SIZE: usize = 25;
+
+/// Lists the characters standing in a place, grouped by player.
+#[poise::command(slash_command, guild_only, rename = "place_occupants")]
+pub async fn occupants(
+    ctx: Context<'_>,
+    #[description = "place_occupants.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let (place_name, characters) = match _occupants(&ctx, place.trim(), &mut args).await {
+        Ok(occupants) => occupants,
+        Err(e) => {
+            let Ok(_) = reply_with_args(ctx, Err(e), Some(args)).await else { return Err("reply__reply_failed".into()) };
+            return Ok(());
+        }
+    };
+    if characters.is_empty() {
+        let Ok(_) = reply(ctx, Ok("place_occupants__empty")).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
+
+    let pages = page_count_by(characters.len(), OCCUPANTS_PAGE_SIZE);
+    send_pages(ctx, pages, |page| page_embed(&ctx, &place_name, &characters, page)).await
+}
+
+/// Name of the place given by `place_input` and the characters standing in it. Players only see
+/// the occupants of the place their character is in, moderators those of any place. `args`
+/// receives the place for error messages.
+///
+/// # Errors
+/// - `"create_place__server_not_found"`: the server is not part of a universe.
+/// - `"delete_place__not_found"`: the place does not exist.
+/// - `"place_occupants__not_here"`: the author is not a moderator and their character is elsewhere.
+/// - `"create_place__database_not_found"`: a query failed.
+async fn _occupants(ctx: &Context<'_>, place_input: &str, args: &mut FluentArgs<'_>) -> Result<(String, Vec<Character>), Error> {
+    args.set("place", place_input.to_string());
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_place__server_not_found".into()),
+        Err(_) => return Err("create_place__database_not_found".into()),
+    };
+    let place = match resolve_place(server.universe_id, place_input).await {
+        Ok(Some(place)) => place,
+        Ok(None) => return Err("delete_place__not_found".into()),
+        Err(_) => return Err("create_place__database_not_found".into()),
+    };
+    args.set("place", place.name.clone());
+
+    if !is_moderator(ctx, &server).await {
+        let here = match server.get_active_character(ctx.author().id.get()).await {
+            Ok(character) => character.is_some_and(|character| character.current_place_id == Some(place.category_id)),
+            Err(_) => return Err("create_place__database_not_found".into()),
+        };
+        if !here {
+            return Err("place_occupants__not_here".into());
+        }
+    }
+
+    let Ok(characters) = Character::get_characters_in_place(server.universe_id, place.category_id).await else { return Err("create_place__database_not_found".into()) };
+    Ok((place.name, characters))
+}
+
+/// Names of `characters`, grouped by player in the order of `characters`.
+pub fn group_by_owner(characters: &[Character]) -> Vec<(u64, Vec<&str>)> {
+    let mut groups: Vec<(u64, Vec<&str>)> = vec![];
+    for character in characters {
+        match groups.last_mut() {
+            Some((owner, names)) if *owner == character.user_id => names.push(&character.name),
+            _ => groups.push((character.user_id, vec![&character.name])),
+        }
+    }
+    groups
+}
+
+fn page_embed(ctx: &Context<'_>, place_name: &str, characters: &[Character], page: usize) -> CreateEmbed {
+    let lines = group_by_owner(page_of_by(characters, page, OCCUPANTS_PAGE_SIZE)).into_iter()
+        .map(|(owner, names)| {
+            let mut args = FluentArgs::new();
+            args.set("user", format!("<@{owner}>"));
+            args.set("names", names.join(", "));
+            tr!(*ctx, "place_occupants__row", @message; Some(&args))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut args = FluentArgs::new();
+    args.set("place", place_name.to_string());
+    args.set("page", page + 1);
+    args.set("pages", page_count_by(characters.len(), OCCUPANTS_PAGE_SIZE));
+    args.set("count", characters.len());
+    CreateEmbed::new()
+        .title(tr!(*ctx, "place_occupants__page", @title; Some(&args)))
+        .description(lines)
+        .color(Color::from_rgb(0x6f, 0x00, 0xff))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+
+    #[test]
+    fn test_group_by_owner() {
+        let universe_id = ObjectId::new();
+        let characters = vec![
+            Character::new(universe_id, 2, 1, "Alys".to_string(), &[]),
+            Character::new(universe_id, 2, 1, "Bram".to_string(), &[]),
+            Character::new(universe_id, 2, 3, "Cid".to_string(), &[]),
+        ];
+        assert_eq!(group_by_owner(&characters), vec![(1, vec!["Alys", "Bram"]), (3, vec!["Cid"])]);
+        assert!(group_by_owner(&[]).is_empty());
+    }
+}
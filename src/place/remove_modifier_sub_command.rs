@@ -0,0 +1,65 @@
+use fluent::FluentArgs;
+use crate::database::modifiers::ModifierType;
+use crate::database::places::{check_existing_place, pull_place_modifiers};
+use crate::database::server::get_server_by_id;
+use crate::database::stats::get_stat_by_name;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::place::info_place_sub_command::modifier_lines;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::reply::reply_with_args;
+
+/// Removes the modifiers of a kind on a stat from a place.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "place_remove_modifier")]
+pub async fn remove_modifier(
+    ctx: Context<'_>,
+    #[description = "place_remove_modifier.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String,
+    #[description = "place_remove_modifier.stat"]
+    stat: String,
+    #[description = "place_remove_modifier.kind"]
+    kind: ModifierType,
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _remove_modifier(&ctx, place, stat.trim().to_string(), kind, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Removes the modifiers of kind `kind` on the stat named `stat_name` from the place whose
+/// category is `place_str`. `args` receives the updated list of modifiers of the place.
+///
+/// # Errors
+/// - `"delete_place__invalid_place"` / `"delete_place__not_found"`: the place cannot be resolved.
+/// - `"place_modifier__stat_not_found"`: the universe has no stat with this name.
+/// - `"place_modifier__not_found"`: the place has no modifier of this kind on the stat.
+/// - `"create_place__database_not_found"`: a database request failed.
+pub async fn _remove_modifier(ctx: &Context<'_>, place_str: String, stat_name: String, kind: ModifierType, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(category_id) = parse_channel_id(place_str.trim()) else { return Err("delete_place__invalid_place".into()) };
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+
+    let Ok(place_opt) = check_existing_place(server.universe_id, category_id).await else { return Err("create_place__database_not_found".into()) };
+    let Some(mut place) = place_opt else { return Err("delete_place__not_found".into()) };
+    args.set("place", place.name.clone());
+    args.set("stat", stat_name.clone());
+
+    let Ok(stat_opt) = get_stat_by_name(server.universe_id, &stat_name).await else { return Err("create_place__database_not_found".into()) };
+    let Some(stat) = stat_opt else { return Err("place_modifier__stat_not_found".into()) };
+
+    let before = place.modifiers.len();
+    place.modifiers.retain(|modifier| modifier.stat != stat._id || modifier.modifier_type != kind);
+    if place.modifiers.len() == before {
+        return Err("place_modifier__not_found".into());
+    }
+    if pull_place_modifiers(server.universe_id, place.category_id, stat._id, kind).await.is_err() {
+        return Err("create_place__database_not_found".into());
+    }
+    args.set("removed", before - place.modifiers.len());
+    args.set("modifiers", modifier_lines(ctx, &place.modifiers).await.join("\n"));
+
+    Ok("place_modifier__removed")
+}
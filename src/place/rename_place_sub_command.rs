@@ -0,0 +1,176 @@
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, EditChannel, EditRole, GuildId, RoleId};
+use crate::database::places::{check_existing_place, get_place_by_name, set_place_name};
+use crate::database::road::{set_road_name, Road};
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::reply::reply_with_args;
+
+/// Renames a place: its category, its role, its database record and the roads named after it.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "place_rename_place")]
+pub async fn rename_place(
+    ctx: Context<'_>,
+    #[description = "place_rename_place.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: String,
+    #[description = "place_rename_place.name"]
+    #[max_length = 100]
+    name: String,
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _rename_place(&ctx, place, name.trim().to_string(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// A Discord rename already applied, with the name to restore if a later step fails.
+enum AppliedRename {
+    Channel(ChannelId, String),
+    Role(GuildId, RoleId, String),
+}
+
+/// Name of `road` once the place `place_id`, currently named `old`, is renamed to `new`.
+///
+/// Roads are named `place_one-place_two` at creation, with the names of the places sorted. A
+/// road whose name no longer follows this convention was renamed by hand and is left as is
+/// (`None`).
+pub fn renamed_road(road: &Road, place_id: u64, old: &str, new: &str) -> Option<String> {
+    if road.place_one_id != place_id && road.place_two_id != place_id {
+        return None;
+    }
+    let renamed = road.road_name.strip_prefix(&format!("{old}-")).map(|rest| format!("{new}-{rest}"))
+        .or_else(|| road.road_name.strip_suffix(&format!("-{old}")).map(|rest| format!("{rest}-{new}")))?;
+    Some(renamed.chars().take(100).collect())
+}
+
+/// Renames the place whose category is `place_str` to `name`.
+///
+/// The category, the role and the channels and roles of the roads named after the place are
+/// renamed on Discord first. If one of these edits fails, the edits already applied are
+/// reverted, and the database is only updated once every Discord edit went through.
+///
+/// # Errors
+/// - `"rename_place__empty_name"`: the new name is empty.
+/// - `"delete_place__invalid_place"` / `"delete_place__not_found"`: the place cannot be resolved.
+/// - `"rename_place__other_server"`: the place belongs to another server of the universe.
+/// - `"create_place__name_already_exists"`: another place of the universe has this name.
+/// - `"rename_place__discord_edit_failed"`: a Discord edit failed and the others were reverted.
+/// - `"rename_place__revert_failed"`: a Discord edit failed and reverting the others failed too.
+/// - `"rename_place__database_failed"`: the database could not be updated, Discord edits were reverted.
+pub async fn _rename_place(ctx: &Context<'_>, place_str: String, name: String, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    if name.is_empty() {
+        return Err("rename_place__empty_name".into());
+    }
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+
+    let Some(category_id) = parse_channel_id(place_str.trim()) else { return Err("delete_place__invalid_place".into()) };
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+
+    let Ok(place_opt) = check_existing_place(server.universe_id, category_id).await else { return Err("create_place__database_not_found".into()) };
+    let Some(place) = place_opt else { return Err("delete_place__not_found".into()) };
+    args.set("old", place.name.clone());
+    args.set("new", name.clone());
+    if place.server_id != server.server_id {
+        return Err("rename_place__other_server".into());
+    }
+    match get_place_by_name(server.universe_id, &name).await {
+        Ok(Some(other)) if other.category_id != place.category_id => return Err("create_place__name_already_exists".into()),
+        Ok(_) => {}
+        Err(_) => return Err("create_place__database_not_found".into()),
+    }
+
+    let Ok(roads) = place.clone().get_roads().await else { return Err("create_place__database_not_found".into()) };
+    let renamed_roads: Vec<(Road, String)> = roads.into_iter()
+        .filter_map(|road| renamed_road(&road, place.category_id, &place.name, &name).map(|new_name| (road, new_name)))
+        .collect();
+    args.set("roads", renamed_roads.len());
+
+    // Every rename to apply, with the name to restore on failure
+    let mut renames = vec![
+        (AppliedRename::Channel(ChannelId::new(place.category_id), place.name.clone()), name.clone()),
+        (AppliedRename::Role(guild_id, RoleId::new(place.role), place.name.clone()), name.clone()),
+    ];
+    for (road, new_name) in &renamed_roads {
+        renames.push((AppliedRename::Channel(ChannelId::new(road.channel_id), road.road_name.clone()), new_name.clone()));
+        renames.push((AppliedRename::Role(GuildId::new(road.server_id), RoleId::new(road.role_id), road.road_name.clone()), new_name.clone()));
+    }
+
+    let mut applied = vec![];
+    let mut failed = false;
+    for (rename, new_name) in renames {
+        let result = match &rename {
+            AppliedRename::Channel(channel_id, _) => channel_id.edit(ctx, EditChannel::new().name(new_name)).await.map(|_| ()),
+            AppliedRename::Role(guild_id, role_id, _) => guild_id.edit_role(ctx, *role_id, EditRole::new().name(new_name)).await.map(|_| ()),
+        };
+        if result.is_err() {
+            failed = true;
+            break;
+        }
+        applied.push(rename);
+    }
+    if failed {
+        return match revert(ctx, applied).await {
+            true => Err("rename_place__discord_edit_failed".into()),
+            false => Err("rename_place__revert_failed".into()),
+        };
+    }
+
+    let mut saved = set_place_name(server.universe_id, place.category_id, &name).await.is_ok();
+    for (road, new_name) in &renamed_roads {
+        saved = saved && set_road_name(server.universe_id, road.channel_id, new_name).await.is_ok();
+    }
+    if !saved {
+        tracing::error!(guild_id = guild_id.get(), category_id = place.category_id, "failed to save the new place name");
+        // Restore the previous names in the database too, in case part of the update went through
+        let _ = set_place_name(server.universe_id, place.category_id, &place.name).await;
+        for (road, _) in &renamed_roads {
+            let _ = set_road_name(server.universe_id, road.channel_id, &road.road_name).await;
+        }
+        let _ = revert(ctx, applied).await;
+        return Err("rename_place__database_failed".into());
+    }
+
+    Ok("rename_place__success")
+}
+
+/// Restores the previous names of `applied`, latest first. Returns `false` if any revert failed.
+async fn revert(ctx: &Context<'_>, applied: Vec<AppliedRename>) -> bool {
+    let mut reverted = true;
+    for rename in applied.into_iter().rev() {
+        let result = match rename {
+            AppliedRename::Channel(channel_id, old) => channel_id.edit(ctx, EditChannel::new().name(old)).await.map(|_| ()),
+            AppliedRename::Role(guild_id, role_id, old) => guild_id.edit_role(ctx, role_id, EditRole::new().name(old)).await.map(|_| ()),
+        };
+        if let Err(e) = result {
+            tracing::error!("failed to revert a place rename: {e}");
+            reverted = false;
+        }
+    }
+    reverted
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn road(name: &str, place_one_id: u64, place_two_id: u64) -> Road {
+        Road { road_name: name.to_string(), ..Road::between(place_one_id, place_two_id) }
+    }
+
+    #[test]
+    fn test_renamed_road() {
+        assert_eq!(renamed_road(&road("Town-Port", 1, 2), 1, "Town", "City"), Some("City-Port".to_string()));
+        assert_eq!(renamed_road(&road("Port-Town", 2, 1), 1, "Town", "City"), Some("Port-City".to_string()));
+        // Sorted names: the first place may be at the end of the name
+        assert_eq!(renamed_road(&road("Port-Town", 1, 2), 1, "Town", "City"), Some("Port-City".to_string()));
+        // Renamed by hand: left alone
+        assert_eq!(renamed_road(&road("Coast road", 1, 2), 1, "Town", "City"), None);
+        // Not connected to the place
+        assert_eq!(renamed_road(&road("Town-Port", 3, 4), 1, "Town", "City"), None);
+    }
+}
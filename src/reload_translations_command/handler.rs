@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use fluent::FluentArgs;
+use crate::discord::poise_structs::{Context, Error};
+use crate::translation::read_ftl_report;
+use crate::translation_keys::check_keys;
+use crate::utility::reply::reply_with_args;
+
+/// Reads the translation files again and replaces the translations in use.
+///
+/// A fixed typo then shows without restarting the bot; the names and descriptions of the
+/// commands keep the translations they were registered with. Only the owners listed in
+/// `OWNER_IDS` can run it.
+#[poise::command(slash_command, owners_only, rename = "reload_translations")]
+pub async fn reload_translations(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _reload_translations(&ctx, &mut args);
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Swaps the translations of `ctx` for those read from the files, unless one of them could not
+/// be read or parsed; the static messages cached by the previous ones go with them. `args`
+/// receives the number of locales and messages loaded and of messages defined twice, or the errors.
+///
+/// # Errors
+/// - `"reload_translations__failed"`: a translation file could not be read or parsed; the
+///   translations in use are kept.
+fn _reload_translations(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let report = match read_ftl_report() {
+        Ok(report) => report,
+        Err(e) => {
+            tracing::error!("failed to reload the translations: {e}");
+            args.set("errors", e.to_string());
+            return Err("reload_translations__failed".into());
+        }
+    };
+    if !report.skipped.is_empty() {
+        tracing::error!(skipped = report.skipped.len(), "refused to reload broken translations");
+        args.set("errors", report.skipped.join("\n"));
+        return Err("reload_translations__failed".into());
+    }
+
+    check_keys(&report.translations);
+    args.set("locales", report.translations.other.len() + 1);
+    args.set("messages", report.messages);
+    args.set("conflicts", report.conflicts.len());
+    ctx.data().translations.store(Arc::new(report.translations));
+    tracing::info!("translations reloaded");
+    Ok("reload_translations__success")
+}
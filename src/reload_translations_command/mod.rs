@@ -0,0 +1,3 @@
+//! A module for the owner-only 'reload_translations' command, reading the translation files again.
+
+pub mod handler;
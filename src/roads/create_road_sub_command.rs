@@ -1,42 +1,42 @@
+use fluent::FluentArgs;
 use mongodb::bson::oid::ObjectId;
-use serenity::all::{CreateChannel, PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
+use serenity::all::{ChannelType, PermissionOverwrite, PermissionOverwriteType, Permissions, RoleId};
 use serenity::builder::EditRole;
 use tokio::join;
-use crate::database::places::{check_existing_place};
 use crate::database::road::{Road, count_non_secret_roads_for_place};
 use crate::database::server::{get_server_by_id};
+use crate::discord::audit::log_action;
+use crate::discord::channels::{create_channel_from_spec, ChannelSpec};
 use crate::discord::poise_structs::{Context, Error};
-use crate::utility::reply::reply;
-
-fn parse_channel_id(input: &str) -> Option<u64> {
-    if let Ok(id) = input.parse::<u64>() {
-        return Some(id);
-    }
-    if input.starts_with("<#") && input.ends_with('>') {
-        if let Ok(id) = input[2..input.len() - 1].parse::<u64>() {
-            return Some(id);
-        }
-    }
-    None
-}
+use crate::discord::roles::preflight;
+use crate::database::universe::get_universe_by_id;
+use crate::place::{autocomplete_place, resolve_place};
+use crate::roads::road_slowmode;
+use crate::utility::reply::reply_with_args;
 
 #[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "road_create_road")]
 pub async fn create_road(
     ctx: Context<'_>,
     #[description = "road_create_road.place_one"]
+    #[autocomplete = "autocomplete_place"]
     place_one: String,
     #[description = "road_create_road.place_two"]
+    #[autocomplete = "autocomplete_place"]
     place_two: String,
     #[description = "road_create_road.distance"]
     distance: u64,
     #[description = "road_create_road.secret_channel"]
-    secret_channel: Option<bool>
+    secret_channel: Option<bool>,
+    #[description = "road_create_road.name"]
+    #[max_length = 100]
+    name: Option<String>,
 ) -> Result<(), Error> {
     let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
 
     // Si place_one et place_two ne sont pas sur le même serveur, on vérifie qu'ils sont dans le même univers
-    let result = _create_road(&ctx, place_one, place_two, distance, secret_channel).await;
-    let Ok(_) = reply(ctx.clone(), result).await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _create_road(&ctx, place_one, place_two, distance, secret_channel, name, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
     Ok(())
 }
 
@@ -46,9 +46,13 @@ pub async fn create_road(
 ///  # Arguments
 ///
 ///  * `ctx` - The contextual information for the operation, which includes the guild and bot interaction data.
-///  * `place_one` - The first `GuildChannel` that represents one end of the road.
-///  * `place_two` - The second `GuildChannel` that represents the other end of the road.
+///  * `place_one` - The first end of the road, resolved with [`resolve_place`]: a category id or
+///    mention, or the name of the place.
+///  * `place_two` - The other end of the road, resolved the same way.
 ///  * `distance` - The numeric distance between the two places.
+///  * `custom_name` - Name of the road, its role and its channel. Defaults to [`default_road_name`].
+///  * `args` - Receives the name of the road, or the channel of the existing road when the
+///    places are already connected.
 ///
 ///  # Returns
 ///
@@ -58,8 +62,13 @@ pub async fn create_road(
 ///
 ///  This function can return the following errors:
 ///
+///  * `"preflight__missing_permissions"`: The bot cannot manage roles or channels, `args` lists why.
 ///  * `"create_road__server_not_found"`: The server information could not be found in the database.
 ///  * `"create_road__database_error"`: A database operation failed.
+///  * `"create_road__setup_required"`: The server has no road category, the setup was not run.
+///  * `"create_road__same_place"`: Both ends of the road are the same place.
+///  * `"create_road__already_exists"`: A road already connects the two places, in either order.
+///    Its channel is set in `args`.
 ///  * `"create_place__place_one_not_found"`: The first place does not exist in the universe.
 ///  * `"create_place__place_two_not_found"`: The second place does not exist in the universe.
 ///  * `"create_road__role_creation_failed"`: Failed to create the role for this road.
@@ -74,24 +83,24 @@ pub async fn create_road(
 ///  1. Fetches the server from the database using the guild ID.
 ///  2. Verifies the existence of `place_one` and `place_two` in the server's universe.
 ///  3. Creates a role in the guild for the road.
-///  4. Sets up permission overwrites and creates a corresponding channel in the appropriate category.
+///  4. Sets up permission overwrites and creates a corresponding channel in the appropriate category,
+///     with the slowmode given by [`road_slowmode`].
 ///  5. Inserts metadata related to the new road into the database.
 ///  6. Rolls back created resources (role and/or channel) in case of failures.
 ///
 ///  # Example
 ///
 ///  ```rust
-///  let result = _create_road(ctx, guild_channel_one, guild_channel_two, 100).await;
+///  let result = _create_road(ctx, guild_channel_one, guild_channel_two, 100, None, None, &mut args).await;
 ///  match result {
 ///      Ok(success_message) => println!("Road created successfully: {}", success_message),
 ///      Err(error_message) => eprintln!("Failed to create road: {}", error_message),
 ///  }
 ///  ```
-pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_str: String, distance: u64, secret_channel: Option<bool>) -> Result<&'static str, Error>{
+pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_str: String, distance: u64, secret_channel: Option<bool>, custom_name: Option<String>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
     let guild_id = ctx.guild_id().unwrap();
-
-    let place_one_id = parse_channel_id(&place_one_str).ok_or_else(|| Error::from("create_road__invalid_place_one"))?;
-    let place_two_id = parse_channel_id(&place_two_str).ok_or_else(|| Error::from("create_road__invalid_place_two"))?;
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+    preflight(ctx, args).await?;
 
     let server = get_server_by_id(guild_id.get()).await;
     let server = match server {
@@ -104,10 +113,15 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
          Err(_) => {return Err("create_road__database_error".into())}
      };
 
+    let road_category_id = server.road_category()?;
     let universe_id = server.universe_id.clone();
+    let universe = match get_universe_by_id(universe_id).await {
+        Ok(Some(universe)) => universe,
+        _ => return Err("create_road__database_error".into()),
+    };
 
-    let check_place_one = check_existing_place(universe_id, place_one_id);
-    let check_place_two = check_existing_place(universe_id, place_two_id);
+    let check_place_one = resolve_place(universe_id, &place_one_str);
+    let check_place_two = resolve_place(universe_id, &place_two_str);
     let (result_one, result_two) = join!(check_place_one, check_place_two);
     let place_one = match result_one {
         Ok(result) => {
@@ -132,9 +146,15 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
     if place_one.universe_id != universe_id || place_two.universe_id != universe_id {
         return Err("create_road__universe_mismatch".into());
     }
+    validate_road_ends(place_one.category_id, place_two.category_id)?;
 
-    if let Ok(Some(_)) = server.clone().get_road(place_one.category_id, place_two.category_id).await {
-        return Err("create_road__already_exists".into());
+    match server.clone().get_road(place_one.category_id, place_two.category_id).await {
+        Ok(None) => {}
+        Ok(Some(existing)) => {
+            args.set("channel", format!("<#{}>", existing.channel_id));
+            return Err("create_road__already_exists".into());
+        }
+        Err(_) => return Err("create_road__database_error".into()),
     }
 
     let secret_channel_value = if secret_channel.is_some() {secret_channel.unwrap()} else {false};
@@ -148,8 +168,11 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
         }
     }
 
-    let name = format!("{}-{}", place_one.name, place_two.name);
-    let name = if name.len() > 100 { name[..100].to_string() } else { name };
+    let name = match custom_name.as_deref().map(str::trim) {
+        Some(custom) if !custom.is_empty() => custom.chars().take(100).collect(),
+        _ => default_road_name(&place_one.name, &place_two.name),
+    };
+    args.set("road", name.clone());
 
     let role = EditRole::new()
         .name(name.clone())
@@ -173,11 +196,12 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
         kind: PermissionOverwriteType::Role(RoleId::new(guild_id.get())),
     }];
 
-    let channel = CreateChannel::new(name.clone())
+    let channel = ChannelSpec::new(name.clone(), ChannelType::Text)
         .permissions(permissions)
-        .category(server.road_category_id.unwrap().id);
+        .parent(road_category_id)
+        .rate_limit_per_user(road_slowmode(distance, universe.road_slowmode_per_distance));
 
-    let channel_result = ctx.guild_id().unwrap().create_channel(ctx, channel ).await;
+    let channel_result = create_channel_from_spec(ctx, channel).await;
     let channel = match channel_result {
         Ok(channel) => { channel }
         Err(_) => {
@@ -188,6 +212,10 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
         }
     };
 
+    let mut log_args = FluentArgs::new();
+    log_args.set("road", name.clone());
+    log_args.set("channel", format!("<#{}>", channel.id.get()));
+
     let road = Road{
         _id: ObjectId::default(),
         universe_id: server.universe_id,
@@ -206,7 +234,11 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
     };
 
     match road.insert().await {
-        Ok(_) => { Ok("create_road__success") }
+        Ok(_) => {
+            log_action(ctx, &server, "create_road__log", log_args).await;
+            args.set("channel", format!("<#{}>", channel.id.get()));
+            Ok("create_road__success")
+        }
         Err(_) => {
             match new_role.delete(ctx).await {
                 Ok(_) => {}
@@ -218,4 +250,38 @@ pub async fn _create_road(ctx: &Context<'_>, place_one_str : String, place_two_s
             }
         }
     }
-}
\ No newline at end of file
+}
+/// Name given to a road between the places `one` and `two` when none is chosen: both names
+/// sorted, so the order of the arguments does not matter, and cut to Discord's 100 characters.
+pub fn default_road_name(one: &str, two: &str) -> String {
+    let (first, second) = if one.to_lowercase() <= two.to_lowercase() { (one, two) } else { (two, one) };
+    format!("{first}-{second}").chars().take(100).collect()
+}
+
+/// Rejects a road whose two ends are the same place.
+pub fn validate_road_ends(place_one_id: u64, place_two_id: u64) -> Result<(), Error> {
+    if place_one_id == place_two_id {
+        return Err("create_road__same_place".into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_road_ends() {
+        assert!(validate_road_ends(1, 2).is_ok());
+        assert!(validate_road_ends(2, 1).is_ok());
+        assert_eq!(validate_road_ends(1, 1).unwrap_err().to_string(), "create_road__same_place");
+    }
+
+    #[test]
+    fn test_default_road_name() {
+        assert_eq!(default_road_name("Tavern", "Forest"), "Forest-Tavern");
+        assert_eq!(default_road_name("Forest", "tavern"), "Forest-tavern");
+        let long = "é".repeat(80);
+        assert_eq!(default_road_name(&long, &long).chars().count(), 100);
+    }
+}
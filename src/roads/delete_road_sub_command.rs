@@ -0,0 +1,108 @@
+use fluent::FluentArgs;
+use mongodb::bson::oid::ObjectId;
+use serenity::all::{ChannelId, GuildChannel, RoleId};
+use crate::database::places::check_existing_place;
+use crate::database::road::{get_road, get_road_by_channel_id, Road};
+use crate::database::server::get_server_by_id;
+use crate::discord::audit::{log_action, notify_moderation};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::reply::reply_with_args;
+
+/// Deletes a road, given its channel or its two places: its channel, its role, then its record.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "road_delete_road")]
+pub async fn delete_road(
+    ctx: Context<'_>,
+    #[description = "road_delete_road.road"]
+    #[channel_types("Text")]
+    road: Option<GuildChannel>,
+    #[description = "road_delete_road.place_one"]
+    #[autocomplete = "autocomplete_place"]
+    place_one: Option<String>,
+    #[description = "road_delete_road.place_two"]
+    #[autocomplete = "autocomplete_place"]
+    place_two: Option<String>,
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _delete_road(&ctx, road.map(|channel| channel.id.get()), place_one, place_two, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Finds the road of the universe `universe_id` whose channel is `channel_id`, or else the road
+/// between `place_one` and `place_two`.
+async fn resolve_road(universe_id: ObjectId, channel_id: Option<u64>, place_one: Option<String>, place_two: Option<String>) -> Result<Road, Error> {
+    let road = match (channel_id, place_one, place_two) {
+        (Some(channel_id), _, _) => get_road_by_channel_id(universe_id, channel_id).await,
+        (None, Some(place_one), Some(place_two)) => {
+            let place_one_id = parse_channel_id(place_one.trim()).ok_or_else(|| Error::from("create_road__invalid_place_one"))?;
+            let place_two_id = parse_channel_id(place_two.trim()).ok_or_else(|| Error::from("create_road__invalid_place_two"))?;
+            get_road(universe_id, place_one_id, place_two_id).await
+        }
+        _ => return Err("delete_road__missing_road".into()),
+    };
+    match road {
+        Ok(Some(road)) => Ok(road),
+        Ok(None) => Err("delete_road__not_found".into()),
+        Err(_) => Err("create_road__database_error".into()),
+    }
+}
+
+/// Deletes the road whose channel is `channel_id`, or the road between `place_one` and
+/// `place_two` when no channel is given.
+///
+/// The channel is deleted first, then the role, then the database record. Each step stops the
+/// deletion when it fails, so the error tells which resources are left.
+///
+/// # Errors
+/// - `"delete_road__missing_road"`: neither the channel nor both places were given.
+/// - `"delete_road__not_found"`: no road matches.
+/// - `"delete_road__other_server"`: the road channel is on another server of the universe.
+/// - `"delete_road__channel_delete_failed"`: the channel could not be deleted, nothing was deleted.
+/// - `"delete_road__role_delete_failed"`: the channel is deleted but not the role and the record.
+/// - `"delete_road__database_delete_failed"`: the channel and role are deleted but not the record.
+pub async fn _delete_road(ctx: &Context<'_>, channel_id: Option<u64>, place_one: Option<String>, place_two: Option<String>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_road__server_not_found".into()),
+        Err(_) => return Err("create_road__database_error".into()),
+    };
+    let road = resolve_road(server.universe_id, channel_id, place_one, place_two).await?;
+    args.set("road", road.road_name.clone());
+    for (key, place_id) in [("place_one", road.place_one_id), ("place_two", road.place_two_id)] {
+        let name = match check_existing_place(server.universe_id, place_id).await {
+            Ok(Some(place)) => place.name,
+            _ => format!("<#{place_id}>"),
+        };
+        args.set(key, name);
+    }
+    if road.server_id != server.server_id {
+        return Err("delete_road__other_server".into());
+    }
+
+    let Ok(channels) = guild_id.channels(ctx).await else { return Err("delete_road__channel_delete_failed".into()) };
+    if let Some(channel) = channels.get(&ChannelId::new(road.channel_id))
+        && let Err(e) = channel.delete(ctx).await {
+        tracing::warn!(guild_id = guild_id.get(), channel_id = road.channel_id, "failed to delete road channel: {e}");
+        return Err("delete_road__channel_delete_failed".into());
+    }
+    if let Ok(role) = guild_id.role(ctx, RoleId::new(road.role_id)).await
+        && let Err(e) = guild_id.delete_role(ctx, role.id).await {
+        tracing::warn!(guild_id = guild_id.get(), role_id = road.role_id, "failed to delete road role: {e}");
+        return Err("delete_road__role_delete_failed".into());
+    }
+    if let Err(e) = road.delete().await {
+        tracing::error!(guild_id = guild_id.get(), channel_id = road.channel_id, "road deleted on Discord but not in the database: {e:?}");
+        return Err("delete_road__database_delete_failed".into());
+    }
+
+    let audit_args = || FluentArgs::from_iter([("road", road.road_name.clone())]);
+    notify_moderation(ctx, &server, "delete_road__notice", audit_args()).await;
+    log_action(ctx, &server, "delete_road__log", audit_args()).await;
+    Ok("delete_road__success")
+}
@@ -0,0 +1,74 @@
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, EditChannel, GuildChannel};
+use crate::database::road::get_road_by_channel_id;
+use crate::database::server::get_server_by_id;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::roads::road_slowmode;
+use crate::utility::reply::reply_with_args;
+
+/// Changes the distance of a road.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "road_edit_road")]
+pub async fn edit_road(
+    ctx: Context<'_>,
+    #[description = "road_edit_road.road"]
+    #[channel_types("Text")]
+    road: GuildChannel,
+    #[description = "road_edit_road.distance"]
+    distance: u64,
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _edit_road(&ctx, road.id.get(), distance, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Sets the distance of the road whose channel is `channel_id` to `distance`. `args` receives
+/// the previous and new distances.
+///
+/// When the universe gives roads a slowmode per kilometer, the slowmode of the channel is updated
+/// too; if that fails, the new distance is kept and `"edit_road__slowmode_not_applied"` is returned.
+///
+/// # Errors
+/// - `"edit_road__invalid_distance"`: the distance is 0.
+/// - `"delete_road__not_found"`: the channel is not a road of the universe.
+/// - `"create_road__database_error"`: the road could not be read or saved.
+pub async fn _edit_road(ctx: &Context<'_>, channel_id: u64, distance: u64, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    if distance == 0 {
+        return Err("edit_road__invalid_distance".into());
+    }
+    let guild_id = ctx.guild_id().unwrap();
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("create_road__server_not_found".into()),
+        Err(_) => return Err("create_road__database_error".into()),
+    };
+    let mut road = match get_road_by_channel_id(server.universe_id, channel_id).await {
+        Ok(Some(road)) => road,
+        Ok(None) => return Err("delete_road__not_found".into()),
+        Err(_) => return Err("create_road__database_error".into()),
+    };
+    args.set("road", road.road_name.clone());
+    args.set("old_distance", road.distance);
+    args.set("new_distance", distance);
+
+    road.distance = distance;
+    if road.update().await.is_err() {
+        return Err("create_road__database_error".into());
+    }
+
+    // A slowmode depending on the distance has to follow it
+    let per_distance = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe.road_slowmode_per_distance,
+        _ => return Ok("edit_road__slowmode_not_applied"),
+    };
+    if per_distance > 0 {
+        let slowmode = EditChannel::new().rate_limit_per_user(road_slowmode(distance, per_distance));
+        if let Err(e) = ChannelId::new(road.channel_id).edit(ctx, slowmode).await {
+            tracing::warn!(guild_id = guild_id.get(), channel_id = road.channel_id, "failed to apply the road slowmode: {e}");
+            return Ok("edit_road__slowmode_not_applied");
+        }
+    }
+    Ok("edit_road__success")
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use fluent::FluentArgs;
+use futures::TryStreamExt;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::places::get_places_by_universe_id_sorted;
+use crate::database::road::{get_roads_by_place, get_roads_by_universe_id, Road};
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::autocomplete_place;
+use crate::tr;
+use crate::utility::mention::parse_channel_id;
+use crate::utility::pagination::{page_count, page_of, send_pages};
+use crate::utility::reply::reply;
+
+/// A road of the list, with the names of the places at its ends.
+pub struct RoadRow {
+    pub place_one: String,
+    pub place_two: String,
+    pub distance: u64,
+    pub channel_id: u64,
+}
+
+/// Lists the roads of the universe, or only those of one place, ten per page.
+#[poise::command(slash_command, guild_only, rename = "road_list_roads")]
+pub async fn list_roads(
+    ctx: Context<'_>,
+    #[description = "road_list_roads.place"]
+    #[autocomplete = "autocomplete_place"]
+    place: Option<String>,
+) -> Result<(), Error>{
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let rows = match _list_roads(&ctx, place).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+            return Ok(());
+        }
+    };
+    if rows.is_empty() {
+        let Ok(_) = reply(ctx, Ok("road_list__empty")).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
+
+    send_pages(ctx, page_count(rows.len()), |page| page_embed(&ctx, &rows, page)).await
+}
+
+/// Roads of the universe touching `place_str`, or every road when it is `None`, sorted by the
+/// names of their places. Secret roads are only listed to administrators.
+async fn _list_roads(ctx: &Context<'_>, place_str: Option<String>) -> Result<Vec<RoadRow>, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("create_road__database_error".into()) };
+    let Some(server) = server_opt else { return Err("create_road__server_not_found".into()) };
+
+    let roads = match place_str {
+        Some(place_str) => {
+            let Some(category_id) = parse_channel_id(place_str.trim()) else { return Err("delete_place__invalid_place".into()) };
+            get_roads_by_place(server.universe_id, category_id).await
+        }
+        None => match get_roads_by_universe_id(server.universe_id).await {
+            Ok(cursor) => cursor.try_collect().await,
+            Err(e) => Err(e),
+        },
+    };
+    let Ok(roads) = roads else { return Err("create_road__database_error".into()) };
+    let Ok(places) = get_places_by_universe_id_sorted(server.universe_id).await else { return Err("create_road__database_error".into()) };
+    let names: HashMap<u64, String> = places.into_iter().map(|place| (place.category_id, place.name)).collect();
+
+    let show_secret = ctx.author_member().await
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.administrator());
+    let roads: Vec<Road> = roads.into_iter().filter(|road| show_secret || !road.secret).collect();
+    Ok(road_rows(roads, &names))
+}
+
+/// Rows of `roads`, with place names taken from `names` and sorted by them. A place missing from
+/// `names` is shown as a channel mention.
+pub fn road_rows(roads: Vec<Road>, names: &HashMap<u64, String>) -> Vec<RoadRow> {
+    let name = |id: u64| names.get(&id).cloned().unwrap_or_else(|| format!("<#{id}>"));
+    let mut rows: Vec<RoadRow> = roads.into_iter()
+        .map(|road| RoadRow {
+            place_one: name(road.place_one_id),
+            place_two: name(road.place_two_id),
+            distance: road.distance,
+            channel_id: road.channel_id,
+        })
+        .collect();
+    rows.sort_by_key(|row| (row.place_one.to_lowercase(), row.place_two.to_lowercase()));
+    rows
+}
+
+fn page_embed(ctx: &Context<'_>, rows: &[RoadRow], page: usize) -> CreateEmbed {
+    let lines = page_of(rows, page).iter()
+        .map(|row| {
+            let mut args = FluentArgs::new();
+            args.set("place_one", row.place_one.clone());
+            args.set("place_two", row.place_two.clone());
+            args.set("distance", row.distance);
+            args.set("channel", format!("<#{}>", row.channel_id));
+            tr!(*ctx, "road_list__row", @message; Some(&args))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut args = FluentArgs::new();
+    args.set("page", page + 1);
+    args.set("pages", page_count(rows.len()));
+    args.set("count", rows.len());
+    CreateEmbed::new()
+        .title(tr!(*ctx, "road_list__page", @title; Some(&args)))
+        .description(lines)
+        .color(Color::from_rgb(0x6f, 0x00, 0xff))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn road(place_one_id: u64, place_two_id: u64, channel_id: u64) -> Road {
+        Road { channel_id, ..Road::between(place_one_id, place_two_id) }
+    }
+
+    #[test]
+    fn test_road_rows() {
+        let names = HashMap::from([(1, "port".to_string()), (2, "Town".to_string()), (3, "Abbey".to_string())]);
+        let rows = road_rows(vec![road(2, 1, 10), road(1, 3, 11), road(3, 4, 12)], &names);
+
+        let ends: Vec<(&str, &str)> = rows.iter().map(|row| (row.place_one.as_str(), row.place_two.as_str())).collect();
+        assert_eq!(ends, vec![("Abbey", "<#4>"), ("port", "Abbey"), ("Town", "port")]);
+        assert_eq!(rows[0].channel_id, 12);
+    }
+}
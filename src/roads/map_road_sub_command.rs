@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+use std::io::Write;
+use fluent::FluentArgs;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use futures::TryStreamExt;
+use poise::CreateReply;
+use serenity::all::{Color, CreateAttachment, CreateEmbed};
+use crate::database::places::{get_places_by_universe_id, Place};
+use crate::database::road::{get_roads_by_universe_id, Road};
+use crate::database::server::get_server_by_id;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::utility::reply::reply;
+
+/// Largest map sent as plain text, in bytes. Larger maps are sent gzipped so they stay below
+/// the attachment limit of Discord.
+pub static MAX_PLAIN_MAP_BYTES: usize = 8 * 1024 * 1024;
+
+/// Exports the roads of the universe as a Graphviz map.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "road_map")]
+pub async fn map_road(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let sent = match _map_road(&ctx).await {
+        Ok((embed, attachment)) => ctx.send(CreateReply::default().embed(embed).attachment(attachment)).await.map(|_| "reply__reply_success").map_err(Error::from),
+        Err(e) => reply(ctx, Err(e)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Escapes `text` for a quoted Graphviz string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', " ")
+}
+
+/// Graphviz description of the road network named `name`: places are nodes, roads are edges
+/// labelled with their distance. Secret roads are dashed and roads with active modifiers are
+/// drawn in orange, their modifier count in the tooltip.
+pub fn road_map_dot(name: &str, places: &[Place], roads: &[Road]) -> String {
+    let mut dot = format!("graph \"{}\" {{\n    node [shape=box, style=rounded];\n", escape(name));
+    let mut known = HashSet::new();
+    for place in places {
+        known.insert(place.category_id);
+        dot.push_str(&format!("    p{} [label=\"{}\"];\n", place.category_id, escape(&place.name)));
+    }
+    // Places missing from the database still appear, named after their id
+    for road in roads {
+        for end in [road.place_one_id, road.place_two_id] {
+            if known.insert(end) {
+                dot.push_str(&format!("    p{end} [label=\"{end}\", style=dashed];\n"));
+            }
+        }
+    }
+    for road in roads {
+        let mut attributes = vec![format!("label=\"{} km\"", road.distance)];
+        if road.secret {
+            attributes.push("style=dashed".to_string());
+        }
+        let modifiers = road.modifiers.iter().filter(|modifier| modifier.is_active()).count();
+        if modifiers > 0 {
+            attributes.push("color=orange".to_string());
+            attributes.push(format!("tooltip=\"{}: {modifiers} modifier(s)\"", escape(&road.road_name)));
+        } else {
+            attributes.push(format!("tooltip=\"{}\"", escape(&road.road_name)));
+        }
+        dot.push_str(&format!("    p{} -- p{} [{}];\n", road.place_one_id, road.place_two_id, attributes.join(", ")));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// `dot` as an attachment, gzipped when it is larger than [`MAX_PLAIN_MAP_BYTES`].
+fn map_attachment(dot: String) -> Result<CreateAttachment, std::io::Error> {
+    if dot.len() <= MAX_PLAIN_MAP_BYTES {
+        return Ok(CreateAttachment::bytes(dot.into_bytes(), "road_map.dot"));
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(dot.as_bytes())?;
+    Ok(CreateAttachment::bytes(encoder.finish()?, "road_map.dot.gz"))
+}
+
+/// Builds the map of the universe of the server, and an embed summing it up.
+async fn _map_road(ctx: &Context<'_>) -> Result<(CreateEmbed, CreateAttachment), Error> {
+    let Ok(server_opt) = get_server_by_id(ctx.guild_id().unwrap().get()).await else { return Err("create_place__database_not_found".into()) };
+    let Some(server) = server_opt else { return Err("create_place__server_not_found".into()) };
+    let name = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe.name,
+        Ok(None) => return Err("create_place__server_not_found".into()),
+        Err(_) => return Err("create_place__database_not_found".into()),
+    };
+    let Ok(cursor) = get_places_by_universe_id(server.universe_id).await else { return Err("create_place__database_not_found".into()) };
+    let Ok(places) = cursor.try_collect::<Vec<Place>>().await else { return Err("create_place__database_not_found".into()) };
+    let Ok(cursor) = get_roads_by_universe_id(server.universe_id).await else { return Err("create_place__database_not_found".into()) };
+    let Ok(roads) = cursor.try_collect::<Vec<Road>>().await else { return Err("create_place__database_not_found".into()) };
+    if roads.is_empty() {
+        return Err("road_list__empty".into());
+    }
+
+    let Ok(attachment) = map_attachment(road_map_dot(&name, &places, &roads)) else { return Err("road_map__export_failed".into()) };
+    let mut args = FluentArgs::new();
+    args.set("places", places.len());
+    args.set("roads", roads.len());
+    let embed = CreateEmbed::new()
+        .title(tr!(*ctx, "road_map__success", @title))
+        .description(tr!(*ctx, "road_map__success", @message; Some(&args)))
+        .color(Color::from_rgb(0, 255, 0));
+    Ok((embed, attachment))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::modifiers::{Modifier, ModifierType};
+    use crate::database::stats::StatValue;
+
+    fn road(place_one_id: u64, place_two_id: u64, distance: u64, secret: bool, modifiers: Vec<Modifier>) -> Road {
+        Road { road_name: "road".to_string(), distance, secret, modifiers, ..Road::between(place_one_id, place_two_id) }
+    }
+
+    #[test]
+    fn test_road_map_dot() {
+        let mud = Modifier::on(ObjectId::new(), ModifierType::Multiplier, StatValue::F64(0.5));
+        let places = vec![Place::named("The \"Tavern\"", 10), Place::named("Capital", 11)];
+        let roads = vec![road(10, 11, 12, false, vec![mud]), road(11, 12, 3, true, vec![])];
+        let dot = road_map_dot("Realm", &places, &roads);
+
+        assert!(dot.starts_with("graph \"Realm\" {\n"));
+        assert!(dot.contains("    p10 [label=\"The \\\"Tavern\\\"\"];\n"));
+        assert!(dot.contains("    p12 [label=\"12\", style=dashed];\n"));
+        assert!(dot.contains("    p10 -- p11 [label=\"12 km\", color=orange, tooltip=\"road: 1 modifier(s)\"];\n"));
+        assert!(dot.contains("    p11 -- p12 [label=\"3 km\", style=dashed, tooltip=\"road\"];\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_map_attachment_compresses_large_maps() {
+        let small = map_attachment("graph {}".to_string()).unwrap();
+        assert_eq!(small.filename, "road_map.dot");
+        let large = map_attachment("a".repeat(MAX_PLAIN_MAP_BYTES + 1)).unwrap();
+        assert_eq!(large.filename, "road_map.dot.gz");
+        assert!(large.data.len() < MAX_PLAIN_MAP_BYTES);
+    }
+}
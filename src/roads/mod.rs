@@ -1,9 +1,45 @@
 use crate::roads::create_road_sub_command::create_road;
+use crate::roads::delete_road_sub_command::delete_road;
+use crate::roads::edit_road_sub_command::edit_road;
+use crate::roads::list_roads_sub_command::list_roads;
+use crate::roads::map_road_sub_command::map_road;
 use crate::discord::poise_structs::{Context, Error};
 
 pub mod create_road_sub_command;
+pub mod delete_road_sub_command;
+pub mod edit_road_sub_command;
+pub mod list_roads_sub_command;
+pub mod map_road_sub_command;
 
-#[poise::command(slash_command, subcommands("create_road"), subcommand_required, rename = "road")]
+#[poise::command(slash_command, subcommands("create_road", "delete_road", "list_roads", "edit_road", "map_road"), subcommand_required, rename = "road")]
 pub async fn road(_ctx: Context<'_>) -> Result<(), Error>{
     Ok(())
 }
+
+/// Slowmode of road channels, in seconds, when it does not depend on the distance, so
+/// travellers cannot flood a road.
+static ROAD_SLOWMODE: u16 = 5;
+/// Longest slowmode Discord accepts, in seconds.
+static MAX_SLOWMODE: u64 = 21600;
+
+/// Slowmode of the channel of a road of `distance` km, in a universe giving `per_distance`
+/// seconds of slowmode per km. `0` disables the distance-based slowmode.
+pub fn road_slowmode(distance: u64, per_distance: u64) -> u16 {
+    if per_distance == 0 {
+        return ROAD_SLOWMODE;
+    }
+    distance.saturating_mul(per_distance).min(MAX_SLOWMODE) as u16
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_road_slowmode() {
+        assert_eq!(road_slowmode(12, 0), ROAD_SLOWMODE);
+        assert_eq!(road_slowmode(12, 30), 360);
+        assert_eq!(road_slowmode(1_000, 60), 21600);
+        assert_eq!(road_slowmode(u64::MAX, 2), 21600);
+    }
+}
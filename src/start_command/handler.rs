@@ -1,11 +1,24 @@
+use std::time::Duration;
+use fluent::FluentArgs;
 use poise::CreateReply;
-use serenity::all::{Color, CreateEmbed, CreateEmbedFooter};
+use serenity::all::{ButtonStyle, Color, CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter};
+use crate::database::server::get_server_by_id;
 use crate::discord::poise_structs::{Context, Error};
+use crate::start_command::onboarding::{OnboardingState, OnboardingStep, StepAction};
+use crate::tr;
+use crate::universe::add_server_sub_command::_add_server;
+use crate::universe::setup::setup_sub_command::{_setup, authorize, SetupType};
+use crate::utility::confirm::{AuthorPresses, ButtonPresses};
+use crate::utility::custom_id::custom_id;
+use crate::utility::reply::reply_with_args;
+
+/// How long the buttons of the onboarding wait to be pressed.
+static ONBOARDING_TIMEOUT: Duration = Duration::from_secs(120);
 
 /// Starts an administrator-only guild slash command.
 ///
-/// This command sends a message defined by the "start_message" localization key to the channel
-/// where the command was invoked.
+/// This command walks the administrator through the steps of setting the bot up: creating a
+/// universe, linking the server to one and running the setup.
 ///
 /// # Arguments
 /// * `ctx` - The command context providing access to the interaction data, including the guild, channel, and invoking user.
@@ -15,13 +28,17 @@ use crate::discord::poise_structs::{Context, Error};
 ///
 /// # Attributes
 /// * `#[poise::command]` - Marks this function as a Poise command.
-///     - `slash_command` - Indicates this command is a slash command.
-///     - `required_permissions = "ADMINISTRATOR"` - Restricts the command to users with administrator permissions.
-///     - `guild_only` - Limits the command usage to guilds (servers) and prevents its usage in direct messages.
+///   - `slash_command` - Indicates this command is a slash command.
+///   - `required_permissions = "ADMINISTRATOR"` - Restricts the command to users with administrator permissions.
+///   - `guild_only` - Limits the command usage to guilds (servers) and prevents its usage in direct messages.
 ///
 /// # Behavior
-/// * Sends a localized response ("start_message") defined by the `tr!` macro.
-/// * If the message cannot be sent (`await.unwrap()`), the program panics.
+/// * Reads whether the server is already linked to a universe and set up.
+/// * Sends an embed listing the steps, the ones done checked, with a button for each step; the
+///   buttons of the steps which do not apply to the server are disabled.
+/// * The pressed button links the server with `_add_server` or runs `_setup`, and the create
+///   universe button replies with the command to run, as a universe needs a name.
+/// * The buttons are removed once one was pressed or after two minutes.
 ///
 /// # Example
 /// ```
@@ -31,14 +48,89 @@ use crate::discord::poise_structs::{Context, Error};
 /// ```
 #[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "start")]
 pub async fn start(ctx: Context<'_>) -> Result<(), Error> {
-    let _ = ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::new()
-                .title(crate::translation::get(ctx, "start_message", Some("title"), None))
-                .description(crate::translation::get(ctx, "start_message", Some("description"), None))
-                .footer(CreateEmbedFooter::new("start_message"))
-                .color(Color::from_rgb(0x6f, 0x00, 0xff))
-        ),
-    ).await;
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let state = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(server) => OnboardingState::of(server.as_ref()),
+        Err(_) => {
+            let Ok(_) = reply_with_args(ctx, Err("start__database_error".into()), None).await else { return Err("reply__reply_failed".into()) };
+            return Ok(());
+        }
+    };
+
+    let Ok(message) = ctx.send(
+        CreateReply::default()
+            .embed(onboarding_embed(ctx, state))
+            .components(step_buttons(ctx, state))
+            .ephemeral(true),
+    ).await else { return Err("reply__reply_failed".into()) };
+
+    let ids: Vec<String> = OnboardingStep::ALL.iter().map(|step| custom_id(step.name(), ctx.id())).collect();
+    let pressed = AuthorPresses(ctx).next_press(&ids, ONBOARDING_TIMEOUT).await;
+    // The steps stay shown, without the buttons which no longer do anything
+    let _ = message.edit(ctx, CreateReply::default().embed(onboarding_embed(ctx, state)).components(vec![])).await;
+
+    let Some(step) = pressed.and_then(|pressed| OnboardingStep::ALL.into_iter().find(|step| custom_id(step.name(), ctx.id()) == pressed)) else {
+        return Ok(());
+    };
+    let mut args = FluentArgs::new();
+    let result = match step.action(state) {
+        StepAction::ShowCommand(key) => Ok(key),
+        StepAction::Link(setup_type) => _add_server(&ctx, setup_type, &mut args).await,
+        StepAction::Setup(setup_type) => run_setup(&ctx, setup_type, &mut args).await,
+        // Its button was disabled
+        StepAction::Unavailable => return Ok(()),
+    };
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
     Ok(())
 }
+
+/// Runs the setup as `/universe setup` does, once the author is allowed to. `args` receives what
+/// the bot is missing, or what a repair recreated.
+///
+/// # Errors
+/// - the errors of [`authorize`] and [`_setup`].
+async fn run_setup(
+    ctx: &Context<'_>,
+    setup_type: SetupType,
+    args: &mut FluentArgs<'_>,
+) -> Result<&'static str, Error> {
+    authorize(ctx).await?;
+    _setup(ctx, setup_type, args).await
+}
+
+/// Embed listing the steps of the onboarding, those done in `state` checked, and the state.
+fn onboarding_embed(ctx: Context<'_>, state: OnboardingState) -> CreateEmbed {
+    let steps = OnboardingStep::ALL.iter()
+        .map(|step| {
+            let mark = if step.done(state) { "✅" } else { "▫️" };
+            format!("{mark} {}", tr!(ctx, "start__step", @(step.name())))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    CreateEmbed::new()
+        .title(tr!(ctx, "start__onboarding", @title))
+        .description(format!(
+            "{}\n\n{steps}\n\n{}",
+            tr!(ctx, "start__onboarding", @message),
+            tr!(ctx, "start__state", @(state.translation_attr())),
+        ))
+        .footer(CreateEmbedFooter::new("start__onboarding"))
+        .color(Color::from_rgb(0x6f, 0x00, 0xff))
+}
+
+/// Button of each step, disabled when the step does not apply to `state`.
+fn step_buttons(ctx: Context<'_>, state: OnboardingState) -> Vec<CreateActionRow> {
+    let buttons = OnboardingStep::ALL.iter()
+        .map(|step| {
+            let action = step.action(state);
+            CreateButton::new(custom_id(step.name(), ctx.id()))
+                .style(match action {
+                    StepAction::ShowCommand(_) => ButtonStyle::Secondary,
+                    _ => ButtonStyle::Primary,
+                })
+                .label(tr!(ctx, "start__button", @(step.name())))
+                .disabled(action == StepAction::Unavailable)
+        })
+        .collect();
+    vec![CreateActionRow::Buttons(buttons)]
+}
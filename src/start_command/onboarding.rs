@@ -0,0 +1,135 @@
+use crate::database::server::Server;
+use crate::universe::setup::setup_sub_command::SetupType;
+
+/// How far the guild went in setting the bot up, which `/start` adapts its steps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingState {
+    /// The guild is not part of a universe.
+    Unlinked,
+    /// The guild is part of a universe but was not set up.
+    Linked,
+    /// The guild is part of a universe and has some of its roles, categories or channels.
+    SetUp,
+}
+
+impl OnboardingState {
+    /// State of the guild stored as `server`, if it is. A guild the bot was removed from counts as
+    /// unlinked, adding it back reattaches it.
+    pub fn of(server: Option<&Server>) -> OnboardingState {
+        match server {
+            Some(server) if server.detached_at.is_none() && server.is_setup() => OnboardingState::SetUp,
+            Some(server) if server.detached_at.is_none() => OnboardingState::Linked,
+            _ => OnboardingState::Unlinked,
+        }
+    }
+
+    /// Attribute of `start__state` describing the state.
+    pub fn translation_attr(&self) -> &'static str {
+        match self {
+            OnboardingState::Unlinked => "unlinked",
+            OnboardingState::Linked => "linked",
+            OnboardingState::SetUp => "set_up",
+        }
+    }
+}
+
+/// Steps `/start` walks an administrator through, each with its button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    CreateUniverse,
+    LinkServer,
+    RunSetup,
+}
+
+/// What pressing the button of a step does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepAction {
+    /// Replies with this key, giving the slash command to run: the step needs an argument the
+    /// button cannot ask for.
+    ShowCommand(&'static str),
+    /// Links the server to a universe the author picks, then sets it up with this type.
+    Link(SetupType),
+    /// Runs the setup of this type.
+    Setup(SetupType),
+    /// The step does not apply yet or any more, and its button is disabled.
+    Unavailable,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 3] = [OnboardingStep::CreateUniverse, OnboardingStep::LinkServer, OnboardingStep::RunSetup];
+
+    /// Name of the step in the custom id of its button and in the attributes of `start__step`
+    /// and `start__button`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OnboardingStep::CreateUniverse => "create_universe",
+            OnboardingStep::LinkServer => "link_server",
+            OnboardingStep::RunSetup => "run_setup",
+        }
+    }
+
+    /// Whether the guild went past the step in `state`.
+    pub fn done(&self, state: OnboardingState) -> bool {
+        match self {
+            OnboardingStep::CreateUniverse | OnboardingStep::LinkServer => state != OnboardingState::Unlinked,
+            OnboardingStep::RunSetup => state == OnboardingState::SetUp,
+        }
+    }
+
+    /// What the button of the step does in `state`. A universe needs a name, so it is only ever
+    /// shown how to create one; the link uses the partial setup, which creates the least, and a
+    /// server already set up is repaired rather than set up again.
+    pub fn action(&self, state: OnboardingState) -> StepAction {
+        match (self, state) {
+            (OnboardingStep::CreateUniverse, _) => StepAction::ShowCommand("start__create_universe"),
+            (OnboardingStep::LinkServer, OnboardingState::Unlinked) => StepAction::Link(SetupType::PartialSetup),
+            (OnboardingStep::LinkServer, _) => StepAction::Unavailable,
+            (OnboardingStep::RunSetup, OnboardingState::Unlinked) => StepAction::Unavailable,
+            (OnboardingStep::RunSetup, OnboardingState::Linked) => StepAction::Setup(SetupType::PartialSetup),
+            (OnboardingStep::RunSetup, OnboardingState::SetUp) => StepAction::Setup(SetupType::RepairSetup),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::server::{Id, IdType};
+
+    #[test]
+    fn test_onboarding_state() {
+        assert_eq!(OnboardingState::of(None), OnboardingState::Unlinked);
+        let mut server = Server::default();
+        assert_eq!(OnboardingState::of(Some(&server)), OnboardingState::Linked);
+        server.road_category_id = Some(Id { id: 1, id_type: IdType::Category });
+        assert_eq!(OnboardingState::of(Some(&server)), OnboardingState::SetUp);
+        // A guild the bot left is linked again through the add_server flow
+        server.detached_at = Some(1);
+        assert_eq!(OnboardingState::of(Some(&server)), OnboardingState::Unlinked);
+    }
+
+    #[test]
+    fn test_steps_adapt_to_the_state() {
+        let actions = |state| OnboardingStep::ALL.map(|step| step.action(state));
+        assert_eq!(actions(OnboardingState::Unlinked), [
+            StepAction::ShowCommand("start__create_universe"),
+            StepAction::Link(SetupType::PartialSetup),
+            StepAction::Unavailable,
+        ]);
+        assert_eq!(actions(OnboardingState::Linked), [
+            StepAction::ShowCommand("start__create_universe"),
+            StepAction::Unavailable,
+            StepAction::Setup(SetupType::PartialSetup),
+        ]);
+        assert_eq!(actions(OnboardingState::SetUp), [
+            StepAction::ShowCommand("start__create_universe"),
+            StepAction::Unavailable,
+            StepAction::Setup(SetupType::RepairSetup),
+        ]);
+
+        let done = |state| OnboardingStep::ALL.map(|step| step.done(state));
+        assert_eq!(done(OnboardingState::Unlinked), [false, false, false]);
+        assert_eq!(done(OnboardingState::Linked), [true, true, false]);
+        assert_eq!(done(OnboardingState::SetUp), [true, true, true]);
+    }
+}
@@ -0,0 +1,64 @@
+use fluent::FluentArgs;
+use mongodb::bson::oid::ObjectId;
+use crate::database::stats::{Stat, StatType};
+use crate::discord::poise_structs::{Context, Error};
+use crate::stats::{check_formula, clean_formula, parse_bound, parse_value, universe_id_of};
+use crate::utility::reply::reply_with_args;
+
+/// Defines a new stat of the universe.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "stat_create")]
+pub async fn create_stat(
+    ctx: Context<'_>,
+    #[description = "stat_create.name"]
+    name: String,
+    #[description = "stat_create.kind"]
+    kind: StatType,
+    #[description = "stat_create.base_value"]
+    base_value: String,
+    #[description = "stat_create.min"]
+    min: Option<String>,
+    #[description = "stat_create.max"]
+    max: Option<String>,
+    #[description = "stat_create.formula"]
+    formula: Option<String>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _create_stat(&ctx, name.trim().to_string(), kind, base_value, min, max, clean_formula(formula), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Inserts a stat named `name` holding values of kind `kind`. `args` receives its name and the
+/// value that could not be parsed, if any.
+///
+/// # Errors
+/// - `"stat__empty_name"`: the name is blank.
+/// - `"stat__invalid_value"`: the base value or a bound is not of kind `kind`.
+/// - `"stat_formula__invalid"` / `"stat_formula__too_expensive"`: the formula does not compile;
+///   `args` receives the reason.
+/// - `"stat__bounds_not_numeric"` / `"stat__invalid_bounds"` / `"stat__out_of_bounds"`: see [`Stat::check_definition`].
+/// - `"stat_insert__duplicate_name"`: the universe already has a stat with this name.
+/// - `"stat__no_universe"` / `"stat__database_error"`: the universe could not be found.
+/// - `"stat_insert__failed"`: the stat could not be saved.
+#[allow(clippy::too_many_arguments)]
+pub async fn _create_stat(ctx: &Context<'_>, name: String, kind: StatType, base_value: String, min: Option<String>, max: Option<String>, formula: Option<String>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("name", name.clone());
+    if name.is_empty() {
+        return Err("stat__empty_name".into());
+    }
+    let universe_id = universe_id_of(ctx).await?;
+
+    args.set("value", base_value.clone());
+    let base_value = parse_value(kind, &base_value)?;
+    args.set("value", min.clone().unwrap_or_default());
+    let min = parse_bound(kind, min.as_deref())?;
+    args.set("value", max.clone().unwrap_or_default());
+    let max = parse_bound(kind, max.as_deref())?;
+    check_formula(formula.as_deref(), args)?;
+    let stat = Stat { _id: ObjectId::new(), universe_id, name, base_value, formula, min, max, modifiers: vec![] };
+    stat.check_definition()?;
+
+    stat.insert_stat().await?;
+    Ok("stat_create__success")
+}
@@ -0,0 +1,47 @@
+use fluent::FluentArgs;
+use crate::characters::stat::autocomplete_stat;
+use crate::database::stats::get_stat_by_name;
+use crate::discord::poise_structs::{Context, Error};
+use crate::stats::universe_id_of;
+use crate::utility::reply::reply_with_args;
+
+/// Deletes a stat that no character or modifier uses.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "stat_delete")]
+pub async fn delete_stat(
+    ctx: Context<'_>,
+    #[description = "stat_delete.stat"]
+    #[autocomplete = "autocomplete_stat"]
+    stat: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _delete_stat(&ctx, stat.trim().to_string(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Deletes the stat named `stat_name`. `args` receives its name.
+///
+/// # Errors
+/// - `"stat__not_found"`: the universe has no stat with this name.
+/// - `"stat_delete__in_use"`: a character has a value for it or a modifier applies to it.
+/// - `"stat__database_error"`: a query failed.
+pub async fn _delete_stat(ctx: &Context<'_>, stat_name: String, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("name", stat_name.clone());
+    let universe_id = universe_id_of(ctx).await?;
+    let stat = match get_stat_by_name(universe_id, &stat_name).await {
+        Ok(Some(stat)) => stat,
+        Ok(None) => return Err("stat__not_found".into()),
+        Err(_) => return Err("stat__database_error".into()),
+    };
+    args.set("name", stat.name.clone());
+    match stat.is_referenced().await {
+        Ok(false) => {}
+        Ok(true) => return Err("stat_delete__in_use".into()),
+        Err(_) => return Err("stat__database_error".into()),
+    }
+    if stat.delete().await.is_err() {
+        return Err("stat__database_error".into());
+    }
+    Ok("stat_delete__success")
+}
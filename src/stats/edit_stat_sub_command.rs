@@ -0,0 +1,137 @@
+use fluent::FluentArgs;
+use crate::characters::stat::autocomplete_stat;
+use crate::database::characters::Character;
+use crate::database::stats::{get_stat_by_name, StatType, StatValue};
+use crate::discord::poise_structs::{Context, Error};
+use crate::stats::{check_formula, clean_formula, parse_value, universe_id_of, CLEAR_FIELD};
+use crate::utility::reply::reply_with_args;
+
+/// Changes the name, base value, bounds or formula of a stat. `-` removes a bound or the formula.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "stat_edit")]
+pub async fn edit_stat(
+    ctx: Context<'_>,
+    #[description = "stat_edit.stat"]
+    #[autocomplete = "autocomplete_stat"]
+    stat: String,
+    #[description = "stat_edit.name"]
+    name: Option<String>,
+    #[description = "stat_edit.base_value"]
+    base_value: Option<String>,
+    #[description = "stat_edit.min"]
+    min: Option<String>,
+    #[description = "stat_edit.max"]
+    max: Option<String>,
+    #[description = "stat_edit.formula"]
+    formula: Option<String>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let changes = StatChanges { name, base_value, min, max, formula };
+    let result = _edit_stat(&ctx, stat.trim().to_string(), changes, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Fields given to `/stat edit`, as typed.
+pub struct StatChanges {
+    pub name: Option<String>,
+    pub base_value: Option<String>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub formula: Option<String>,
+}
+
+impl StatChanges {
+    fn is_empty(&self) -> bool {
+        self.name.is_none() && self.base_value.is_none() && self.min.is_none() && self.max.is_none() && self.formula.is_none()
+    }
+}
+
+/// New value of an optional bound of kind `kind`: `None` when it is not changed, `Some(None)`
+/// when it is removed.
+fn parse_bound_change(kind: StatType, value: Option<&str>) -> Result<Option<Option<StatValue>>, Error> {
+    match value.map(str::trim) {
+        None => Ok(None),
+        Some(value) if value == CLEAR_FIELD => Ok(Some(None)),
+        Some(value) => Ok(Some(Some(parse_value(kind, value)?))),
+    }
+}
+
+/// Applies `changes` to the stat named `stat_name`. `args` receives the name of the stat and the
+/// value that could not be parsed, if any. Renaming the stat renames it in the characters too.
+///
+/// # Errors
+/// - `"stat_edit__nothing_to_change"`: no field was given.
+/// - `"stat__not_found"`: the universe has no stat with this name.
+/// - `"stat__empty_name"` / `"stat_insert__duplicate_name"`: the new name is blank or already used.
+/// - `"stat__invalid_value"`: the base value or a bound is not of the kind of the stat.
+/// - `"stat_formula__invalid"` / `"stat_formula__too_expensive"`: the new formula does not
+///   compile; `args` receives the reason.
+/// - `"stat__bounds_not_numeric"` / `"stat__invalid_bounds"` / `"stat__out_of_bounds"`: see
+///   [`crate::database::stats::Stat::check_definition`].
+/// - `"stat__database_error"`: a query failed.
+pub async fn _edit_stat(ctx: &Context<'_>, stat_name: String, changes: StatChanges, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("name", stat_name.clone());
+    if changes.is_empty() {
+        return Err("stat_edit__nothing_to_change".into());
+    }
+    let universe_id = universe_id_of(ctx).await?;
+    let mut stat = match get_stat_by_name(universe_id, &stat_name).await {
+        Ok(Some(stat)) => stat,
+        Ok(None) => return Err("stat__not_found".into()),
+        Err(_) => return Err("stat__database_error".into()),
+    };
+    let old_name = stat.name.clone();
+    let kind = StatType::of(&stat.base_value);
+
+    if let Some(name) = changes.name.map(|name| name.trim().to_string()) {
+        args.set("name", name.clone());
+        if name.is_empty() {
+            return Err("stat__empty_name".into());
+        }
+        match get_stat_by_name(universe_id, &name).await {
+            Ok(Some(other)) if other._id != stat._id => return Err("stat_insert__duplicate_name".into()),
+            Ok(_) => {}
+            Err(_) => return Err("stat__database_error".into()),
+        }
+        stat.name = name;
+    }
+    if let Some(base_value) = changes.base_value {
+        args.set("value", base_value.clone());
+        stat.base_value = parse_value(kind, &base_value)?;
+    }
+    args.set("value", changes.min.clone().unwrap_or_default());
+    if let Some(min) = parse_bound_change(kind, changes.min.as_deref())? {
+        stat.min = min;
+    }
+    args.set("value", changes.max.clone().unwrap_or_default());
+    if let Some(max) = parse_bound_change(kind, changes.max.as_deref())? {
+        stat.max = max;
+    }
+    if let Some(formula) = changes.formula {
+        stat.formula = if formula.trim() == CLEAR_FIELD { None } else { clean_formula(Some(formula)) };
+        check_formula(stat.formula.as_deref(), args)?;
+    }
+    stat.check_definition()?;
+
+    if stat.update().await.is_err() {
+        return Err("stat__database_error".into());
+    }
+    if stat.name != old_name && Character::rename_stat(universe_id, &old_name, &stat.name).await.is_err() {
+        return Err("stat__database_error".into());
+    }
+    Ok("stat_edit__success")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_bound_change() {
+        assert_eq!(parse_bound_change(StatType::Int, None).unwrap(), None);
+        assert_eq!(parse_bound_change(StatType::Int, Some(" - ")).unwrap(), Some(None));
+        assert_eq!(parse_bound_change(StatType::Float, Some("2.5")).unwrap(), Some(Some(StatValue::F64(2.5))));
+        assert!(parse_bound_change(StatType::Int, Some("2.5")).is_err());
+    }
+}
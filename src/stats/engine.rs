@@ -0,0 +1,349 @@
+//! Evaluation of the effective value of a stat: its formula, then the modifiers applying to it.
+//!
+//! Everything here is synchronous and reads nothing but its arguments, so callers gather the
+//! character stats and the active modifiers first. Formulas are written by universe admins, so
+//! they run in a sandboxed engine with a budget: a formula exceeding it fails instead of
+//! hanging the bot.
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Dynamic, Engine, EvalAltResult, ParseError, ParseErrorType, Scope};
+use crate::database::modifiers::{Modifier, ModifierType};
+use crate::database::stats::{Stat, StatType, StatValue};
+
+/// Why the effective value of a stat could not be computed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// A formula is not valid rhai.
+    Syntax(String),
+    /// A division by zero, an overflow, or a result that is not a finite number.
+    Arithmetic(String),
+    /// A value does not have the type the stat or the operation expects.
+    TypeMismatch { expected: &'static str, found: String },
+    /// Any other failure of a formula, such as an unknown variable.
+    Runtime(String),
+    /// A formula used more operations, depth, memory or time than allowed.
+    TooExpensive(String),
+}
+
+impl EvalError {
+    /// Translation key describing the error.
+    pub fn key(&self) -> &'static str {
+        match self {
+            EvalError::Syntax(_) => "stat_formula__invalid",
+            EvalError::Arithmetic(_) => "stat_formula__arithmetic",
+            EvalError::TypeMismatch { .. } => "stat_formula__type_mismatch",
+            EvalError::Runtime(_) => "stat_formula__failed",
+            EvalError::TooExpensive(_) => "stat_formula__too_expensive",
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Syntax(e) | EvalError::Arithmetic(e) | EvalError::Runtime(e) | EvalError::TooExpensive(e) => write!(f, "{e}"),
+            EvalError::TypeMismatch { expected, found } => write!(f, "expected {expected}, found {found}"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+impl From<ParseError> for EvalError {
+    fn from(error: ParseError) -> Self {
+        match *error.0 {
+            ParseErrorType::ExprTooDeep | ParseErrorType::LiteralTooLarge(..) => EvalError::TooExpensive(error.to_string()),
+            _ => EvalError::Syntax(error.to_string()),
+        }
+    }
+}
+
+impl From<Box<EvalAltResult>> for EvalError {
+    fn from(error: Box<EvalAltResult>) -> Self {
+        match *error {
+            EvalAltResult::ErrorParsing(e, position) => ParseError(Box::new(e), position).into(),
+            EvalAltResult::ErrorTooManyOperations(_) | EvalAltResult::ErrorStackOverflow(_)
+            | EvalAltResult::ErrorDataTooLarge(..) | EvalAltResult::ErrorTerminated(..) => EvalError::TooExpensive(error.to_string()),
+            EvalAltResult::ErrorArithmetic(e, _) => EvalError::Arithmetic(e),
+            EvalAltResult::ErrorMismatchDataType(expected, found, _) | EvalAltResult::ErrorMismatchOutputType(expected, found, _) => {
+                EvalError::TypeMismatch { expected: type_label(&expected), found }
+            }
+            e => EvalError::Runtime(e.to_string()),
+        }
+    }
+}
+
+/// Name of a rhai type in the words of [`EvalError::TypeMismatch`].
+fn type_label(rhai_type: &str) -> &'static str {
+    match rhai_type {
+        "i64" | "int" => "integer",
+        "f64" | "float" => "decimal",
+        "bool" => "boolean",
+        "string" | "String" => "text",
+        _ => "value",
+    }
+}
+
+/// Operations a formula evaluation may run.
+static MAX_OPERATIONS: u64 = 50_000;
+/// Depth of the expressions of a formula, and of those in its functions.
+static MAX_EXPR_DEPTH: usize = 32;
+/// Depth of the function calls of a formula.
+static MAX_CALL_LEVELS: usize = 16;
+/// Length of the strings and arrays a formula may build.
+static MAX_DATA_SIZE: usize = 1024;
+/// Time all the formulas of one evaluation may take.
+static EVALUATION_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Engine running stat formulas: bounded in operations, depth, data size and time, without
+/// `eval`, modules nor output.
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_expr_depths(MAX_EXPR_DEPTH, MAX_EXPR_DEPTH)
+        .set_max_call_levels(MAX_CALL_LEVELS)
+        .set_max_string_size(MAX_DATA_SIZE)
+        .set_max_array_size(MAX_DATA_SIZE)
+        .set_max_map_size(MAX_DATA_SIZE)
+        .set_module_resolver(DummyModuleResolver::new())
+        .disable_symbol("eval")
+        .disable_symbol("import")
+        .on_print(|_| {})
+        .on_debug(|_, _, _| {});
+    let start = Instant::now();
+    engine.on_progress(move |_| (start.elapsed() > EVALUATION_TIMEOUT).then_some(Dynamic::UNIT));
+    engine
+}
+
+/// Checks that `formula` compiles within the limits of the sandbox, before it is saved.
+pub fn validate_formula(formula: &str) -> Result<(), EvalError> {
+    sandboxed_engine().compile(formula).map(|_| ()).map_err(EvalError::from)
+}
+
+/// Effective value of `stat` for a character whose stats are `character_stats`.
+///
+/// The value starts from the character's own value of the stat, or the base value of the stat,
+/// and goes through the formula of the stat if it has one. Formulas read the stats of the
+/// character by name, and `base_value`. The modifiers of `modifiers` applying to `stat` then
+/// apply by increasing priority; at equal priority, bases first, then multipliers, then flats, so
+/// that one group gives `multiplier * (value + base) + flat`. A modifier with a formula computes
+/// the new value from `value` and `modifier` instead. The result is kept within the bounds of the
+/// stat and has its type, integers being rounded.
+///
+/// Callers only pass active modifiers: expiry depends on the clock, which is not read here.
+pub fn compute_effective_value(stat: &Stat, character_stats: &HashMap<String, StatValue>, modifiers: &[Modifier]) -> Result<StatValue, EvalError> {
+    let engine = sandboxed_engine();
+    let own_value = character_stats.get(&stat.name).unwrap_or(&stat.base_value);
+    let mut value = own_value.to_dynamic();
+
+    if let Some(formula) = &stat.formula {
+        let mut scope = stat_scope(character_stats);
+        scope.push("base_value", own_value.to_dynamic());
+        value = engine.eval_with_scope::<Dynamic>(&mut scope, formula)?;
+    }
+
+    let mut modifiers: Vec<&Modifier> = modifiers.iter().filter(|modifier| modifier.stat == stat._id).collect();
+    modifiers.sort_by_key(|modifier| (modifier.priority, kind_rank(modifier.modifier_type)));
+    for modifier in modifiers {
+        value = match &modifier.formula {
+            Some(formula) => {
+                let mut scope = stat_scope(character_stats);
+                scope.push("value", value);
+                scope.push("modifier", modifier.value.to_dynamic());
+                engine.eval_with_scope::<Dynamic>(&mut scope, formula)?
+            }
+            None => {
+                let current = as_number(&value)?;
+                let operand = as_number(&modifier.value.to_dynamic())?;
+                Dynamic::from_float(match modifier.modifier_type {
+                    ModifierType::Base | ModifierType::Flats => current + operand,
+                    ModifierType::Multiplier => current * operand,
+                })
+            }
+        };
+    }
+
+    into_stat_value(stat, value)
+}
+
+/// Scope holding each stat of the character as a variable named after it.
+fn stat_scope(character_stats: &HashMap<String, StatValue>) -> Scope<'static> {
+    let mut scope = Scope::new();
+    for (name, value) in character_stats {
+        scope.push(name.clone(), value.to_dynamic());
+    }
+    scope
+}
+
+/// Order of the modifier kinds within a priority.
+fn kind_rank(modifier_type: ModifierType) -> u8 {
+    match modifier_type {
+        ModifierType::Base => 0,
+        ModifierType::Multiplier => 1,
+        ModifierType::Flats => 2,
+    }
+}
+
+fn as_number(value: &Dynamic) -> Result<f64, EvalError> {
+    if let Ok(v) = value.as_int() {
+        return Ok(v as f64);
+    }
+    value.as_float().map_err(|found| EvalError::TypeMismatch { expected: "number", found: found.to_string() })
+}
+
+/// Converts the result of the evaluation of `stat` to its type, within its bounds.
+fn into_stat_value(stat: &Stat, value: Dynamic) -> Result<StatValue, EvalError> {
+    let kind = StatType::of(&stat.base_value);
+    if !kind.is_numeric() {
+        let found = value.type_name().to_string();
+        return match kind {
+            StatType::Bool => value.as_bool().map(StatValue::Bool).map_err(|_| EvalError::TypeMismatch { expected: "boolean", found }),
+            _ => value.into_string().map(StatValue::String).map_err(|_| EvalError::TypeMismatch { expected: "text", found }),
+        };
+    }
+
+    let mut number = as_number(&value)?;
+    if !number.is_finite() {
+        return Err(EvalError::Arithmetic(format!("{} is not a finite number", stat.name)));
+    }
+    if let Some(max) = &stat.max && max.as_f64() < number {
+        number = max.as_f64();
+    }
+    if let Some(min) = &stat.min && min.as_f64() > number {
+        number = min.as_f64();
+    }
+    match kind {
+        StatType::Int => Ok(StatValue::I64(number.round() as i64)),
+        _ => Ok(StatValue::F64(number)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stat(name: &str, base_value: StatValue, formula: Option<&str>) -> Stat {
+        Stat { formula: formula.map(str::to_string), ..Stat::named(name, base_value) }
+    }
+
+    fn modifier(stat: &Stat, modifier_type: ModifierType, value: f64, priority: i32) -> Modifier {
+        Modifier { priority, ..Modifier::on(stat._id, modifier_type, StatValue::F64(value)) }
+    }
+
+    fn stats(values: &[(&str, StatValue)]) -> HashMap<String, StatValue> {
+        values.iter().map(|(name, value)| (name.to_string(), value.clone())).collect()
+    }
+
+    #[test]
+    fn test_without_formula_nor_modifiers() {
+        let speed = stat("speed", StatValue::I64(3), None);
+        assert_eq!(compute_effective_value(&speed, &HashMap::new(), &[]), Ok(StatValue::I64(3)));
+        assert_eq!(compute_effective_value(&speed, &stats(&[("speed", StatValue::I64(5))]), &[]), Ok(StatValue::I64(5)));
+    }
+
+    #[test]
+    fn test_formula_reads_character_stats() {
+        let health = stat("health", StatValue::I64(10), Some("base_value + strength * 2"));
+        let character = stats(&[("health", StatValue::I64(10)), ("strength", StatValue::I64(4))]);
+        assert_eq!(compute_effective_value(&health, &character, &[]), Ok(StatValue::I64(18)));
+
+        let unknown = stat("health", StatValue::I64(10), Some("agility * 2"));
+        assert!(matches!(compute_effective_value(&unknown, &character, &[]), Err(EvalError::Runtime(_))));
+    }
+
+    #[test]
+    fn test_modifiers_in_priority_order() {
+        let mut strength = stat("strength", StatValue::I64(10), None);
+        strength.max = Some(StatValue::I64(40));
+        let other = stat("other", StatValue::I64(0), None);
+        // One group: 2 * (10 + 2) + 1; modifiers of other stats are ignored
+        let group = [
+            modifier(&strength, ModifierType::Flats, 1.0, 0),
+            modifier(&strength, ModifierType::Multiplier, 2.0, 0),
+            modifier(&strength, ModifierType::Base, 2.0, 0),
+            modifier(&other, ModifierType::Flats, 100.0, 0),
+        ];
+        assert_eq!(compute_effective_value(&strength, &HashMap::new(), &group), Ok(StatValue::I64(25)));
+
+        // The flat bonus now applies before the multiplier: 2 * (10 + 1)
+        let ordered = [modifier(&strength, ModifierType::Multiplier, 2.0, 1), modifier(&strength, ModifierType::Flats, 1.0, 0)];
+        assert_eq!(compute_effective_value(&strength, &HashMap::new(), &ordered), Ok(StatValue::I64(22)));
+
+        // Kept within the bounds
+        let huge = [modifier(&strength, ModifierType::Multiplier, 10.0, 0)];
+        assert_eq!(compute_effective_value(&strength, &HashMap::new(), &huge), Ok(StatValue::I64(40)));
+    }
+
+    #[test]
+    fn test_modifier_formula() {
+        let speed = stat("speed", StatValue::F64(4.0), None);
+        let mut slow = modifier(&speed, ModifierType::Base, 2.0, 0);
+        slow.formula = Some("value / modifier - agility".to_string());
+        let character = stats(&[("agility", StatValue::F64(0.5))]);
+        assert_eq!(compute_effective_value(&speed, &character, &[slow]), Ok(StatValue::F64(1.5)));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let integer = stat("ratio", StatValue::I64(1), Some("base_value / 0"));
+        assert!(matches!(compute_effective_value(&integer, &HashMap::new(), &[]), Err(EvalError::Arithmetic(_))));
+
+        let decimal = stat("ratio", StatValue::F64(1.0), Some("base_value / 0.0"));
+        assert!(matches!(compute_effective_value(&decimal, &HashMap::new(), &[]), Err(EvalError::Arithmetic(_))));
+
+        let speed = stat("speed", StatValue::F64(4.0), None);
+        let stop = modifier(&speed, ModifierType::Multiplier, 0.0, 0);
+        assert_eq!(compute_effective_value(&speed, &HashMap::new(), &[stop]), Ok(StatValue::F64(0.0)));
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let cursed = stat("cursed", StatValue::Bool(false), Some("\"yes\""));
+        assert_eq!(
+            compute_effective_value(&cursed, &HashMap::new(), &[]),
+            Err(EvalError::TypeMismatch { expected: "boolean", found: "string".to_string() })
+        );
+
+        let title = stat("title", StatValue::String("knight".to_string()), None);
+        let bonus = modifier(&title, ModifierType::Flats, 1.0, 0);
+        assert!(matches!(compute_effective_value(&title, &HashMap::new(), &[bonus]), Err(EvalError::TypeMismatch { expected: "number", .. })));
+
+        let speed = stat("speed", StatValue::I64(3), Some("base_value + \"fast\""));
+        assert!(compute_effective_value(&speed, &HashMap::new(), &[]).is_err());
+
+        let race = stat("race", StatValue::String("elf".to_string()), Some("base_value + \"-born\""));
+        assert_eq!(compute_effective_value(&race, &HashMap::new(), &[]), Ok(StatValue::String("elf-born".to_string())));
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let broken = stat("speed", StatValue::I64(3), Some("base_value +"));
+        assert!(matches!(compute_effective_value(&broken, &HashMap::new(), &[]), Err(EvalError::Syntax(_))));
+    }
+
+    #[test]
+    fn test_validate_formula() {
+        assert_eq!(validate_formula("base_value + strength * 2"), Ok(()));
+        let error = validate_formula("base_value +").unwrap_err();
+        assert_eq!(error.key(), "stat_formula__invalid");
+        assert!(matches!(validate_formula("eval(\"1\")"), Err(EvalError::Syntax(_))));
+        assert!(matches!(validate_formula("import \"fs\" as fs; 1"), Err(EvalError::Syntax(_))));
+        let deep = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+        assert!(matches!(validate_formula(&deep), Err(EvalError::TooExpensive(_))));
+    }
+
+    #[test]
+    fn test_budgets() {
+        let expensive = |formula: &str| {
+            let stat = stat("speed", StatValue::I64(3), Some(formula));
+            compute_effective_value(&stat, &HashMap::new(), &[])
+        };
+        assert!(matches!(expensive("loop { }"), Err(EvalError::TooExpensive(_))));
+        assert!(matches!(expensive("fn f(x) { f(x + 1) } f(0)"), Err(EvalError::TooExpensive(_))));
+        assert!(matches!(expensive("let s = \"ab\"; for i in 0..20 { s += s; } s.len()"), Err(EvalError::TooExpensive(_))));
+        assert_eq!(expensive("let total = 0; for i in 0..10 { total += i; } total"), Ok(StatValue::I64(45)));
+    }
+}
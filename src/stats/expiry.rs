@@ -0,0 +1,180 @@
+//! Background task removing the modifiers whose end has passed from places, roads and characters.
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, Color, CreateEmbed, CreateMessage, Http};
+use crate::database::characters::Character;
+use crate::database::modifiers::{find_expired_modifier_holders, pull_expired_modifiers, Modifier, ModifierHolder, ModifierType};
+use crate::database::places::Place;
+use crate::database::road::Road;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::get_stat_by_id;
+use crate::translation::get_by_locale;
+use crate::utility::tasks::TASKS;
+
+/// Environment variable overriding the delay between two scans, in seconds.
+static EXPIRY_INTERVAL_VAR: &str = "MODIFIER_EXPIRY_INTERVAL_SECS";
+static DEFAULT_EXPIRY_INTERVAL_SECS: u64 = 60;
+/// Environment variable overriding the number of documents of each kind cleaned per scan.
+static EXPIRY_BATCH_SIZE_VAR: &str = "MODIFIER_EXPIRY_BATCH_SIZE";
+static DEFAULT_EXPIRY_BATCH_SIZE: i64 = 100;
+
+/// A document whose modifiers ended, as reported in the log channel of its server.
+struct ExpiredModifiers {
+    server_id: u64,
+    /// Name of the place, road or character.
+    holder: String,
+    modifiers: Vec<Modifier>,
+}
+
+/// Positive value of the environment variable `var`, or `default`.
+fn env_setting<T: std::str::FromStr + PartialOrd + Default>(var: &str, default: T) -> T {
+    env::var(var).ok()
+        .and_then(|value| value.trim().parse::<T>().ok())
+        .filter(|value| *value > T::default())
+        .unwrap_or(default)
+}
+
+/// Modifiers of `modifiers` ended at `now`.
+pub fn ended_modifiers<'a>(modifiers: impl IntoIterator<Item = &'a Modifier>, now: u64) -> Vec<Modifier> {
+    modifiers.into_iter().filter(|modifier| modifier.has_ended(now)).cloned().collect()
+}
+
+/// Removes the modifiers ended at `now` from up to `batch_size` documents of kind `holder`, and
+/// returns them for the notices.
+async fn expire_holders(holder: ModifierHolder, now: u64, batch_size: i64) -> Vec<ExpiredModifiers> {
+    let expired: Vec<(mongodb::bson::oid::ObjectId, ExpiredModifiers)> = match holder {
+        ModifierHolder::Place => match find_expired_modifier_holders::<Place>(holder, now, batch_size).await {
+            Ok(places) => places.into_iter()
+                .map(|place| (place._id, ExpiredModifiers { server_id: place.server_id, modifiers: ended_modifiers(&place.modifiers, now), holder: place.name }))
+                .collect(),
+            Err(e) => return scan_failed(holder, e),
+        },
+        ModifierHolder::Road => match find_expired_modifier_holders::<Road>(holder, now, batch_size).await {
+            Ok(roads) => roads.into_iter()
+                .map(|road| (road._id, ExpiredModifiers { server_id: road.server_id, modifiers: ended_modifiers(&road.modifiers, now), holder: road.road_name }))
+                .collect(),
+            Err(e) => return scan_failed(holder, e),
+        },
+        ModifierHolder::Character => match find_expired_modifier_holders::<Character>(holder, now, batch_size).await {
+            Ok(characters) => characters.into_iter()
+                .map(|character| {
+                    let modifiers = ended_modifiers(character.stats.iter().flat_map(|stat| &stat.modifiers), now);
+                    (character._id, ExpiredModifiers { server_id: character.server_id, modifiers, holder: character.name })
+                })
+                .collect(),
+            Err(e) => return scan_failed(holder, e),
+        },
+    };
+
+    let mut removed = vec![];
+    for (id, expired) in expired {
+        match pull_expired_modifiers(holder, id, now).await {
+            Ok(_) => removed.push(expired),
+            Err(e) => tracing::warn!(?holder, %id, "failed to remove expired modifiers: {e}"),
+        }
+    }
+    removed
+}
+
+fn scan_failed(holder: ModifierHolder, error: mongodb::error::Error) -> Vec<ExpiredModifiers> {
+    tracing::error!(?holder, "failed to scan for expired modifiers: {error}");
+    vec![]
+}
+
+/// Removes every modifier ended by now, then posts a notice in the log channel of the servers
+/// of the places, roads and characters they applied to.
+async fn expire_modifiers(http: &Http, batch_size: i64) {
+    let now = Utc::now().timestamp() as u64;
+    let mut expired = vec![];
+    for holder in [ModifierHolder::Place, ModifierHolder::Road, ModifierHolder::Character] {
+        expired.extend(expire_holders(holder, now, batch_size).await);
+    }
+
+    let mut locales: HashMap<u64, String> = HashMap::new();
+    let mut stat_names: HashMap<mongodb::bson::oid::ObjectId, String> = HashMap::new();
+    for expired in expired.iter().filter(|expired| !expired.modifiers.is_empty()) {
+        let Ok(Some(server)) = get_server_by_id(expired.server_id).await else { continue };
+        let Some(log_channel) = server.log_channel_id else { continue };
+        if let Entry::Vacant(entry) = locales.entry(expired.server_id) {
+            let locale = match http.get_guild(expired.server_id.into()).await {
+                Ok(guild) => guild.preferred_locale,
+                Err(_) => "en-US".to_string(),
+            };
+            entry.insert(locale);
+        }
+        let locale = &locales[&expired.server_id];
+
+        let mut lines = vec![];
+        for modifier in &expired.modifiers {
+            if let Entry::Vacant(entry) = stat_names.entry(modifier.stat) {
+                let name = match get_stat_by_id(modifier.stat).await {
+                    Ok(Some(stat)) => stat.name,
+                    _ => "?".to_string(),
+                };
+                entry.insert(name);
+            }
+            let mut args = FluentArgs::new();
+            args.set("stat", stat_names[&modifier.stat].clone());
+            args.set("kind", get_by_locale(locale, modifier_kind_key(modifier.modifier_type), None, None));
+            lines.push(get_by_locale(locale, "modifier_expiry__notice", Some("line"), Some(&args)));
+        }
+        let mut args = FluentArgs::new();
+        args.set("holder", expired.holder.clone());
+        let embed = CreateEmbed::new()
+            .title(get_by_locale(locale, "modifier_expiry__notice", Some("title"), Some(&args)))
+            .description(lines.join("\n"))
+            .color(Color::from_rgb(0x6f, 0x00, 0xff));
+        if let Err(e) = ChannelId::new(log_channel.id).send_message(http, CreateMessage::new().embed(embed)).await {
+            tracing::warn!(guild_id = expired.server_id, "failed to post the modifier expiry notice: {e}");
+        }
+    }
+}
+
+/// Translation key naming the kind of a modifier.
+fn modifier_kind_key(modifier_type: ModifierType) -> &'static str {
+    match modifier_type {
+        ModifierType::Base => "modifier_type_base",
+        ModifierType::Multiplier => "modifier_type_multiplier",
+        ModifierType::Flats => "modifier_type_flats",
+    }
+}
+
+/// Starts the periodic removal of expired modifiers.
+pub async fn setup_modifier_expiry(http: Arc<Http>) {
+    let interval = Duration::from_secs(env_setting(EXPIRY_INTERVAL_VAR, DEFAULT_EXPIRY_INTERVAL_SECS));
+    let batch_size = env_setting(EXPIRY_BATCH_SIZE_VAR, DEFAULT_EXPIRY_BATCH_SIZE);
+    TASKS.spawn("modifier_expiry", move |mut shutdown| async move {
+        while shutdown.sleep(interval).await {
+            expire_modifiers(&http, batch_size).await;
+        }
+    }).await;
+    tracing::info!(interval_secs = interval.as_secs(), batch_size, "modifier expiry initialized");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::stats::StatValue;
+
+    fn modifier(end_timestamp: Option<u64>) -> Modifier {
+        Modifier { end_timestamp, ..Modifier::on(ObjectId::new(), ModifierType::Flats, StatValue::F64(1.0)) }
+    }
+
+    #[test]
+    fn test_ended_modifiers() {
+        let modifiers = [modifier(Some(10)), modifier(None), modifier(Some(20)), modifier(Some(11))];
+        let ended = ended_modifiers(&modifiers, 11);
+        assert_eq!(ended.iter().map(|modifier| modifier.end_timestamp).collect::<Vec<_>>(), vec![Some(10), Some(11)]);
+    }
+
+    #[test]
+    fn test_env_setting() {
+        assert_eq!(env_setting("MODIFIER_EXPIRY_TEST_UNSET", 60_u64), 60);
+    }
+}
@@ -0,0 +1,84 @@
+use fluent::FluentArgs;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::stats::{Stat, StatType};
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::info_place_sub_command::format_stat_value;
+use crate::stats::universe_id_of;
+use crate::tr;
+use crate::utility::pagination::{page_count, page_of, send_pages};
+use crate::utility::reply::reply;
+
+/// Lists the stats of the universe, ten per page.
+#[poise::command(slash_command, required_permissions= "ADMINISTRATOR", guild_only, rename = "stat_list")]
+pub async fn list_stats(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let stats = match _list_stats(&ctx).await {
+        Ok(stats) => stats,
+        Err(e) => {
+            let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+            return Ok(());
+        }
+    };
+    if stats.is_empty() {
+        let Ok(_) = reply(ctx, Ok("stat_list__empty")).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
+
+    send_pages(ctx, page_count(stats.len()), |page| page_embed(&ctx, &stats, page)).await
+}
+
+async fn _list_stats(ctx: &Context<'_>) -> Result<Vec<Stat>, Error> {
+    let universe_id = universe_id_of(ctx).await?;
+    let Ok(stats) = Stat::get_stats_by_universe(universe_id).await else { return Err("stat__database_error".into()) };
+    Ok(stats)
+}
+
+/// `min..max` range of `stat`, with `-` for a missing bound, or `None` when it has no bounds.
+pub fn bounds_label(stat: &Stat) -> Option<String> {
+    if stat.min.is_none() && stat.max.is_none() {
+        return None;
+    }
+    let bound = |value: &Option<_>| value.as_ref().map(format_stat_value).unwrap_or_else(|| "-".to_string());
+    Some(format!("{}..{}", bound(&stat.min), bound(&stat.max)))
+}
+
+fn page_embed(ctx: &Context<'_>, stats: &[Stat], page: usize) -> CreateEmbed {
+    let lines = page_of(stats, page).iter()
+        .map(|stat| {
+            let mut args = FluentArgs::new();
+            args.set("name", stat.name.clone());
+            args.set("kind", tr!(*ctx, "stat_type", @(StatType::of(&stat.base_value).label_key())));
+            args.set("base_value", format_stat_value(&stat.base_value));
+            args.set("bounds", bounds_label(stat).unwrap_or_else(|| "-".to_string()));
+            args.set("formula", stat.formula.clone().unwrap_or_else(|| "-".to_string()));
+            tr!(*ctx, "stat_list__row", @message; Some(&args))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut args = FluentArgs::new();
+    args.set("page", page + 1);
+    args.set("pages", page_count(stats.len()));
+    args.set("count", stats.len());
+    CreateEmbed::new()
+        .title(tr!(*ctx, "stat_list__page", @title; Some(&args)))
+        .description(lines)
+        .color(Color::from_rgb(112, 190, 255))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+    use crate::database::stats::StatValue;
+
+    #[test]
+    fn test_bounds_label() {
+        let mut stat = Stat { _id: ObjectId::new(), universe_id: ObjectId::new(), name: "health".to_string(), base_value: StatValue::I64(10), formula: None, min: None, max: None, modifiers: vec![] };
+        assert_eq!(bounds_label(&stat), None);
+        stat.min = Some(StatValue::I64(0));
+        assert_eq!(bounds_label(&stat).as_deref(), Some("0..-"));
+        stat.max = Some(StatValue::I64(20));
+        assert_eq!(bounds_label(&stat).as_deref(), Some("0..20"));
+    }
+}
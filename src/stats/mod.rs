@@ -0,0 +1,83 @@
+use fluent::FluentArgs;
+use mongodb::bson::oid::ObjectId;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::{StatType, StatValue};
+use crate::discord::poise_structs::{Context, Error};
+use crate::stats::create_stat_sub_command::create_stat;
+use crate::stats::delete_stat_sub_command::delete_stat;
+use crate::stats::edit_stat_sub_command::edit_stat;
+use crate::stats::engine::validate_formula;
+use crate::stats::list_stats_sub_command::list_stats;
+
+pub mod create_stat_sub_command;
+pub mod delete_stat_sub_command;
+pub mod edit_stat_sub_command;
+pub mod engine;
+pub mod expiry;
+pub mod list_stats_sub_command;
+
+#[poise::command(slash_command, subcommands("create_stat", "list_stats", "edit_stat", "delete_stat"), subcommand_required, rename = "stat")]
+pub async fn stat(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Value given to an optional field of `/stat edit` to remove it.
+pub static CLEAR_FIELD: &str = "-";
+
+/// Universe linked to the guild of the command.
+///
+/// # Errors
+/// - `"stat__no_universe"`: the guild is not linked to a universe.
+/// - `"stat__database_error"`: the server could not be read.
+pub async fn universe_id_of(ctx: &Context<'_>) -> Result<ObjectId, Error> {
+    match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => Ok(server.universe_id),
+        Ok(None) => Err("stat__no_universe".into()),
+        Err(_) => Err("stat__database_error".into()),
+    }
+}
+
+/// Parses `value` as a value of kind `kind`.
+///
+/// # Errors
+/// - `"stat__invalid_value"`: `value` is not of this kind.
+pub fn parse_value(kind: StatType, value: &str) -> Result<StatValue, Error> {
+    kind.parse(value).ok_or_else(|| "stat__invalid_value".into())
+}
+
+/// Parses the optional bound `value` of a stat of kind `kind`.
+pub fn parse_bound(kind: StatType, value: Option<&str>) -> Result<Option<StatValue>, Error> {
+    value.map(|value| parse_value(kind, value)).transpose()
+}
+
+/// Checks that `formula` compiles, giving the reason in `args` when it does not.
+///
+/// # Errors
+/// - `"stat_formula__invalid"`: the formula is not valid rhai.
+/// - `"stat_formula__too_expensive"`: the formula is too deep or too large.
+pub fn check_formula(formula: Option<&str>, args: &mut FluentArgs<'_>) -> Result<(), Error> {
+    let Some(formula) = formula else { return Ok(()) };
+    validate_formula(formula).map_err(|e| {
+        args.set("error", e.to_string());
+        Error::from(e.key())
+    })
+}
+
+/// Trimmed formula, `None` when it is blank.
+pub fn clean_formula(formula: Option<String>) -> Option<String> {
+    formula.map(|formula| formula.trim().to_string()).filter(|formula| !formula.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_bound() {
+        assert_eq!(parse_bound(StatType::Int, Some("3")).unwrap(), Some(StatValue::I64(3)));
+        assert_eq!(parse_bound(StatType::Int, None).unwrap(), None);
+        assert!(parse_bound(StatType::Int, Some("three")).is_err());
+        assert_eq!(clean_formula(Some("  ".to_string())), None);
+        assert_eq!(clean_formula(Some(" strength * 2 ".to_string())), Some("strength * 2".to_string()));
+    }
+}
@@ -1,18 +1,18 @@
-use log::log;
 use poise::CreateReply;
 use serenity::all::{CreateEmbedFooter};
 use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
 
 #[poise::command(slash_command, rename = "support_command")]
 pub async fn support_command(ctx: Context<'_>) -> Result<(), Error>{
     match ctx.send(CreateReply::default()
-        .content(format!("## {}\n{}", crate::translation::get(ctx, "tips", Some("title"), None), crate::translation::get(ctx, "tips", Some("message"), None)))
+        .content(format!("## {}\n{}", tr!(ctx, "tips", @title), tr!(ctx, "tips", @message)))
         .ephemeral(true),
     )
         .await {
         Ok(_) => {Ok(())}
         Err(e) => {
-            log!(log::Level::Error, "failed to reply:\nserver: {:?},\nerror: {:?}", ctx.guild_id(), e);
+            tracing::error!(guild_id = ?ctx.guild_id(), "failed to reply: {e}");
             Err("reply__reply_failed".into())}
     }
 }
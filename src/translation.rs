@@ -7,9 +7,9 @@
 //!
 //! ## Key Components
 //! - [`Translations`]: Holds the main and locale-specific translation bundles.
-//! - `tr!`: A macro for convenient string translation with argument support.
+//! - `tr!`: A macro for convenient string translation with argument support, of a message or one of its attributes.
 //! - [`format`]: Formats a Fluent message, resolving IDs, attributes, and arguments to a final string.
-//! - [`get`]: Retrieves a localized translation string, falling back gracefully if not found.
+//! - [`get`]: Retrieves a localized translation string, falling back gracefully if not found; deprecated for `tr!`.
 //! - [`read_ftl`]: Loads `.ftl` translation files into memory.
 //! - [`apply_translations`]: Applies translations to structured command definitions.
 //! - [`smart_tr`]: Enriches translations by auto-resolving missing variables.
@@ -17,21 +17,35 @@
 //! ## Usage
 //! This module primarily supports applications where localization for commands and messaging is necessary,
 //! such as bots or internationalized software systems.
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use crate::{Context, Data, Error};
 use fluent::{FluentArgs, FluentValue};
 use fluent::bundle::FluentBundle;
 use fluent::FluentResource;
+use fluent_syntax::ast::{Entry, Pattern, PatternElement};
 use intl_memoizer::concurrent::IntlLangMemoizer;
+use arc_swap::ArcSwap;
 use lazy_static::lazy_static;
+use lru::LruCache;
+use once_cell::sync::Lazy;
 use regex::Regex;
+use serenity::prelude::TypeMapKey;
+use unic_langid::LanguageIdentifier;
+use crate::utility::guild_locales::GUILD_LOCALES;
 
 /// Type alias for a Fluent bundle with concurrent memoization
 type Bundle = FluentBundle<FluentResource, IntlLangMemoizer>;
 
+/// Translations shared by the commands, the event handlers and [`get_by_locale`], swapped as a
+/// whole when they are reloaded.
+pub type SharedTranslations = Arc<ArcSwap<Translations>>;
+
 lazy_static!(
-    pub static ref TRANSLATIONS: Translations = read_ftl().expect("failed to read translation files");
+    pub static ref TRANSLATIONS: SharedTranslations = Arc::new(ArcSwap::from_pointee(read_ftl().expect("failed to read translation files")));
 );
 
 /// A structure that holds translation bundles for managing multilingual support.
@@ -61,16 +75,100 @@ lazy_static!(
 /// let mut other_bundles: HashMap<String, Bundle> = HashMap::new();
 /// other_bundles.insert("fr".to_string(), Bundle::new());
 ///
-/// let translations = Translations {
-///     main: main_bundle,
-///     other: other_bundles,
-/// };
+/// let translations = Translations::new(main_bundle, other_bundles);
 ///
 /// assert!(translations.other.contains_key("fr"));
 /// ```
 pub struct Translations {
     pub main: Bundle,
     pub other: HashMap<String, Bundle>,
+    /// Messages without variables formatted from these bundles, dropped with them when
+    /// `/reload_translations` swaps them.
+    pub static_messages: StaticMessages,
+}
+
+impl Translations {
+    pub fn new(main: Bundle, other: HashMap<String, Bundle>) -> Translations {
+        Translations { main, other, static_messages: StaticMessages::default() }
+    }
+
+    /// Whether a translation file was loaded for `locale`.
+    pub fn has_locale(&self, locale: &str) -> bool {
+        locale == MAIN_LOCALE || self.other.contains_key(locale)
+    }
+
+    /// Bundle to translate into `locale`: the one of this exact locale, else the one of its
+    /// language alone (`fr` for `fr-FR`), else one of another locale of this language (`fr-FR`
+    /// for `fr` or `fr-CA`). `None` when no bundle shares its language, for the main bundle to be used.
+    pub fn bundle_for(&self, locale: &str) -> Option<&Bundle> {
+        if let Some(bundle) = self.other.get(locale) {
+            return Some(bundle);
+        }
+        let requested: LanguageIdentifier = locale.parse().ok()?;
+        let mut same_language: Vec<(&String, LanguageIdentifier)> = self.other.keys()
+            .filter_map(|key| Some((key, key.parse::<LanguageIdentifier>().ok()?)))
+            .filter(|(_, available)| available.language == requested.language)
+            .collect();
+        // Sorted so that the same bundle is chosen whatever the order of the map
+        same_language.sort_by_key(|(key, _)| *key);
+        let key = same_language.iter()
+            .find(|(_, available)| *available == requested)
+            .or_else(|| same_language.iter().find(|(_, available)| {
+                available.script.is_none() && available.region.is_none() && available.variants().len() == 0
+            }))
+            .or(same_language.first())
+            .map(|(key, _)| *key)?;
+        self.other.get(key)
+    }
+}
+
+/// Most messages [`StaticMessages`] keeps formatted.
+static STATIC_MESSAGES_CAPACITY: NonZeroUsize = NonZeroUsize::new(512).unwrap();
+
+/// Locale of the bundle, id and attribute of a message.
+type StaticMessageKey = (String, String, Option<String>);
+
+/// The last messages without placeables [`smart_tr`] formatted, such as the labels of list rows,
+/// which would otherwise be formatted again for every row.
+pub struct StaticMessages {
+    cache: Mutex<LruCache<StaticMessageKey, String>>,
+    /// How many times a message was formatted because it was not cached.
+    formatted: AtomicUsize,
+}
+
+impl Default for StaticMessages {
+    fn default() -> StaticMessages {
+        StaticMessages { cache: Mutex::new(LruCache::new(STATIC_MESSAGES_CAPACITY)), formatted: AtomicUsize::new(0) }
+    }
+}
+
+impl StaticMessages {
+    /// The message `id` (its attribute `attr`) of the bundle of `locale`, formatted with `format`
+    /// unless it is cached.
+    fn get_or_format(&self, locale: &str, id: &str, attr: Option<&str>, format: impl FnOnce() -> String) -> String {
+        let key = (locale.to_string(), id.to_string(), attr.map(str::to_string));
+        let mut cache = self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(text) = cache.get(&key) {
+            return text.clone();
+        }
+        self.formatted.fetch_add(1, Ordering::Relaxed);
+        let text = format();
+        cache.put(key, text.clone());
+        text
+    }
+
+    /// How many times a message was formatted because it was not cached.
+    #[cfg(test)]
+    pub fn formatted(&self) -> usize {
+        self.formatted.load(Ordering::Relaxed)
+    }
+}
+
+/// `TypeMap` key giving serenity event handlers access to the current translations.
+pub struct TranslationsKey;
+
+impl TypeMapKey for TranslationsKey {
+    type Value = SharedTranslations;
 }
 
 /// A macro for performing translations using Fluent-based argument substitution.
@@ -84,12 +182,22 @@ pub struct Translations {
 /// ```
 /// tr!(context, id);
 /// tr!(context, id, argname: value, ...);
+/// tr!(context, id, @attribute);
+/// tr!(context, id, @attribute, argname: value, ...);
+/// tr!(context, id, @(attribute_expression), argname: value, ...);
+/// tr!(context, id, @attribute; optional_args);
 /// ```
 ///
 /// - When called with just `context` and `id`, it attempts to fetch the associated
 ///   translation from the Fluent resources.
 /// - When called with additional arguments in the form `argname: value`, it substitutes
 ///   placeholders in the translation with the specified values.
+/// - When called with `@attribute`, it translates that attribute of the message, such as
+///   `.title`, rather than its value; `@(expression)` names the attribute with a `&str`.
+/// - When the arguments follow a `;`, they are an `Option<&FluentArgs>` built beforehand.
+///
+/// Variables missing from the arguments are resolved as the messages of the same name, for the
+/// attributes too; see [`smart_tr`](crate::translation::smart_tr).
 ///
 /// # Parameters
 ///
@@ -131,20 +239,66 @@ pub struct Translations {
 /// let translation = tr!(context, "unknown_key");
 /// assert_eq!(translation, "unknown_key");
 /// ```
+///
+/// An attribute, with arguments built beforehand:
+/// ```rust
+/// let title = tr!(context, "setup__error", @title);
+/// let message = tr!(context, "add_server__confirm", @message; Some(&args));
+/// ```
 
 #[macro_export]
 macro_rules! tr {
+    (@attr $attr:ident) => { stringify!($attr) };
+    (@attr ($attr:expr)) => { $attr };
+    ( $ctx:expr, $id:expr, @$attr:tt; $args:expr ) => {{
+        $crate::translation::smart_tr($ctx, $id, Some($crate::tr!(@attr $attr)), $args).unwrap_or_else(|_| $id.to_string())
+    }};
+    ( $ctx:expr, $id:expr, @$attr:tt $(, $argname:ident: $argvalue:expr )* $(,)? ) => {{
+        #[allow(unused_mut)]
+        let mut args = fluent::FluentArgs::new();
+        $( args.set(stringify!($argname), $argvalue); )*
+        $crate::translation::smart_tr($ctx, $id, Some($crate::tr!(@attr $attr)), Some(&args)).unwrap_or_else(|_| $id.to_string())
+    }};
+    ( $ctx:expr, $id:expr; $args:expr ) => {{
+        $crate::translation::smart_tr($ctx, $id, None, $args).unwrap_or_else(|_| $id.to_string())
+    }};
     ( $ctx:expr, $id:expr $(, $argname:ident: $argvalue:expr )* $(,)? ) => {{
         #[allow(unused_mut)]
         let mut args = fluent::FluentArgs::new();
         $( args.set(stringify!($argname), $argvalue); )*
-        $crate::translation::smart_tr($ctx, $id, Some(&args)).unwrap_or_else(|_| $id.to_string())
+        $crate::translation::smart_tr($ctx, $id, None, Some(&args)).unwrap_or_else(|_| $id.to_string())
     }};
     ( $ctx:expr, $id:expr ) => {{
-        $crate::translation::smart_tr($ctx, $id, None).unwrap_or_else(|_| $id.to_string())
+        $crate::translation::smart_tr($ctx, $id, None, None).unwrap_or_else(|_| $id.to_string())
     }};
 }
 
+/// Translates a message depending on a number, like [`tr!`] with the number as the `count`
+/// argument.
+///
+/// The number is given to Fluent as a number, not as text, so that the message can select its
+/// plural form with `{ $count -> [one] ... *[other] ... }`; numbers given to [`tr!`] and to
+/// `FluentArgs::set` keep their type the same way and may be selected on too.
+///
+/// # Syntax
+/// ```
+/// trn!(context, id, count);
+/// trn!(context, id, count, argname: value, ...);
+/// ```
+///
+/// # Examples
+/// ```rust
+/// // setup__repair_recreated = { $count -> [one] {$count} resource recreated *[other] {$count} resources recreated }
+/// let heading = trn!(context, "setup__repair_recreated", 2);
+/// assert_eq!(heading, "2 resources recreated");
+/// ```
+#[macro_export]
+macro_rules! trn {
+    ( $ctx:expr, $id:expr, $count:expr $(, $argname:ident: $argvalue:expr )* $(,)? ) => {
+        $crate::tr!($ctx, $id, count: fluent::FluentValue::from($count) $(, $argname: $argvalue )*)
+    };
+}
+
 /// A macro for performing translations using Fluent-based argument substitution with explicit locale.
 ///
 /// This macro provides a way to localize strings based on a locale string (e.g., "en-US") and
@@ -302,8 +456,9 @@ pub fn format(
 ///
 /// # Behavior
 ///
-/// 1. Fetches the translation resource based on the current locale from `ctx.data().translations`.
-/// 2. Attempts to format the string using `translations.other` for the given locale.
+/// 1. Fetches the translation resource based on the current locale from `ctx.data().translations`,
+///    or on the locale of the server or of its universe when the interaction has none (see [`context_bundle`]).
+/// 2. Attempts to format the string using the bundle [`Translations::bundle_for`] picks for the locale.
 /// 3. Falls back to a global/main translation resource if the locale-specific resource is not found.
 /// 4. Logs a warning if the translation is missing and uses the `id` as the fallback value.
 ///
@@ -330,15 +485,16 @@ pub fn format(
 ///
 /// [`Context`]: poise::Context
 #[allow(unused)]
+#[deprecated(note = "use `tr!` with an `@attribute` selector, which also resolves the missing variables")]
 pub fn get(
     ctx: Context,
     id: &str,
     attr: Option<&str>,
     args: Option<&FluentArgs<'_>>,
 ) -> String {
-    let translations = &ctx.data().translations;
-    ctx.locale()
-        .and_then(|locale| format(translations.other.get(locale)?, id, attr, args))
+    let translations = ctx.data().translations.load();
+    let fallback_locale = cached_fallback_locale(ctx);
+    format(context_bundle(&translations, ctx.locale(), fallback_locale.as_deref()), id, attr, args)
         .or_else(|| format(&translations.main, id, attr, args))
         .unwrap_or_else(|| {
             tracing::warn!("Unknown Fluent message identifier `{}`", id);
@@ -346,15 +502,62 @@ pub fn get(
         })
 }
 
+/// Bundle to translate a reply into: the one of the interaction's `locale`, else the one of
+/// `fallback_locale`, the locale of the server or the default one of its universe, else the main one.
+pub fn context_bundle<'a>(translations: &'a Translations, locale: Option<&str>, fallback_locale: Option<&str>) -> &'a Bundle {
+    locale.or(fallback_locale)
+        .and_then(|locale| translations.bundle_for(locale))
+        .unwrap_or(&translations.main)
+}
+
+/// Locale of the server `ctx` runs on, else the default one of its universe, when the
+/// interaction has no locale and the pre-command hook cached it.
+fn cached_fallback_locale(ctx: Context) -> Option<String> {
+    if ctx.locale().is_some() {
+        return None;
+    }
+    let locale = ctx.data().guild_locales.cached(ctx.guild_id()?.get(), std::time::Instant::now())?;
+    locale.preferred().map(str::to_string)
+}
+
+/// Retrieves a localized string from an explicit `Translations` instance.
+///
+/// This is the context-free counterpart of [`get`]: it is meant for code that does not have a
+/// poise `Context`, such as the serenity event handler, which fetches the shared translations
+/// from the client's `TypeMap` with [`TranslationsKey`].
+///
+/// # Arguments
+///
+/// * `translations` - A reference to the `Translations` struct containing translation bundles.
+/// * `locale` - A string slice identifying the locale (e.g., "en-US", "fr", "es").
+/// * `id` - A string slice identifying the translation message.
+/// * `attr` - An optional attribute to retrieve a specific variant of the translation (can be `None`).
+/// * `args` - An optional set of arguments of type `FluentArgs` to interpolate into the message (can be `None`).
+///
+/// # Returns
+///
+/// The formatted string for `locale`, falling back to the main bundle and then to `id` itself.
+pub fn tr_raw(
+    translations: &Translations,
+    locale: &str,
+    id: &str,
+    attr: Option<&str>,
+    args: Option<&FluentArgs<'_>>,
+) -> String {
+    translations.bundle_for(locale)
+        .and_then(|bundle| format(bundle, id, attr, args))
+        .or_else(|| format(&translations.main, id, attr, args))
+        .unwrap_or_else(|| id.to_string())
+}
+
 /// Retrieves a localized string based on the given identifier, locale, and optional attributes or arguments.
 ///
-/// This function attempts to fetch a translation string from the provided translations for a specific
+/// This function attempts to fetch a translation string from the global [`TRANSLATIONS`] for a specific
 /// locale string (e.g., "en-US"). If the locale-specific translation is not found, it falls back to
 /// the main/default translation resource. If neither is available, it returns the identifier itself.
 ///
 /// # Arguments
 ///
-/// * `translations` - A reference to the `Translations` struct containing translation bundles.
 /// * `locale` - A string slice identifying the locale (e.g., "en-US", "fr", "es").
 /// * `id` - A string slice identifying the translation message.
 /// * `attr` - An optional attribute to retrieve a specific variant of the translation (can be `None`).
@@ -364,17 +567,10 @@ pub fn get(
 ///
 /// Returns the formatted localized string. If no translation is found, the identifier itself is returned as a fallback.
 ///
-/// # Behavior
-///
-/// 1. Fetches the translation resource based on the provided locale from `translations.other`.
-/// 2. Attempts to format the string using the locale-specific bundle.
-/// 3. Falls back to the main translation resource if the locale-specific resource is not found.
-/// 4. Returns the `id` as the fallback value if the translation is missing.
-///
 /// # Example
 ///
 /// ```rust
-/// let message = get_by_locale(&translations, "en-US", "welcome_message", None, None);
+/// let message = get_by_locale("en-US", "welcome_message", None, None);
 /// println!("{}", message);
 /// ```
 ///
@@ -386,39 +582,78 @@ pub fn get_by_locale(
     attr: Option<&str>,
     args: Option<&FluentArgs<'_>>,
 ) -> String {
-    TRANSLATIONS.other
-        .get(locale)
-        .and_then(|bundle| format(bundle, id, attr, args))
-        .or_else(|| format(&TRANSLATIONS.main, id, attr, args))
-        .unwrap_or_else(|| id.to_string())
+    tr_raw(&TRANSLATIONS.load(), locale, id, attr, args)
 }
 
-/// Reads Fluent translation files from the "translations" directory and returns a `Translations` object.
+/// Locale of the messages the bot posts on its own on the guild `guild_id`, such as
+/// announcements and logs: the locale of the server when set, else the default locale of its
+/// universe, else [`MAIN_LOCALE`]. Read through [`GUILD_LOCALES`], as for the replies.
+pub async fn guild_locale_or_main(guild_id: u64) -> String {
+    GUILD_LOCALES.load(guild_id).await
+        .and_then(|locale| locale.preferred().map(str::to_string))
+        .unwrap_or_else(|| MAIN_LOCALE.to_string())
+}
+
+/// Locale of the main translation file, used whenever a translation is missing from another one.
+pub static MAIN_LOCALE: &str = "en-US";
+
+/// Environment variable naming a directory of `.ftl` files which override or extend the
+/// translation files embedded in the binary. The translations of a locale may be one `fr.ftl`
+/// file, several files of a `fr` subdirectory, or both.
+pub static TRANSLATIONS_DIR_VAR: &str = "TRANSLATIONS_DIR";
+
+/// Translation files shipped with the bot, embedded at compile time so that it runs from any
+/// working directory.
+static EMBEDDED_FTL: [(&str, &str); 2] = [
+    ("en-US", include_str!("../translations/en-US.ftl")),
+    ("fr", include_str!("../translations/fr.ftl")),
+];
+
+/// Text of a translation file and where it was loaded from; a locale may be split across several.
+struct FtlFile {
+    source: FtlSource,
+    text: String,
+}
+
+/// Where the translations of a locale were loaded from, as logged at startup.
+#[derive(Debug, Clone, PartialEq)]
+enum FtlSource {
+    Embedded,
+    File(PathBuf),
+}
+
+impl std::fmt::Display for FtlSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FtlSource::Embedded => write!(f, "embedded"),
+            FtlSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Builds the `Translations` object from the Fluent translation files embedded in the binary and
+/// those of the directory named by [`TRANSLATIONS_DIR_VAR`], if any.
 ///
 /// # Description
 /// This function processes Fluent `.ftl` files to create a `Translations` object, which contains:
 /// - The main translations bundle (`main`) built from the `en-US.ftl` file.
-/// - Any additional translation bundles (`other`) present in the "translations" directory.
+/// - Any additional translation bundles (`other`) for the other locales.
 ///
-/// Each `.ftl` file is expected to have a valid locale name as its filename (e.g., `en-US.ftl`).
+/// Each `.ftl` file is expected to have a valid locale name as its filename (e.g., `fr.ftl`), or
+/// to be in a subdirectory named after its locale (e.g., `fr/roads.ftl`): all the files of a
+/// locale are added to its bundle, and a message defined by two of them is logged as a conflict
+/// and keeps its first definition. The files of a locale in the directory replace the embedded
+/// file of this locale and those of a new locale add it. Files without the `.ftl` extension are ignored, and a locale which cannot be
+/// read or parsed is logged and skipped so that the other ones still load. The locales loaded
+/// are logged with where they were loaded from.
 ///
 /// # Return
 /// Returns a `Result` which:
 /// - On success, contains a `Translations` object with the loaded translation bundles.
-/// - On failure, contains an `Error` describing what went wrong during the reading or parsing process.
+/// - On failure, contains an `Error` describing what went wrong during the parsing process.
 ///
 /// # Errors
-/// The function can fail for several reasons:
-/// - Problems reading a `.ftl` file (e.g., file not found or permission issues).
-/// - Invalid or unparsable `.ftl` file contents.
-/// - Issues deriving localization settings from the filenames.
-/// - Problems parsing locales or building the Fluent `Bundle`.
-///
-/// # Internal Helper Function
-/// `read_single_ftl`:
-///   - A helper function that reads a single `.ftl` file, parses its contents, and returns a tuple containing:
-///     - The locale string (derived from the filename).
-///     - An associated Fluent `Bundle` object.
+/// The function fails when the main translation file cannot be parsed.
 ///
 /// # Examples
 /// ```
@@ -432,45 +667,163 @@ pub fn get_by_locale(
 ///     Err(e) => eprintln!("Error loading translations: {:?}", e),
 /// }
 /// ```
-///
-/// # See Also
-/// - `FluentResource`: Used for compiling Fluent translation strings.
-/// - `Bundle`: Represents a collection of Fluent localization data.
-///
-/// # Dependencies
-/// - The "translations" directory must be available and contain valid `.ftl` files.
-/// - The `translations/en-US.ftl` file is expected to exist and serve as the main translation file.
-///
-/// # Arguments
-/// None.
-///
-/// # Return Type
-/// `Result<Translations, Error>`
-/// - On success, contains the `Translations` object.
-/// - On failure, an `Error` variant.
 pub fn read_ftl() -> Result<Translations, Error> {
-    fn read_single_ftl(path: &Path) -> Result<(String, Bundle), Error> {
-        let locale = path.file_stem()
-            .and_then(|s| s.to_str())
-            .ok_or("Invalid .ftl filename")?;
+    Ok(read_ftl_report()?.translations)
+}
 
-        let file_contents = std::fs::read_to_string(path)?;
-        let resource = FluentResource::try_new(file_contents)
-            .map_err(|(_, e)| format!("Failed to parse {:?}: {:?}", path, e))?;
+/// Translations read by [`read_ftl_report`], with what was loaded and what was skipped.
+pub struct FtlReport {
+    pub translations: Translations,
+    /// Messages of all the loaded translation files.
+    pub messages: usize,
+    /// Each translation file or directory which could not be read or parsed, and why.
+    pub skipped: Vec<String>,
+    /// Each message defined by two files of a locale, only the first definition being kept.
+    pub conflicts: Vec<String>,
+}
 
-        let mut bundle = Bundle::new_concurrent(vec![locale.parse()?]);
-        bundle.add_resource(resource)
-            .map_err(|e| format!("Failed to add resource to bundle: {:?}", e))?;
+/// Reads the translations as [`read_ftl`] does, reporting the files it skipped so that a reload
+/// can refuse a broken set.
+pub fn read_ftl_report() -> Result<FtlReport, Error> {
+    let dir = std::env::var(TRANSLATIONS_DIR_VAR).ok().filter(|dir| !dir.is_empty()).map(PathBuf::from);
+    read_ftl_from(dir.as_deref())
+}
 
-        Ok((locale.to_string(), bundle))
+/// Builds the translations of the embedded files, overridden and extended by those of `dir`, as
+/// described in [`read_ftl`]. A directory which cannot be listed is logged and ignored.
+fn read_ftl_from(dir: Option<&Path>) -> Result<FtlReport, Error> {
+    let mut skipped = Vec::new();
+    let mut conflicts = Vec::new();
+    let mut locales: BTreeMap<String, Vec<FtlFile>> = EMBEDDED_FTL.iter()
+        .map(|(locale, text)| (locale.to_string(), vec![FtlFile { source: FtlSource::Embedded, text: text.to_string() }]))
+        .collect();
+    if let Some(dir) = dir {
+        match read_ftl_dir(dir, &mut skipped) {
+            Ok(dir_locales) => locales.extend(dir_locales),
+            Err(e) => {
+                tracing::warn!(dir = %dir.display(), "could not read the translation directory: {e}");
+                skipped.push(format!("{}: {e}", dir.display()));
+            }
+        }
+    }
+
+    let files = locales.remove(MAIN_LOCALE).ok_or("The main translation file is missing")?;
+    let sources = sources_of(&files);
+    let (main, mut messages) = build_bundle(MAIN_LOCALE, files, &mut conflicts)
+        .map_err(|e| format!("Failed to load the main translations from {sources}: {e}"))?;
+    tracing::info!(locale = MAIN_LOCALE, %sources, "loaded translations");
+
+    let mut other = HashMap::new();
+    for (locale, files) in locales {
+        let sources = sources_of(&files);
+        match build_bundle(&locale, files, &mut conflicts) {
+            Ok((bundle, count)) => {
+                tracing::info!(locale, %sources, "loaded translations");
+                other.insert(locale, bundle);
+                messages += count;
+            }
+            Err(e) => {
+                tracing::warn!(locale, %sources, "skipped a translation file: {e}");
+                skipped.push(format!("{locale} ({sources}): {e}"));
+            }
+        }
+    }
+    Ok(FtlReport { translations: Translations::new(main, other), messages, skipped, conflicts })
+}
+
+/// Where `files` were loaded from, as logged.
+fn sources_of(files: &[FtlFile]) -> String {
+    files.iter().map(|file| file.source.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Files of each locale of `dir`: its `.ftl` files, named after their locale, and the `.ftl`
+/// files of its subdirectories, named after theirs. The file of a locale comes before the files
+/// of its subdirectory, which come by name. Other files are ignored, and files or subdirectories
+/// which cannot be read are logged and added to `skipped`.
+fn read_ftl_dir(dir: &Path, skipped: &mut Vec<String>) -> Result<BTreeMap<String, Vec<FtlFile>>, Error> {
+    let mut locales: BTreeMap<String, Vec<FtlFile>> = BTreeMap::new();
+    let mut subdirs = Vec::new();
+    for path in sorted_entries(dir)? {
+        if path.is_dir() {
+            subdirs.push(path);
+        } else if let Some((locale, file)) = read_ftl_file(&path, skipped) {
+            locales.entry(locale).or_default().push(file);
+        }
+    }
+    for subdir in subdirs {
+        let Some(locale) = subdir.file_name().and_then(|name| name.to_str()) else { continue };
+        let paths = match sorted_entries(&subdir) {
+            Ok(paths) => paths,
+            Err(e) => {
+                tracing::warn!(dir = %subdir.display(), "could not read the translation directory: {e}");
+                skipped.push(format!("{}: {e}", subdir.display()));
+                continue;
+            }
+        };
+        for path in paths.iter().filter(|path| !path.is_dir()) {
+            if let Some((_, file)) = read_ftl_file(path, skipped) {
+                locales.entry(locale.to_string()).or_default().push(file);
+            }
+        }
     }
+    Ok(locales)
+}
 
-    Ok(Translations {
-        main: read_single_ftl("translations/en-US.ftl".as_ref())?.1,
-        other: std::fs::read_dir("translations")?
-            .map(|entry| read_single_ftl(&entry?.path()))
-            .collect::<Result<_, _>>()?,
-    })
+/// Paths of the entries of `dir`, sorted so that the files of a locale are added in the same order
+/// on every system.
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut paths = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Stem and text of the file at `path`; `None` when it has not the `.ftl` extension, or when it
+/// cannot be read, which is logged and added to `skipped`.
+fn read_ftl_file(path: &Path, skipped: &mut Vec<String>) -> Option<(String, FtlFile)> {
+    if path.extension().is_none_or(|extension| extension != "ftl") {
+        return None;
+    }
+    let stem = path.file_stem().and_then(|stem| stem.to_str())?;
+    match std::fs::read_to_string(path) {
+        Ok(text) => Some((stem.to_string(), FtlFile { source: FtlSource::File(path.to_path_buf()), text })),
+        Err(e) => {
+            tracing::warn!(path = %path.display(), "skipped a translation file: {e}");
+            skipped.push(format!("{}: {e}", path.display()));
+            None
+        }
+    }
+}
+
+/// Bundle of the translation `files` of `locale`, with the number of messages it holds. A message
+/// or term defined again by a later file keeps its first definition, and the conflict is logged
+/// and added to `conflicts` with both files.
+fn build_bundle(locale: &str, files: Vec<FtlFile>, conflicts: &mut Vec<String>) -> Result<(Bundle, usize), Error> {
+    let mut bundle = Bundle::new_concurrent(vec![locale.parse()?]);
+    let mut defined_in: HashMap<String, String> = HashMap::new();
+    for FtlFile { source, text } in files {
+        let resource = FluentResource::try_new(text)
+            .map_err(|(_, e)| format!("Failed to parse {locale} ({source}): {e:?}"))?;
+        let source = source.to_string();
+        for entry in resource.entries() {
+            let id = match entry {
+                Entry::Message(message) => message.id.name.to_string(),
+                Entry::Term(term) => format!("-{}", term.id.name),
+                _ => continue,
+            };
+            if let Some(first) = defined_in.get(&id) {
+                tracing::warn!(locale, id, first = %first, ignored = %source, "a translation is defined twice");
+                conflicts.push(format!("{locale}: `{id}` of {source} is already defined in {first}"));
+            } else {
+                defined_in.insert(id, source.clone());
+            }
+        }
+        // Fluent only fails on the messages defined twice, already reported, and keeps the first
+        let _ = bundle.add_resource(resource);
+    }
+    let messages = defined_in.keys().filter(|id| !id.starts_with('-')).count();
+    Ok((bundle, messages))
 }
 
 /// Updates the localization for commands and their subcommands.
@@ -513,47 +866,76 @@ pub fn read_ftl() -> Result<Translations, Error> {
 /// # Example
 ///
 /// ```rust
-/// let translations = Translations {
-///     main: Bundle { /* main translation bundle */ },
-///     other: HashMap::from([
+/// let translations = Translations::new(
+///     Bundle { /* main translation bundle */ },
+///     HashMap::from([
 ///         ("es".into(), Bundle { /* Spanish translation bundle */ }),
 ///         ("fr".into(), Bundle { /* French translation bundle */ }),
 ///     ]),
-/// };
+/// );
 ///
 /// let mut commands = vec![/* some poise::Command values */];
-/// apply_translations(&translations, &mut commands);
+/// let coverage = apply_translations(&translations, &mut commands);
+/// log_coverage(&coverage);
 /// ```
 ///
 /// After executing the function, the `commands` slice will have all the names, descriptions,
 /// parameters, and subcommands updated as per the localization definitions.
+///
+/// # Returns
+///
+/// The [`LocaleCoverage`] of each locale, the main one included: how much of the commands it
+/// localized and the keys it lacks. When the name of a command is missing from a locale, the
+/// rest of the command is not localized in it either and its keys are counted as missing too.
 pub fn apply_translations(
     translations: &Translations,
     commands: &mut [poise::Command<Data, Error>],
+) -> CoverageReport {
+    let mut report: CoverageReport = translations.other.keys()
+        .map(|locale| (locale.clone(), LocaleCoverage::default()))
+        .collect();
+    report.insert(MAIN_LOCALE.to_string(), LocaleCoverage::default());
+    apply_translations_to(translations, commands, &mut report);
+    report
+}
+
+fn apply_translations_to(
+    translations: &Translations,
+    commands: &mut [poise::Command<Data, Error>],
+    report: &mut CoverageReport,
 ) {
     for command in commands {
         let original_name = command.name.clone();
 
         for (locale, bundle) in &translations.other {
-            if let Some(name) = format(bundle, &original_name, None, None) {
+            let coverage = report.entry(locale.clone()).or_default();
+            let name = format(bundle, &original_name, None, None);
+            let named = name.is_some();
+            // The rest of the command is only localized along with its name
+            let attribute = |attr: &str| named.then(|| format(bundle, &original_name, Some(attr), None)).flatten();
+
+            if let Some(name) = coverage.names.count(&mut coverage.missing, original_name.clone(), name) {
                 command.name_localizations.insert(locale.clone(), name);
-                if let Some(desc) = format(bundle, &original_name, Some("description"), None) {
-                    command.description_localizations.insert(locale.clone(), desc);
-                }
+            }
+            let desc = attribute("description");
+            if let Some(desc) = coverage.descriptions.count(&mut coverage.missing, format!("{original_name}.description"), desc) {
+                command.description_localizations.insert(locale.clone(), desc);
+            }
 
-                for param in &mut command.parameters {
-                    if let Some(p_name) = format(bundle, &original_name, Some(&param.name), None) {
-                        param.name_localizations.insert(locale.clone(), p_name);
-                    }
-                    if let Some(p_desc) =
-                        format(bundle, &original_name, Some(&format!("{}-description", param.name)), None)
-                    {
-                        param.description_localizations.insert(locale.clone(), p_desc);
-                    }
-                    for choice in &mut param.choices {
-                        if let Some(c_name) = format(bundle, &choice.name, None, None) {
-                            choice.localizations.insert(locale.clone(), c_name);
-                        }
+            for param in &mut command.parameters {
+                let p_name = attribute(&param.name);
+                if let Some(p_name) = coverage.parameters.count(&mut coverage.missing, format!("{original_name}.{}", param.name), p_name) {
+                    param.name_localizations.insert(locale.clone(), p_name);
+                }
+                let description_attr = format!("{}-description", param.name);
+                let p_desc = attribute(&description_attr);
+                if let Some(p_desc) = coverage.descriptions.count(&mut coverage.missing, format!("{original_name}.{description_attr}"), p_desc) {
+                    param.description_localizations.insert(locale.clone(), p_desc);
+                }
+                for choice in &mut param.choices {
+                    let c_name = named.then(|| format(bundle, &choice.name, None, None)).flatten();
+                    if let Some(c_name) = coverage.choices.count(&mut coverage.missing, choice.name.clone(), c_name) {
+                        choice.localizations.insert(locale.clone(), c_name);
                     }
                 }
             }
@@ -561,41 +943,141 @@ pub fn apply_translations(
 
         // Fallback to main bundle
         let bundle = &translations.main;
-        if let Some(name) = format(bundle, &original_name, None, None) {
+        let coverage = report.entry(MAIN_LOCALE.to_string()).or_default();
+        let name = format(bundle, &original_name, None, None);
+        let named = name.is_some();
+        let attribute = |attr: &str| named.then(|| format(bundle, &original_name, Some(attr), None)).flatten();
+
+        if let Some(name) = coverage.names.count(&mut coverage.missing, original_name.clone(), name) {
             command.name = name;
-            if let Some(desc) = format(bundle, &original_name, Some("description"), None) {
-                command.description = Some(desc);
-            }
+        }
+        let desc = attribute("description");
+        if let Some(desc) = coverage.descriptions.count(&mut coverage.missing, format!("{original_name}.description"), desc) {
+            command.description = Some(desc);
+        }
 
-            for param in &mut command.parameters {
-                let original_param_name = param.name.clone();
+        for param in &mut command.parameters {
+            let original_param_name = param.name.clone();
 
-                // IMPORTANT: do not overwrite param.name (internal option name)
-                if let Some(p_name) = format(bundle, &original_name, Some(&original_param_name), None) {
-                    param.name_localizations.insert("en-US".to_string(), p_name);
-                }
+            // IMPORTANT: do not overwrite param.name (internal option name)
+            let p_name = attribute(&original_param_name);
+            if let Some(p_name) = coverage.parameters.count(&mut coverage.missing, format!("{original_name}.{original_param_name}"), p_name) {
+                param.name_localizations.insert("en-US".to_string(), p_name);
+            }
 
-                if let Some(p_desc) =
-                    format(bundle, &original_name, Some(&format!("{}-description", original_param_name)), None)
-                {
-                    param.description = Some(p_desc);
-                }
+            let description_attr = format!("{}-description", original_param_name);
+            let p_desc = attribute(&description_attr);
+            if let Some(p_desc) = coverage.descriptions.count(&mut coverage.missing, format!("{original_name}.{description_attr}"), p_desc) {
+                param.description = Some(p_desc);
+            }
 
-                // IMPORTANT: do not overwrite choice.name (internal choice key)
-                for choice in &mut param.choices {
-                    if let Some(c_name) = format(bundle, &choice.name, None, None) {
-                        choice.localizations.insert("en-US".to_string(), c_name);
-                    }
+            // IMPORTANT: do not overwrite choice.name (internal choice key)
+            for choice in &mut param.choices {
+                let c_name = named.then(|| format(bundle, &choice.name, None, None)).flatten();
+                if let Some(c_name) = coverage.choices.count(&mut coverage.missing, choice.name.clone(), c_name) {
+                    choice.localizations.insert("en-US".to_string(), c_name);
                 }
             }
         }
 
         if !command.subcommands.is_empty() {
-            apply_translations(translations, &mut command.subcommands);
+            apply_translations_to(translations, &mut command.subcommands, report);
         }
     }
 }
 
+/// How many of the command texts of a kind were localized, out of how many.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Coverage {
+    pub localized: usize,
+    pub total: usize,
+}
+
+impl Coverage {
+    /// Counts the text of `key`, localized as `value` or else added to `missing`. Gives `value` back.
+    fn count(&mut self, missing: &mut Vec<String>, key: String, value: Option<String>) -> Option<String> {
+        self.total += 1;
+        match value {
+            Some(_) => self.localized += 1,
+            None => missing.push(key),
+        }
+        value
+    }
+}
+
+impl std::fmt::Display for Coverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.localized, self.total)
+    }
+}
+
+/// How much of the commands a locale localizes, as reported by [`apply_translations`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LocaleCoverage {
+    /// Names of the commands and subcommands.
+    pub names: Coverage,
+    /// Descriptions of the commands and of their parameters.
+    pub descriptions: Coverage,
+    /// Names of the parameters.
+    pub parameters: Coverage,
+    /// Names of the choices of the parameters.
+    pub choices: Coverage,
+    /// Keys of the texts which were not localized, as `command`, `command.attribute` or `choice`.
+    pub missing: Vec<String>,
+}
+
+impl LocaleCoverage {
+    /// Texts of every kind, localized and in all.
+    pub fn overall(&self) -> Coverage {
+        [self.names, self.descriptions, self.parameters, self.choices].iter()
+            .fold(Coverage::default(), |sum, coverage| Coverage {
+                localized: sum.localized + coverage.localized,
+                total: sum.total + coverage.total,
+            })
+    }
+}
+
+/// [`LocaleCoverage`] of each locale.
+pub type CoverageReport = BTreeMap<String, LocaleCoverage>;
+
+/// Logs the coverage of each locale at startup, as a warning listing the missing keys when another
+/// locale than the main one does not localize all of the commands.
+pub fn log_coverage(report: &CoverageReport) {
+    for (locale, coverage) in report {
+        let overall = coverage.overall();
+        if locale != MAIN_LOCALE && overall.localized < overall.total {
+            tracing::warn!(
+                locale,
+                %overall,
+                names = %coverage.names,
+                descriptions = %coverage.descriptions,
+                parameters = %coverage.parameters,
+                choices = %coverage.choices,
+                missing = coverage.missing.join(", "),
+                "commands are not fully localized"
+            );
+        } else {
+            tracing::info!(
+                locale,
+                %overall,
+                names = %coverage.names,
+                descriptions = %coverage.descriptions,
+                parameters = %coverage.parameters,
+                choices = %coverage.choices,
+                "commands localized"
+            );
+        }
+    }
+}
+
+/// Finds the `{$var}` placeholders of a formatted pattern, compiled once.
+static VARIABLE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\$(\w+)\}").unwrap());
+
+/// Whether `pattern` is plain text, without placeables to format.
+fn is_static(pattern: &Pattern<&str>) -> bool {
+    pattern.elements.iter().all(|element| matches!(element, PatternElement::TextElement { .. }))
+}
+
 /// Extracts variable names enclosed within `{$...}` placeholders from a given pattern string.
 ///
 /// The function takes a string pattern and uses a regular expression to identify all occurrences
@@ -621,11 +1103,10 @@ pub fn apply_translations(
 ///
 /// # Panics
 ///
-/// This function panics if the regular expression fails to compile. However, the regex used
+/// The first call panics if the regular expression fails to compile. However, the regex used
 /// in this function (`r"\{\$(\w+)\}"`) is hardcoded and should always compile successfully.
 fn extract_variables_from_pattern(pattern: &str) -> Vec<String> {
-    Regex::new(r"\{\$(\w+)\}")
-        .unwrap()
+    VARIABLE_REGEX
         .captures_iter(pattern)
         .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
         .collect()
@@ -638,33 +1119,97 @@ fn extract_variables_from_pattern(pattern: &str) -> Vec<String> {
 ///
 /// * `ctx` - The `Context` containing translation data and locale information.
 /// * `id` - The identifier of the translation token to be retrieved.
+/// * `attr` - The attribute of the message to translate, such as `title`, or `None` for its value.
 /// * `explicit_args` - Optional arguments (`FluentArgs`) that may be explicitly provided for substitution
 ///   in the translation string.
 ///
 /// # Returns
 ///
-/// * `Result<String, Error>` - If successful,
+/// * `Result<String, Error>` - If successful, the formatted message. Variables missing from
+///   `explicit_args` are resolved as the messages of the same name; one which cannot be is
+///   handled as the [`UnresolvedVariablePolicy`] of `ctx` says.
 pub fn smart_tr(
     ctx: Context,
     id: &str,
+    attr: Option<&str>,
     explicit_args: Option<&FluentArgs>,
 ) -> Result<String, Error> {
-    let translations = &ctx.data().translations;
-    let bundle = ctx.locale()
-        .and_then(|locale| translations.other.get(locale))
-        .unwrap_or(&translations.main);
-
-    // If the token doesn't exist, just return it (visible + debuggable).
-    let message = match bundle.get_message(id).or_else(|| translations.main.get_message(id)) {
-        Some(message) => message,
-        None => return Ok(id.to_string()),
-    };
+    let translations = ctx.data().translations.load();
+    let fallback_locale = cached_fallback_locale(ctx);
+    let bundle = context_bundle(&translations, ctx.locale(), fallback_locale.as_deref());
+    Ok(format_resolving(&translations, bundle, id, attr, explicit_args, ctx.data().unresolved_variable_policy))
+}
+
+/// Environment variable choosing the [`UnresolvedVariablePolicy`]: `id`, `raw_pattern` or
+/// `placeholder`, the default.
+pub static UNRESOLVED_VARIABLE_POLICY_VAR: &str = "UNRESOLVED_VARIABLE_POLICY";
+
+/// What [`smart_tr`] returns for a message with a variable it can neither find in its arguments
+/// nor resolve as a message of the same name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum UnresolvedVariablePolicy {
+    /// The id of the message, as if it had no translation.
+    ReturnId,
+    /// The message formatted without any argument, every variable shown as `{$var}`.
+    ReturnRawPattern,
+    /// The message formatted with the arguments found, only the unresolved variables shown as `{$var}`.
+    #[default]
+    ReturnWithPlaceholder,
+}
 
-    // If the message exists but has no value, also fall back to the token.
-    let pattern = match message.value() {
-        Some(pattern) => pattern,
-        None => return Ok(id.to_string()),
+impl UnresolvedVariablePolicy {
+    /// Policy named by `value`, the value of [`UNRESOLVED_VARIABLE_POLICY_VAR`]. The default one
+    /// when it is missing or unknown.
+    pub fn from_env_value(value: Option<&str>) -> UnresolvedVariablePolicy {
+        match value.map(|value| value.trim().to_lowercase()).as_deref() {
+            Some("id") => UnresolvedVariablePolicy::ReturnId,
+            Some("raw_pattern") => UnresolvedVariablePolicy::ReturnRawPattern,
+            Some("placeholder") | Some("") | None => UnresolvedVariablePolicy::ReturnWithPlaceholder,
+            Some(other) => {
+                tracing::warn!("unknown {UNRESOLVED_VARIABLE_POLICY_VAR} \"{other}\", falling back to \"placeholder\"");
+                UnresolvedVariablePolicy::ReturnWithPlaceholder
+            }
+        }
+    }
+
+    /// Policy chosen by [`UNRESOLVED_VARIABLE_POLICY_VAR`].
+    pub fn from_env() -> UnresolvedVariablePolicy {
+        UnresolvedVariablePolicy::from_env_value(std::env::var(UNRESOLVED_VARIABLE_POLICY_VAR).ok().as_deref())
+    }
+}
+
+/// Formats the message `id` of `bundle`, else of the main bundle of `translations`, with
+/// `explicit_args`; its attribute `attr` when given. A variable missing from them is given the
+/// translation of the message of the same name, such as `{$support}`; one without such a message
+/// is logged and handled as `policy` says. Messages without placeables are cached in `translations`.
+fn format_resolving(
+    translations: &Translations,
+    bundle: &Bundle,
+    id: &str,
+    attr: Option<&str>,
+    explicit_args: Option<&FluentArgs>,
+    policy: UnresolvedVariablePolicy,
+) -> String {
+    fn pattern_of<'a>(bundle: &'a Bundle, id: &str, attr: Option<&str>) -> Option<&'a Pattern<&'a str>> {
+        let message = bundle.get_message(id)?;
+        match attr {
+            Some(attr) => message.get_attribute(attr).map(|attribute| attribute.value()),
+            None => message.value(),
+        }
+    }
+    let main = &translations.main;
+    // If the token or its attribute doesn't exist, just return the token (visible + debuggable).
+    let Some((bundle, pattern)) = pattern_of(bundle, id, attr).map(|pattern| (bundle, pattern))
+        .or_else(|| pattern_of(main, id, attr).map(|pattern| (main, pattern))) else {
+        return id.to_string();
     };
+    let locale = bundle.locales.first().map(ToString::to_string).unwrap_or_default();
+    if is_static(pattern) {
+        return translations.static_messages.get_or_format(&locale, id, attr, || {
+            bundle.format_pattern(pattern, None, &mut vec![]).into_owned()
+        });
+    }
 
     let raw_text = bundle.format_pattern(pattern, None, &mut vec![]).into_owned();
     let used_vars = extract_variables_from_pattern(&raw_text);
@@ -676,20 +1221,374 @@ pub fn smart_tr(
         }
     }
 
+    let mut unresolved = false;
     for var in used_vars {
-        if args.get(&var).is_none() {
-            let fallback_id = var.clone();
-            if let Some(value) = format(bundle, &fallback_id, None, None)
-                .or_else(|| format(&translations.main, &fallback_id, None, None))
-            {
-                args.set(var.clone(), FluentValue::from(value));
-            } else {
-                // Can't resolve a required variable -> return the token
-                // (alternatively, return `raw_text` to show `{$var}` placeholders).
-                return Ok(id.to_string());
+        if args.get(&var).is_some() {
+            continue;
+        }
+        match format(bundle, &var, None, None).or_else(|| format(main, &var, None, None)) {
+            Some(value) => args.set(var, FluentValue::from(value)),
+            None => {
+                tracing::warn!(id, variable = var, locale, "could not resolve a variable of a message");
+                unresolved = true;
+            }
+        }
+    }
+
+    match policy {
+        UnresolvedVariablePolicy::ReturnId if unresolved => id.to_string(),
+        UnresolvedVariablePolicy::ReturnRawPattern if unresolved => raw_text,
+        // Fluent shows the variables missing from the arguments as `{$var}`
+        _ => bundle.format_pattern(pattern, Some(&args), &mut vec![]).into_owned(),
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tr_raw_fallbacks() {
+        let translations = read_ftl().unwrap();
+        assert_eq!(tr_raw(&translations, "fr", "ping", None, None), "ping");
+        assert_eq!(tr_raw(&translations, "fr", "readme_channel_name", None, None), "lisez-moi");
+        // Unknown locale falls back to the main bundle, unknown id to the id itself
+        assert_eq!(tr_raw(&translations, "xx", "readme_channel_name", None, None), "readme");
+        assert_eq!(tr_raw(&translations, "fr", "unknown__key", None, None), "unknown__key");
+    }
+
+    #[test]
+    fn test_has_locale() {
+        let translations = read_ftl().unwrap();
+        assert!(translations.has_locale("en-US"));
+        assert!(translations.has_locale("fr"));
+        assert!(!translations.has_locale("xx"));
+    }
+
+    #[test]
+    fn test_read_ftl_embedded() {
+        let translations = read_ftl_from(None).unwrap().translations;
+        let mut locales: Vec<&String> = translations.other.keys().collect();
+        locales.sort();
+        assert_eq!(locales, vec!["fr"]);
+        assert_eq!(tr_raw(&translations, "fr", "readme_channel_name", None, None), "lisez-moi");
+        // A directory which cannot be listed leaves the embedded files
+        let missing = std::env::temp_dir().join(format!("rpbot_translations_{}", uuid::Uuid::new_v4()));
+        let report = read_ftl_from(Some(&missing)).unwrap();
+        assert!(report.translations.has_locale("fr"));
+        assert_eq!(report.skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_read_ftl_dir_skips_junk_and_broken_locales() {
+        let dir = std::env::temp_dir().join(format!("rpbot_translations_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("en-US.ftl"), "ping = ping\n").unwrap();
+        std::fs::write(dir.join("fr.ftl"), "ping = pong\n").unwrap();
+        std::fs::write(dir.join("de.ftl"), "ping = {\n").unwrap();
+        std::fs::write(dir.join("README.md"), "# Translations\n").unwrap();
+        std::fs::write(dir.join(".gitkeep"), "").unwrap();
+
+        let report = read_ftl_from(Some(&dir));
+        let _ = std::fs::remove_dir_all(&dir);
+        let report = report.unwrap();
+        let translations = &report.translations;
+        let mut locales: Vec<&String> = translations.other.keys().collect();
+        locales.sort();
+        assert_eq!(locales, vec!["fr"]);
+        assert!(translations.has_locale("en-US"));
+        assert_eq!(tr_raw(translations, "fr", "ping", None, None), "pong");
+        assert_eq!(report.messages, 2);
+        assert_eq!(report.skipped.len(), 1);
+        assert!(report.skipped[0].starts_with("de "), "{:?}", report.skipped);
+    }
+
+    /// Translations whose other bundles hold `ping` translated as their own locale.
+    fn translations_for(locales: &[&str]) -> Translations {
+        let bundle = |locale: &str, source: String| {
+            let mut bundle = Bundle::new_concurrent(vec![locale.parse().unwrap()]);
+            bundle.add_resource(FluentResource::try_new(source).unwrap()).unwrap();
+            bundle
+        };
+        Translations::new(
+            bundle(MAIN_LOCALE, "ping = main\n".to_string()),
+            locales.iter().map(|locale| (locale.to_string(), bundle(locale, format!("ping = {locale}\n")))).collect(),
+        )
+    }
+
+    #[test]
+    fn test_bundle_for_exact_locale() {
+        let translations = translations_for(&["fr", "fr-FR", "fr-CA"]);
+        assert_eq!(tr_raw(&translations, "fr-FR", "ping", None, None), "fr-FR");
+        assert_eq!(tr_raw(&translations, "fr", "ping", None, None), "fr");
+        assert_eq!(tr_raw(&translations, "fr-ca", "ping", None, None), "fr-CA");
+    }
+
+    #[test]
+    fn test_bundle_for_language_only() {
+        let translations = translations_for(&["fr", "fr-CA"]);
+        assert_eq!(tr_raw(&translations, "fr-FR", "ping", None, None), "fr");
+        let translations = translations_for(&["fr-FR"]);
+        assert_eq!(tr_raw(&translations, "fr", "ping", None, None), "fr-FR");
+        assert_eq!(tr_raw(&translations, "fr-BE", "ping", None, None), "fr-FR");
+    }
+
+    #[test]
+    fn test_bundle_for_falls_back_to_main() {
+        let translations = translations_for(&["fr-FR"]);
+        assert!(translations.bundle_for("es-ES").is_none());
+        assert_eq!(tr_raw(&translations, "es-ES", "ping", None, None), "main");
+        assert_eq!(tr_raw(&translations, "not a locale", "ping", None, None), "main");
+    }
+
+    #[test]
+    fn test_context_bundle_falls_back_to_guild_locale() {
+        let translations = translations_for(&["fr", "de"]);
+        let ping = |bundle| format(bundle, "ping", None, None).unwrap();
+        assert_eq!(ping(context_bundle(&translations, Some("de"), Some("fr"))), "de");
+        assert_eq!(ping(context_bundle(&translations, None, Some("fr"))), "fr");
+        assert_eq!(ping(context_bundle(&translations, None, None)), "main");
+        assert_eq!(ping(context_bundle(&translations, None, Some("es"))), "main");
+    }
+
+    /// `text` without the marks isolating each placeable.
+    fn unisolated(text: String) -> String {
+        text.replace(['\u{2068}', '\u{2069}'], "")
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        let translations = read_ftl_from(None).unwrap().translations;
+        let recreated = |locale: &str, count: FluentValue<'static>| {
+            let mut args = FluentArgs::new();
+            args.set("count", count);
+            unisolated(tr_raw(&translations, locale, "setup__repair_recreated", None, Some(&args)))
+        };
+        assert_eq!(recreated("en-US", 1.into()), "1 resource recreated:");
+        assert_eq!(recreated("en-US", 0.into()), "0 resources recreated:");
+        assert_eq!(recreated("en-US", 3.into()), "3 resources recreated:");
+        // French uses the singular for zero
+        assert_eq!(recreated("fr", 0.into()), "0 ressource recréée :");
+        assert_eq!(recreated("fr", 1.into()), "1 ressource recréée :");
+        assert_eq!(recreated("fr", 2.into()), "2 ressources recréées :");
+        // A number given as text cannot select its plural form
+        assert_eq!(recreated("en-US", "1".into()), "1 resources recreated:");
+    }
+
+    #[derive(poise::ChoiceParameter)]
+    enum Shape {
+        #[name = "shape_round"]
+        Round,
+        #[name = "shape_square"]
+        Square,
+    }
+
+    #[poise::command(slash_command, subcommands("survey"))]
+    async fn poll(_ctx: Context<'_>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[poise::command(slash_command)]
+    async fn survey(_ctx: Context<'_>, #[description = "shape"] shape: Shape, #[description = "name"] name: String) -> Result<(), Error> {
+        let _ = (shape, name);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_translations_reports_coverage() {
+        let bundle = |locale: &str, source: &str| {
+            let mut bundle = Bundle::new_concurrent(vec![locale.parse().unwrap()]);
+            bundle.add_resource(FluentResource::try_new(source.to_string()).unwrap()).unwrap();
+            bundle
+        };
+        let main = bundle(MAIN_LOCALE, "poll = poll\n    .description = Polls\nsurvey = survey\n    .description = Survey\n    .shape = shape\n    .shape-description = Shape\n    .name = name\n    .name-description = Name\nshape_round = round\nshape_square = square\n");
+        let fr = bundle("fr", "poll = sondage\n    .description = Sondages\nsurvey = enquete\n    .shape = forme\nshape_round = rond\n");
+        let translations = Translations::new(main, HashMap::from([("fr".to_string(), fr)]));
+        let mut commands = vec![poll()];
+
+        let report = apply_translations(&translations, &mut commands);
+        assert_eq!(report.keys().collect::<Vec<_>>(), vec!["en-US", "fr"]);
+        let main = &report[MAIN_LOCALE];
+        assert_eq!(main.overall(), Coverage { localized: 10, total: 10 });
+        assert!(main.missing.is_empty());
+
+        let fr = &report["fr"];
+        assert_eq!(fr.names, Coverage { localized: 2, total: 2 });
+        assert_eq!(fr.descriptions, Coverage { localized: 1, total: 4 });
+        assert_eq!(fr.parameters, Coverage { localized: 1, total: 2 });
+        assert_eq!(fr.choices, Coverage { localized: 1, total: 2 });
+        assert_eq!(fr.overall(), Coverage { localized: 5, total: 10 });
+        assert_eq!(fr.missing, vec!["survey.description", "survey.shape-description", "shape_square", "survey.name", "survey.name-description"]);
+        // The translations are applied as before
+        let survey = &commands[0].subcommands[0];
+        assert_eq!(survey.name_localizations["fr"], "enquete");
+        assert_eq!(survey.parameters[0].name_localizations["fr"], "forme");
+        assert_eq!(survey.parameters[0].choices[0].localizations["fr"], "rond");
+        assert!(!survey.parameters[0].choices[1].localizations.contains_key("fr"));
+    }
+
+    #[test]
+    fn test_reload_success_tells_conflicts() {
+        let translations = read_ftl().unwrap();
+        let success = |locale, conflicts: usize| {
+            let mut args = FluentArgs::new();
+            args.set("locales", 2);
+            args.set("messages", 10);
+            args.set("conflicts", conflicts);
+            unisolated(tr_raw(&translations, locale, "reload_translations__success", Some("message"), Some(&args)))
+        };
+        assert_eq!(success("en-US", 0), "2 locales and 10 messages are now in use.");
+        assert_eq!(success("en-US", 1), "2 locales and 10 messages are now in use. 1 message defined by two files kept its first definition.");
+        assert_eq!(success("fr", 3), "2 langues et 10 messages sont désormais utilisés. 3 messages définis par deux fichiers ont gardé leur première définition.");
+    }
+
+    #[test]
+    fn test_plural_forms_in_attributes() {
+        let translations = read_ftl_from(None).unwrap().translations;
+        let map = |locale: &str, places: usize, roads: usize| {
+            let mut args = FluentArgs::new();
+            args.set("places", places);
+            args.set("roads", roads);
+            unisolated(tr_raw(&translations, locale, "road_map__success", Some("message"), Some(&args)))
+        };
+        assert!(map("en-US", 1, 2).starts_with("Map of 1 place and 2 roads,"), "{}", map("en-US", 1, 2));
+        assert!(map("fr", 2, 1).starts_with("Carte de 2 lieux et 1 route,"), "{}", map("fr", 2, 1));
+    }
+
+    #[test]
+    fn test_unresolved_variable_policies() {
+        let translations = translations_for(&[]);
+        let mut bundle = Bundle::new_concurrent(vec!["fr".parse().unwrap()]);
+        bundle.add_resource(FluentResource::try_new("greeting = { $user } joins { $place }, { ping }: {$support}\nsupport = help@example.com\n".to_string()).unwrap()).unwrap();
+        let greet = |place: Option<&'static str>, policy| {
+            let mut args = FluentArgs::new();
+            args.set("user", "Alys");
+            if let Some(place) = place {
+                args.set("place", place);
             }
+            unisolated(format_resolving(&translations, &bundle, "greeting", None, Some(&args), policy))
+        };
+
+        assert_eq!(greet(None, UnresolvedVariablePolicy::ReturnId), "greeting");
+        assert_eq!(greet(None, UnresolvedVariablePolicy::ReturnRawPattern), "{$user} joins {$place}, {ping}: {$support}");
+        assert_eq!(greet(None, UnresolvedVariablePolicy::ReturnWithPlaceholder), "Alys joins {$place}, {ping}: help@example.com");
+
+        // Every policy formats the message once its variables are resolved
+        for policy in [UnresolvedVariablePolicy::ReturnId, UnresolvedVariablePolicy::ReturnRawPattern, UnresolvedVariablePolicy::ReturnWithPlaceholder] {
+            assert_eq!(greet(Some("Lyon"), policy), "Alys joins Lyon, {ping}: help@example.com");
+        }
+        // Messages missing from the bundle come from the main one
+        assert_eq!(format_resolving(&translations, &bundle, "ping", None, None, UnresolvedVariablePolicy::ReturnId), "main");
+    }
+
+    #[test]
+    fn test_attributes_resolve_variables() {
+        let translations = translations_for(&[]);
+        let mut bundle = Bundle::new_concurrent(vec!["fr".parse().unwrap()]);
+        bundle.add_resource(FluentResource::try_new("setup__error = Error\n    .title = { $universe } failed\n    .message = Contact {$support}\nsupport = help@example.com\n".to_string()).unwrap()).unwrap();
+        let attribute = |attr, args: Option<&FluentArgs>| {
+            unisolated(format_resolving(&translations, &bundle, "setup__error", Some(attr), args, UnresolvedVariablePolicy::default()))
+        };
+
+        // Without explicit arguments, a variable is given the message of the same name
+        assert_eq!(attribute("message", None), "Contact help@example.com");
+        assert_eq!(attribute("title", None), "{$universe} failed");
+
+        // With explicit arguments, they are used along with the resolved variables
+        let mut args = FluentArgs::new();
+        args.set("universe", "Avalon");
+        assert_eq!(attribute("title", Some(&args)), "Avalon failed");
+        assert_eq!(attribute("message", Some(&args)), "Contact help@example.com");
+        args.set("support", "the support");
+        assert_eq!(attribute("message", Some(&args)), "Contact the support");
+
+        // The value is kept apart from the attributes, and a missing attribute gives the token
+        assert_eq!(format_resolving(&translations, &bundle, "setup__error", None, None, UnresolvedVariablePolicy::default()), "Error");
+        assert_eq!(attribute("unknown", None), "setup__error");
+    }
+
+    #[test]
+    fn test_static_messages_are_formatted_once() {
+        let translations = read_ftl().unwrap();
+        let fr = translations.bundle_for("fr").unwrap();
+        let format = |id, attr| format_resolving(&translations, fr, id, attr, None, UnresolvedVariablePolicy::default());
+
+        let label = format("readme_channel_name", None);
+        for _ in 0..100 {
+            assert_eq!(format("readme_channel_name", None), label);
         }
+        assert_eq!(translations.static_messages.formatted(), 1);
+        // Each locale and attribute is cached apart
+        assert_eq!(format_resolving(&translations, &translations.main, "readme_channel_name", None, None, UnresolvedVariablePolicy::default()), "readme");
+        format("tips", Some("title"));
+        assert_eq!(translations.static_messages.formatted(), 3);
+        // Messages with placeables are formatted every time
+        let mut args = FluentArgs::new();
+        args.set("count", 2);
+        for _ in 0..10 {
+            format_resolving(&translations, fr, "place_list__page", Some("title"), Some(&args), UnresolvedVariablePolicy::default());
+        }
+        assert_eq!(translations.static_messages.formatted(), 3);
+
+        // Reloaded translations start without the messages of the previous ones
+        let reloaded = read_ftl().unwrap();
+        assert_eq!(reloaded.static_messages.formatted(), 0);
+    }
+
+    #[test]
+    fn test_extract_variables_from_pattern() {
+        assert_eq!(extract_variables_from_pattern("Hello, {$name}. Welcome to {$location}."), vec!["name", "location"]);
+        assert!(extract_variables_from_pattern("No variables").is_empty());
     }
 
-    Ok(bundle.format_pattern(pattern, Some(&args), &mut vec![]).into_owned())
-}
\ No newline at end of file
+    #[test]
+    fn test_unresolved_variable_policy_from_env() {
+        assert_eq!(UnresolvedVariablePolicy::from_env_value(None), UnresolvedVariablePolicy::ReturnWithPlaceholder);
+        assert_eq!(UnresolvedVariablePolicy::from_env_value(Some("id")), UnresolvedVariablePolicy::ReturnId);
+        assert_eq!(UnresolvedVariablePolicy::from_env_value(Some(" Raw_Pattern ")), UnresolvedVariablePolicy::ReturnRawPattern);
+        assert_eq!(UnresolvedVariablePolicy::from_env_value(Some("placeholder")), UnresolvedVariablePolicy::ReturnWithPlaceholder);
+        assert_eq!(UnresolvedVariablePolicy::from_env_value(Some("silent")), UnresolvedVariablePolicy::ReturnWithPlaceholder);
+    }
+
+    #[test]
+    fn test_read_ftl_dir_overrides_and_extends_embedded() {
+        let dir = std::env::temp_dir().join(format!("rpbot_translations_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("fr.ftl"), "ping = pong\n").unwrap();
+        std::fs::write(dir.join("de.ftl"), "ping = Ping\n").unwrap();
+
+        let translations = read_ftl_from(Some(&dir));
+        let _ = std::fs::remove_dir_all(&dir);
+        let translations = translations.unwrap().translations;
+        // The main locale stays embedded, fr is replaced and de added
+        assert_eq!(tr_raw(&translations, "en-US", "readme_channel_name", None, None), "readme");
+        assert_eq!(tr_raw(&translations, "fr", "ping", None, None), "pong");
+        assert_eq!(tr_raw(&translations, "fr", "readme_channel_name", None, None), "readme");
+        assert_eq!(tr_raw(&translations, "de", "ping", None, None), "Ping");
+    }
+
+    #[test]
+    fn test_read_ftl_dir_merges_the_files_of_a_locale() {
+        let dir = std::env::temp_dir().join(format!("rpbot_translations_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(dir.join("fr")).unwrap();
+        std::fs::write(dir.join("fr.ftl"), "ping = pong\n").unwrap();
+        std::fs::write(dir.join("fr").join("roads.ftl"), "road_list__page = Routes\n    .title = Routes\n").unwrap();
+        std::fs::write(dir.join("fr").join("places.ftl"), "place_list__page = Lieux\nping = pang\n").unwrap();
+        std::fs::write(dir.join("fr").join("notes.txt"), "ping = ignored\n").unwrap();
+
+        let report = read_ftl_from(Some(&dir));
+        let _ = std::fs::remove_dir_all(&dir);
+        let report = report.unwrap();
+        let translations = &report.translations;
+        assert!(report.skipped.is_empty(), "{:?}", report.skipped);
+        assert_eq!(tr_raw(translations, "fr", "road_list__page", Some("title"), None), "Routes");
+        assert_eq!(tr_raw(translations, "fr", "place_list__page", None, None), "Lieux");
+        // The flat file comes first, so its definition of the duplicate is kept
+        assert_eq!(tr_raw(translations, "fr", "ping", None, None), "pong");
+        assert_eq!(report.conflicts.len(), 1, "{:?}", report.conflicts);
+        let conflict = &report.conflicts[0];
+        assert!(conflict.starts_with("fr: `ping`"), "{conflict}");
+        assert!(conflict.contains("places.ftl") && conflict.contains("fr.ftl"), "{conflict}");
+        // The embedded fr translations are replaced as a whole
+        assert_eq!(tr_raw(translations, "fr", "readme_channel_name", None, None), "readme");
+    }
+}
@@ -0,0 +1,491 @@
+//! Registry of the Fluent keys the code emits, checked against the main translation file.
+//!
+//! Errors and results are plain translation keys, so a typo only shows once a user gets the raw
+//! key in an embed. Every key the code can send is listed in [`FLUENT_KEYS`] with the attributes
+//! it is formatted with; [`check_keys`] reports those missing at startup and a test fails on them.
+//! A new key is added to the registry along with its translations.
+use crate::translation::Translations;
+use crate::universe::setup::names::CONFIGURABLE_NAMES;
+
+/// Attributes of the keys sent through `reply`, which formats their title and message.
+const REPLY: &[&str] = &["title", "message"];
+
+/// Attributes of the keys of the prompts sent through `confirm`, with the labels of their buttons.
+const PROMPT: &[&str] = &["title", "message", "cancel", "confirm"];
+
+/// Fluent keys the code emits, with the attributes it formats them with. A key without
+/// attributes is formatted on its own and must have a value.
+pub static FLUENT_KEYS: &[(&str, &[&str])] = &[
+    ("accept_character__member_not_found", REPLY),
+    ("accept_character__no_player_role_id", REPLY),
+    ("add_server__cancelled", REPLY),
+    ("add_server__confirm", PROMPT),
+    ("add_server__confirmation_timeout", REPLY),
+    ("add_server__database_error", REPLY),
+    ("add_server__log", REPLY),
+    ("add_server__selection_timeout", REPLY),
+    ("add_server__universe_not_found", REPLY),
+    ("add_server_to_universe__already_bind", REPLY),
+    ("add_server_to_universe__guild_linked", REPLY),
+    ("add_server_to_universe__guild_reattached", REPLY),
+    ("add_server_to_universe__universes_unavailable", REPLY),
+    ("botstats__command", &[]),
+    ("botstats__latency", &[]),
+    ("botstats__latency_over", &[]),
+    ("botstats__report", REPLY),
+    ("cancel_setup", &[]),
+    ("character__no_active", REPLY),
+    ("character__not_found", REPLY),
+    ("character__pending_approval", REPLY),
+    ("character__rejected", REPLY),
+    ("character_approval__already_decided", &[]),
+    ("character_approval__approved", &[]),
+    ("character_approval__not_found", &[]),
+    ("character_approval__rejected", &[]),
+    ("character_approval__rejected_notice", REPLY),
+    ("character_create__name_taken", REPLY),
+    ("character_delete__cancelled", REPLY),
+    ("character_delete__confirm", PROMPT),
+    ("character_delete__notice", REPLY),
+    ("character_delete__success", REPLY),
+    ("character_delete__timeout", REPLY),
+    ("character_list__empty", REPLY),
+    ("character_list__no_place", &["message"]),
+    ("character_list__page", &["title"]),
+    ("character_list__row", &["message"]),
+    ("character_sheet__fields", &["owner", "description", "stats", "status", "place"]),
+    ("character_sheet__no_description", &["message"]),
+    ("character_sheet__no_stats", &["message"]),
+    ("character_sheet__update_failed", &["message"]),
+    ("character_stat__invalid_value", REPLY),
+    ("character_stat__out_of_bounds", REPLY),
+    ("character_stat__set", REPLY),
+    ("character_stat__set_sheet_not_updated", REPLY),
+    ("character_stat__stat_not_found", REPLY),
+    ("character_status", &["pending", "approved", "rejected"]),
+    ("character_switch__already_active", REPLY),
+    ("character_switch__success", REPLY),
+    ("character_switch__travelling", REPLY),
+    ("character_teleport__log", &[]),
+    ("character_teleport__no_character", REPLY),
+    ("character_teleport__success", REPLY),
+    ("choose_universe", &[]),
+    ("commands_channel_topic", &[]),
+    ("contest__invalid_die", REPLY),
+    ("contest__missing_stat", REPLY),
+    ("contest__opponent_no_active", REPLY),
+    ("contest__opponent_not_playable", REPLY),
+    ("contest__result", &["title", "winner", "draw"]),
+    ("contest__side", &["message"]),
+    ("continue_setup", &[]),
+    ("create_character__accept_character", &[]),
+    ("create_character__choose_place", REPLY),
+    ("create_character__database_error", REPLY),
+    ("create_character__delete_character", &[]),
+    ("create_character__guild_only", REPLY),
+    ("create_character__invalid_embed_title", REPLY),
+    ("create_character__invalid_footer", REPLY),
+    ("create_character__invalid_interaction", &[]),
+    ("create_character__invalid_place_selected", &[]),
+    ("create_character__message_not_found", REPLY),
+    ("create_character__modify_character", &[]),
+    ("create_character__no_member", REPLY),
+    ("create_character__no_permission", REPLY),
+    ("create_character__no_universe_found", REPLY),
+    ("create_character__not_owner", REPLY),
+    ("create_character__refuse_character", &[]),
+    ("create_character__refused", REPLY),
+    ("create_character__start_place", &[]),
+    ("create_character__submit_character", &[]),
+    ("create_character__submit_notification", &[]),
+    ("create_character__submitted", REPLY),
+    ("create_character__timed_out", REPLY),
+    ("create_character__type_mismatch", REPLY),
+    ("create_character__wrong_channel", REPLY),
+    ("create_item__db_error", REPLY),
+    ("create_place__channel_id", &[]),
+    ("create_place__character_too_long", REPLY),
+    ("create_place__database_not_found", REPLY),
+    ("create_place__log", REPLY),
+    ("create_place__name_already_exists", REPLY),
+    ("create_place__new_place_title", &[]),
+    ("create_place__place_one_not_found", REPLY),
+    ("create_place__place_two_not_found", REPLY),
+    ("create_place__role_not_created", REPLY),
+    ("create_place__rollback_complete", REPLY),
+    ("create_place__server_not_found", REPLY),
+    ("create_place__setup_required", REPLY),
+    ("create_place__success", REPLY),
+    ("create_place__success_wiki_failed", REPLY),
+    ("create_place__success_with_wiki", REPLY),
+    ("create_place__wiki_template", &[]),
+    ("create_road__already_exists", REPLY),
+    ("create_road__create_channel_failed_rollback_failed", REPLY),
+    ("create_road__create_channel_failed_rollback_success", REPLY),
+    ("create_road__database_error", REPLY),
+    ("create_road__insert_road_failed_rollback_channel_failed", REPLY),
+    ("create_road__insert_road_failed_rollback_role_failed", REPLY),
+    ("create_road__insert_road_failed_rollback_success", REPLY),
+    ("create_road__invalid_place_one", REPLY),
+    ("create_road__invalid_place_two", REPLY),
+    ("create_road__limit_reached", REPLY),
+    ("create_road__log", REPLY),
+    ("create_road__role_creation_failed", REPLY),
+    ("create_road__same_place", REPLY),
+    ("create_road__server_not_found", REPLY),
+    ("create_road__setup_required", REPLY),
+    ("create_road__success", REPLY),
+    ("create_road__universe_mismatch", REPLY),
+    ("create_role__rollback_failed", REPLY),
+    ("create_universe__already_exist_for_this_server", REPLY),
+    ("create_universe__check_universe_limit_failed", REPLY),
+    ("create_universe__get_server_failed", REPLY),
+    ("create_universe__name_characters", REPLY),
+    ("create_universe__name_length", REPLY),
+    ("create_universe__name_taken", REPLY),
+    ("create_universe__run_full_setup", &[]),
+    ("create_universe__run_partial_setup", &[]),
+    ("create_universe__server_insert_failed", REPLY),
+    ("create_universe__setup_constraints_failed", REPLY),
+    ("create_universe__speed_stat_insert_failed", REPLY),
+    ("create_universe__universe_insert_failed", REPLY),
+    ("create_universe__universe_limit_reached", REPLY),
+    ("create_universe__universe_successfully_created", REPLY),
+    ("delete_place__database_delete_failed", REPLY),
+    ("delete_place__discord_delete_failed", REPLY),
+    ("delete_place__invalid_place", REPLY),
+    ("delete_place__log", REPLY),
+    ("delete_place__not_found", REPLY),
+    ("delete_place__notice", REPLY),
+    ("delete_place__other_server", REPLY),
+    ("delete_place__roads_remaining", REPLY),
+    ("delete_place__success", REPLY),
+    ("delete_road__channel_delete_failed", REPLY),
+    ("delete_road__database_delete_failed", REPLY),
+    ("delete_road__log", REPLY),
+    ("delete_road__missing_road", REPLY),
+    ("delete_road__not_found", REPLY),
+    ("delete_road__notice", REPLY),
+    ("delete_road__other_server", REPLY),
+    ("delete_road__role_delete_failed", REPLY),
+    ("delete_road__success", REPLY),
+    ("dice__invalid_expression", REPLY),
+    ("dice__invalid_keep", REPLY),
+    ("dice__too_many_dice", REPLY),
+    ("dice__too_many_sides", REPLY),
+    ("edit_road__invalid_distance", REPLY),
+    ("edit_road__slowmode_not_applied", REPLY),
+    ("edit_road__success", REPLY),
+    ("error__argument_parse", REPLY),
+    ("error__cooldown_hit", REPLY),
+    ("error__internal_error", REPLY),
+    ("error__not_an_owner", REPLY),
+    ("grant_limits__failed", REPLY),
+    ("grant_limits__success", REPLY),
+    ("grant_limits__success_until", REPLY),
+    ("guild_delete__server_detached", REPLY),
+    ("id__channel_delete_failed", REPLY),
+    ("id__channel_delete_sucess", REPLY),
+    ("id__nothing_to_delete", REPLY),
+    ("id__role_delete_failed", REPLY),
+    ("id__role_delete_success", REPLY),
+    ("item__server_not_found", REPLY),
+    ("join__already_player", REPLY),
+    ("join__log", REPLY),
+    ("join__success", REPLY),
+    ("join__success_with_place", REPLY),
+    ("leave__log", REPLY),
+    ("leave__not_in_game", REPLY),
+    ("leave__success", REPLY),
+    ("membership__bot_role_too_low", REPLY),
+    ("membership__role_failed", REPLY),
+    ("membership__setup_required", REPLY),
+    ("modifier_expiry__notice", &["title", "line"]),
+    ("move_from_place__road_not_found", REPLY),
+    ("navigate__already_there", &["message"]),
+    ("navigate__leg", &["message"]),
+    ("navigate__no_place", REPLY),
+    ("navigate__route", &["title", "distance", "duration"]),
+    ("navigate__too_many_roads", REPLY),
+    ("navigate__unknown_duration", &["message"]),
+    ("navigate__unreachable", REPLY),
+    ("partial_setup__get_guild_roles_error", REPLY),
+    ("ping__latency", &[]),
+    ("ping__report", &["title", "interaction", "gateway", "database", "unavailable"]),
+    ("place_info__fields", &["role", "category", "roads", "modifiers"]),
+    ("place_info__modifier", &["message"]),
+    ("place_info__modifier_kind", &["base", "multiplier", "flat"]),
+    ("place_info__no_modifiers", &["message"]),
+    ("place_info__no_roads", &["message"]),
+    ("place_info__permanent", &["message"]),
+    ("place_info__road", &["message"]),
+    ("place_list__empty", REPLY),
+    ("place_list__page", &["title"]),
+    ("place_list__row", &["message"]),
+    ("place_modifier__added", REPLY),
+    ("place_modifier__not_found", REPLY),
+    ("place_modifier__removed", REPLY),
+    ("place_modifier__stat_not_found", REPLY),
+    ("place_modifier__time_stopped", REPLY),
+    ("place_occupants__empty", REPLY),
+    ("place_occupants__not_here", REPLY),
+    ("place_occupants__page", &["title"]),
+    ("place_occupants__row", &["message"]),
+    ("preflight__missing_manage_channels", &[]),
+    ("preflight__missing_manage_roles", &[]),
+    ("preflight__missing_permissions", REPLY),
+    ("preflight__role_too_low", &[]),
+    ("readme_channel_name", &[]),
+    ("reconcile__deleted_resource", REPLY),
+    ("reload_translations__failed", REPLY),
+    ("reload_translations__success", REPLY),
+    ("rename_place__database_failed", REPLY),
+    ("rename_place__discord_edit_failed", REPLY),
+    ("rename_place__empty_name", REPLY),
+    ("rename_place__other_server", REPLY),
+    ("rename_place__revert_failed", REPLY),
+    ("rename_place__success", REPLY),
+    ("reply__reply_failed", REPLY),
+    ("reply__reply_success", REPLY),
+    ("resolve_stat__character_not_found", REPLY),
+    ("resolve_stat__database_error", REPLY),
+    ("road_list__empty", REPLY),
+    ("road_list__page", &["title"]),
+    ("road_list__row", &["message"]),
+    ("road_map__export_failed", REPLY),
+    ("road_map__success", REPLY),
+    ("role_menu__approval_required", REPLY),
+    ("role_menu__button", &["player", "spectator"]),
+    ("role_menu__database_error", REPLY),
+    ("role_menu__menu", REPLY),
+    ("role_menu__no_channel", &[]),
+    ("role_menu__post_failed", &[]),
+    ("role_menu__server_not_found", REPLY),
+    ("role_menu__spectator_added", REPLY),
+    ("role_menu__spectator_removed", REPLY),
+    ("role_menu__unknown_button", REPLY),
+    ("roll__fields", &["dice", "stat", "total"]),
+    ("roll__result", &["title"]),
+    ("roll__stat_bonus", &["message"]),
+    ("roll__stat_not_numeric", REPLY),
+    ("setup__admin_category_not_created", REPLY),
+    ("setup__admin_role_not_created", REPLY),
+    ("setup__already_in_progress", REPLY),
+    ("setup__bot_role_too_low", REPLY),
+    ("setup__channel_setup_failed", REPLY),
+    ("setup__commands_channel_not_created", REPLY),
+    ("setup__continue_setup_message", PROMPT),
+    ("setup__dry_run", REPLY),
+    ("setup__dry_run_get_channels_error", REPLY),
+    ("setup__error_during_role_creation", REPLY),
+    ("setup__full_setup_success", REPLY),
+    ("setup__log", REPLY),
+    ("setup__log_channel_not_created", REPLY),
+    ("setup__moderation_channel_not_created", REPLY),
+    ("setup__moderator_role_not_created", REPLY),
+    ("setup__nrp_category_not_created", REPLY),
+    ("setup__nrp_general_channel_not_created", REPLY),
+    ("setup__player_role_not_created", REPLY),
+    ("setup__progress", &["title", "roles", "road_category", "categories", "channels", "created"]),
+    ("setup__reorder_went_wrong", REPLY),
+    ("setup__repair_failed", &[]),
+    ("setup__repair_missing_dependency", REPLY),
+    ("setup__repair_recreated", &[]),
+    ("setup__repair_success", REPLY),
+    ("setup__repair_untouched", &[]),
+    ("setup__road_category_not_created", REPLY),
+    ("setup__roles_setup_failed", REPLY),
+    ("setup__rp_category_not_created", REPLY),
+    ("setup__rp_character_channel_not_created", REPLY),
+    ("setup__server_already_setup_timeout", REPLY),
+    ("setup__server_not_found", REPLY),
+    ("setup__server_update_failed", REPLY),
+    ("setup__setup_success_message", REPLY),
+    ("setup__spectator_role_not_created", REPLY),
+    ("setup__universal_time_channel_not_created", REPLY),
+    ("setup__wiki_channel_not_created", REPLY),
+    ("setup_server__cancelled", REPLY),
+    ("setup_server__failed", REPLY),
+    ("setup_server__success", REPLY),
+    ("spectate__already", REPLY),
+    ("spectate__is_player", REPLY),
+    ("spectate__log", REPLY),
+    ("spectate__success", REPLY),
+    ("start__button", &["create_universe", "link_server", "run_setup"]),
+    ("start__create_universe", REPLY),
+    ("start__database_error", REPLY),
+    ("start__onboarding", &["title", "message"]),
+    ("start__state", &["unlinked", "linked", "set_up"]),
+    ("start__step", &["create_universe", "link_server", "run_setup"]),
+    ("start_message", &["title", "description"]),
+    ("stat__bounds_not_numeric", REPLY),
+    ("stat__database_error", REPLY),
+    ("stat__empty_name", REPLY),
+    ("stat__invalid_bounds", REPLY),
+    ("stat__invalid_value", REPLY),
+    ("stat__no_universe", REPLY),
+    ("stat__not_found", REPLY),
+    ("stat__out_of_bounds", REPLY),
+    ("stat_create__success", REPLY),
+    ("stat_delete__in_use", REPLY),
+    ("stat_delete__success", REPLY),
+    ("stat_edit__nothing_to_change", REPLY),
+    ("stat_edit__success", REPLY),
+    ("stat_formula__arithmetic", REPLY),
+    ("stat_formula__failed", REPLY),
+    ("stat_formula__invalid", REPLY),
+    ("stat_formula__too_expensive", REPLY),
+    ("stat_formula__type_mismatch", REPLY),
+    ("stat_insert__duplicate_name", REPLY),
+    ("stat_insert__failed", REPLY),
+    ("stat_list__empty", REPLY),
+    ("stat_list__page", &["title"]),
+    ("stat_list__row", &["message"]),
+    ("stat_type", &["int", "float", "text", "bool"]),
+    ("time__midnight", &[]),
+    ("time__noon", &[]),
+    ("time__sunrise", &[]),
+    ("time__sunset", &[]),
+    ("tips", REPLY),
+    ("travel__already_moving_to_destination", REPLY),
+    ("travel__arrived_at_destination", &[]),
+    ("travel__character_not_found", REPLY),
+    ("travel__database_error", REPLY),
+    ("travel__invalid_road_destination", REPLY),
+    ("travel__invitation", REPLY),
+    ("travel__moving_to_place", &[]),
+    ("travel__no_road_available", REPLY),
+    ("travel__not_in_move", REPLY),
+    ("travel__not_in_place", REPLY),
+    ("travel__place_not_found", REPLY),
+    ("travel__reached_destination", &[]),
+    ("travel__server_not_found", REPLY),
+    ("travel__source_place_not_found", REPLY),
+    ("travel__started", &[]),
+    ("travel__stopped", &[]),
+    ("travel__taking_unknown_road", &[]),
+    ("travel_cancel__not_traveling", REPLY),
+    ("travel_cancel__success", REPLY),
+    ("travel_status__in_place", REPLY),
+    ("travel_status__moving", REPLY),
+    ("travel_status__stopped", REPLY),
+    ("travel_without_destination__database_error", REPLY),
+    ("travel_without_destination__reply_failed", REPLY),
+    ("universe__check_server_limit_failed", REPLY),
+    ("universe__not_universe_admin", REPLY),
+    ("universe__not_universe_creator", REPLY),
+    ("universe_add_admin__already_admin", REPLY),
+    ("universe_add_admin__bot", REPLY),
+    ("universe_add_admin__creator", REPLY),
+    ("universe_add_admin__success", REPLY),
+    ("universe_export__failed", REPLY),
+    ("universe_export__success", REPLY),
+    ("universe_import__cancelled", REPLY),
+    ("universe_import__confirm_transfer", PROMPT),
+    ("universe_import__download_failed", REPLY),
+    ("universe_import__failed", REPLY),
+    ("universe_import__invalid_file", REPLY),
+    ("universe_import__server_conflict", REPLY),
+    ("universe_import__server_limit", REPLY),
+    ("universe_import__server_not_administered", REPLY),
+    ("universe_import__success", REPLY),
+    ("universe_import__timeout", REPLY),
+    ("universe_import__unsupported_version", REPLY),
+    ("universe_remove_admin__not_admin", REPLY),
+    ("universe_remove_admin__success", REPLY),
+    ("universe_set_server_locale__log", REPLY),
+    ("universe_set_server_locale__success", REPLY),
+    ("universe_set_server_locale__unknown_locale", REPLY),
+    ("universe_settings__empty_name", REPLY),
+    ("universe_settings__invalid_color", REPLY),
+    ("universe_settings__unknown_name_key", REPLY),
+    ("universe_settings_apply_names__success", REPLY),
+    ("universe_settings_contest_die__success", REPLY),
+    ("universe_settings_general__invalid_time_modifier", REPLY),
+    ("universe_settings_general__locale", &[]),
+    ("universe_settings_general__log", REPLY),
+    ("universe_settings_general__success", REPLY),
+    ("universe_settings_general__time_modifier", &[]),
+    ("universe_settings_general__timed_out", REPLY),
+    ("universe_settings_general__title", &[]),
+    ("universe_settings_general__unknown_locale", REPLY),
+    ("universe_settings_join__success", REPLY),
+    ("universe_settings_road_slowmode__applied", REPLY),
+    ("universe_settings_road_slowmode__success", REPLY),
+    ("universe_settings_set_name__success", REPLY),
+    ("universe_status__report", &["title"]),
+    ("universe_status__row", &["admin_role_id", "moderator_role_id", "spectator_role_id", "player_role_id", "everyone_role_id", "admin_category_id", "nrp_category_id", "rp_category_id", "road_category_id", "rp_wiki_channel_id", "log_channel_id", "moderation_channel_id", "commands_channel_id", "nrp_general_channel_id", "rp_character_channel_id", "universal_time_channel_id", "readme_channel_id"]),
+    ("universe_status__state", &["present", "missing", "not_configured"]),
+    ("universe_teardown__cancelled", REPLY),
+    ("universe_teardown__confirm", PROMPT),
+    ("universe_teardown__log", REPLY),
+    ("universe_teardown__notice", REPLY),
+    ("universe_teardown__success", REPLY),
+    ("universe_teardown__timeout", REPLY),
+    ("universe_time__current_time", REPLY),
+    ("universe_time__invalid_modifier", REPLY),
+    ("verify__database_error", REPLY),
+    ("verify__discord_error", REPLY),
+    ("verify__no_drift", REPLY),
+    ("verify__report", REPLY),
+    ("whereis__in_place", REPLY),
+    ("whereis__no_character", REPLY),
+    ("whereis__nowhere", REPLY),
+    ("whereis__travelling", REPLY),
+];
+
+/// Keys of [`FLUENT_KEYS`] and of the names the setup gives, with their attributes.
+fn registered_keys() -> impl Iterator<Item = (&'static str, &'static [&'static str])> {
+    FLUENT_KEYS.iter().copied()
+        .chain(CONFIGURABLE_NAMES.iter().map(|(key, _)| (*key, &[] as &[&str])))
+}
+
+/// Registered keys, as `key` or `key.attribute`, which the main bundle of `translations` cannot format.
+pub fn missing_keys(translations: &Translations) -> Vec<String> {
+    let mut missing = Vec::new();
+    for (key, attributes) in registered_keys() {
+        let Some(message) = translations.main.get_message(key) else {
+            missing.push(key.to_string());
+            continue;
+        };
+        if attributes.is_empty() && message.value().is_none() {
+            missing.push(key.to_string());
+        }
+        for attribute in attributes {
+            if message.get_attribute(attribute).is_none() {
+                missing.push(format!("{key}.{attribute}"));
+            }
+        }
+    }
+    missing
+}
+
+/// Logs the registered keys the main bundle of `translations` cannot format, which users would
+/// get as raw keys.
+pub fn check_keys(translations: &Translations) {
+    let missing = missing_keys(translations);
+    if !missing.is_empty() {
+        tracing::error!(count = missing.len(), "translation keys missing from the main locale: {}", missing.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::translation::read_ftl;
+
+    #[test]
+    fn test_registered_keys_are_translated() {
+        let missing = missing_keys(&read_ftl().unwrap());
+        assert!(missing.is_empty(), "translation keys missing from the main locale: {missing:#?}");
+    }
+
+    #[test]
+    fn test_registry_has_no_duplicates() {
+        let mut keys: Vec<&str> = FLUENT_KEYS.iter().map(|(key, _)| *key).collect();
+        keys.sort();
+        let count = keys.len();
+        keys.dedup();
+        assert_eq!(keys.len(), count);
+    }
+}
@@ -8,14 +8,15 @@ use tokio::sync::{Mutex};
 use tokio::task::JoinHandle;
 use crate::database::travel::{PlayerMove, SpaceType};
 use crate::database::characters::Character;
-use chrono::{Local, Timelike, Utc};
+use chrono::Utc;
 use fluent::FluentArgs;
-use tokio::time::sleep;
+use crate::database::places::{check_existing_place, Place};
 use crate::database::road::get_road_by_channel_id;
 use crate::database::stats::{get_stat_by_name, SPEED_STAT};
 use crate::database::universe::get_universe_by_id;
 use crate::tr_locale;
-use crate::translation::{get_by_locale};
+use crate::translation::{get_by_locale, guild_locale_or_main};
+use crate::utility::tasks::TASKS;
 
 pub static MOVES: Lazy<Arc<Mutex<Vec<PlayerMove>>>> = Lazy::new(|| Arc::new(Mutex::new(vec![])));
 pub static SLEEPER: Lazy<Arc<Mutex<Option<JoinHandle<()>>>>> = Lazy::new(|| Arc::new(Mutex::new(None)));
@@ -66,7 +67,7 @@ async fn send_travel_invitation(http: &Arc<Http>, user: &serenity::all::User, us
 
     // Vérifier si l'utilisateur est déjà sur le serveur cible
     if let Ok(_member) = http.get_member(GuildId::new(target_guild_id), user.id).await {
-        println!("User {} is already in guild {}, skipping invitation.", user.id, target_guild_id);
+        tracing::debug!("User {} is already in guild {}, skipping invitation.", user.id, target_guild_id);
         return;
     }
 
@@ -80,8 +81,9 @@ async fn send_travel_invitation(http: &Arc<Http>, user: &serenity::all::User, us
     args.set("universe", universe_name.as_str());
     args.set("link", url.trim());
 
-    let title = get_by_locale("fr", "travel__invitation", Some("title"), None);
-    let description = get_by_locale("fr", "travel__invitation", Some("message"), Some(&args));
+    let locale = guild_locale_or_main(target_guild_id).await;
+    let title = get_by_locale(&locale, "travel__invitation", Some("title"), None);
+    let description = get_by_locale(&locale, "travel__invitation", Some("message"), Some(&args));
 
     let embed = CreateEmbed::new()
         .title(title)
@@ -93,8 +95,13 @@ async fn send_travel_invitation(http: &Arc<Http>, user: &serenity::all::User, us
 }
 
 fn move_process(delay: u64) -> JoinHandle<()> {
+    let mut shutdown = TASKS.shutdown_signal();
     tokio::spawn(async move {
-        sleep(Duration::from_secs(delay)).await;
+        // Nothing is lost by stopping while waiting: the moves are recovered from the database at startup
+        if !shutdown.sleep(Duration::from_secs(delay)).await {
+            return;
+        }
+        let _in_flight = TASKS.track();
 
         let mut next_delay: Option<u64> = None;
         let mut next_id: Option<String> = None;
@@ -117,9 +124,7 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                 Ok(updated_move) => {
                     if current_move.is_end {
                         // Le voyage est totalement fini (le temps d'attente pour l'arrivée est écoulé)
-                        let current = Local::now();
-                        let date = format!("{:02}:{:02}:{:02}", current.hour(), current.minute(), current.second());
-                        println!("[{date}] Move for user {} finished", current_move.user_id);
+                        tracing::info!("Move for user {} finished", current_move.user_id);
                         
                         // Envoi des messages de fin de voyage
                         let http_opt = {
@@ -129,6 +134,7 @@ fn move_process(delay: u64) -> JoinHandle<()> {
 
                         if let Some(http) = http_opt {
                             let guild_id = current_move.server_id;
+                            let road_guild_id = current_move.road_server_id.unwrap_or(guild_id);
                             let user_id = current_move.user_id;
                             let road_id = current_move.road_id;
                             let dest_id = current_move.destination_id;
@@ -137,7 +143,7 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                             tokio::spawn(async move {
                                 let http_arc = http.clone();
                                 if let Ok(user) = http_arc.get_user(UserId::new(user_id)).await {
-                                    let character_name = if let Ok(Some(char)) = Character::get_character_by_user_id(universe_id, user_id).await {
+                                    let character_name = if let Ok(Some(char)) = Character::get_active_character(universe_id, user_id).await {
                                         char.name
                                     } else {
                                         let member_nick = http_arc.get_member(GuildId::new(guild_id), UserId::new(user_id)).await.ok().and_then(|m| m.nick.clone());
@@ -153,7 +159,8 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                                                  destination_name = place.name;
                                              }
                                         }
-                                        let msg = tr_locale!("fr", "travel__reached_destination", user: user_display_name.as_str(), destination: destination_name.as_str());
+                                        let locale = guild_locale_or_main(road_guild_id).await;
+                                        let msg = tr_locale!(&locale, "travel__reached_destination", user: user_display_name.as_str(), destination: destination_name.as_str());
                                         let _ = ChannelId::new(rid).send_message(&http_arc, CreateMessage::new().content(msg)).await;
                                     }
 
@@ -170,7 +177,8 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                                                             .or_else(|| channels.iter().find(|c| c.is_text_based() && c.kind != serenity::all::ChannelType::Voice && c.kind != serenity::all::ChannelType::Stage));
 
                                                         if let Some(target_channel) = target_channel {
-                                                            let msg = tr_locale!("fr", "travel__arrived_at_destination", user: user_display_name.as_str());
+                                                            let locale = guild_locale_or_main(target_guild_id).await;
+                                                            let msg = tr_locale!(&locale, "travel__arrived_at_destination", user: user_display_name.as_str());
                                                             let _ = target_channel.id.send_message(&http_arc, CreateMessage::new().content(msg)).await;
                                                         }
                                                     }
@@ -180,7 +188,7 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                         }
 
                         // Déclenche le retrait du rôle de la route et l'ajout du rôle du lieu de destination
-                        println!("[{date}] Trip for user {} finished. Destination role: {:?}, Road role: {:?}", current_move.user_id, current_move.destination_role_id, current_move.road_role_id);
+                        tracing::info!("Trip for user {} finished. Destination role: {:?}, Road role: {:?}", current_move.user_id, current_move.destination_role_id, current_move.road_role_id);
                         
                         // Récupère la route pour savoir sur quel serveur est le salon de la route
                         let road_guild_id = current_move.road_server_id.unwrap_or(current_move.server_id);
@@ -199,7 +207,7 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                     }
                 }
                 Err(e) => {
-                    eprintln!("Error in next_step for user {}: {:?}", current_move.user_id, e);
+                    tracing::error!("Error in next_step for user {}: {:?}", current_move.user_id, e);
                     // On pourrait décider de le remettre ou non, ici on l'abandonne pour éviter les boucles infinies d'erreurs
                 }
             }
@@ -218,10 +226,8 @@ fn move_process(delay: u64) -> JoinHandle<()> {
         if let Some(delay) = next_delay {
             let mut sleeper = SLEEPER.lock().await;
             *sleeper = Some(move_process(delay));
-            let current = Local::now();
-            let date = format!("{:02}:{:02}:{:02}", current.hour(), current.minute(), current.second());
             if let Some(id) = next_id {
-                println!("[{date}] Next move process started for user {} with delay {}s", id, delay);
+                tracing::debug!("Next move process started for user {} with delay {}s", id, delay);
             }
         } else {
             let mut sleeper = SLEEPER.lock().await;
@@ -240,7 +246,7 @@ fn move_process(delay: u64) -> JoinHandle<()> {
                     manage_roles(http.clone(), guild_id, user_id, add, remove).await;
                 }
             } else {
-                eprintln!("HTTP client not initialized in move_process, cannot update roles");
+                tracing::error!("HTTP client not initialized in move_process, cannot update roles");
             }
         }
     })
@@ -255,15 +261,13 @@ pub async fn remove_move(user_id: u64){
         let p_move_user_id = moves[player_move_index].user_id;
         moves.remove(player_move_index);
 
-        let current = Local::now();
-        let date = format!("{:02}:{:02}:{:02}", current.hour(), current.minute(), current.second());
-        println!("[{date}] Move for user {p_move_user_id} successfully removed");
+        tracing::debug!("Move for user {p_move_user_id} successfully removed");
 
         if player_move_index == 0 {
             let mut sleeper = SLEEPER.lock().await;
             if let Some(handle) = sleeper.take() {
                 handle.abort();
-                println!("[{date}] Move for user {p_move_user_id} task aborted");
+                tracing::debug!("Move for user {p_move_user_id} task aborted");
             }
 
             if let Some(next_move) = moves.first() {
@@ -271,12 +275,12 @@ pub async fn remove_move(user_id: u64){
                     let now = Utc::now().timestamp() as u64;
                     let delay = end_ts.saturating_sub(now);
                     *sleeper = Some(move_process(delay));
-                    println!("[{date}] Move for user {} started (new first)", next_move.user_id);
+                    tracing::debug!("Move for user {} started (new first)", next_move.user_id);
                 }
             }
         }
     } else {
-        println!("task not found for user {user_id}");
+        tracing::debug!("task not found for user {user_id}");
     }
 }
 
@@ -289,33 +293,45 @@ pub async fn add_move(player_move: PlayerMove){
 
     moves.insert(i, player_move.clone());
 
-    let current = Local::now();
-    let date = format!("{:02}:{:02}:{:02}", current.hour(), current.minute(), current.second());
-    println!("[{date}] Move for user {} successfully added at index {i}", player_move.user_id);
+    tracing::debug!("Move for user {} successfully added at index {i}", player_move.user_id);
 
     if i == 0 {
         let mut sleeper = SLEEPER.lock().await;
         if let Some(handle) = sleeper.take() {
             handle.abort();
-            println!("[{date}] Previous first move task aborted.");
+            tracing::debug!("Previous first move task aborted.");
         }
 
         if let Some(end_ts) = player_move.step_end_timestamp {
             let now = Utc::now().timestamp() as u64;
             let delay = end_ts.saturating_sub(now);
             *sleeper = Some(move_process(delay));
-            println!("[{date}] Move for user {} started (new first)", player_move.user_id);
+            tracing::debug!("Move for user {} started (new first)", player_move.user_id);
         }
     }
 }
 
 
+/// Speed, in km/h, of a traveler whose speed stat is `speed_stat` in a universe whose time
+/// runs at `global_time_modifier` percent.
+pub fn travel_speed_kmh(speed_stat: f64, global_time_modifier: u32) -> f64 {
+    speed_stat * (global_time_modifier as f64) / 100.0
+}
+
+/// Seconds needed to travel `distance_km` at `speed_kmh`, `None` when the traveler cannot move.
+pub fn travel_seconds(distance_km: f64, speed_kmh: f64) -> Option<u64> {
+    if speed_kmh <= 0.0 {
+        return None;
+    }
+    Some((distance_km / speed_kmh * 3600.0).ceil() as u64)
+}
+
 pub async fn next_step_logic(actual_move: &PlayerMove) -> Result<PlayerMove, anyhow::Error> {
     let mut new_move = actual_move.clone();
 
     // Si le move est déjà marqué comme terminé, on applique la logique d'arrivée
     if actual_move.is_end {
-        println!("[Arrival DB Update] User: {}, DestRole: {:?}, RoadRole: {:?}", new_move.user_id, new_move.destination_role_id, new_move.road_role_id);
+        tracing::debug!("arrival: User: {}, DestRole: {:?}, RoadRole: {:?}", new_move.user_id, new_move.destination_role_id, new_move.road_role_id);
         new_move.actual_space_id = new_move.destination_id.unwrap_or(new_move.actual_space_id);
         new_move.actual_space_type = SpaceType::Place;
         new_move.road_id = None;
@@ -324,12 +340,24 @@ pub async fn next_step_logic(actual_move: &PlayerMove) -> Result<PlayerMove, any
         new_move.destination_role_id = None;
         new_move.step_end_timestamp = None;
         new_move.step_start_timestamp = None;
+        new_move.departure_timestamp = None;
         new_move.is_in_move = false;
         new_move.is_end = false;
         
         // Sauvegarde en base de données pour la persistance de l'arrivée
         if let Err(e) = new_move.upsert().await {
-            eprintln!("Failed to update player move {} in DB at arrival: {:?}", new_move.user_id, e);
+            tracing::error!("Failed to update player move {} in DB at arrival: {:?}", new_move.user_id, e);
+        }
+
+        // Le personnage se trouve désormais dans le lieu de destination
+        match Character::get_active_character(new_move.universe_id, new_move.user_id).await {
+            Ok(Some(mut character)) => {
+                if let Err(e) = character.set_current_place(Some(new_move.actual_space_id)).await {
+                    tracing::error!("Failed to update the place of the character of user {} at arrival: {:?}", new_move.user_id, e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => tracing::error!("Failed to get the character of user {} at arrival: {:?}", new_move.user_id, e),
         }
         
         return Ok(new_move);
@@ -372,7 +400,7 @@ pub async fn next_step_logic(actual_move: &PlayerMove) -> Result<PlayerMove, any
     let universe = universe_opt.ok_or_else(|| anyhow::anyhow!("universe not found"))?;
 
     // final_speed en km/h
-    let final_speed_kmh = stat_speed_kmh * (universe.global_time_modifier as f64) / 100.0;
+    let final_speed_kmh = travel_speed_kmh(stat_speed_kmh, universe.global_time_modifier);
     if final_speed_kmh <= 0.0 {
         bail!("final_speed must be > 0");
     }
@@ -390,9 +418,7 @@ pub async fn next_step_logic(actual_move: &PlayerMove) -> Result<PlayerMove, any
     }
 
     // temps total nécessaire pour finir (secondes)
-    // remaining_distance_km / final_speed_kmh => heures ; *3600 => secondes
-    let time_needed_secs_f = (remaining_distance_km / final_speed_kmh) * 3600.0;
-    let full_time_needed_secs = time_needed_secs_f.ceil() as u64;
+    let full_time_needed_secs = travel_seconds(remaining_distance_km, final_speed_kmh).unwrap_or(0);
 
     // clamp par le shortest_modifier s'il existe (modifier.end_timestamp est un timestamp absolu)
     let mut time_to_wait_secs = full_time_needed_secs;
@@ -423,7 +449,7 @@ pub async fn next_step_logic(actual_move: &PlayerMove) -> Result<PlayerMove, any
 
     // Sauvegarde en base de données pour la persistance
     if let Err(e) = new_move.upsert().await {
-        eprintln!("Failed to update player move {} in DB: {:?}", new_move.user_id, e);
+        tracing::error!("Failed to update player move {} in DB: {:?}", new_move.user_id, e);
     }
 
     Ok(new_move)
@@ -434,7 +460,7 @@ pub async fn setup(){
     let universes = match crate::database::universe::Universe::get_all_universes().await {
         Ok(u) => u,
         Err(e) => {
-            eprintln!("Failed to get universes for travel setup: {:?}", e);
+            tracing::error!("Failed to get universes for travel setup: {:?}", e);
             return;
         }
     };
@@ -444,12 +470,12 @@ pub async fn setup(){
     for universe in universes {
         match crate::database::travel::PlayerMove::get_active_moves(universe.universe_id).await {
             Ok(moves) => all_moves.extend(moves),
-            Err(e) => eprintln!("Failed to get active moves for universe {}: {:?}", universe.universe_id, e),
+            Err(e) => tracing::error!("Failed to get active moves for universe {}: {:?}", universe.universe_id, e),
         }
     }
 
     if all_moves.is_empty() {
-        println!("Travel system initialized: 0 active moves.");
+        tracing::info!("Travel system initialized: 0 active moves.");
         return;
     }
 
@@ -490,9 +516,7 @@ pub async fn setup(){
                 } else {
                     // Voyage terminé, on gère les rôles et messages si c'était une arrivée
                     if m.is_end {
-                        let current = Local::now();
-                        let date = format!("{:02}:{:02}:{:02}", current.hour(), current.minute(), current.second());
-                        println!("[{date}] Recovered move for user {} finished during setup", user_id);
+                        tracing::info!("Recovered move for user {} finished during setup", user_id);
                         
                         let http_opt = {
                             let lock = HTTP_CLIENT.lock().await;
@@ -501,6 +525,7 @@ pub async fn setup(){
 
                         if let Some(http) = http_opt {
                             let guild_id = m.server_id;
+                            let road_guild_id = m.road_server_id.unwrap_or(guild_id);
                             let road_id = m.road_id;
                             let dest_id = m.destination_id;
                             let universe_id = m.universe_id;
@@ -510,7 +535,7 @@ pub async fn setup(){
                             tokio::spawn(async move {
                                 let http_arc = http.clone();
                                 if let Ok(user) = http_arc.get_user(UserId::new(user_id)).await {
-                                    let character_name = if let Ok(Some(char)) = Character::get_character_by_user_id(universe_id, user_id).await {
+                                    let character_name = if let Ok(Some(char)) = Character::get_active_character(universe_id, user_id).await {
                                         char.name
                                     } else {
                                         let member_nick = http_arc.get_member(GuildId::new(guild_id), UserId::new(user_id)).await.ok().and_then(|m| m.nick.clone());
@@ -526,7 +551,8 @@ pub async fn setup(){
                                                  destination_name = place.name;
                                              }
                                         }
-                                        let msg = tr_locale!("fr", "travel__reached_destination", user: user_display_name.as_str(), destination: destination_name.as_str());
+                                        let locale = guild_locale_or_main(road_guild_id).await;
+                                        let msg = tr_locale!(&locale, "travel__reached_destination", user: user_display_name.as_str(), destination: destination_name.as_str());
                                         let _ = ChannelId::new(rid).send_message(&http_arc, CreateMessage::new().content(msg)).await;
                                     }
 
@@ -541,7 +567,8 @@ pub async fn setup(){
                                                 .or_else(|| channels.iter().find(|c| c.is_text_based() && c.kind != serenity::all::ChannelType::Voice && c.kind != serenity::all::ChannelType::Stage));
 
                                             if let Some(target_channel) = target_channel {
-                                                let msg = tr_locale!("fr", "travel__arrived_at_destination", user: user_display_name.as_str());
+                                                let locale = guild_locale_or_main(target_guild_id).await;
+                                                let msg = tr_locale!(&locale, "travel__arrived_at_destination", user: user_display_name.as_str());
                                                 let _ = target_channel.id.send_message(&http_arc, CreateMessage::new().content(msg)).await;
                                             }
                                         }
@@ -555,12 +582,12 @@ pub async fn setup(){
                     }
                 }
             }
-            Err(e) => eprintln!("Failed to process recovered move for user {}: {:?}", user_id, e),
+            Err(e) => tracing::error!("Failed to process recovered move for user {}: {:?}", user_id, e),
         }
     }
 
     if pending_moves.is_empty() {
-        println!("Travel system initialized: 0 pending moves (all recovered were processed).");
+        tracing::info!("Travel system initialized: 0 pending moves (all recovered were processed).");
         return;
     }
 
@@ -577,7 +604,7 @@ pub async fn setup(){
                 let delay = end_ts.saturating_sub(now);
                 let mut sleeper = SLEEPER.lock().await;
                 *sleeper = Some(move_process(delay));
-                println!("Travel system initialized: {} active moves, next in {}s", moves.len(), delay);
+                tracing::info!("Travel system initialized: {} active moves, next in {}s", moves.len(), delay);
             }
         }
     }
@@ -586,14 +613,12 @@ pub async fn setup(){
 pub async fn manage_roles(http: Arc<Http>, guild_id: u64, user_id: u64, role_to_add: Option<u64>, role_to_remove: Option<u64>) {
     let guild_id_obj = GuildId::new(guild_id);
     let user_id_obj = UserId::new(user_id);
-    let current = Local::now();
-    let date = format!("{:02}:{:02}:{:02}", current.hour(), current.minute(), current.second());
 
     // Vérifier si l'utilisateur est présent sur le serveur
     let member = match http.get_member(guild_id_obj, user_id_obj).await {
         Ok(m) => m,
         Err(_) => {
-            println!("[{date}] User {} not found on guild {}, skipping role updates.", user_id, guild_id);
+            tracing::debug!("User {} not found on guild {}, skipping role updates.", user_id, guild_id);
             return;
         }
     };
@@ -602,7 +627,7 @@ pub async fn manage_roles(http: Arc<Http>, guild_id: u64, user_id: u64, role_to_
     let guild_roles = match http.get_guild_roles(guild_id_obj).await {
         Ok(roles) => roles,
         Err(_) => {
-            eprintln!("[{date}] Failed to fetch roles for guild {}, skipping role updates.", guild_id);
+            tracing::error!("Failed to fetch roles for guild {}, skipping role updates.", guild_id);
             return;
         }
     };
@@ -610,26 +635,26 @@ pub async fn manage_roles(http: Arc<Http>, guild_id: u64, user_id: u64, role_to_
     if let Some(role_id) = role_to_remove {
         if guild_roles.iter().any(|r| r.id.get() == role_id) {
             if member.roles.iter().any(|r| r.get() == role_id) {
-                println!("[{date}] Removing role {} from user {} on guild {}", role_id, user_id, guild_id);
+                tracing::debug!("Removing role {} from user {} on guild {}", role_id, user_id, guild_id);
                 if let Err(e) = http.remove_member_role(guild_id_obj, user_id_obj, RoleId::new(role_id), None).await {
-                    eprintln!("[{date}] Failed to remove role {} from member {} on guild {}: {:?}", role_id, user_id, guild_id, e);
+                    tracing::error!("Failed to remove role {} from member {} on guild {}: {:?}", role_id, user_id, guild_id, e);
                 }
             }
         } else {
-            println!("[{date}] Role {} does not exist on guild {}, skipping removal.", role_id, guild_id);
+            tracing::debug!("Role {} does not exist on guild {}, skipping removal.", role_id, guild_id);
         }
     }
 
     if let Some(role_id) = role_to_add {
         if guild_roles.iter().any(|r| r.id.get() == role_id) {
             if !member.roles.iter().any(|r| r.get() == role_id) {
-                println!("[{date}] Adding role {} to user {} on guild {}", role_id, user_id, guild_id);
+                tracing::debug!("Adding role {} to user {} on guild {}", role_id, user_id, guild_id);
                 if let Err(e) = http.add_member_role(guild_id_obj, user_id_obj, RoleId::new(role_id), None).await {
-                    eprintln!("[{date}] Failed to add role {} to member {} on guild {}: {:?}", role_id, user_id, guild_id, e);
+                    tracing::error!("Failed to add role {} to member {} on guild {}: {:?}", role_id, user_id, guild_id, e);
                 }
             }
         } else {
-            println!("[{date}] Role {} does not exist on guild {}, skipping addition.", role_id, guild_id);
+            tracing::debug!("Role {} does not exist on guild {}, skipping addition.", role_id, guild_id);
         }
     }
 }
@@ -650,6 +675,7 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
     player_move.step_start_timestamp = Some(now);
     player_move.step_end_timestamp = Some(now);
     player_move.modified_speed = 0.0;
+    player_move.departure_timestamp.get_or_insert(now);
     
     // Déterminer le serveur cible au cas où la route commence sur un autre serveur
     let mut start_guild_id = guild_id;
@@ -680,7 +706,7 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
         let road_id = player_move.road_id;
         let universe_id = player_move.universe_id;
         
-        println!("[Invitation Debug] Road server {} is different from current server {}. Attempting to send road invitation.", start_guild_id, guild_id);
+        tracing::debug!("Road server {} is different from current server {}. Attempting to send road invitation.", start_guild_id, guild_id);
         
         tokio::spawn(async move {
             if let Some(rid) = road_id {
@@ -708,10 +734,10 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
                         };
 
                         if let Some(target_channel) = target_channel {
-                             println!("[Invitation Debug] Found channel {} on road server {}. Creating invite.", target_channel.id, start_guild_id);
+                             tracing::debug!("Found channel {} on road server {}. Creating invite.", target_channel.id, start_guild_id);
                              let url = get_or_create_invite(&http_arc, start_guild_id, target_channel.id).await;
                              
-                             let character_name = if let Ok(Some(char)) = Character::get_character_by_user_id(universe_id, user_id).await {
+                             let character_name = if let Ok(Some(char)) = Character::get_active_character(universe_id, user_id).await {
                                  char.name
                              } else {
                                  let member_nick = http_arc.get_member(GuildId::new(guild_id), UserId::new(user_id)).await.ok().and_then(|m| m.nick.clone());
@@ -721,10 +747,10 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
                              
                              send_travel_invitation(&http_arc, &user, &user_display_name, universe_id, &url, start_guild_id).await;
                         } else {
-                             println!("[Invitation Debug] No suitable channel found on road server {} for road invitation.", start_guild_id);
+                             tracing::debug!("No suitable channel found on road server {} for road invitation.", start_guild_id);
                         }
                     } else {
-                        println!("[Invitation Debug] Failed to fetch channels for road server {}.", start_guild_id);
+                        tracing::debug!("Failed to fetch channels for road server {}.", start_guild_id);
                     }
                 }
             }
@@ -739,7 +765,7 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
             let dest_id = player_move.destination_id;
             let universe_id = player_move.universe_id;
             
-            println!("[Invitation Debug] Destination server {} is different from current server {} and road server. Attempting to send destination invitation.", dest_guild_id, guild_id);
+            tracing::debug!("Destination server {} is different from current server {} and road server. Attempting to send destination invitation.", dest_guild_id, guild_id);
 
             tokio::spawn(async move {
                 if let Some(did) = dest_id {
@@ -751,10 +777,10 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
                                 .or_else(|| channels.iter().find(|c| c.is_text_based() && c.kind != serenity::all::ChannelType::Voice && c.kind != serenity::all::ChannelType::Stage));
 
                             if let Some(target_channel) = target_channel {
-                                println!("[Invitation Debug] Found channel {} for destination {} on server {}. Creating invite.", target_channel.id, did, dest_guild_id);
+                                tracing::debug!("Found channel {} for destination {} on server {}. Creating invite.", target_channel.id, did, dest_guild_id);
                                 let url = get_or_create_invite(&http_arc, dest_guild_id, target_channel.id).await;
                                 
-                                let character_name = if let Ok(Some(char)) = Character::get_character_by_user_id(universe_id, user_id).await {
+                                let character_name = if let Ok(Some(char)) = Character::get_active_character(universe_id, user_id).await {
                                     char.name
                                 } else {
                                     let member_nick = http_arc.get_member(GuildId::new(guild_id), UserId::new(user_id)).await.ok().and_then(|m| m.nick.clone());
@@ -764,10 +790,10 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
 
                                 send_travel_invitation(&http_arc, &user, &user_display_name, universe_id, &url, dest_guild_id).await;
                             } else {
-                                println!("[Invitation Debug] No suitable channel found for destination {} on server {}.", did, dest_guild_id);
+                                tracing::debug!("No suitable channel found for destination {} on server {}.", did, dest_guild_id);
                             }
                         } else {
-                            println!("[Invitation Debug] Failed to fetch channels for destination server {}.", dest_guild_id);
+                            tracing::debug!("Failed to fetch channels for destination server {}.", dest_guild_id);
                         }
                     }
                 }
@@ -790,7 +816,7 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
                 
         tokio::spawn(async move {
             if let Ok(user) = http_clone.get_user(UserId::new(user_id)).await {
-                let character_name = if let Ok(Some(char)) = Character::get_character_by_user_id(universe_id, user_id).await {
+                let character_name = if let Ok(Some(char)) = Character::get_active_character(universe_id, user_id).await {
                     char.name
                 } else {
                     let member_nick = http_clone.get_member(GuildId::new(guild_id), UserId::new(user_id)).await.ok().and_then(|m| m.nick.clone());
@@ -812,7 +838,8 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
                     }
                 }
                 
-                let msg = tr_locale!("fr", "travel__moving_to_place", user: user_display_name.as_str(), destination: destination_name.as_str());
+                let road_locale = guild_locale_or_main(first_step.road_server_id.unwrap_or(guild_id)).await;
+                let msg = tr_locale!(&road_locale, "travel__moving_to_place", user: user_display_name.as_str(), destination: destination_name.as_str());
                 let _ = ChannelId::new(road_id).send_message(&http_clone, CreateMessage::new().content(msg.clone())).await;
 
                 // Envoi du message dans le lieu de départ si applicable
@@ -824,10 +851,11 @@ pub async fn add_travel(http: Arc<Http>, guild_id: u64, mut player_move: PlayerM
                             .or_else(|| channels.iter().find(|c| c.is_text_based() && c.kind != serenity::all::ChannelType::Voice && c.kind != serenity::all::ChannelType::Stage));
 
                         if let Some(target_channel) = target_channel {
+                            let source_locale = guild_locale_or_main(source_guild_id).await;
                             let departure_msg = if is_secret {
-                                tr_locale!("fr", "travel__taking_unknown_road", user: user_display_name.as_str())
+                                tr_locale!(&source_locale, "travel__taking_unknown_road", user: user_display_name.as_str())
                             } else {
-                                msg
+                                tr_locale!(&source_locale, "travel__moving_to_place", user: user_display_name.as_str(), destination: destination_name.as_str())
                             };
                             let _ = target_channel.id.send_message(&http_clone, CreateMessage::new().content(departure_msg)).await;
                         }
@@ -884,4 +912,122 @@ pub async fn stop_travel(user_id: u64) -> Result<PlayerMove, anyhow::Error> {
     remove_move(user_id).await;
     
     Ok(player_move)
-}
\ No newline at end of file
+}
+
+/// Seconds left before a journey on a road of `road_distance` km ends, at the speed of its
+/// current step. `None` when the traveler is not moving.
+pub fn remaining_seconds(player_move: &PlayerMove, road_distance: f64) -> Option<u64> {
+    if !player_move.is_in_move || player_move.modified_speed <= 0.0 {
+        return None;
+    }
+    let remaining_km = (road_distance - calculate_current_distance(player_move)).max(0.0);
+    travel_seconds(remaining_km, player_move.modified_speed)
+}
+
+/// Ends the journey of `player_move` and brings the traveler back to the place it left: the
+/// road role is swapped for the role of this place, and the character stands there again.
+pub async fn cancel_travel(http: Arc<Http>, mut player_move: PlayerMove) -> Result<PlayerMove, anyhow::Error> {
+    if player_move.actual_space_type != SpaceType::Road {
+        bail!("User {} is not on a road", player_move.user_id);
+    }
+    let origin_id = player_move.source_id.ok_or_else(|| anyhow::anyhow!("source_id missing"))?;
+    remove_move(player_move.user_id).await;
+
+    let road_guild_id = player_move.road_server_id.unwrap_or(player_move.server_id);
+    let origin_guild_id = player_move.source_server_id.unwrap_or(player_move.server_id);
+    manage_roles(http.clone(), road_guild_id, player_move.user_id, None, player_move.road_role_id).await;
+    manage_roles(http, origin_guild_id, player_move.user_id, player_move.source_role_id, None).await;
+
+    settle(&mut player_move, origin_id);
+    player_move.upsert().await?;
+
+    if let Some(mut character) = Character::get_active_character(player_move.universe_id, player_move.user_id).await? {
+        character.set_current_place(Some(origin_id)).await?;
+    }
+    Ok(player_move)
+}
+
+/// Brings `player_move` to rest in the place `place_id`, forgetting the journey it was on.
+fn settle(player_move: &mut PlayerMove, place_id: u64) {
+    player_move.actual_space_id = place_id;
+    player_move.actual_space_type = SpaceType::Place;
+    player_move.is_in_move = false;
+    player_move.is_end = false;
+    player_move.step_start_timestamp = None;
+    player_move.step_end_timestamp = None;
+    player_move.departure_timestamp = None;
+    player_move.road_id = None;
+    player_move.road_role_id = None;
+    player_move.road_server_id = None;
+    player_move.destination_id = None;
+    player_move.destination_role_id = None;
+    player_move.destination_server_id = None;
+    player_move.modified_speed = 0.0;
+    player_move.distance_traveled = 0.0;
+}
+
+/// Moves `character` to `destination` at once, without travelling. Any journey of
+/// `player_move` is dropped, the roles of the road or places the character was in are swapped
+/// for the role of `destination`, and the move now starts from there.
+pub async fn teleport(http: Arc<Http>, mut player_move: PlayerMove, character: &mut Character, destination: &Place) -> Result<PlayerMove, anyhow::Error> {
+    remove_move(player_move.user_id).await;
+
+    let mut left = vec![];
+    if player_move.actual_space_type == SpaceType::Road
+        && let Some(road_role_id) = player_move.road_role_id {
+        left.push((player_move.road_server_id.unwrap_or(player_move.server_id), road_role_id));
+    }
+    let place_ids = [Some(player_move.actual_space_id), character.current_place_id].into_iter().flatten();
+    for place_id in place_ids {
+        if let Ok(Some(place)) = check_existing_place(player_move.universe_id, place_id).await {
+            left.push((place.server_id, place.role));
+        }
+    }
+    left.dedup();
+    for (guild_id, role_id) in left.into_iter().filter(|(_, role_id)| *role_id != destination.role) {
+        manage_roles(http.clone(), guild_id, player_move.user_id, None, Some(role_id)).await;
+    }
+    manage_roles(http, destination.server_id, player_move.user_id, Some(destination.role), None).await;
+
+    settle(&mut player_move, destination.category_id);
+    player_move.source_id = Some(destination.category_id);
+    player_move.source_role_id = Some(destination.role);
+    player_move.source_server_id = Some(destination.server_id);
+    player_move.upsert().await?;
+    character.set_current_place(Some(destination.category_id)).await?;
+    Ok(player_move)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_travel_time() {
+        assert_eq!(travel_speed_kmh(5.0, 200), 10.0);
+        assert_eq!(travel_seconds(15.0, 10.0), Some(5400));
+        assert_eq!(travel_seconds(15.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_remaining_seconds() {
+        let now = Utc::now().timestamp() as u64;
+        let mut player_move = PlayerMove {
+            is_in_move: true,
+            step_start_timestamp: Some(now),
+            step_end_timestamp: Some(now + 7200),
+            modified_speed: 5.0,
+            distance_traveled: 2.0,
+            ..Default::default()
+        };
+        // 8 km left at 5 km/h
+        let remaining = remaining_seconds(&player_move, 10.0).unwrap();
+        assert!((5755..=5760).contains(&remaining), "{remaining}");
+
+        player_move.distance_traveled = 12.0;
+        assert_eq!(remaining_seconds(&player_move, 10.0), Some(0));
+
+        player_move.is_in_move = false;
+        assert_eq!(remaining_seconds(&player_move, 10.0), None);
+    }
+}
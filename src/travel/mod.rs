@@ -1,3 +1,5 @@
 #[allow(non_snake_case)]
 pub mod travel__sub_command;
-pub mod logic;
\ No newline at end of file
+pub mod logic;
+pub mod navigate_command;
+pub mod route;
\ No newline at end of file
@@ -0,0 +1,152 @@
+use fluent::FluentArgs;
+use futures::TryStreamExt;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::characters::{effective_stat_value, get_active_character};
+use crate::database::places::check_existing_place;
+use crate::database::road::get_roads_by_universe_id;
+use crate::database::server::get_server_by_id;
+use crate::database::stats::SPEED_STAT;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::place::{autocomplete_place, resolve_place};
+use crate::tr;
+use crate::travel::logic::{travel_seconds, travel_speed_kmh};
+use crate::travel::route::{Edge, RoadGraph};
+use crate::utility::reply::reply_with_args;
+
+/// Most roads a universe can have for `/navigate` to search it.
+pub static MAX_NAVIGATION_ROADS: usize = 5000;
+
+/// Shows the shortest way from the place of your character to a destination.
+#[poise::command(slash_command, guild_only, rename = "navigate")]
+pub async fn navigate(
+    ctx: Context<'_>,
+    #[description = "navigate.destination"]
+    #[autocomplete = "autocomplete_place"]
+    destination: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let sent = match _navigate(&ctx, destination.trim(), &mut args).await {
+        Ok(embed) => ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await.map(|_| "reply__reply_success").map_err(Error::from),
+        Err(e) => reply_with_args(ctx, Err(e), Some(args)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Short form of a duration of `seconds`, such as `2h 05m` or `45m`.
+pub fn format_duration(seconds: u64) -> String {
+    let (days, hours, minutes) = (seconds / 86_400, seconds % 86_400 / 3600, seconds % 3600 / 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{seconds}s"),
+        (0, 0, _) => format!("{minutes}m"),
+        (0, _, _) => format!("{hours}h {minutes:02}m"),
+        _ => format!("{days}d {hours:02}h {minutes:02}m"),
+    }
+}
+
+/// Finds the shortest route from the place of the character the author plays to
+/// `destination_input`, and describes it leg by leg with its total distance and the time the
+/// character would take, computed like `/travel`. Secret roads are only used for administrators.
+/// `args` receives the destination for error messages.
+///
+/// # Errors
+/// - `"travel__server_not_found"` / `"travel__database_error"`: the server could not be read.
+/// - `"character__no_active"`: the author plays no character.
+/// - `"navigate__no_place"`: the character is not in any place.
+/// - `"travel__place_not_found"`: the destination does not exist.
+/// - `"navigate__too_many_roads"`: the universe has more than [`MAX_NAVIGATION_ROADS`] roads.
+/// - `"navigate__unreachable"`: no road leads to the destination.
+async fn _navigate(ctx: &Context<'_>, destination_input: &str, args: &mut FluentArgs<'_>) -> Result<CreateEmbed, Error> {
+    args.set("destination", destination_input.to_string());
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(s)) => s,
+        Ok(None) => return Err("travel__server_not_found".into()),
+        Err(_) => return Err("travel__database_error".into()),
+    };
+    let character = get_active_character(ctx).await?;
+    let Some(start) = character.current_place_id else { return Err("navigate__no_place".into()) };
+    let destination = match resolve_place(server.universe_id, destination_input).await {
+        Ok(Some(place)) => place,
+        Ok(None) => return Err("travel__place_not_found".into()),
+        Err(_) => return Err("travel__database_error".into()),
+    };
+    args.set("destination", destination.name.clone());
+
+    let show_secret = ctx.author_member().await
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.administrator());
+    let mut graph = RoadGraph::new();
+    let Ok(mut cursor) = get_roads_by_universe_id(server.universe_id).await else { return Err("travel__database_error".into()) };
+    loop {
+        let road = match cursor.try_next().await {
+            Ok(Some(road)) => road,
+            Ok(None) => break,
+            Err(_) => return Err("travel__database_error".into()),
+        };
+        if road.secret && !show_secret {
+            continue;
+        }
+        if graph.len() >= MAX_NAVIGATION_ROADS {
+            args.set("max", MAX_NAVIGATION_ROADS);
+            return Err("navigate__too_many_roads".into());
+        }
+        graph.add(&Edge { place_one: road.place_one_id, place_two: road.place_two_id, road: road.channel_id, distance: road.distance });
+    }
+    let Some(legs) = graph.shortest_path(start, destination.category_id) else { return Err("navigate__unreachable".into()) };
+
+    let mut lines = vec![];
+    for (index, leg) in legs.iter().enumerate() {
+        let name = match check_existing_place(server.universe_id, leg.to).await {
+            Ok(Some(place)) => place.name,
+            _ => format!("<#{}>", leg.to),
+        };
+        let mut leg_args = FluentArgs::new();
+        leg_args.set("index", index + 1);
+        leg_args.set("place", name);
+        leg_args.set("road", format!("<#{}>", leg.road));
+        leg_args.set("distance", leg.distance);
+        lines.push(tr!(*ctx, "navigate__leg", @message; Some(&leg_args)));
+    }
+    if lines.is_empty() {
+        lines.push(tr!(*ctx, "navigate__already_there", @message));
+    }
+
+    let total: u64 = legs.iter().map(|leg| leg.distance).sum();
+    let global_time_modifier = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe.global_time_modifier,
+        _ => 100,
+    };
+    let duration = match effective_stat_value(&character, SPEED_STAT).await {
+        Ok((_, speed)) => travel_seconds(total as f64, travel_speed_kmh(speed.as_f64(), global_time_modifier)),
+        Err(_) => None,
+    };
+    let duration = match duration {
+        Some(seconds) => format_duration(seconds),
+        None => tr!(*ctx, "navigate__unknown_duration", @message),
+    };
+
+    let mut title_args = FluentArgs::new();
+    title_args.set("destination", destination.name.clone());
+    Ok(CreateEmbed::new()
+        .title(tr!(*ctx, "navigate__route", @title; Some(&title_args)))
+        .description(lines.join("\n"))
+        .color(Color::from_rgb(0x6f, 0x00, 0xff))
+        .field(tr!(*ctx, "navigate__route", @distance), format!("{total} km"), true)
+        .field(tr!(*ctx, "navigate__route", @duration), duration, true))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(45 * 60), "45m");
+        assert_eq!(format_duration(2 * 3600 + 5 * 60), "2h 05m");
+        assert_eq!(format_duration(86_400 + 3600), "1d 01h 00m");
+    }
+}
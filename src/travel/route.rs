@@ -0,0 +1,144 @@
+//! Shortest paths over the road network of a universe.
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// A road seen as an edge between the categories of two places.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+    pub place_one: u64,
+    pub place_two: u64,
+    /// Channel of the road.
+    pub road: u64,
+    pub distance: u64,
+}
+
+/// One road taken by a route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leg {
+    pub from: u64,
+    pub to: u64,
+    pub road: u64,
+    pub distance: u64,
+}
+
+/// Roads between places, keyed by place category id.
+#[derive(Debug, Default)]
+pub struct RoadGraph {
+    adjacency: HashMap<u64, Vec<(u64, u64, u64)>>,
+    edges: usize,
+}
+
+impl RoadGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of roads in the graph.
+    pub fn len(&self) -> usize {
+        self.edges
+    }
+
+    /// Adds a road, which can be taken both ways.
+    pub fn add(&mut self, edge: &Edge) {
+        self.adjacency.entry(edge.place_one).or_default().push((edge.place_two, edge.road, edge.distance));
+        self.adjacency.entry(edge.place_two).or_default().push((edge.place_one, edge.road, edge.distance));
+        self.edges += 1;
+    }
+
+    /// Shortest route from `from` to `to`, leg by leg, with Dijkstra's algorithm. Empty when
+    /// both are the same place, `None` when `to` cannot be reached.
+    pub fn shortest_path(&self, from: u64, to: u64) -> Option<Vec<Leg>> {
+        let mut distances: HashMap<u64, u64> = HashMap::from([(from, 0)]);
+        let mut previous: HashMap<u64, Leg> = HashMap::new();
+        let mut queue = BinaryHeap::from([State { distance: 0, place: from }]);
+
+        while let Some(State { distance, place }) = queue.pop() {
+            if place == to {
+                break;
+            }
+            if distances.get(&place).is_some_and(|best| distance > *best) {
+                continue;
+            }
+            for (next, road, length) in self.adjacency.get(&place).into_iter().flatten() {
+                let candidate = distance.saturating_add(*length);
+                if distances.get(next).is_none_or(|best| candidate < *best) {
+                    distances.insert(*next, candidate);
+                    previous.insert(*next, Leg { from: place, to: *next, road: *road, distance: *length });
+                    queue.push(State { distance: candidate, place: *next });
+                }
+            }
+        }
+
+        if from != to && !previous.contains_key(&to) {
+            return None;
+        }
+        let mut legs = vec![];
+        let mut place = to;
+        while place != from {
+            let leg = previous.remove(&place)?;
+            place = leg.from;
+            legs.push(leg);
+        }
+        legs.reverse();
+        Some(legs)
+    }
+}
+
+/// Entry of the Dijkstra queue, ordered so that the closest place comes out first.
+#[derive(Debug, PartialEq, Eq)]
+struct State {
+    distance: u64,
+    place: u64,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance).then_with(|| self.place.cmp(&other.place))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph(edges: &[(u64, u64, u64)]) -> RoadGraph {
+        let mut graph = RoadGraph::new();
+        for (road, (one, two, distance)) in edges.iter().enumerate() {
+            graph.add(&Edge { place_one: *one, place_two: *two, road: 100 + road as u64, distance: *distance });
+        }
+        graph
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        // 1 -> 2 -> 3 is shorter than the direct road 1 -> 3
+        let graph = graph(&[(1, 2, 4), (2, 3, 5), (1, 3, 12), (3, 4, 1)]);
+        let legs = graph.shortest_path(1, 4).unwrap();
+        assert_eq!(legs, vec![
+            Leg { from: 1, to: 2, road: 100, distance: 4 },
+            Leg { from: 2, to: 3, road: 101, distance: 5 },
+            Leg { from: 3, to: 4, road: 103, distance: 1 },
+        ]);
+        assert_eq!(graph.len(), 4);
+    }
+
+    #[test]
+    fn test_roads_go_both_ways() {
+        let graph = graph(&[(1, 2, 4)]);
+        assert_eq!(graph.shortest_path(2, 1).unwrap(), vec![Leg { from: 2, to: 1, road: 100, distance: 4 }]);
+    }
+
+    #[test]
+    fn test_unreachable_and_same_place() {
+        let graph = graph(&[(1, 2, 4), (3, 4, 1)]);
+        assert_eq!(graph.shortest_path(1, 4), None);
+        assert_eq!(graph.shortest_path(1, 99), None);
+        assert_eq!(graph.shortest_path(1, 1), Some(vec![]));
+    }
+}
@@ -1,29 +1,21 @@
 use poise::serenity_prelude::Context as SerenityContext;
 use serenity::all::{CreateActionRow, CreateSelectMenuOption, ComponentInteraction};
+use crate::characters::get_active_character;
+use crate::database::characters::Character;
 use crate::database::places::{get_place_by_category_id,};
 use crate::database::server::{get_server_by_id, Server};
 use crate::database::travel::{PlayerMove, SpaceType};
 use crate::discord::poise_structs::{Context, Error};
-use crate::travel::logic::{add_travel, stop_travel};
+use crate::utility::mention::parse_channel_id;
+use crate::travel::logic::{add_travel, cancel_travel, remaining_seconds, stop_travel, MOVES};
 use crate::utility::reply::{reply, reply_with_args};
+use fluent::FluentArgs;
 use futures::{TryStreamExt};
 use poise::{CreateReply};
 use crate::database::road::{get_road, get_road_by_channel_id, get_road_by_source, Road};
 use crate::roads::road;
 
-fn parse_channel_id(input: &str) -> Option<u64> {
-    if let Ok(id) = input.parse::<u64>() {
-        return Some(id);
-    }
-    if input.starts_with("<#") && input.ends_with('>') {
-        if let Ok(id) = input[2..input.len() - 1].parse::<u64>() {
-            return Some(id);
-        }
-    }
-    None
-}
-
-#[poise::command(slash_command, guild_only, subcommands("stop", "start"), rename = "travel")]
+#[poise::command(slash_command, guild_only, subcommands("stop", "start", "status", "cancel"), rename = "travel")]
 pub async fn travel(ctx: Context<'_>) -> Result<(), Error> {
     Ok(())
 }
@@ -41,10 +33,8 @@ pub async fn start(
         _ => return Err("travel__server_not_found".into()),
     };
 
-    let _character = match server.clone().get_character_by_user_id(ctx.author().id.get()).await {
-        Ok(Some(c)) => c,
-        _ => return Err("travel__character_not_found".into()),
-    };
+    let character = get_active_character(&ctx).await?;
+    character.check_playable()?;
 
     let player_move = match server.clone().get_player_move(ctx.author().id.get()).await {
         Ok(Some(m)) => m,
@@ -86,30 +76,142 @@ pub async fn stop(ctx: Context<'_>) -> Result<(), Error> {
     }
 }
 
+/// Shows where the character you play is, and how long its journey still lasts.
+#[poise::command(slash_command, guild_only, rename = "travel_status")]
+pub async fn status(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _status(&ctx, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Describes the position of the author. The journey under way is read from the travel queue,
+/// which is more recent than the saved document. `args` receives the place, the destination,
+/// the distance left and the expected arrival.
+///
+/// # Errors
+/// - `"travel__server_not_found"`: the server is not part of a universe.
+/// - `"travel__character_not_found"`: the author has no position in the universe.
+/// - `"travel__database_error"`: a query failed.
+async fn _status(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(s)) => s,
+        Ok(None) => return Err("travel__server_not_found".into()),
+        Err(_) => return Err("travel__database_error".into()),
+    };
+    let user_id = ctx.author().id.get();
+    let queued = MOVES.lock().await.iter().find(|m| m.user_id == user_id && m.universe_id == server.universe_id).cloned();
+    let player_move = match queued {
+        Some(m) => m,
+        None => match server.clone().get_player_move(user_id).await {
+            Ok(Some(m)) => m,
+            Ok(None) => return Err("travel__character_not_found".into()),
+            Err(_) => return Err("travel__database_error".into()),
+        },
+    };
+
+    let place_name = |place_id: Option<u64>| async move {
+        let Some(place_id) = place_id else { return "-".to_string() };
+        match get_place_by_category_id(server.universe_id, place_id).await {
+            Ok(Some(place)) => place.name,
+            _ => format!("<#{place_id}>"),
+        }
+    };
+    if player_move.actual_space_type == SpaceType::Place {
+        args.set("place", place_name(Some(player_move.actual_space_id)).await);
+        return Ok("travel_status__in_place");
+    }
+
+    let road_distance = match player_move.road_id {
+        Some(road_id) => match get_road_by_channel_id(server.universe_id, road_id).await {
+            Ok(Some(road)) => road.distance as f64,
+            Ok(None) => 0.0,
+            Err(_) => return Err("travel__database_error".into()),
+        },
+        None => 0.0,
+    };
+    args.set("destination", place_name(player_move.destination_id).await);
+    args.set("road", player_move.road_id.map(|road_id| format!("<#{road_id}>")).unwrap_or_default());
+    let distance_left = (road_distance - crate::travel::logic::calculate_current_distance(&player_move)).max(0.0);
+    args.set("distance", format!("{distance_left:.2}"));
+    match remaining_seconds(&player_move, road_distance) {
+        Some(seconds) => {
+            let now = chrono::Utc::now().timestamp() as u64;
+            args.set("arrival", format!("<t:{}:R>", now + seconds));
+            args.set("departure", player_move.departure_timestamp.map(|t| format!("<t:{t}:R>")).unwrap_or_else(|| "-".to_string()));
+            Ok("travel_status__moving")
+        }
+        None => Ok("travel_status__stopped"),
+    }
+}
+
+/// Gives up the current journey: the character you play goes back to the place it left.
+#[poise::command(slash_command, guild_only, rename = "travel_cancel")]
+pub async fn cancel(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _cancel(&ctx, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Brings the author back to the place its journey started from. `args` receives this place.
+///
+/// # Errors
+/// - `"travel__server_not_found"`: the server is not part of a universe.
+/// - `"travel__character_not_found"`: the author has no position in the universe.
+/// - `"travel_cancel__not_traveling"`: the author is not on a road.
+/// - `"travel__database_error"`: a query failed.
+async fn _cancel(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(s)) => s,
+        Ok(None) => return Err("travel__server_not_found".into()),
+        Err(_) => return Err("travel__database_error".into()),
+    };
+    let player_move = match server.clone().get_player_move(ctx.author().id.get()).await {
+        Ok(Some(m)) => m,
+        Ok(None) => return Err("travel__character_not_found".into()),
+        Err(_) => return Err("travel__database_error".into()),
+    };
+    if player_move.actual_space_type != SpaceType::Road {
+        return Err("travel_cancel__not_traveling".into());
+    }
+    let origin = match cancel_travel(ctx.serenity_context().http.clone(), player_move).await {
+        Ok(m) => m.actual_space_id,
+        Err(e) => {
+            tracing::error!(user_id = ctx.author().id.get(), "failed to cancel a journey: {e:?}");
+            return Err("travel__database_error".into());
+        }
+    };
+    let origin_name = match get_place_by_category_id(server.universe_id, origin).await {
+        Ok(Some(place)) => place.name,
+        _ => format!("<#{origin}>"),
+    };
+    args.set("place", origin_name);
+    Ok("travel_cancel__success")
+}
+
 pub async fn _travel(ctx: Context<'_>, destination_input: String) -> Result<(), Error>{
     let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
         Ok(Some(s)) => s,
         Ok(None) => return Err("travel__server_not_found".into()),
         Err(e) => {
-            log::error!("Database error in _travel when fetching server: {:?}", e);
+            tracing::error!("failed to fetch the server of a journey: {e:?}");
             return Err("travel__database_error".into());
         }
     };
 
     let destination_category_id = parse_channel_id(&destination_input).ok_or_else(|| Error::from("travel__place_not_found"))?;
 
-    println!("travel__place_category_id: {:?}", destination_category_id);
-
     let destination_place = match get_place_by_category_id(server.universe_id, destination_category_id).await {
         Ok(Some(p)) => p,
         _ => return Err("travel__place_not_found".into()),
     };
 
 
-    let _character = match server.clone().get_character_by_user_id(ctx.author().id.get()).await {
-        Ok(Some(c)) => c,
-        _ => return Err("travel__character_not_found".into()),
-    };
+    let character = get_active_character(&ctx).await?;
+    character.check_playable()?;
 
     let player_move = match server.clone().get_player_move(ctx.author().id.get()).await {
         Ok(Some(m)) => m,
@@ -161,9 +263,7 @@ async fn move_from_road(_ctx: &SerenityContext, destination_id: u64, server: Ser
         add_travel(_ctx.http.clone(), server.server_id.into(), player_move.clone()).await?;
 
     } else {
-        println!("dest_id: {:?}", dest_id);
-        println!("player_move.destination_id: {:?}", player_move.destination_id);
-        println!("player_move.source_id: {:?}", player_move.source_id);
+        tracing::debug!(?dest_id, destination_id = ?player_move.destination_id, source_id = ?player_move.source_id, "road does not lead to the destination");
         return Err("travel__invalid_road_destination".into());
     }
     Ok("travel__started")
@@ -175,7 +275,7 @@ async fn travel_without_destination(ctx: Context<'_>) -> Result<(), Error>{
         Ok(Some(s)) => s,
         Ok(None) => return Err("travel__server_not_found".into()),
         Err(e) => {
-            log::error!("Database error in travel_without_destination when fetching server: {:?}", e);
+            tracing::error!("failed to fetch the server of a journey: {e:?}");
             return Err("travel_without_destination__database_error".into());
         }
     };
@@ -212,7 +312,7 @@ async fn travel_without_destination(ctx: Context<'_>) -> Result<(), Error>{
                     let res = cursor.try_collect().await;
                     match res {
                         Ok(road) => {road}
-                        Err(e) => {println!("{:?}", e); Vec::new()}
+                        Err(e) => {tracing::error!("failed to read the roads of a place: {e:?}"); Vec::new()}
                     }
 
                 },
@@ -244,7 +344,7 @@ async fn travel_without_destination(ctx: Context<'_>) -> Result<(), Error>{
     match result {
         Ok(_) => Ok(()),
         Err(e) => {
-            log::error!("Reply failed in travel_without_destination: {:?}", e);
+            tracing::error!("failed to reply with the roads of a place: {e:?}");
             Err("travel_without_destination__reply_failed".into())
         }
     }
@@ -261,7 +361,7 @@ pub async fn travel_from_handler(ctx: SerenityContext, interaction: ComponentInt
         Ok(Some(s)) => s,
         Ok(None) => return Err("travel__server_not_found".into()),
         Err(e) => {
-            log::error!("Database error in _travel when fetching server: {:?}", e);
+            tracing::error!("failed to fetch the server of a journey: {e:?}");
             return Err("travel__database_error".into());
         }
     };
@@ -274,10 +374,12 @@ pub async fn travel_from_handler(ctx: SerenityContext, interaction: ComponentInt
     };
 
 
-    let _character = match server.clone().get_character_by_user_id(interaction.user.id.get()).await {
+    let character = match server.get_active_character(interaction.user.id.get()).await {
         Ok(Some(c)) => c,
-        _ => return Err("travel__character_not_found".into()),
+        Ok(None) => return Err("character__no_active".into()),
+        Err(_) => return Err("travel__character_not_found".into()),
     };
+    character.check_playable()?;
 
     let player_move = match server.clone().get_player_move(interaction.user.id.get()).await {
         Ok(Some(m)) => m,
@@ -305,6 +407,12 @@ async fn move_from_place(ctx: &SerenityContext, source_id: u64, destination_id:
 
     let dest_id = destination_id;
 
+    // Le personnage doit partir du lieu où il se trouve
+    if let Ok(Some(character)) = Character::get_active_character(server.universe_id, player_move.user_id).await
+        && character.current_place_id.is_some_and(|place_id| place_id != source_id) {
+        return Err("travel__not_in_place".into());
+    }
+
     let road = match server.clone().get_road(source_id, dest_id).await {
         Ok(Some(r)) => r,
         _ => return Err("move_from_place__road_not_found".into())
@@ -0,0 +1,51 @@
+use fluent::FluentArgs;
+use serenity::all::User;
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_creator;
+use crate::utility::reply::reply_with_args;
+
+/// Makes a user an admin of the universe, able to change its settings and add servers to it.
+#[poise::command(slash_command, guild_only, rename = "universe_add_admin")]
+pub async fn add_admin(
+    ctx: Context<'_>,
+    #[description = "universe_add_admin.user"]
+    user: User,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _add_admin(&ctx, &user, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Adds `user` to the admins of the universe of the server. Only the creator can do it. `args`
+/// receives the user.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"universe__not_universe_creator"`: the author did not create the universe.
+/// - `"universe_add_admin__creator"`: `user` created the universe.
+/// - `"universe_add_admin__already_admin"`: `user` is already an admin.
+/// - `"universe_add_admin__bot"`: `user` is a bot.
+/// - `"verify__database_error"`: a query failed.
+async fn _add_admin(ctx: &Context<'_>, user: &User, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("user", format!("<@{}>", user.id));
+    let universe = match get_universe_by_server_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    require_universe_creator(ctx, &universe).await?;
+    if user.bot {
+        return Err("universe_add_admin__bot".into());
+    }
+    if user.id.get() == universe.creator_id {
+        return Err("universe_add_admin__creator".into());
+    }
+    if universe.admins.contains(&user.id.get()) {
+        return Err("universe_add_admin__already_admin".into());
+    }
+    let Ok(_) = universe.add_admin(user.id.get()).await else { return Err("verify__database_error".into()) };
+    Ok("universe_add_admin__success")
+}
@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use std::time::Duration;
 use mongodb::bson::oid::ObjectId;
 use crate::discord::poise_structs::{Context, Error};
 use crate::tr;
@@ -8,10 +9,15 @@ use poise::serenity_prelude::ComponentInteractionCollector;
 use serenity::all::CreateSelectMenu;
 use serenity::all::CreateSelectMenuKind;
 use serenity::all::CreateSelectMenuOption;
-use serenity::all::{ComponentInteractionDataKind, CreateActionRow};
-use crate::database::server::Server;
+use fluent::FluentArgs;
+use serenity::all::{ComponentInteractionDataKind, CreateActionRow, CreateInteractionResponse, CreateInteractionResponseMessage};
+use crate::database::server::{get_server_by_id, Server};
+use crate::discord::audit::log_action;
 use crate::universe::setup::setup_sub_command::{SetupType, _setup};
-use crate::utility::reply::reply;
+use crate::utility::confirm::{confirm, ConfirmOutcome};
+use crate::utility::custom_id::{custom_id, from_invocation};
+use crate::utility::pagination::{page_buttons, page_count_by, page_of_by};
+use crate::utility::reply::reply_with_args;
 
 #[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_add_server")]
 pub async fn add_server(
@@ -20,40 +26,46 @@ pub async fn add_server(
     setup_type: SetupType
 ) -> Result<(), Error> {
     let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
-    let result = _add_server(&ctx, setup_type).await;
-    let Ok(_) = reply(ctx, result).await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _add_server(&ctx, setup_type, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
     Ok(())
 }
 
-pub async fn _add_server(ctx: &Context<'_>, setup_type: SetupType) -> Result<&'static str, Error>{
-    if check_server_in_universe(ctx.guild_id().unwrap().get()).await.is_ok() {
+pub async fn _add_server(ctx: &Context<'_>, setup_type: SetupType, args: &mut FluentArgs<'_>) -> Result<&'static str, Error>{
+    let guild_id = ctx.guild_id().unwrap().get();
+    if check_server_in_universe(guild_id).await.is_ok() {
+        // The bot was removed then re-added: reuse the existing document instead of creating a duplicate
+        if let Ok(Some(mut server)) = get_server_by_id(guild_id).await
+            && server.detached_at.is_some() {
+            server.detached_at = None;
+            let Ok(_) = server.update().await else { return Err("setup__server_update_failed".into()) };
+            _setup(ctx, setup_type, args).await?;
+            return Ok("add_server_to_universe__guild_reattached");
+        }
         return Ok("add_server_to_universe__already_bind");
     }
 
-    let universes: Vec<Universe> = Universe::get_creator_universes(ctx.author().id.get()).await;
+    let universes: Vec<Universe> = Universe::get_administrable_universes(ctx.author().id.get()).await;
 
     if universes.is_empty() {
         return Err("add_server_to_universe__universes_unavailable".into());
     }
 
-    let mut options = vec![];
-    for universe in &universes {
-        options.push(CreateSelectMenuOption::new(
-            universe.name.clone(),
-            universe.universe_id.to_string().clone(),
-        ))
-    }
-
-    let action_row = CreateActionRow::SelectMenu(CreateSelectMenu::new(
-        "selected_universe",
-        CreateSelectMenuKind::String { options },
-    ));
+    let ctx_id = ctx.id();
+    let menu = UniverseMenu {
+        select_id: custom_id("selected_universe", ctx_id),
+        prev_id: custom_id("page_prev", ctx_id),
+        next_id: custom_id("page_next", ctx_id),
+        pages: page_count_by(universes.len(), UNIVERSE_MENU_SIZE),
+    };
+    let mut page = 0;
 
     let Ok(message) = ctx
         .send(
             CreateReply::default()
                 .content(tr!(*ctx, "choose_universe"))
-                .components(vec![action_row])
+                .components(menu.components(&universes, page))
                 .ephemeral(true),
         )
         .await else { return Err("reply__reply_failed".into()) };
@@ -61,53 +73,150 @@ pub async fn _add_server(ctx: &Context<'_>, setup_type: SetupType) -> Result<&'s
     let serenity_context = ctx.serenity_context();
 
     while let Some(mci) = ComponentInteractionCollector::new(serenity_context)
+        .author_id(ctx.author().id)
         .timeout(std::time::Duration::from_secs(120))
-        .filter(move |mci| mci.data.custom_id == "selected_universe")
+        .filter(move |mci| from_invocation(&mci.data.custom_id, ctx_id))
         .await
     {
-        if let ComponentInteractionDataKind::StringSelect { values } = &mci.data.kind {
-            if let Some(selected) = values.get(0) {
+        let selected = match &mci.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } => values.first().map(String::as_str),
+            _ => None,
+        };
+        match menu.action(&mci.data.custom_id, selected, page) {
+            MenuAction::Page(new_page) => {
+                page = new_page;
+                let _ = mci.create_response(ctx, CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new().components(menu.components(&universes, page))
+                )).await;
+            }
+            MenuAction::Ignore => {}
+            MenuAction::Select(selected) => {
+                let _ = mci.defer(ctx).await;
                 let _ = message.delete(*ctx).await;
-
-                let Ok(universe_opt) = get_universe_by_id(ObjectId::from_str(selected.as_str())?).await else { return Err("create_character__database_error".into()) };
-                let Some(universe) = universe_opt else {return Err("create_character__no_universe_found".into())};
-
-                let Ok(res) = universe.clone().check_server_limit().await else { return Err("universe__check_server_limit_failed".into()) };
-
-                if !res{
-                    return Err("exceed_limit_number_of_servers_per_universe".into())
-                }
-
-                let Ok(_) = Server{
-                    _id: Default::default(),
-                    universe_id: universe.universe_id,
-                    server_id: ctx.guild_id().unwrap().get(),
-                    admin_role_id: Default::default(),
-                    moderator_role_id: Default::default(),
-                    spectator_role_id: Default::default(),
-                    player_role_id: Default::default(),
-                    everyone_role_id: Default::default(),
-                    admin_category_id: Default::default(),
-                    nrp_category_id: Default::default(),
-                    rp_category_id: Default::default(),
-                    road_category_id: Default::default(),
-                    rp_wiki_channel_id: Default::default(),
-                    log_channel_id: Default::default(),
-                    moderation_channel_id: Default::default(),
-                    commands_channel_id: Default::default(),
-                    nrp_general_channel_id: Default::default(),
-                    rp_character_channel_id: Default::default(),
-                    universal_time_channel_id: Default::default(),
-                    universal_invite_url: Default::default(),
-                }.insert_server().await else { return Err("create_universe__server_insert_failed".into()) };
-                let Ok(_) = _setup(&ctx, setup_type).await else { return Err("setup_server__failed".into()) };
-
-                return Ok("add_server_to_universe__guild_linked");
+                let universe = chosen_universe(&selected).await?;
+                return confirm_link(ctx, &universe, setup_type, args).await;
             }
         }
     };
 
-    Ok("")
+    let _ = message.delete(*ctx).await;
+    Err("add_server__selection_timeout".into())
+}
+
+/// Universe of the id `selected` in the menu, if a server can still be added to it.
+///
+/// # Errors
+/// - `"add_server__universe_not_found"`: the id is malformed or the universe was deleted.
+/// - `"universe__check_server_limit_failed"`: the servers of the universe could not be counted.
+/// - `"exceed_limit_number_of_servers_per_universe"`: the universe has no room for another server.
+/// - `"add_server__database_error"`: the universe could not be read.
+async fn chosen_universe(selected: &str) -> Result<Universe, Error> {
+    let Ok(universe_id) = ObjectId::from_str(selected) else { return Err("add_server__universe_not_found".into()) };
+    let Ok(universe_opt) = get_universe_by_id(universe_id).await else { return Err("add_server__database_error".into()) };
+    let Some(universe) = universe_opt else { return Err("add_server__universe_not_found".into()) };
+
+    let Ok(res) = universe.clone().check_server_limit().await else { return Err("universe__check_server_limit_failed".into()) };
+
+    if !res{
+        return Err("exceed_limit_number_of_servers_per_universe".into())
+    }
+    Ok(universe)
+}
+
+/// Asks the author to confirm that the server joins `universe`, then links it. Nothing is
+/// stored when they cancel or do not answer in time.
+///
+/// # Errors
+/// - `"add_server__confirmation_timeout"`: no button was pressed in time.
+/// - the errors of [`link_server`].
+async fn confirm_link(ctx: &Context<'_>, universe: &Universe, setup_type: SetupType, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let mut confirm_args = FluentArgs::new();
+    confirm_args.set("universe", universe.name.clone());
+    match confirm(*ctx, "add_server__confirm", Some(confirm_args), Duration::from_secs(60)).await? {
+        ConfirmOutcome::Confirmed => link_server(ctx, universe, setup_type, args).await,
+        ConfirmOutcome::Cancelled => Ok("add_server__cancelled"),
+        ConfirmOutcome::TimedOut => Err("add_server__confirmation_timeout".into()),
+    }
+}
+
+/// Stores the server as part of `universe` and runs the setup, removing the server again when
+/// the setup fails so that it is never left half-linked.
+///
+/// # Errors
+/// - `"create_universe__server_insert_failed"`: the server could not be stored.
+/// - the errors of [`_setup`], after which the server is unlinked again.
+async fn link_server(ctx: &Context<'_>, universe: &Universe, setup_type: SetupType, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let server = Server::default()
+        .universe_id(universe.universe_id)
+        .server_id(ctx.guild_id().unwrap().get()).clone();
+    let Ok(_) = server.insert_server().await else { return Err("create_universe__server_insert_failed".into()) };
+    if let Err(setup_error) = _setup(ctx, setup_type, args).await {
+        if let Err(e) = server.delete().await {
+            tracing::error!(guild_id = server.server_id, "failed to unlink the server after a failed setup: {e}");
+        }
+        return Err(setup_error);
+    }
+
+    // The setup created the log channel, which only the stored server knows about
+    if let Ok(Some(server)) = get_server_by_id(server.server_id).await {
+        let mut log_args = FluentArgs::new();
+        log_args.set("universe", universe.name.clone());
+        log_action(ctx, &server, "add_server__log", log_args).await;
+    }
+    Ok("add_server_to_universe__guild_linked")
+}
+
+/// Discord shows at most 25 options in a select menu.
+static UNIVERSE_MENU_SIZE: usize = 25;
+
+/// Select menu of the universes a server can join, split in pages of `UNIVERSE_MENU_SIZE`
+/// universes when there are more.
+struct UniverseMenu {
+    select_id: String,
+    prev_id: String,
+    next_id: String,
+    pages: usize,
+}
+
+/// What a click on the universe menu asks for.
+#[derive(Debug, PartialEq)]
+enum MenuAction {
+    /// Show this page of the menu.
+    Page(usize),
+    /// Join the universe with this id.
+    Select(String),
+    /// The click is not one of the menu's.
+    Ignore,
+}
+
+impl UniverseMenu {
+    /// Action of a click on the component `custom_id` while `page` is shown, `selected` being
+    /// the chosen option for the select menu.
+    fn action(&self, custom_id: &str, selected: Option<&str>, page: usize) -> MenuAction {
+        if custom_id == self.next_id {
+            MenuAction::Page((page + 1).min(self.pages - 1))
+        } else if custom_id == self.prev_id {
+            MenuAction::Page(page.saturating_sub(1))
+        } else if custom_id == self.select_id && let Some(selected) = selected {
+            MenuAction::Select(selected.to_string())
+        } else {
+            MenuAction::Ignore
+        }
+    }
+
+    /// Select menu listing the universes of `page`, each identified by its id, followed by the
+    /// page buttons.
+    fn components(&self, universes: &[Universe], page: usize) -> Vec<CreateActionRow> {
+        let options = page_of_by(universes, page, UNIVERSE_MENU_SIZE).iter()
+            .map(|universe| CreateSelectMenuOption::new(universe.name.clone(), universe.universe_id.to_string()))
+            .collect();
+        let mut components = vec![CreateActionRow::SelectMenu(CreateSelectMenu::new(
+            self.select_id.clone(),
+            CreateSelectMenuKind::String { options },
+        ))];
+        components.extend(page_buttons(&self.prev_id, &self.next_id, page, self.pages));
+        components
+    }
 }
 
 /// Asynchronously checks if a specific guild (server) is associated with a universe.
@@ -152,3 +261,25 @@ pub async fn check_server_in_universe(guild_id: u64) -> Result<Universe, String>
     }
     Err(format!("Guild {} not bind to any existing universe", guild_id))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_menu_action() {
+        let menu = UniverseMenu {
+            select_id: custom_id("selected_universe", 1),
+            prev_id: custom_id("page_prev", 1),
+            next_id: custom_id("page_next", 1),
+            pages: 3,
+        };
+        assert_eq!(menu.action(&menu.next_id, None, 0), MenuAction::Page(1));
+        assert_eq!(menu.action(&menu.next_id, None, 2), MenuAction::Page(2));
+        assert_eq!(menu.action(&menu.prev_id, None, 0), MenuAction::Page(0));
+        assert_eq!(menu.action(&menu.prev_id, None, 2), MenuAction::Page(1));
+        assert_eq!(menu.action(&menu.select_id, Some("abc"), 1), MenuAction::Select("abc".to_string()));
+        assert_eq!(menu.action(&menu.select_id, None, 1), MenuAction::Ignore);
+        assert_eq!(menu.action(&custom_id("page_next", 2), None, 0), MenuAction::Ignore);
+    }
+}
@@ -0,0 +1,109 @@
+use serenity::all::RoleId;
+use crate::database::server::{get_server_by_id, Id};
+use crate::database::universe::Universe;
+use crate::discord::poise_structs::{Context, Error};
+
+/// Authority of a member over a universe, from the lowest to the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UniverseRole {
+    Member,
+    /// Is one of the admins of the universe, or has the admin role the setup created on the server.
+    Admin,
+    /// Created the universe.
+    Creator,
+}
+
+/// Authority of `user_id`, who has the `member_roles`, over the universe created by `creator_id`
+/// and run with `admins`. `admin_role` is the admin role of the server the member is on.
+///
+/// The administrator permission of Discord is not taken into account: it only covers one server,
+/// while the universe spans all of its servers.
+pub fn universe_role(creator_id: u64, admins: &[u64], user_id: u64, member_roles: &[RoleId], admin_role: Option<Id>) -> UniverseRole {
+    if user_id == creator_id {
+        UniverseRole::Creator
+    } else if admins.contains(&user_id) || admin_role.is_some_and(|role| member_roles.contains(&RoleId::new(role.id))) {
+        UniverseRole::Admin
+    } else {
+        UniverseRole::Member
+    }
+}
+
+/// Checks that `role` is at least `required`.
+///
+/// # Errors
+/// - `"universe__not_universe_creator"`: the creator of the universe is required.
+/// - `"universe__not_universe_admin"`: an admin of the universe is required.
+pub fn check_universe_role(role: UniverseRole, required: UniverseRole) -> Result<(), &'static str> {
+    if role >= required {
+        return Ok(());
+    }
+    match required {
+        UniverseRole::Creator => Err("universe__not_universe_creator"),
+        _ => Err("universe__not_universe_admin"),
+    }
+}
+
+/// Authority of the author over `universe`, read from the server the command runs on.
+async fn author_role(ctx: &Context<'_>, universe: &Universe) -> Result<UniverseRole, Error> {
+    let user_id = ctx.author().id.get();
+    if user_id == universe.creator_id {
+        return Ok(UniverseRole::Creator);
+    }
+    if universe.admins.contains(&user_id) {
+        return Ok(UniverseRole::Admin);
+    }
+    let admin_role = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(server) => server.and_then(|server| server.admin_role_id),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    let member_roles = ctx.author_member().await.map(|member| member.roles.clone()).unwrap_or_default();
+    Ok(universe_role(universe.creator_id, &universe.admins, user_id, &member_roles, admin_role))
+}
+
+/// Checks that the author created `universe`, for the operations that destroy or rebuild it.
+///
+/// # Errors
+/// - `"universe__not_universe_creator"`: the author did not create the universe.
+/// - `"verify__database_error"`: the server could not be read.
+pub async fn require_universe_creator(ctx: &Context<'_>, universe: &Universe) -> Result<(), Error> {
+    check_universe_role(author_role(ctx, universe).await?, UniverseRole::Creator)?;
+    Ok(())
+}
+
+/// Checks that the author created `universe`, is one of its admins or has the admin role of the
+/// server, for the operations that change how it is configured.
+///
+/// # Errors
+/// - `"universe__not_universe_admin"`: the author is neither the creator nor an admin.
+/// - `"verify__database_error"`: the server could not be read.
+pub async fn require_universe_admin(ctx: &Context<'_>, universe: &Universe) -> Result<(), Error> {
+    check_universe_role(author_role(ctx, universe).await?, UniverseRole::Admin)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::server::IdType;
+
+    #[test]
+    fn test_universe_role() {
+        let admin_role = Some(Id { id: 10, id_type: IdType::Role });
+        assert_eq!(universe_role(1, &[], 1, &[], admin_role), UniverseRole::Creator);
+        assert_eq!(universe_role(1, &[1], 1, &[], admin_role), UniverseRole::Creator);
+        assert_eq!(universe_role(1, &[], 2, &[RoleId::new(10)], admin_role), UniverseRole::Admin);
+        assert_eq!(universe_role(1, &[2, 3], 2, &[], None), UniverseRole::Admin);
+        assert_eq!(universe_role(1, &[3], 2, &[RoleId::new(11)], admin_role), UniverseRole::Member);
+        assert_eq!(universe_role(1, &[], 2, &[RoleId::new(10)], None), UniverseRole::Member);
+    }
+
+    #[test]
+    fn test_check_universe_role() {
+        assert_eq!(check_universe_role(UniverseRole::Creator, UniverseRole::Creator), Ok(()));
+        assert_eq!(check_universe_role(UniverseRole::Creator, UniverseRole::Admin), Ok(()));
+        assert_eq!(check_universe_role(UniverseRole::Admin, UniverseRole::Admin), Ok(()));
+        assert_eq!(check_universe_role(UniverseRole::Admin, UniverseRole::Creator), Err("universe__not_universe_creator"));
+        assert_eq!(check_universe_role(UniverseRole::Member, UniverseRole::Creator), Err("universe__not_universe_creator"));
+        assert_eq!(check_universe_role(UniverseRole::Member, UniverseRole::Admin), Err("universe__not_universe_admin"));
+    }
+}
@@ -1,11 +1,21 @@
+use std::time::Duration;
 use chrono::{Utc, TimeZone};
+use fluent::FluentArgs;
+use poise::CreateReply;
+use serenity::all::{ButtonStyle, ComponentInteractionCollector, CreateActionRow, CreateButton};
 use crate::database::universe::{Universe};
 use crate::discord::poise_structs::*;
 use crate::database::server::{get_server_by_id, Server};
 use crate::database::stats::{Stat, SPEED_STAT};
 use crate::database::stats::StatValue::I64;
 use crate::universe::setup::setup_sub_command::{SetupType, _setup};
-use crate::utility::reply::reply;
+use crate::tr;
+use crate::discord::roles::preflight;
+use crate::utility::custom_id::{custom_id, from_invocation};
+use crate::utility::reply::{reply, reply_with_args, result_embed};
+
+/// How long the setup buttons shown after a creation stay active.
+static SETUP_OFFER_TIMEOUT: Duration = Duration::from_secs(120);
 
 /// Creates a new universe with the specified name and setup type.
 ///
@@ -17,6 +27,7 @@ use crate::utility::reply::reply;
 /// - `ctx`: The command context.
 /// - `universe_name`: The name of the universe to be created.
 /// - `setup_type`: The type of setup to initialize for the universe. This value is defined by the `SetupType` enum.
+///   When omitted, the reply offers buttons to run a full or partial setup right away.
 ///
 /// # Returns
 /// - `Ok(())` if the universe is created successfully.
@@ -46,15 +57,54 @@ pub async fn create_universe(
     #[description = "universe_create_universe.universe_name"]
     universe_name: String,
     #[description = "universe_create_universe.setup_type"]
-    setup_type: SetupType
+    setup_type: Option<SetupType>
 ) -> Result<(), Error> {
     let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    if let Err(e) = preflight(&ctx, &mut args).await {
+        let Ok(_) = reply_with_args(ctx, Err(e), Some(args)).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
     let result = _create_universe(&ctx, universe_name, setup_type).await;
-    println!("{:?}", result);
+    if setup_type.is_none() && let Ok(created) = result {
+        return offer_setup(ctx, created).await;
+    }
     let Ok(_) = reply(ctx.clone(), result).await else { return Err("reply__reply_failed".into()) };
     Ok(())
 }
 
+/// Replies with the `created` message and buttons to run a full or a partial setup. Only the
+/// author can press them; the message then shows the result of the setup. The buttons are
+/// removed when unused for `SETUP_OFFER_TIMEOUT`.
+async fn offer_setup(ctx: Context<'_>, created: &str) -> Result<(), Error> {
+    let full_id = custom_id("full_setup", ctx.id());
+    let partial_id = custom_id("partial_setup", ctx.id());
+    let created_embed = result_embed(ctx, &Ok(created), None);
+    let reply = CreateReply::default()
+        .embed(created_embed.clone())
+        .components(vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(&full_id).style(ButtonStyle::Success).label(tr!(ctx, "create_universe__run_full_setup")),
+            CreateButton::new(&partial_id).style(ButtonStyle::Primary).label(tr!(ctx, "create_universe__run_partial_setup")),
+        ])]);
+    let Ok(handle) = ctx.send(reply).await else { return Err("reply__reply_failed".into()) };
+
+    let ctx_id = ctx.id();
+    let press = ComponentInteractionCollector::new(ctx)
+        .author_id(ctx.author().id)
+        .filter(move |press| from_invocation(&press.data.custom_id, ctx_id))
+        .timeout(SETUP_OFFER_TIMEOUT)
+        .await;
+    let _ = handle.edit(ctx, CreateReply::default().embed(created_embed).components(vec![])).await;
+    let Some(press) = press else { return Ok(()) };
+    // The command itself was deferred already, only the button press is acknowledged here
+    let Ok(_) = press.defer(ctx).await else { return Err("reply__reply_failed".into()) };
+
+    let setup_type = if press.data.custom_id == full_id { SetupType::FullSetup } else { SetupType::PartialSetup };
+    let result = _setup(&ctx, setup_type, &mut FluentArgs::new()).await;
+    let Ok(_) = handle.edit(ctx, CreateReply::default().embed(result_embed(ctx, &result, None)).components(vec![])).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
 /// Asynchronously creates a universe and sets it up with initial parameters.
 ///
 /// This function performs several steps to create a new universe:
@@ -69,7 +119,8 @@ pub async fn create_universe(
 /// # Parameters
 /// * `ctx` - The execution context containing information about the current user and server.
 /// * `universe_name` - A `String` specifying the name of the universe to be created.
-/// * `setup_type` - An instance of `SetupType` indicating the type of setup to perform (e.g., custom initialization).
+/// * `setup_type` - An instance of `SetupType` indicating the type of setup to perform (e.g., custom initialization),
+///   or `None` to leave the server without setup.
 ///
 /// # Returns
 /// A `Result` which:
@@ -78,6 +129,10 @@ pub async fn create_universe(
 ///
 /// # Errors
 /// The function may return errors in the following scenarios:
+/// - `create_universe__name_length`, `create_universe__name_characters`:
+///   The name is rejected by [`validate_universe_name`].
+/// - `create_universe__name_taken`:
+///   The user already created a universe with this name, ignoring case.
 /// - `create_universe__check_universe_limit_failed`:
 ///   Failed to check the universe limit for the user.
 /// - `create_universe__universe_limit_reached`:
@@ -96,9 +151,11 @@ pub async fn create_universe(
 ///   Failed to insert the default speed stat.
 /// - Any errors arising from `_setup` when configuring the universe.
 ///
+/// The universe is removed again when its indexes, its server or its speed stat cannot be saved.
+///
 /// # Example
 /// ```rust
-/// let result = _create_universe(ctx, "MyUniverse".to_string(), SetupType::Advanced).await;
+/// let result = _create_universe(ctx, "MyUniverse".to_string(), Some(SetupType::FullSetup)).await;
 /// match result {
 ///     Ok(message) => println!("{}", message),
 ///     Err(error) => eprintln!("Error creating universe: {:#?}", error),
@@ -107,15 +164,24 @@ pub async fn create_universe(
 pub async fn _create_universe(
     ctx: &Context<'_>,
     universe_name: String,
-    setup_type: SetupType
+    setup_type: Option<SetupType>
 ) -> Result<&'static str, Error> {
+    let universe_name = validate_universe_name(&universe_name)?;
+    match Universe::creator_has_universe_named(ctx.author().id.get(), &universe_name).await {
+        Ok(false) => {}
+        Ok(true) => return Err("create_universe__name_taken".into()),
+        Err(_) => return Err("create_universe__check_universe_limit_failed".into()),
+    }
+
     let Ok(result) = Universe::check_universe_limit(ctx.author().id.into()).await
         else {return Err("create_universe__check_universe_limit_failed".into())};
 
     if !result { return Err("create_universe__universe_limit_reached".into()); }
 
-    let Ok(server) = get_server_by_id(ctx.guild_id().unwrap().get()).await
-        else {return Err("create_universe__get_server_failed".into())};
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(server) => server,
+        Err(e) => return Err(creation_failed(ctx, "create_universe__get_server_failed", e)),
+    };
 
     if server.is_some(){ return Err("create_universe__already_exist_for_this_server".into()) }
 
@@ -136,20 +202,33 @@ pub async fn _create_universe(
         creator_id: ctx.author().id.get(),
         global_time_modifier: 100,
         time_origin_timestamp: time_origin_ms,
-        creation_timestamp: now_ms
+        creation_timestamp: now_ms,
+        name_overrides: Default::default(),
+        road_slowmode_per_distance: 0,
+        contest_die: None,
+        starting_place_id: None,
+        join_requires_approval: false,
+        admins: vec![],
+        default_locale: None,
     };
 
     match universe.insert_universe().await{
         Ok(_) => {
-            if universe.setup_constraints().await.is_err() { return Err("create_universe__setup_constraints_failed".into()); }
+            if let Err(e) = universe.setup_constraints().await {
+                let _ = universe.delete().await;
+                return Err(creation_failed(ctx, "create_universe__setup_constraints_failed", e));
+            }
 
             let server = Server::default()
                 .universe_id(universe.universe_id)
                 .server_id(ctx.guild_id().unwrap().get()).clone();
 
-            if server.insert_server().await.is_err(){ return Err("create_universe__server_insert_failed".into())}
+            if let Err(e) = server.insert_server().await {
+                let _ = universe.delete().await;
+                return Err(creation_failed(ctx, "create_universe__server_insert_failed", e));
+            }
         }
-        Err(_) => { return Err("create_universe__universe_insert_failed".into()) }
+        Err(e) => { return Err(creation_failed(ctx, "create_universe__universe_insert_failed", e)) }
     };
 
     let speed_stat = Stat{
@@ -163,12 +242,59 @@ pub async fn _create_universe(
         modifiers: vec![],
     };
 
-    let Ok(_) = speed_stat.insert_stat().await else {
+    if let Err(e) = speed_stat.insert_stat().await {
         let _ = universe.delete().await;
-        return Err("create_universe__speed_stat_insert_failed".into());
-    };
+        return Err(creation_failed(ctx, "create_universe__speed_stat_insert_failed", e));
+    }
 
-    let Ok(_) = _setup(ctx, setup_type).await else { return Err("setup_server__failed".into()) };
+    if let Some(setup_type) = setup_type {
+        let Ok(_) = _setup(ctx, setup_type, &mut FluentArgs::new()).await else { return Err("setup_server__failed".into()) };
+    }
 
     Ok("create_universe__universe_successfully_created")
+}
+
+/// Logs the database error `e` that stopped the creation of a universe and returns `key` to
+/// report it to the user.
+fn creation_failed(ctx: &Context<'_>, key: &'static str, e: impl std::fmt::Display) -> Error {
+    tracing::error!(guild_id = ctx.guild_id().map(|id| id.get()), user_id = ctx.author().id.get(), "{key}: {e}");
+    key.into()
+}
+
+/// Shortest and longest name a universe can have, in characters.
+static UNIVERSE_NAME_LENGTH: (usize, usize) = (2, 64);
+
+/// Trims `name` and checks that it can name a universe: between 2 and 64 characters, made of
+/// letters, digits, spaces and the punctuation `-`, `_` and `'`.
+///
+/// # Errors
+/// - `"create_universe__name_length"`: the name is too short or too long.
+/// - `"create_universe__name_characters"`: the name contains another character.
+pub fn validate_universe_name(name: &str) -> Result<String, &'static str> {
+    let name = name.trim();
+    let length = name.chars().count();
+    if length < UNIVERSE_NAME_LENGTH.0 || length > UNIVERSE_NAME_LENGTH.1 {
+        return Err("create_universe__name_length");
+    }
+    if !name.chars().all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '\'')) {
+        return Err("create_universe__name_characters");
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_universe_name() {
+        assert_eq!(validate_universe_name("  Terres d'Éther "), Ok("Terres d'Éther".to_string()));
+        assert_eq!(validate_universe_name("Neo-Tokyo_2"), Ok("Neo-Tokyo_2".to_string()));
+        assert_eq!(validate_universe_name("A"), Err("create_universe__name_length"));
+        assert_eq!(validate_universe_name(&"a".repeat(65)), Err("create_universe__name_length"));
+        assert!(validate_universe_name(&"é".repeat(64)).is_ok());
+        assert_eq!(validate_universe_name("my.universe"), Err("create_universe__name_characters"));
+        assert_eq!(validate_universe_name("a/b"), Err("create_universe__name_characters"));
+        assert_eq!(validate_universe_name("$where"), Err("create_universe__name_characters"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,217 @@
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use fluent::FluentArgs;
+use futures::TryStreamExt;
+use poise::CreateReply;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use serenity::all::CreateAttachment;
+use crate::database::characters::Character;
+use crate::database::places::{get_places_by_universe_id, Place};
+use crate::database::road::{get_roads_by_universe_id, Road};
+use crate::database::server::{get_server_by_id, Server};
+use crate::database::stats::Stat;
+use crate::database::universe::{get_servers_from_universe, get_universe_by_id, Universe};
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_creator;
+use crate::utility::reply::{reply_with_args, result_embed};
+
+/// Version of the export format, written in every export so that the import knows how to read it.
+pub static EXPORT_FORMAT_VERSION: u32 = 1;
+/// Largest export sent as plain JSON, in bytes. Larger exports are sent gzipped so they stay
+/// below the attachment limit of Discord.
+pub static MAX_PLAIN_EXPORT_BYTES: usize = 8 * 1024 * 1024;
+
+/// Backup of a universe and of everything stored about it.
+#[derive(Serialize)]
+pub struct UniverseExport<'a> {
+    pub format_version: u32,
+    /// Unix timestamp of the export, in seconds.
+    pub exported_at: u64,
+    pub universe: &'a Universe,
+    pub servers: &'a [Server],
+    pub places: &'a [Place],
+    pub roads: &'a [Road],
+    pub stats: &'a [Stat],
+    pub characters: &'a [Character],
+}
+
+/// Sends a JSON backup of the universe of the server, for its creator only.
+#[poise::command(slash_command, guild_only, rename = "universe_export")]
+pub async fn export(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let sent = match _export(&ctx, &mut args).await {
+        Ok(attachment) => {
+            let embed = result_embed(ctx, &Ok("universe_export__success"), Some(&args));
+            ctx.send(CreateReply::default().embed(embed).attachment(attachment).ephemeral(true)).await.map(|_| "reply__reply_success").map_err(Error::from)
+        }
+        Err(e) => reply_with_args(ctx, Err(e), Some(args)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Reads the universe of the server and everything stored about it, and returns it as an
+/// attachment. `args` receives the universe name and the number of documents of each kind.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"universe__not_universe_creator"`: the author did not create the universe.
+/// - `"verify__database_error"`: a query failed.
+/// - `"universe_export__failed"`: the export could not be written.
+async fn _export(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<CreateAttachment, Error> {
+    let server = match get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    let universe = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    require_universe_creator(ctx, &universe).await?;
+
+    let universe_id = universe.universe_id;
+    let Ok(servers) = async { get_servers_from_universe(&universe_id).await?.try_collect::<Vec<Server>>().await }.await else { return Err("verify__database_error".into()) };
+    let Ok(places) = async { get_places_by_universe_id(universe_id).await?.try_collect::<Vec<Place>>().await }.await else { return Err("verify__database_error".into()) };
+    let Ok(roads) = async { get_roads_by_universe_id(universe_id).await?.try_collect::<Vec<Road>>().await }.await else { return Err("verify__database_error".into()) };
+    let Ok(stats) = Stat::get_stats_by_universe(universe_id).await else { return Err("verify__database_error".into()) };
+    let Ok(characters) = Character::get_characters_by_universe(universe_id).await else { return Err("verify__database_error".into()) };
+
+    args.set("universe", universe.name.clone());
+    args.set("servers", servers.len());
+    args.set("places", places.len());
+    args.set("roads", roads.len());
+    args.set("stats", stats.len());
+    args.set("characters", characters.len());
+
+    let export = UniverseExport {
+        format_version: EXPORT_FORMAT_VERSION,
+        exported_at: SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs(),
+        universe: &universe,
+        servers: &servers,
+        places: &places,
+        roads: &roads,
+        stats: &stats,
+        characters: &characters,
+    };
+    let file_name = format!("universe_{}.json", universe_id.to_hex());
+    match export_json(&export).and_then(|json| export_attachment(json, &file_name)) {
+        Ok(attachment) => Ok(attachment),
+        Err(e) => {
+            tracing::error!(universe_id = %universe_id, "failed to write a universe export: {e}");
+            Err("universe_export__failed".into())
+        }
+    }
+}
+
+/// `export` as indented JSON, its object ids written as plain strings.
+pub fn export_json(export: &UniverseExport) -> Result<String, std::io::Error> {
+    let mut value = serde_json::to_value(export)?;
+    stringify_object_ids(&mut value);
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+/// Replaces the `{"$oid": "..."}` objects the BSON object ids are serialized as by their hex string.
+pub fn stringify_object_ids(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let Some(hex) = object_id_hex(map) {
+                *value = Value::String(hex);
+                return;
+            }
+            map.values_mut().for_each(stringify_object_ids);
+        }
+        Value::Array(values) => values.iter_mut().for_each(stringify_object_ids),
+        _ => {}
+    }
+}
+
+fn object_id_hex(map: &Map<String, Value>) -> Option<String> {
+    match (map.len(), map.get("$oid")) {
+        (1, Some(Value::String(hex))) => Some(hex.clone()),
+        _ => None,
+    }
+}
+
+/// `json` as an attachment named `file_name`, gzipped when it is larger than [`MAX_PLAIN_EXPORT_BYTES`].
+fn export_attachment(json: String, file_name: &str) -> Result<CreateAttachment, std::io::Error> {
+    if json.len() <= MAX_PLAIN_EXPORT_BYTES {
+        return Ok(CreateAttachment::bytes(json.into_bytes(), file_name));
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(json.as_bytes())?;
+    Ok(CreateAttachment::bytes(encoder.finish()?, format!("{file_name}.gz")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mongodb::bson::oid::ObjectId;
+    use serde_json::json;
+
+    #[test]
+    fn test_stringify_object_ids() {
+        let mut value = json!({
+            "_id": {"$oid": "65f0c0ffee00000000000001"},
+            "places": [{"universe_id": {"$oid": "65f0c0ffee00000000000002"}, "role": "42"}],
+            "not_an_id": {"$oid": "65f0c0ffee00000000000003", "other": 1},
+        });
+        stringify_object_ids(&mut value);
+        assert_eq!(value["_id"], "65f0c0ffee00000000000001");
+        assert_eq!(value["places"][0]["universe_id"], "65f0c0ffee00000000000002");
+        assert_eq!(value["places"][0]["role"], "42");
+        assert!(value["not_an_id"].is_object());
+    }
+
+    #[test]
+    fn test_export_json() {
+        let universe_id = ObjectId::new();
+        let mut server = Server::default();
+        server.universe_id(universe_id).server_id(7u64);
+        let universe = Universe {
+            universe_id,
+            name: "Terra".to_string(),
+            creator_id: 1,
+            global_time_modifier: 100,
+            time_origin_timestamp: 0,
+            creation_timestamp: 0,
+            name_overrides: Default::default(),
+            road_slowmode_per_distance: 0,
+            contest_die: None,
+            starting_place_id: None,
+            join_requires_approval: false,
+            admins: vec![],
+            default_locale: None,
+        };
+        let export = UniverseExport {
+            format_version: EXPORT_FORMAT_VERSION,
+            exported_at: 1,
+            universe: &universe,
+            servers: &[server],
+            places: &[],
+            roads: &[],
+            stats: &[],
+            characters: &[],
+        };
+        let value: Value = serde_json::from_str(&export_json(&export).unwrap()).unwrap();
+        assert_eq!(value["format_version"], EXPORT_FORMAT_VERSION);
+        assert_eq!(value["universe"]["_id"], universe_id.to_hex());
+        assert_eq!(value["universe"]["creator_id"], "1");
+        assert_eq!(value["servers"][0]["universe_id"], universe_id.to_hex());
+        assert_eq!(value["servers"][0]["server_id"], "7");
+    }
+
+    #[test]
+    fn test_export_attachment() {
+        let small = export_attachment("{}".to_string(), "universe.json").unwrap();
+        assert_eq!(small.filename, "universe.json");
+        let large = export_attachment(" ".repeat(MAX_PLAIN_EXPORT_BYTES + 1), "universe.json").unwrap();
+        assert_eq!(large.filename, "universe.json.gz");
+        assert!(large.data.len() < MAX_PLAIN_EXPORT_BYTES);
+    }
+}
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::time::Duration;
+use flate2::read::GzDecoder;
+use fluent::FluentArgs;
+use mongodb::bson::oid::ObjectId;
+use poise::CreateReply;
+use serde::Deserialize;
+use serde_json::Value;
+use serenity::all::{Attachment, GuildId, Role};
+use crate::database::characters::Character;
+use crate::database::modifiers::Modifier;
+use crate::database::places::Place;
+use crate::database::road::Road;
+use crate::database::server::{get_server_by_id, Server};
+use crate::database::stats::Stat;
+use crate::database::universe::Universe;
+use crate::database::user_limits::get_limits_for_user;
+use crate::discord::roles::guild_permissions;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::create_universe_sub_command::validate_universe_name;
+use crate::universe::export_sub_command::EXPORT_FORMAT_VERSION;
+use crate::utility::confirm::{confirm, ConfirmOutcome};
+use crate::utility::reply::{reply_with_args, result_embed};
+
+/// Largest backup read, once decompressed, in bytes.
+pub static MAX_BACKUP_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Backup written by `/universe export`, read back by the import.
+#[derive(Deserialize)]
+pub struct UniverseBackup {
+    pub format_version: u32,
+    pub universe: Universe,
+    #[serde(default)]
+    pub servers: Vec<Server>,
+    #[serde(default)]
+    pub places: Vec<Place>,
+    #[serde(default)]
+    pub roads: Vec<Road>,
+    #[serde(default)]
+    pub stats: Vec<Stat>,
+    #[serde(default)]
+    pub characters: Vec<Character>,
+}
+
+/// Restores a universe from a backup sent by `/universe export`, for repair by the setup.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_import")]
+pub async fn import(
+    ctx: Context<'_>,
+    #[description = "universe_import.file"]
+    file: Attachment,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let sent = match _import(&ctx, &file, &mut args).await {
+        Ok(key) => {
+            let embed = result_embed(ctx, &Ok(key), Some(&args));
+            ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await.map(|_| "reply__reply_success").map_err(Error::from)
+        }
+        Err(e) => reply_with_args(ctx, Err(e), Some(args)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Reads the backup in `file`, asks the author to take the universe over when someone else
+/// created it, checks that nothing conflicts with it, then inserts it under fresh ids. `args`
+/// receives the universe name and the number of documents of each kind.
+///
+/// # Errors
+/// - `"universe_import__download_failed"`: the attachment could not be downloaded.
+/// - `"universe_import__invalid_file"`: the attachment is not a backup.
+/// - `"universe_import__unsupported_version"`: the backup was written in another format version.
+/// - `"universe_import__timeout"`: the ownership transfer was not confirmed in time.
+/// - `"create_universe__name_length"`, `"create_universe__name_characters"`: the name of the
+///   universe is no longer valid.
+/// - `"create_universe__name_taken"`: the author already created a universe with this name.
+/// - `"create_universe__universe_limit_reached"`: the author created too many universes.
+/// - `"universe_import__server_limit"`: the backup has more servers than the author's universes can span.
+/// - `"universe_import__server_not_administered"`: the bot is not on a server of the backup, or
+///   the author is not one of its administrators.
+/// - `"universe_import__server_conflict"`: a server of the backup is already part of a universe.
+/// - `"verify__database_error"`: a query failed.
+/// - `"universe_import__failed"`: the backup could not be written; nothing is left of it.
+async fn _import(ctx: &Context<'_>, file: &Attachment, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let Ok(bytes) = file.download().await else { return Err("universe_import__download_failed".into()) };
+    let mut backup = match read_backup(&bytes) {
+        Ok(backup) => backup,
+        Err(e) => {
+            args.set("version", EXPORT_FORMAT_VERSION);
+            return Err(e.into());
+        }
+    };
+    args.set("universe", backup.universe.name.clone());
+
+    let author_id = ctx.author().id.get();
+    if backup.universe.creator_id != author_id {
+        let mut confirm_args = FluentArgs::new();
+        confirm_args.set("universe", backup.universe.name.clone());
+        confirm_args.set("creator", format!("<@{}>", backup.universe.creator_id));
+        match confirm(*ctx, "universe_import__confirm_transfer", Some(confirm_args), Duration::from_secs(60)).await? {
+            ConfirmOutcome::Confirmed => {}
+            ConfirmOutcome::Cancelled => return Ok("universe_import__cancelled"),
+            ConfirmOutcome::TimedOut => return Err("universe_import__timeout".into()),
+        }
+        backup.universe.creator_id = author_id;
+        backup.universe.admins.retain(|admin| *admin != author_id);
+    }
+
+    check_conflicts(ctx, &backup, args).await?;
+    remap_ids(&mut backup);
+
+    if let Err(e) = write_backup(&backup).await {
+        tracing::error!(universe_id = %backup.universe.universe_id, user_id = author_id, "failed to import a universe: {e}");
+        let _ = backup.universe.delete().await;
+        return Err("universe_import__failed".into());
+    }
+
+    args.set("servers", backup.servers.len());
+    args.set("places", backup.places.len());
+    args.set("roads", backup.roads.len());
+    args.set("stats", backup.stats.len());
+    args.set("characters", backup.characters.len());
+    Ok("universe_import__success")
+}
+
+/// Backup held in `bytes`, gzipped or not.
+///
+/// # Errors
+/// - `"universe_import__invalid_file"`: `bytes` is not a backup.
+/// - `"universe_import__unsupported_version"`: the backup was written in another format version.
+pub fn read_backup(bytes: &[u8]) -> Result<UniverseBackup, &'static str> {
+    let mut json = Vec::new();
+    let read = if bytes.starts_with(&[0x1f, 0x8b]) {
+        GzDecoder::new(bytes).take(MAX_BACKUP_BYTES).read_to_end(&mut json)
+    } else {
+        bytes.take(MAX_BACKUP_BYTES).read_to_end(&mut json)
+    };
+    let Ok(_) = read else { return Err("universe_import__invalid_file") };
+    let Ok(value) = serde_json::from_slice::<Value>(&json) else { return Err("universe_import__invalid_file") };
+    match value.get("format_version").and_then(Value::as_u64) {
+        Some(version) if version == EXPORT_FORMAT_VERSION as u64 => {}
+        Some(_) => return Err("universe_import__unsupported_version"),
+        None => return Err("universe_import__invalid_file"),
+    }
+    serde_json::from_value(value).map_err(|_| "universe_import__invalid_file")
+}
+
+/// Checks, before anything is written, that the author can own the universe of `backup` with all
+/// of its servers, that they administer each of these servers and that none of them is already
+/// part of a universe. `args` receives the offending server.
+async fn check_conflicts(ctx: &Context<'_>, backup: &UniverseBackup, args: &mut FluentArgs<'_>) -> Result<(), Error> {
+    let universe = &backup.universe;
+    validate_universe_name(&universe.name)?;
+    match Universe::creator_has_universe_named(universe.creator_id, &universe.name).await {
+        Ok(false) => {}
+        Ok(true) => return Err("create_universe__name_taken".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    }
+    match Universe::check_universe_limit(universe.creator_id).await {
+        Ok(true) => {}
+        Ok(false) => return Err("create_universe__universe_limit_reached".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    }
+    let Ok(limits) = get_limits_for_user(universe.creator_id).await else { return Err("verify__database_error".into()) };
+    let servers = backup.servers.len() as u64;
+    if servers > 0 && !limits.allows_server(servers - 1) {
+        args.set("servers", servers);
+        args.set("max", limits.max_servers_per_universe);
+        return Err("universe_import__server_limit".into());
+    }
+    for server in &backup.servers {
+        if !author_administers(ctx, GuildId::new(server.server_id)).await {
+            args.set("server_id", server.server_id.to_string());
+            return Err("universe_import__server_not_administered".into());
+        }
+        match get_server_by_id(server.server_id).await {
+            Ok(None) => {}
+            Ok(Some(_)) => {
+                args.set("server_id", server.server_id.to_string());
+                return Err("universe_import__server_conflict".into());
+            }
+            Err(_) => return Err("verify__database_error".into()),
+        }
+    }
+    Ok(())
+}
+
+/// Whether the bot is on the server `guild_id` and the author has the administrator permission
+/// there, as owner or through their roles.
+async fn author_administers(ctx: &Context<'_>, guild_id: GuildId) -> bool {
+    let (Ok(guild), Ok(member)) = (guild_id.to_partial_guild(ctx).await, guild_id.member(ctx, ctx.author().id).await) else { return false };
+    if guild.owner_id == member.user.id {
+        return true;
+    }
+    let roles: Vec<Role> = guild.roles.values().cloned().collect();
+    guild_permissions(&roles, guild_id.everyone_role(), &member.roles).administrator()
+}
+
+/// Fresh ids given to the documents of a backup, by their id in the backup.
+#[derive(Default)]
+pub struct IdMapping(HashMap<ObjectId, ObjectId>);
+
+impl IdMapping {
+    /// Gives a fresh id to the document `old`.
+    fn fresh(&mut self, old: ObjectId) -> ObjectId {
+        // The default object id is a new one
+        *self.0.entry(old).or_default()
+    }
+
+    /// New id of the document `old`, or `old` itself when the document is not part of the
+    /// backup, as items are not.
+    pub fn get(&self, old: ObjectId) -> ObjectId {
+        self.0.get(&old).copied().unwrap_or(old)
+    }
+
+    fn remap_modifiers(&self, modifiers: &mut [Modifier]) {
+        for modifier in modifiers {
+            modifier.stat = self.get(modifier.stat);
+            modifier.source = self.get(modifier.source);
+        }
+    }
+}
+
+/// Gives a fresh id to every document of `backup` and updates the ids they refer to each other
+/// by. Servers are marked as needing a repair, since their roles and channels may be gone.
+pub fn remap_ids(backup: &mut UniverseBackup) -> IdMapping {
+    let mut mapping = IdMapping::default();
+    backup.universe.universe_id = mapping.fresh(backup.universe.universe_id);
+    backup.servers.iter_mut().for_each(|server| server._id = mapping.fresh(server._id));
+    backup.places.iter_mut().for_each(|place| place._id = mapping.fresh(place._id));
+    backup.roads.iter_mut().for_each(|road| road._id = mapping.fresh(road._id));
+    backup.stats.iter_mut().for_each(|stat| stat._id = mapping.fresh(stat._id));
+    backup.characters.iter_mut().for_each(|character| character._id = mapping.fresh(character._id));
+
+    let universe_id = backup.universe.universe_id;
+    for server in &mut backup.servers {
+        server.universe_id = universe_id;
+        server.needs_repair = true;
+    }
+    for place in &mut backup.places {
+        place.universe_id = universe_id;
+        mapping.remap_modifiers(&mut place.modifiers);
+    }
+    for road in &mut backup.roads {
+        road.universe_id = universe_id;
+        mapping.remap_modifiers(&mut road.modifiers);
+    }
+    for stat in &mut backup.stats {
+        stat.universe_id = universe_id;
+        mapping.remap_modifiers(&mut stat.modifiers);
+    }
+    for character in &mut backup.characters {
+        character.universe_id = universe_id;
+        for stat in &mut character.stats {
+            mapping.remap_modifiers(&mut stat.modifiers);
+        }
+    }
+    mapping
+}
+
+/// Inserts every document of `backup`, the universe first. Stops at the first failure, leaving
+/// the documents already inserted for the caller to delete with the universe.
+async fn write_backup(backup: &UniverseBackup) -> Result<(), Error> {
+    backup.universe.insert_universe().await?;
+    backup.universe.setup_constraints().await?;
+    for server in &backup.servers {
+        server.insert_server().await?;
+    }
+    for stat in &backup.stats {
+        stat.insert_stat().await?;
+    }
+    for place in &backup.places {
+        place.insert_place().await?;
+    }
+    for road in &backup.roads {
+        road.clone().insert().await?;
+    }
+    for character in &backup.characters {
+        character.insert().await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use crate::database::modifiers::ModifierType;
+    use crate::database::stats::StatValue;
+    use crate::universe::export_sub_command::{export_json, UniverseExport};
+    use super::*;
+
+    fn export_bytes(universe: &Universe, servers: &[Server], stats: &[Stat], places: &[Place]) -> Vec<u8> {
+        let export = UniverseExport {
+            format_version: EXPORT_FORMAT_VERSION,
+            exported_at: 1,
+            universe,
+            servers,
+            places,
+            roads: &[],
+            stats,
+            characters: &[],
+        };
+        export_json(&export).unwrap().into_bytes()
+    }
+
+    #[test]
+    fn test_read_backup() {
+        let universe = Universe::named("Terra", 1);
+        let mut server = Server::default();
+        server.universe_id(universe.universe_id).server_id(7u64);
+        let bytes = export_bytes(&universe, &[server], &[], &[]);
+
+        let backup = read_backup(&bytes).unwrap();
+        assert_eq!(backup.universe.universe_id, universe.universe_id);
+        assert_eq!(backup.servers[0].server_id, 7);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&bytes).unwrap();
+        assert!(read_backup(&encoder.finish().unwrap()).is_ok());
+
+        assert_eq!(read_backup(b"not json").err(), Some("universe_import__invalid_file"));
+        assert_eq!(read_backup(b"{\"universe\": {}}").err(), Some("universe_import__invalid_file"));
+        assert_eq!(read_backup(b"{\"format_version\": 999}").err(), Some("universe_import__unsupported_version"));
+    }
+
+    #[test]
+    fn test_remap_ids() {
+        let universe = Universe::named("Terra", 1);
+        let stat = Stat { universe_id: universe.universe_id, ..Stat::named("speed", StatValue::I64(3)) };
+        let place_id = ObjectId::new();
+        let item_id = ObjectId::new();
+        let modifier = |source| Modifier { source, ..Modifier::on(stat._id, ModifierType::Flats, StatValue::I64(1)) };
+        let place = Place {
+            _id: place_id,
+            universe_id: universe.universe_id,
+            server_id: 7,
+            role: 9,
+            modifiers: vec![modifier(place_id), modifier(item_id)],
+            ..Place::named("Town", 8)
+        };
+        let mut server = Server::default();
+        server.universe_id(universe.universe_id).server_id(7u64);
+        let mut backup = read_backup(&export_bytes(&universe, &[server], &[stat], &[place])).unwrap();
+
+        let mapping = remap_ids(&mut backup);
+        let universe_id = backup.universe.universe_id;
+        assert_ne!(universe_id, universe.universe_id);
+        assert_eq!(mapping.get(universe.universe_id), universe_id);
+        assert!(backup.servers.iter().all(|server| server.universe_id == universe_id && server.needs_repair));
+
+        let (stat, place) = (&backup.stats[0], &backup.places[0]);
+        assert_eq!(stat.universe_id, universe_id);
+        assert_ne!(place._id, place_id);
+        assert_eq!(place.modifiers[0].stat, stat._id);
+        assert_eq!(place.modifiers[0].source, place._id);
+        // Items are not part of the backup and keep their id
+        assert_eq!(place.modifiers[1].source, item_id);
+    }
+}
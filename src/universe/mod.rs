@@ -1,14 +1,34 @@
 pub mod create_universe_sub_command;
+pub mod export_sub_command;
+pub mod import_sub_command;
+pub mod add_admin_sub_command;
 pub mod add_server_sub_command;
+pub mod authorization;
 pub mod setup;
+pub mod remove_admin_sub_command;
+pub mod settings;
+pub mod set_server_locale_sub_command;
+pub mod status_sub_command;
+pub mod teardown_sub_command;
 pub mod time;
 pub mod time_sub_command;
+pub mod verify;
+pub mod verify_sub_command;
 
 use crate::universe::setup::setup_sub_command::setup;
+use crate::universe::add_admin_sub_command::add_admin;
 use crate::universe::add_server_sub_command::add_server;
 use crate::discord::poise_structs::{Context, Error};
 use crate::universe::create_universe_sub_command::create_universe;
+use crate::universe::export_sub_command::export;
+use crate::universe::import_sub_command::import;
+use crate::universe::remove_admin_sub_command::remove_admin;
+use crate::universe::settings::settings;
+use crate::universe::set_server_locale_sub_command::set_server_locale;
+use crate::universe::status_sub_command::status;
+use crate::universe::teardown_sub_command::teardown;
 use crate::universe::time_sub_command::time;
+use crate::universe::verify_sub_command::verify;
 
 /// Handles the `/universe` slash command with multiple subcommands.
 ///
@@ -19,8 +39,16 @@ use crate::universe::time_sub_command::time;
 /// ### Subcommands:
 /// - **create_universe**: Command to create a new universe.
 /// - **add_server**: Command to add a server to an existing universe.
+/// - **add_admin** / **remove_admin**: Let the creator share the administration of the universe.
 /// - **setup**: Command to configure or set up the universe.
+/// - **settings**: Universe-wide settings, such as the names given by the setup.
+/// - **set_server_locale**: Set the language of the server, over the default one of the universe.
+/// - **status**: Show which resources managed by the setup exist on the server.
+/// - **teardown**: Delete every role, category and channel managed by the bot on the server.
 /// - **time**: Display current universe time.
+/// - **export**: Send a JSON backup of the universe to its creator.
+/// - **import**: Restore a universe from such a backup.
+/// - **verify**: Compare the stored configuration with the live Discord state.
 ///
 /// ### Parameters:
 /// - `ctx`: The command context, which provides access to Discord interaction data
@@ -36,7 +64,7 @@ use crate::universe::time_sub_command::time;
 /// ### Notes:
 /// - This command requires specifying one of the listed subcommands as it does not
 ///   have a default action.
-#[poise::command(slash_command, subcommands("create_universe", "add_server", "setup", "time"), subcommand_required, rename = "universe")]
+#[poise::command(slash_command, subcommands("create_universe", "add_server", "add_admin", "remove_admin", "setup", "settings", "set_server_locale", "status", "teardown", "time", "verify", "export", "import"), subcommand_required, rename = "universe")]
 pub async fn universe(_ctx: Context<'_>) -> Result<(), Error>{
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,43 @@
+use fluent::FluentArgs;
+use serenity::all::User;
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_creator;
+use crate::utility::reply::reply_with_args;
+
+/// Removes a user from the admins of the universe.
+#[poise::command(slash_command, guild_only, rename = "universe_remove_admin")]
+pub async fn remove_admin(
+    ctx: Context<'_>,
+    #[description = "universe_remove_admin.user"]
+    user: User,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _remove_admin(&ctx, &user, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Removes `user` from the admins of the universe of the server. Only the creator can do it.
+/// `args` receives the user.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"universe__not_universe_creator"`: the author did not create the universe.
+/// - `"universe_remove_admin__not_admin"`: `user` is not an admin.
+/// - `"verify__database_error"`: a query failed.
+async fn _remove_admin(ctx: &Context<'_>, user: &User, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("user", format!("<@{}>", user.id));
+    let universe = match get_universe_by_server_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    require_universe_creator(ctx, &universe).await?;
+    if !universe.admins.contains(&user.id.get()) {
+        return Err("universe_remove_admin__not_admin".into());
+    }
+    let Ok(_) = universe.remove_admin(user.id.get()).await else { return Err("verify__database_error".into()) };
+    Ok("universe_remove_admin__success")
+}
@@ -0,0 +1,87 @@
+use fluent::FluentArgs;
+use crate::database::server::get_server_by_id;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::audit::log_action;
+use crate::discord::poise_structs::{Context, Error};
+use crate::translation::TRANSLATIONS;
+use crate::universe::authorization::require_universe_admin;
+use crate::utility::reply::reply_with_args;
+
+/// Sets the language of this server, preferred over the default one of the universe.
+#[poise::command(slash_command, guild_only, rename = "universe_set_server_locale")]
+pub async fn set_server_locale(
+    ctx: Context<'_>,
+    #[description = "universe_set_server_locale.locale"]
+    #[max_length = 16]
+    locale: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _set_server_locale(&ctx, &locale, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Checks the locale given to the command: `-` clears the locale of the server, any other must
+/// have a translation file for `has_locale`.
+///
+/// # Errors
+/// - `"universe_set_server_locale__unknown_locale"`: no translation file exists for the locale.
+pub fn parse_server_locale(locale: &str, has_locale: impl Fn(&str) -> bool) -> Result<Option<String>, &'static str> {
+    match locale.trim() {
+        "-" => Ok(None),
+        locale if has_locale(locale) => Ok(Some(locale.to_string())),
+        _ => Err("universe_set_server_locale__unknown_locale"),
+    }
+}
+
+/// Saves the locale of the server, forgets the cached one and reports the change in the log
+/// channel. `args` receives the new locale.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"universe__not_universe_admin"`: the author does not administrate the universe.
+/// - the errors of [`parse_server_locale`].
+/// - `"verify__database_error"`: a query failed.
+async fn _set_server_locale(ctx: &Context<'_>, locale: &str, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap().get();
+    let mut server = match get_server_by_id(guild_id).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    let universe = match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    require_universe_admin(ctx, &universe).await?;
+
+    let locale = parse_server_locale(locale, |locale| TRANSLATIONS.load().has_locale(locale))?;
+    let shown = locale.clone().unwrap_or_else(|| "-".to_string());
+    args.set("locale", shown.clone());
+    server.locale = locale;
+    let Ok(_) = server.update().await else { return Err("verify__database_error".into()) };
+    ctx.data().guild_locales.invalidate_guild(guild_id);
+
+    let mut log_args = FluentArgs::new();
+    log_args.set("universe", universe.name.clone());
+    log_args.set("locale", shown);
+    log_action(ctx, &server, "universe_set_server_locale__log", log_args).await;
+    Ok("universe_set_server_locale__success")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_server_locale() {
+        let has_locale = |locale: &str| locale == "fr" || locale == "en-US";
+        assert_eq!(parse_server_locale("fr", has_locale), Ok(Some("fr".to_string())));
+        assert_eq!(parse_server_locale(" en-US ", has_locale), Ok(Some("en-US".to_string())));
+        assert_eq!(parse_server_locale("-", has_locale), Ok(None));
+        assert_eq!(parse_server_locale("de", has_locale), Err("universe_set_server_locale__unknown_locale"));
+        assert_eq!(parse_server_locale("", has_locale), Err("universe_set_server_locale__unknown_locale"));
+    }
+}
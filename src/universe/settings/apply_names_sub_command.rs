@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, EditChannel, EditRole, RoleId};
+use crate::database::server::{get_server_by_id, IdType};
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_admin;
+use crate::universe::setup::names::{get_name_overrides, same_name, setup_name, CONFIGURABLE_NAMES};
+use crate::utility::reply::{reply, reply_with_args};
+
+/// Renames the setup-created roles and channels of this server after their current names.
+///
+/// Overrides set with `set_name` take precedence over the translations. Resources are edited
+/// in place, never recreated.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_settings_apply_names")]
+pub async fn apply_names(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let sent = match _apply_names(&ctx).await {
+        Ok((renamed, failed)) => {
+            let mut args = FluentArgs::new();
+            args.set("renamed", renamed);
+            args.set("failed", failed);
+            reply_with_args(ctx, Ok("universe_settings_apply_names__success"), Some(args)).await
+        }
+        Err(e) => reply(ctx, Err(e)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Returns the number of renamed resources and of resources that could not be renamed.
+async fn _apply_names(ctx: &Context<'_>) -> Result<(usize, usize), Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("verify__database_error".into()) };
+    let Some(server) = server_opt else { return Err("setup__server_not_found".into()) };
+    match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => require_universe_admin(ctx, &universe).await?,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    }
+
+    let Ok(roles) = ctx.http().get_guild_roles(guild_id).await else { return Err("verify__discord_error".into()) };
+    let Ok(channels) = ctx.http().get_channels(guild_id).await else { return Err("verify__discord_error".into()) };
+    let current_names: HashMap<u64, String> = roles.into_iter().map(|r| (r.id.get(), r.name))
+        .chain(channels.into_iter().map(|c| (c.id.get(), c.name)))
+        .collect();
+
+    let overrides = get_name_overrides(server.universe_id).await;
+    let stored = server.managed_ids();
+    let (mut renamed, mut failed) = (0, 0);
+
+    for (key, field) in CONFIGURABLE_NAMES {
+        let Some((_, Some(id))) = stored.iter().find(|(name, _)| *name == field) else { continue };
+        let Some(current) = current_names.get(&id.id) else { continue };
+        let expected = setup_name(ctx, &overrides, key);
+        if same_name(current, &expected) {
+            continue;
+        }
+
+        let result = match id.id_type {
+            IdType::Role => guild_id.edit_role(ctx, RoleId::new(id.id), EditRole::new().name(expected)).await.map(|_| ()),
+            IdType::Channel | IdType::Category => ChannelId::new(id.id).edit(ctx, EditChannel::new().name(expected)).await.map(|_| ()),
+        };
+        match result {
+            Ok(_) => renamed += 1,
+            Err(e) => {
+                tracing::warn!(guild_id = guild_id.get(), field, "failed to rename resource: {e}");
+                failed += 1;
+            }
+        }
+    }
+
+    Ok((renamed, failed))
+}
@@ -0,0 +1,33 @@
+use fluent::FluentArgs;
+use crate::database::universe::get_universe_by_server_id;
+use crate::dice::DiceExpression;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_admin;
+use crate::utility::reply::reply_with_args;
+
+/// Sets the dice each side of a `/contest` rolls, such as `1d20` or `2d10kh1`.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_settings_contest_die")]
+pub async fn contest_die_setting(
+    ctx: Context<'_>,
+    #[description = "universe_settings_contest_die.expression"]
+    expression: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _contest_die(&ctx, expression.trim(), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Checks that `expression` is a valid dice expression and saves it on the universe. `args`
+/// receives the expression.
+async fn _contest_die(ctx: &Context<'_>, expression: &str, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    args.set("expression", expression.to_string());
+    DiceExpression::parse(expression).map_err(|e| e.key())?;
+    let guild_id = ctx.guild_id().unwrap().get();
+    let Ok(universe_opt) = get_universe_by_server_id(guild_id).await else { return Err("verify__database_error".into()) };
+    let Some(universe) = universe_opt else { return Err("setup__server_not_found".into()) };
+    require_universe_admin(ctx, &universe).await?;
+    let Ok(_) = universe.set_contest_die(expression).await else { return Err("verify__database_error".into()) };
+    Ok("universe_settings_contest_die__success")
+}
@@ -0,0 +1,118 @@
+use std::sync::atomic::Ordering;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use fluent::FluentArgs;
+use serenity::all::{CreateInputText, CreateInteractionResponse, InputTextStyle};
+use serenity::utils::CreateQuickModal;
+use crate::database::server::get_server_by_id;
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::audit::log_action;
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::translation::TRANSLATIONS;
+use crate::universe::authorization::require_universe_admin;
+use crate::universe::time::{rebased_time_origin, reschedule_universal_time};
+use crate::utility::reply::reply_with_args;
+
+static LOCALE_INPUT: &str = "universe_settings_general__locale";
+static TIME_MODIFIER_INPUT: &str = "universe_settings_general__time_modifier";
+
+/// Changes the default language and the time speed of the universe in a form.
+#[poise::command(slash_command, guild_only, rename = "universe_settings_general")]
+pub async fn general_setting(ctx: Context<'_>) -> Result<(), Error> {
+    let mut args = FluentArgs::new();
+    let result = _general_setting(&ctx, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Checks the inputs of the settings form: an empty locale clears the default locale, any other
+/// must have a translation file for `has_locale`, and the time modifier must be a positive
+/// percentage.
+///
+/// # Errors
+/// - `"universe_settings_general__unknown_locale"`: no translation file exists for the locale.
+/// - `"universe_settings_general__invalid_time_modifier"`: the modifier is not a positive integer.
+pub fn parse_settings(locale: &str, time_modifier: &str, has_locale: impl Fn(&str) -> bool) -> Result<(Option<String>, u32), &'static str> {
+    let locale = match locale.trim() {
+        "" => None,
+        locale if has_locale(locale) => Some(locale.to_string()),
+        _ => return Err("universe_settings_general__unknown_locale"),
+    };
+    match time_modifier.trim().parse::<u32>() {
+        Ok(modifier) if modifier > 0 => Ok((locale, modifier)),
+        _ => Err("universe_settings_general__invalid_time_modifier"),
+    }
+}
+
+/// Opens a form pre-filled with the default locale and the time modifier of the universe, saves
+/// the submitted values, reschedules the time of day announcements and reports the change in the
+/// log channel. `args` receives the new values.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"universe__not_universe_admin"`: the author does not administrate the universe.
+/// - `"universe_settings_general__timed_out"`: the form was not submitted in time.
+/// - the errors of [`parse_settings`].
+/// - `"verify__database_error"`: a query failed.
+async fn _general_setting(ctx: &Context<'_>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let universe = match get_universe_by_server_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    require_universe_admin(ctx, &universe).await?;
+    let Context::Application(app_ctx) = ctx else { return Err("setup__server_not_found".into()) };
+
+    let modal = CreateQuickModal::new(tr!(*ctx, "universe_settings_general__title"))
+        .field(
+            CreateInputText::new(InputTextStyle::Short, tr!(*ctx, LOCALE_INPUT), LOCALE_INPUT)
+                .required(false).max_length(16)
+                .value(universe.default_locale.clone().unwrap_or_default())
+        )
+        .field(
+            CreateInputText::new(InputTextStyle::Short, tr!(*ctx, TIME_MODIFIER_INPUT), TIME_MODIFIER_INPUT)
+                .required(true).max_length(6)
+                .value(universe.global_time_modifier.to_string())
+        )
+        .timeout(Duration::from_secs(600));
+    let Ok(Some(response)) = app_ctx.interaction.quick_modal(ctx.serenity_context(), modal).await else { return Err("universe_settings_general__timed_out".into()) };
+    app_ctx.has_sent_initial_response.store(true, Ordering::SeqCst);
+    let Ok(_) = response.interaction.create_response(ctx, CreateInteractionResponse::Acknowledge).await else { return Err("reply__reply_failed".into()) };
+
+    let (locale, time_modifier) = parse_settings(&response.inputs[0], &response.inputs[1], |locale| TRANSLATIONS.load().has_locale(locale))?;
+    args.set("locale", locale.clone().unwrap_or_else(|| "-".to_string()));
+    args.set("time_modifier", time_modifier);
+
+    let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_millis();
+    let time_origin = rebased_time_origin(now_ms, universe.time_origin_timestamp, universe.global_time_modifier, time_modifier);
+    let Ok(_) = universe.update_settings(locale.as_deref(), time_modifier, time_origin).await else { return Err("verify__database_error".into()) };
+    ctx.data().guild_locales.invalidate_universe(universe.universe_id);
+    if time_modifier != universe.global_time_modifier {
+        reschedule_universal_time().await;
+    }
+    if let Ok(Some(server)) = get_server_by_id(ctx.guild_id().unwrap().get()).await {
+        let mut log_args = FluentArgs::new();
+        log_args.set("universe", universe.name.clone());
+        log_args.set("locale", locale.unwrap_or_else(|| "-".to_string()));
+        log_args.set("time_modifier", time_modifier);
+        log_action(ctx, &server, "universe_settings_general__log", log_args).await;
+    }
+    Ok("universe_settings_general__success")
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_settings() {
+        let has_locale = |locale: &str| locale == "fr" || locale == "en-US";
+        assert_eq!(parse_settings("fr", "200", has_locale), Ok((Some("fr".to_string()), 200)));
+        assert_eq!(parse_settings("  ", " 50 ", has_locale), Ok((None, 50)));
+        assert_eq!(parse_settings("de", "100", has_locale), Err("universe_settings_general__unknown_locale"));
+        assert_eq!(parse_settings("fr", "0", has_locale), Err("universe_settings_general__invalid_time_modifier"));
+        assert_eq!(parse_settings("fr", "-5", has_locale), Err("universe_settings_general__invalid_time_modifier"));
+        assert_eq!(parse_settings("fr", "fast", has_locale), Err("universe_settings_general__invalid_time_modifier"));
+    }
+}
@@ -0,0 +1,51 @@
+use fluent::FluentArgs;
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_admin;
+use crate::place::{autocomplete_place, resolve_place};
+use crate::stats::CLEAR_FIELD;
+use crate::utility::reply::reply_with_args;
+
+/// Sets the place new players start in and whether joining needs an approved character.
+///
+/// Options left empty keep their current value; `-` as the starting place removes it.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_settings_join")]
+pub async fn join_setting(
+    ctx: Context<'_>,
+    #[description = "universe_settings_join.starting_place"]
+    #[autocomplete = "autocomplete_place"]
+    starting_place: Option<String>,
+    #[description = "universe_settings_join.requires_approval"]
+    requires_approval: Option<bool>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _join_setting(&ctx, starting_place, requires_approval, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Saves the join settings of the universe. `args` receives the resulting starting place and
+/// whether an approved character is required.
+async fn _join_setting(ctx: &Context<'_>, starting_place: Option<String>, requires_approval: Option<bool>, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap().get();
+    let Ok(universe_opt) = get_universe_by_server_id(guild_id).await else { return Err("verify__database_error".into()) };
+    let Some(universe) = universe_opt else { return Err("setup__server_not_found".into()) };
+    require_universe_admin(ctx, &universe).await?;
+
+    let starting_place_id = match starting_place.as_deref().map(str::trim) {
+        None => universe.starting_place_id,
+        Some(input) if input == CLEAR_FIELD => None,
+        Some(input) => match resolve_place(universe.universe_id, input).await {
+            Ok(Some(place)) => Some(place.category_id),
+            Ok(None) => return Err("delete_place__not_found".into()),
+            Err(_) => return Err("verify__database_error".into()),
+        },
+    };
+    let requires_approval = requires_approval.unwrap_or(universe.join_requires_approval);
+    let Ok(_) = universe.set_join_settings(starting_place_id, requires_approval).await else { return Err("verify__database_error".into()) };
+
+    args.set("place", starting_place_id.map(|id| format!("<#{id}>")).unwrap_or_else(|| "-".to_string()));
+    args.set("requires_approval", requires_approval.to_string());
+    Ok("universe_settings_join__success")
+}
@@ -0,0 +1,28 @@
+pub mod set_name_sub_command;
+pub mod apply_names_sub_command;
+pub mod road_slowmode_sub_command;
+pub mod contest_die_sub_command;
+pub mod join_sub_command;
+pub mod general_sub_command;
+
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::settings::apply_names_sub_command::apply_names;
+use crate::universe::settings::road_slowmode_sub_command::road_slowmode_setting;
+use crate::universe::settings::contest_die_sub_command::contest_die_setting;
+use crate::universe::settings::join_sub_command::join_setting;
+use crate::universe::settings::general_sub_command::general_setting;
+use crate::universe::settings::set_name_sub_command::set_name;
+
+/// Groups the `/universe settings` subcommands.
+///
+/// ### Subcommands:
+/// - **set_name**: Customize the name the setup gives to a role or channel.
+/// - **apply_names**: Rename the existing roles and channels of the server after the current names.
+/// - **road_slowmode**: Set the slowmode of road channels per kilometer of road.
+/// - **contest_die**: Set the dice each side of a contest rolls.
+/// - **join**: Set the place new players start in and whether joining needs an approved character.
+/// - **general**: Set the default language and the time speed of the universe in a form.
+#[poise::command(slash_command, subcommands("set_name", "apply_names", "road_slowmode_setting", "contest_die_setting", "join_setting", "general_setting"), subcommand_required, rename = "universe_settings")]
+pub async fn settings(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
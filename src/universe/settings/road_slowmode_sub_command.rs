@@ -0,0 +1,60 @@
+use fluent::FluentArgs;
+use futures::TryStreamExt;
+use serenity::all::{ChannelId, EditChannel};
+use crate::database::road::{get_roads_by_universe_id, Road};
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_admin;
+use crate::roads::road_slowmode;
+use crate::utility::reply::reply_with_args;
+
+/// Sets the seconds of slowmode road channels get per kilometer, 0 for a fixed slowmode.
+///
+/// Existing roads keep their slowmode unless `apply_to_existing` is set, in which case the
+/// slowmode of every road channel of the universe is updated.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_settings_road_slowmode")]
+pub async fn road_slowmode_setting(
+    ctx: Context<'_>,
+    #[description = "universe_settings_road_slowmode.seconds_per_km"]
+    #[max = 21600]
+    seconds_per_km: u64,
+    #[description = "universe_settings_road_slowmode.apply_to_existing"]
+    apply_to_existing: Option<bool>,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let mut args = FluentArgs::new();
+    let result = _road_slowmode(&ctx, seconds_per_km, apply_to_existing.unwrap_or(false), &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Saves `seconds_per_km` on the universe and, when `apply_to_existing` is set, applies the
+/// resulting slowmode to the road channels. `args` receives the number of updated and failed roads.
+async fn _road_slowmode(ctx: &Context<'_>, seconds_per_km: u64, apply_to_existing: bool, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap().get();
+    let Ok(universe_opt) = get_universe_by_server_id(guild_id).await else { return Err("verify__database_error".into()) };
+    let Some(universe) = universe_opt else { return Err("setup__server_not_found".into()) };
+    require_universe_admin(ctx, &universe).await?;
+    let Ok(_) = universe.set_road_slowmode_per_distance(seconds_per_km).await else { return Err("verify__database_error".into()) };
+    args.set("seconds", seconds_per_km);
+    if !apply_to_existing {
+        return Ok("universe_settings_road_slowmode__success");
+    }
+
+    let Ok(cursor) = get_roads_by_universe_id(universe.universe_id).await else { return Err("verify__database_error".into()) };
+    let Ok(roads) = cursor.try_collect::<Vec<Road>>().await else { return Err("verify__database_error".into()) };
+    let (mut updated, mut failed) = (0, 0);
+    for road in roads {
+        let slowmode = EditChannel::new().rate_limit_per_user(road_slowmode(road.distance, seconds_per_km));
+        match ChannelId::new(road.channel_id).edit(ctx, slowmode).await {
+            Ok(_) => updated += 1,
+            Err(e) => {
+                tracing::warn!(guild_id, channel_id = road.channel_id, "failed to apply the road slowmode: {e}");
+                failed += 1;
+            }
+        }
+    }
+    args.set("updated", updated);
+    args.set("failed", failed);
+    Ok("universe_settings_road_slowmode__applied")
+}
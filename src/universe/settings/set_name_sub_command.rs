@@ -0,0 +1,62 @@
+use fluent::FluentArgs;
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_admin;
+use crate::universe::setup::names::{is_configurable_color, is_configurable_name, parse_color, CONFIGURABLE_COLORS, CONFIGURABLE_NAMES};
+use crate::utility::reply::{reply, reply_with_args};
+
+async fn autocomplete_name_key<'a>(_ctx: Context<'_>, partial: &'a str) -> impl Iterator<Item = &'static str> + 'a {
+    CONFIGURABLE_NAMES.iter()
+        .chain(CONFIGURABLE_COLORS.iter())
+        .map(|(key, _)| *key)
+        .filter(move |key| key.contains(partial))
+}
+
+/// Sets the name the setup gives to a role or channel, for every server of the universe.
+///
+/// `key` is the translation key of the default name (e.g. `admin_role_name`). Existing
+/// resources keep their name until `/universe settings apply_names` is run. The color of the
+/// setup roles is set the same way, with `key` like `admin_role_color` and a `#rrggbb` value;
+/// it applies to the roles the setup creates afterwards.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_settings_set_name")]
+pub async fn set_name(
+    ctx: Context<'_>,
+    #[description = "universe_settings_set_name.key"]
+    #[autocomplete = "autocomplete_name_key"]
+    key: String,
+    #[description = "universe_settings_set_name.value"]
+    #[max_length = 100]
+    value: String,
+) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let sent = match _set_name(&ctx, &key, value.trim()).await {
+        Ok(result) => {
+            let mut args = FluentArgs::new();
+            args.set("key", key);
+            args.set("value", value.trim().to_string());
+            reply_with_args(ctx, Ok(result), Some(args)).await
+        }
+        Err(e) => reply(ctx, Err(e)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+async fn _set_name(ctx: &Context<'_>, key: &str, value: &str) -> Result<&'static str, Error> {
+    if !is_configurable_name(key) && !is_configurable_color(key) {
+        return Err("universe_settings__unknown_name_key".into());
+    }
+    if value.is_empty() {
+        return Err("universe_settings__empty_name".into());
+    }
+    if is_configurable_color(key) && parse_color(value).is_none() {
+        return Err("universe_settings__invalid_color".into());
+    }
+
+    let guild_id = ctx.guild_id().unwrap().get();
+    let Ok(universe_opt) = get_universe_by_server_id(guild_id).await else { return Err("verify__database_error".into()) };
+    let Some(universe) = universe_opt else { return Err("setup__server_not_found".into()) };
+    require_universe_admin(ctx, &universe).await?;
+    let Ok(_) = universe.set_name_override(key, value).await else { return Err("verify__database_error".into()) };
+    Ok("universe_settings_set_name__success")
+}
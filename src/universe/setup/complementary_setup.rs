@@ -1,8 +1,12 @@
-use serenity::all::{ChannelType, CreateForumTag, EditChannel};
-use crate::database::server::{Id, IdType, Server};
-use crate::discord::channels::{create_channel, get_admin_category_permission_set, get_rp_character_permission_set, get_universal_time_permission_set, ITEM_TAG, PLACE_TAG, SPACE_TAG};
+use serenity::all::{AutoArchiveDuration, ChannelId, ChannelType, CreateForumTag, EditChannel, GuildChannel};
+use crate::database::server::{IdType, Server};
+use crate::discord::channels::{ensure_channel, get_admin_category_permission_set, get_rp_character_permission_set, get_universal_time_permission_set, ChannelSpec, ITEM_TAG, PLACE_TAG, SPACE_TAG};
 use crate::discord::poise_structs::{Context, Error};
+use crate::universe::setup::names::{get_name_overrides, setup_name};
 use crate::tr;
+use crate::membership::role_menu::post_role_menu;
+use crate::translation::guild_locale_or_main;
+use crate::universe::setup::progress::{SetupPhase, SetupProgress};
 
 /// Asynchronous function that sets up the necessary categories and channels for a server.
 /// This function creates the required channel categories and text channels for the server setup
@@ -12,6 +16,7 @@ use crate::tr;
 /// - `ctx`: A reference to the async `Context` object for executing Discord operations such as API calls.
 /// - `server`: A mutable reference to the server configuration that gets modified during the setup.
 /// - `snapshot`: A snapshot of the original server configuration to serve as a fallback during the process.
+/// - `progress`: The progress message, updated when the categories and the channels are ready.
 ///
 /// # Returns
 /// A `Result` containing:
@@ -38,14 +43,22 @@ use crate::tr;
 ///   - Moderation channel
 /// - An additional general channel for each of the NRP and RP categories is created.
 /// - Tracks errors during the creation process and halts further processing in case of failure.
+/// - Every resource is stored on `server` as soon as it is created, so a rollback deletes all
+///   the resources created by this run and only them: resources that existed before the setup
+///   are part of `snapshot` and survive a failure (see `Server::created_since`).
+/// - The role menu is posted in the NRP general channel, unless it is already there (see
+///   `post_role_menu`). A failure to post it does not fail the setup.
+/// - The managed categories are moved to the top of the server; the other categories keep their
+///   relative order and channels are not moved (see `category_order`).
 ///
 /// # Notes
-/// - The function leverages pre-defined localized strings for category and channel names.
+/// - The function leverages pre-defined localized strings for category and channel names, unless the
+///   universe overrides them.
 ///
 /// # Examples
 /// ```rust
 /// let mut server_config = configure_server();
-/// let setup_result = complementary_setup(ctx, &mut server_config, snapshot).await;
+/// let setup_result = complementary_setup(ctx, &mut server_config, snapshot, &mut progress).await;
 ///
 /// match setup_result {
 ///     Ok(success_msg) => println!("Setup completed successfully: {}", success_msg),
@@ -54,7 +67,7 @@ use crate::tr;
 /// ```
 ///
 /// # Related Functions
-/// - `create_channel`: Handles the actual API call for creating Discord channels.
+/// - `ensure_channel`: Reuses a stored channel that still exists, or creates it.
 /// - `get_admin_category_permission_set`: Configures the permission overrides for the Admin category.
 ///
 /// # Context
@@ -64,305 +77,90 @@ use crate::tr;
 /// # Dependencies
 /// Relies on asynchronous Discord API interactions through the `Context` object, as well as utilities
 /// for managing permissions, channel types, and localized translations.
-pub async fn complementary_setup<'a>(ctx: &Context<'_>, server : &'a mut Server, snapshot: Server) -> Result<&'a str, Error> {
-    let mut errors: Vec<&str> = vec![];
+pub async fn complementary_setup<'a>(ctx: &Context<'_>, server : &'a mut Server, snapshot: Server, progress: &mut SetupProgress<'_>) -> Result<&'a str, Error> {
+    progress.begin(SetupPhase::Categories).await;
+    let overrides = get_name_overrides(server.universe_id).await;
+    let mut created: Vec<GuildChannel> = vec![];
 
     let admin_category_permissions = get_admin_category_permission_set(
-        server.everyone_role_id.clone().unwrap().id.into(),
-        server.spectator_role_id.clone().unwrap().id.into(),
-        server.player_role_id.clone().unwrap().id.into(),
-        server.moderator_role_id.clone().unwrap().id.into());
+        server.everyone_role_id.unwrap().id.into(),
+        server.spectator_role_id.unwrap().id.into(),
+        server.player_role_id.unwrap().id.into(),
+        server.moderator_role_id.unwrap().id.into());
 
-    let admin_category_result = match server.admin_category_id{
-        None => {
-            match create_channel(ctx, tr!(*ctx, "admin_category_name"), ChannelType::Category, 0, admin_category_permissions, None).await {
-                Ok(category) => { Ok(category)}
-                Err(e) => {errors.push("setup__admin_category_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => {Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    match create_channel(ctx, tr!(*ctx, "admin_category_name"), ChannelType::Category, 0, admin_category_permissions, None).await {
-                        Ok(category) => {
-                            server.admin_category_id((category.id.get(), IdType::Category));
-                            Ok(category)}
-                        Err(e) => {errors.push("setup__admin_category_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let nrp_category_result = match server.nrp_category_id{
-        None => {
-            match create_channel(ctx, tr!(*ctx, "nrp_category_name"), ChannelType::Category, 1, vec![], None).await {
-                Ok(category) => { Ok(category)}
-                Err(e) => {errors.push("setup__nrp_category_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => {Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    match create_channel(ctx, tr!(*ctx, "nrp_category_name"), ChannelType::Category, 1, vec![], None).await {
-                        Ok(category) => {
-                            server.nrp_category_id((category.id.get(), IdType::Category));
-                            Ok(category)}
-                        Err(e) => {errors.push("setup__nrp_category_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let rp_category_result = match server.rp_category_id{
-        None => {
-            match create_channel(ctx, tr!(*ctx, "rp_category_name"), ChannelType::Category, 1, vec![], None).await {
-                Ok(category) => { Ok(category)}
-                Err(e) => {errors.push("setup__rp_category_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => {Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    match create_channel(ctx, tr!(*ctx, "rp_category_name"), ChannelType::Category, 1, vec![], None).await {
-                        Ok(category) => {
-                            server.rp_category_id((category.id.get(), IdType::Category));
-                            Ok(category)}
-                        Err(e) => {errors.push("setup__rp_category_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
+    let category = |key: &str| ChannelSpec::new(setup_name(ctx, &overrides, key), ChannelType::Category);
+    let admin_category = ensure_channel(ctx, server.admin_category_id, category("admin_category_name").permissions(admin_category_permissions), &mut created, "setup__admin_category_not_created").await;
+    let nrp_category = ensure_channel(ctx, server.nrp_category_id, category("nrp_category_name").position(1), &mut created, "setup__nrp_category_not_created").await;
+    let rp_category = ensure_channel(ctx, server.rp_category_id, category("rp_category_name").position(1), &mut created, "setup__rp_category_not_created").await;
 
-    if !errors.is_empty() {
+    if let Ok(channel) = &admin_category { server.admin_category_id((channel.id.get(), IdType::Category)); }
+    if let Ok(channel) = &nrp_category { server.nrp_category_id((channel.id.get(), IdType::Category)); }
+    if let Ok(channel) = &rp_category { server.rp_category_id((channel.id.get(), IdType::Category)); }
 
+    let (Ok(admin_category), Ok(nrp_category), Ok(rp_category)) = (admin_category, nrp_category, rp_category) else {
+        server.rollback(ctx, snapshot).await;
         return Err("setup__roles_setup_failed".into())
-    }
-
-    let admin_category = admin_category_result.unwrap();
-    let nrp_category = nrp_category_result.unwrap();
-    let rp_category = rp_category_result.unwrap();
-
-    let mut errors = vec![];
-
-    let log_channel_result = match server.log_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "log_channel_name"), ChannelType::Text, 0, vec![], Some(admin_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => { Ok(channel)}
-                Err(e) => { errors.push("setup__log_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "log_channel_name"), ChannelType::Text, 0, vec![], Some(admin_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => { errors.push("setup__log_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let commands_channel_result = match server.commands_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "commands_channel_name"), ChannelType::Text, 0, vec![], Some(admin_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => { Ok(channel)}
-                Err(e) => {errors.push("setup__commands_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "commands_channel_name"), ChannelType::Text, 0, vec![], Some(admin_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => {errors.push("setup__commands_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let moderation_channel_result = match server.moderation_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "moderation_channel_name"), ChannelType::Text, 0, vec![], Some(admin_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => { Ok(channel)}
-                Err(e) => {errors.push("setup__moderation_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "moderation_channel_name"), ChannelType::Text, 0, vec![], Some(admin_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => {errors.push("setup__moderation_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let nrp_general_channel_result = match server.nrp_general_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "nrp_general_channel_name"), ChannelType::Text, 0, vec![], Some(nrp_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => { Ok(channel)}
-                Err(e) => {errors.push("setup__nrp_general_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "nrp_general_channel_name"), ChannelType::Text, 0, vec![], Some(nrp_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => {errors.push("setup__nrp_general_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let character_channel_permissions = get_rp_character_permission_set(server.player_role_id.clone().unwrap().id.into());
-
-    let rp_character_channel = match server.rp_character_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "rp_character_channel_name"), ChannelType::Text, 0, character_channel_permissions, Some(rp_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => { Ok(channel)}
-                Err(e) => {errors.push("setup__rp_character_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "rp_character_channel_name"), ChannelType::Text, 0, character_channel_permissions, Some(rp_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => {errors.push("setup__rp_character_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
     };
-
-    let wiki_channel_result = match server.rp_wiki_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "rp_wiki_channel_name"), ChannelType::Forum, 0, vec![], Some(rp_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => {
-                    let _ = channel.clone().edit(ctx, EditChannel::new().available_tags(vec![
-                        CreateForumTag::new(PLACE_TAG),
-                        CreateForumTag::new(SPACE_TAG),
-                        CreateForumTag::new(ITEM_TAG),
-                    ])).await;
-                    Ok(channel)
-                }
-                Err(e) => {errors.push("setup__wiki_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "rp_wiki_channel_name"), ChannelType::Forum, 0, vec![], Some(rp_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => {errors.push("setup__wiki_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    let universal_time_channel_permissions = get_universal_time_permission_set(server.everyone_role_id.clone().unwrap().id.into());
-
-    let universal_time_channel_result = match server.universal_time_channel_id{
-        None => {
-            let result = create_channel(ctx, tr!(*ctx, "universal_time_channel_name"), ChannelType::Text, 0, universal_time_channel_permissions.clone(), Some(rp_category.clone().id.get())).await;
-            match result {
-                Ok(channel) => { Ok(channel)}
-                Err(e) => {errors.push("setup__universal_time_channel_not_created"); Err(e)}
-            }
-        }
-        Some(channel_id) => {
-            match ctx.http().get_channel(channel_id.id.into()).await{
-                Ok(channel) => { Ok(channel.guild().unwrap())}
-                Err(_) => {
-                    let result = create_channel(ctx, tr!(*ctx, "universal_time_channel_name"), ChannelType::Text, 0, universal_time_channel_permissions, Some(rp_category.clone().id.get())).await;
-                    match result{
-                        Ok(channel) => { Ok(channel)}
-                        Err(e) => {errors.push("setup__universal_time_channel_not_created"); Err(e)}
-                    }
-                }
-            }
-        }
-    };
-
-    if !errors.is_empty()  {
+    let categories_created = created.len();
+    progress.done(categories_created, 3).await;
+    progress.begin(SetupPhase::Channels).await;
+
+    let character_channel_permissions = get_rp_character_permission_set(server.player_role_id.unwrap().id.into());
+    let universal_time_channel_permissions = get_universal_time_permission_set(server.everyone_role_id.unwrap().id.into());
+
+    let text = |key: &str, parent: &GuildChannel| ChannelSpec::new(setup_name(ctx, &overrides, key), ChannelType::Text).parent(parent.id.get());
+    let log_channel = ensure_channel(ctx, server.log_channel_id, text("log_channel_name", &admin_category), &mut created, "setup__log_channel_not_created").await;
+    let commands_channel = ensure_channel(ctx, server.commands_channel_id, text("commands_channel_name", &admin_category).topic(tr!(*ctx, "commands_channel_topic")), &mut created, "setup__commands_channel_not_created").await;
+    let moderation_channel = ensure_channel(ctx, server.moderation_channel_id, text("moderation_channel_name", &admin_category), &mut created, "setup__moderation_channel_not_created").await;
+    let nrp_general_channel = ensure_channel(ctx, server.nrp_general_channel_id, text("nrp_general_channel_name", &nrp_category), &mut created, "setup__nrp_general_channel_not_created").await;
+    let rp_character_channel = ensure_channel(ctx, server.rp_character_channel_id, text("rp_character_channel_name", &rp_category).permissions(character_channel_permissions), &mut created, "setup__rp_character_channel_not_created").await;
+    let wiki_spec = ChannelSpec::new(setup_name(ctx, &overrides, "rp_wiki_channel_name"), ChannelType::Forum)
+        .parent(rp_category.id.get())
+        .default_auto_archive_duration(AutoArchiveDuration::OneWeek);
+    let wiki_channel = ensure_channel(ctx, server.rp_wiki_channel_id, wiki_spec, &mut created, "setup__wiki_channel_not_created").await;
+    let universal_time_channel = ensure_channel(ctx, server.universal_time_channel_id, text("universal_time_channel_name", &rp_category).permissions(universal_time_channel_permissions), &mut created, "setup__universal_time_channel_not_created").await;
+
+    if let Ok(channel) = &log_channel { server.log_channel_id((channel.id.get(), IdType::Channel)); }
+    if let Ok(channel) = &commands_channel { server.commands_channel_id((channel.id.get(), IdType::Channel)); }
+    if let Ok(channel) = &moderation_channel { server.moderation_channel_id((channel.id.get(), IdType::Channel)); }
+    if let Ok(channel) = &nrp_general_channel { server.nrp_general_channel_id((channel.id.get(), IdType::Channel)); }
+    if let Ok(channel) = &rp_character_channel { server.rp_character_channel_id((channel.id.get(), IdType::Channel)); }
+    if let Ok(channel) = &wiki_channel { server.rp_wiki_channel_id((channel.id.get(), IdType::Channel)); }
+    if let Ok(channel) = &universal_time_channel { server.universal_time_channel_id((channel.id.get(), IdType::Channel)); }
+
+    let channels = [&log_channel, &commands_channel, &moderation_channel, &nrp_general_channel, &rp_character_channel, &wiki_channel, &universal_time_channel];
+    if let Some(Err(error_key)) = channels.iter().find(|channel| channel.is_err()) {
+        tracing::warn!(guild_id = server.server_id, "complementary setup failed: {error_key}");
         server.rollback(ctx, snapshot).await;
         return Err("setup__channel_setup_failed".into())
     }
 
-    let log_channel = log_channel_result.unwrap();
-    let commands_channel = commands_channel_result.unwrap();
-    let moderation_channel = moderation_channel_result.unwrap();
-    let nrp_general_channel = nrp_general_channel_result.unwrap();
-    let rp_character_channel = rp_character_channel.unwrap();
-    let wiki_channel = wiki_channel_result.unwrap();
-    let universal_time_channel = universal_time_channel_result.unwrap();
-
-
-
-    server.nrp_category_id(Id{ id: nrp_category.id.get(), id_type: IdType::Category });
-    server.rp_category_id(Id{ id: rp_category.id.get(), id_type: IdType::Category });
-    server.admin_category_id(Id{ id: admin_category.id.get(), id_type: IdType::Category });
-    server.log_channel_id(Id{id: log_channel.id.get(), id_type: IdType::Channel });
-    server.commands_channel_id(Id{id: commands_channel.id.get(), id_type: IdType::Channel });
-    server.moderation_channel_id(Id{id: moderation_channel.id.get(), id_type: IdType::Channel });
-    server.nrp_general_channel_id(Id{id: nrp_general_channel.id.get(), id_type: IdType::Channel });
-    server.rp_character_channel_id(Id{id: rp_character_channel.id.get(), id_type: IdType::Channel });
-    server.rp_wiki_channel_id(Id{id: wiki_channel.id.get(), id_type: IdType::Channel });
-    server.universal_time_channel_id(Id{id: universal_time_channel.id.get(), id_type: IdType::Channel });
-
-    let mut channel_order = vec![(admin_category.id, 0), (nrp_category.id, 1), (rp_category.id, 2), (server.road_category_id.unwrap().id.into(), 3)];
-    let channels = ctx.guild_id().unwrap().channels(ctx).await.unwrap();
-
-    let allowed = [
-        admin_category.id.get(),
-        nrp_category.id.get(),
-        rp_category.id.get(),
-        server.road_category_id.unwrap().id.into()
-    ];
-
-    for (channel_id, _) in channels{
-        if !allowed.contains(&channel_id.get()) {
-            channel_order.push((channel_id, 4))
-        }
+    // Forum tags are only added to a wiki created by this run, so an existing wiki keeps its own
+    if let Ok(wiki_channel) = wiki_channel && created.iter().any(|channel| channel.id == wiki_channel.id) {
+        let _ = wiki_channel.clone().edit(ctx, EditChannel::new().available_tags(vec![
+            CreateForumTag::new(PLACE_TAG),
+            CreateForumTag::new(SPACE_TAG),
+            CreateForumTag::new(ITEM_TAG),
+        ])).await;
     }
+    progress.done(created.len() - categories_created, 7).await;
 
+    // The role menu only saves admins from handing out roles, the setup goes on without it
+    let locale = guild_locale_or_main(server.server_id).await;
+    match post_role_menu(ctx.http(), server, &locale).await {
+        Ok(message_id) => server.role_menu_message_id = Some(message_id.get()),
+        Err(error_key) => tracing::warn!(guild_id = server.server_id, "role menu not posted: {error_key}"),
+    }
 
-    let reorder_result = ctx.guild_id().unwrap().reorder_channels(ctx, channel_order).await;
-
-    match reorder_result {
-        Ok(_) => {}
-        Err(_) => {}
+    let road_category = ChannelId::new(server.road_category_id.unwrap().id);
+    let managed = [admin_category.id, nrp_category.id, rp_category.id, road_category];
+    if let Ok(channels) = ctx.guild_id().unwrap().channels(ctx).await {
+        let categories: Vec<(ChannelId, u16)> = channels.values()
+            .filter(|channel| channel.kind == ChannelType::Category)
+            .map(|channel| (channel.id, channel.position))
+            .collect();
+        // The order of the categories is cosmetic, a failure does not undo the setup
+        let _ = ctx.guild_id().unwrap().reorder_channels(ctx, category_order(&managed, &categories)).await;
     }
 
     match server.update().await {
@@ -373,4 +171,43 @@ pub async fn complementary_setup<'a>(ctx: &Context<'_>, server : &'a mut Server,
     };
 
     Ok("setup__setup_success_message")
-}
\ No newline at end of file
+}
+
+/// Computes the category positions putting the `managed` categories on top, in the given order.
+///
+/// Only categories are moved: the other ones keep their relative order below the managed ones,
+/// and channels are left untouched since their position is relative to their category.
+pub fn category_order(managed: &[ChannelId], categories: &[(ChannelId, u16)]) -> Vec<(ChannelId, u64)> {
+    let mut others: Vec<&(ChannelId, u16)> = categories.iter()
+        .filter(|(id, _)| !managed.contains(id))
+        .collect();
+    others.sort_by_key(|(id, position)| (*position, *id));
+
+    managed.iter().copied()
+        .chain(others.into_iter().map(|(id, _)| *id))
+        .enumerate()
+        .map(|(position, id)| (id, position as u64))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use serenity::all::ChannelId;
+    use super::category_order;
+
+    #[test]
+    fn test_category_order() {
+        let managed = [ChannelId::new(10), ChannelId::new(11), ChannelId::new(12), ChannelId::new(13)];
+        // Two user categories around the managed ones, the road category being newly created
+        let categories = vec![
+            (ChannelId::new(2), 5),
+            (ChannelId::new(10), 3),
+            (ChannelId::new(1), 0),
+            (ChannelId::new(11), 4),
+            (ChannelId::new(12), 6),
+        ];
+
+        let order: Vec<(u64, u64)> = category_order(&managed, &categories).iter().map(|(id, pos)| (id.get(), *pos)).collect();
+        assert_eq!(order, vec![(10, 0), (11, 1), (12, 2), (13, 3), (1, 4), (2, 5)]);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::server::{get_server_by_id, Id, Server};
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::universe::setup::names::{get_name_overrides, setup_name};
+use crate::universe::setup::setup_sub_command::SetupType;
+
+/// A resource the setup manages, identified by the translation key of its name.
+#[derive(Debug, PartialEq)]
+pub struct PlannedResource {
+    pub name_key: &'static str,
+    pub exists: bool,
+}
+
+/// Lists the resources a setup of type `setup_type` would handle, and whether each one already
+/// exists and would be reused (`exists == true`) or would be created.
+///
+/// A resource exists when its id is stored on the server and still present on Discord, which is
+/// the same check `Server::snapshot` performs before a real setup.
+pub fn plan(server: &Server, setup_type: SetupType, roles: &HashSet<u64>, channels: &HashSet<u64>) -> Vec<PlannedResource> {
+    let role = |name_key, id: Option<Id>| PlannedResource { name_key, exists: id.is_some_and(|id| roles.contains(&id.id)) };
+    let channel = |name_key, id: Option<Id>| PlannedResource { name_key, exists: id.is_some_and(|id| channels.contains(&id.id)) };
+
+    let mut resources = vec![
+        role("admin_role_name", server.admin_role_id),
+        role("moderator_role_name", server.moderator_role_id),
+        role("spectator_role_name", server.spectator_role_id),
+        role("player_role_name", server.player_role_id),
+        channel("road_channel_name", server.road_category_id),
+    ];
+
+    // A repair covers every resource the full setup manages
+    if !matches!(setup_type, SetupType::PartialSetup) {
+        resources.extend([
+            channel("admin_category_name", server.admin_category_id),
+            channel("nrp_category_name", server.nrp_category_id),
+            channel("rp_category_name", server.rp_category_id),
+            channel("log_channel_name", server.log_channel_id),
+            channel("commands_channel_name", server.commands_channel_id),
+            channel("moderation_channel_name", server.moderation_channel_id),
+            channel("nrp_general_channel_name", server.nrp_general_channel_id),
+            channel("rp_character_channel_name", server.rp_character_channel_id),
+            channel("rp_wiki_channel_name", server.rp_wiki_channel_id),
+            channel("universal_time_channel_name", server.universal_time_channel_id),
+        ]);
+    }
+
+    resources
+}
+
+/// Reports what a setup would do on this server without touching Discord or the database.
+///
+/// The plan is sent as an embed, ✅ marking resources that would be reused and ➕ the ones
+/// that would be created.
+pub async fn _setup_dry_run(ctx: &Context<'_>, setup_type: SetupType) -> Result<&'static str, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("setup__server_not_found".into()) };
+    let Some(server) = server_opt else { return Err("setup__server_not_found".into()) };
+
+    let Ok(roles) = ctx.http().get_guild_roles(guild_id).await else { return Err("partial_setup__get_guild_roles_error".into()) };
+    let Ok(channels) = ctx.http().get_channels(guild_id).await else { return Err("setup__dry_run_get_channels_error".into()) };
+    let roles: HashSet<u64> = roles.iter().map(|r| r.id.get()).collect();
+    let channels: HashSet<u64> = channels.iter().map(|c| c.id.get()).collect();
+
+    let overrides = get_name_overrides(server.universe_id).await;
+    let lines = plan(&server, setup_type, &roles, &channels)
+        .into_iter()
+        .map(|resource| format!("{} {}", if resource.exists { "✅" } else { "➕" }, setup_name(ctx, &overrides, resource.name_key)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let embed = CreateEmbed::new()
+        .title(tr!(*ctx, "setup__dry_run", @title))
+        .description(format!("{}\n\n{}", tr!(*ctx, "setup__dry_run", @message), lines))
+        .color(Color::from_rgb(0x6f, 0x00, 0xff));
+
+    let Ok(_) = ctx.send(CreateReply::default().embed(embed)).await else { return Err("reply__reply_failed".into()) };
+    Ok("setup__dry_run")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::server::IdType;
+
+    #[test]
+    fn test_plan() {
+        let mut server = Server::default();
+        server.admin_role_id((1, IdType::Role))
+            .moderator_role_id((2, IdType::Role))
+            .road_category_id((10, IdType::Category));
+        let roles: HashSet<u64> = [1].into_iter().collect();
+        let channels: HashSet<u64> = [10].into_iter().collect();
+
+        let partial = plan(&server, SetupType::PartialSetup, &roles, &channels);
+        let existing: Vec<_> = partial.iter().filter(|r| r.exists).map(|r| r.name_key).collect();
+        assert_eq!(partial.len(), 5);
+        // The moderator role is stored but was deleted from Discord: it would be created again
+        assert_eq!(existing, vec!["admin_role_name", "road_channel_name"]);
+
+        assert_eq!(plan(&server, SetupType::FullSetup, &roles, &channels).len(), 15);
+    }
+}
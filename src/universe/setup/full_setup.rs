@@ -2,6 +2,7 @@ use crate::database::server::Server;
 use crate::discord::poise_structs::{Context, Error};
 use crate::universe::setup::complementary_setup::complementary_setup;
 use crate::universe::setup::partial_setup::partial_setup;
+use crate::universe::setup::progress::SetupProgress;
 
 /// Performs a complete asynchronous setup of the server by sequentially running the
 /// `partial_setup` and `complementary_setup` functions. If both steps succeed, it returns
@@ -11,6 +12,7 @@ use crate::universe::setup::partial_setup::partial_setup;
 /// * `ctx` - A reference to the operational context providing utilities and shared state.
 /// * `server` - A mutable reference to the server being configured.
 /// * `snapshot` - A snapshot of the server's state used during the setup process.
+/// * `progress` - The progress message updated after each phase.
 ///
 /// # Returns
 /// * `Ok(&'static str)` - A string identifier confirming the successful setup.
@@ -22,14 +24,14 @@ use crate::universe::setup::partial_setup::partial_setup;
 /// # Examples
 /// ```rust
 /// // Assuming `ctx`, `server`, and `snapshot` are already initialized:
-/// let result = full_setup(&ctx, &mut server, snapshot).await;
+/// let result = full_setup(&ctx, &mut server, snapshot, &mut progress).await;
 /// match result {
 ///     Ok(success_message) => println!("{}", success_message),
 ///     Err(e) => eprintln!("Setup failed: {}", e),
 /// }
 /// ```
-pub async fn full_setup<'a>(ctx: &Context<'_>, server: &'a mut Server, snapshot: Server) -> Result<&'static str, Error> {
-    let Ok(_) = partial_setup(ctx, server, snapshot.clone()).await else { return Err("setup_server__failed".into()) };
-    let Ok(_) = complementary_setup(ctx, server, snapshot).await else { return Err("setup_server__failed".into()) };
+pub async fn full_setup<'a>(ctx: &Context<'_>, server: &'a mut Server, snapshot: Server, progress: &mut SetupProgress<'_>) -> Result<&'static str, Error> {
+    let Ok(_) = partial_setup(ctx, server, snapshot.clone(), progress).await else { return Err("setup_server__failed".into()) };
+    let Ok(_) = complementary_setup(ctx, server, snapshot, progress).await else { return Err("setup_server__failed".into()) };
     Ok("setup__full_setup_success")
 }
\ No newline at end of file
@@ -1,4 +1,8 @@
 pub mod setup_sub_command;
 pub mod partial_setup;
 mod complementary_setup;
-pub mod full_setup;
\ No newline at end of file
+pub mod full_setup;
+pub mod dry_run;
+pub mod repair_setup;
+pub mod progress;
+pub mod names;
\ No newline at end of file
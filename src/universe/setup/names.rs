@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::time::Instant;
+use mongodb::bson::oid::ObjectId;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::Context;
+use crate::discord::roles::{self, RoleOptions};
+use crate::tr;
+use crate::translation::tr_raw;
+
+/// Translation keys of the names given by the setup, with the `Server` field storing the
+/// resource created under each name.
+pub static CONFIGURABLE_NAMES: [(&str, &str); 15] = [
+    ("admin_role_name", "admin_role_id"),
+    ("moderator_role_name", "moderator_role_id"),
+    ("spectator_role_name", "spectator_role_id"),
+    ("player_role_name", "player_role_id"),
+    ("admin_category_name", "admin_category_id"),
+    ("nrp_category_name", "nrp_category_id"),
+    ("rp_category_name", "rp_category_id"),
+    ("road_channel_name", "road_category_id"),
+    ("log_channel_name", "log_channel_id"),
+    ("commands_channel_name", "commands_channel_id"),
+    ("moderation_channel_name", "moderation_channel_id"),
+    ("nrp_general_channel_name", "nrp_general_channel_id"),
+    ("rp_character_channel_name", "rp_character_channel_id"),
+    ("rp_wiki_channel_name", "rp_wiki_channel_id"),
+    ("universal_time_channel_name", "universal_time_channel_id"),
+];
+
+/// Setting keys of the colors of the roles created by the setup, with the translation key of
+/// the role's name.
+pub static CONFIGURABLE_COLORS: [(&str, &str); 4] = [
+    ("admin_role_color", "admin_role_name"),
+    ("moderator_role_color", "moderator_role_name"),
+    ("spectator_role_color", "spectator_role_name"),
+    ("player_role_color", "player_role_name"),
+];
+
+/// Returns `true` if `key` is the translation key of a name the setup gives.
+pub fn is_configurable_name(key: &str) -> bool {
+    CONFIGURABLE_NAMES.iter().any(|(name_key, _)| *name_key == key)
+}
+
+/// Loads the name overrides of a universe, empty when the universe cannot be read.
+pub async fn get_name_overrides(universe_id: ObjectId) -> HashMap<String, String> {
+    match get_universe_by_id(universe_id).await {
+        Ok(Some(universe)) => universe.name_overrides,
+        _ => HashMap::new(),
+    }
+}
+
+/// Name of the resource named after `key`: the universe's override if any, the translation
+/// otherwise, into the locale of the server when it has one.
+pub fn setup_name(ctx: &Context<'_>, overrides: &HashMap<String, String>, key: &str) -> String {
+    if let Some(name) = overrides.get(key) {
+        return name.clone();
+    }
+    let server_locale = ctx.guild_id()
+        .and_then(|guild_id| ctx.data().guild_locales.cached(guild_id.get(), Instant::now()))
+        .and_then(|locale| locale.server);
+    match server_locale {
+        Some(locale) => tr_raw(&ctx.data().translations.load(), &locale, key, None, None),
+        None => tr!(*ctx, key),
+    }
+}
+
+/// Returns `true` if `key` is the setting key of the color of a setup role.
+pub fn is_configurable_color(key: &str) -> bool {
+    CONFIGURABLE_COLORS.iter().any(|(color_key, _)| *color_key == key)
+}
+
+/// Parses a `#rrggbb` or `rrggbb` color.
+pub fn parse_color(value: &str) -> Option<u32> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    if hex.len() != 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// Options of the setup role named after `name_key`, with the universe's color override if any.
+pub fn setup_role_options(overrides: &HashMap<String, String>, name_key: &str) -> RoleOptions {
+    let options = roles::setup_role_options(name_key);
+    let color = CONFIGURABLE_COLORS.iter()
+        .find(|(_, role_key)| *role_key == name_key)
+        .and_then(|(color_key, _)| overrides.get(*color_key))
+        .and_then(|value| parse_color(value));
+    match color {
+        Some(color) => options.color(color),
+        None => options,
+    }
+}
+
+/// Compares the name of an existing resource with the expected one, ignoring the lowercase and
+/// dashes Discord applies to text channel names.
+pub fn same_name(current: &str, expected: &str) -> bool {
+    current == expected || current == expected.to_lowercase().replace(' ', "-")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_same_name() {
+        assert!(same_name("Roads", "Roads"));
+        assert!(same_name("universal-time", "Universal time"));
+        assert!(!same_name("Roads", "Routes"));
+        assert!(is_configurable_name("road_channel_name"));
+        assert!(!is_configurable_name("readme_channel_name"));
+    }
+
+    #[test]
+    fn test_setup_role_options() {
+        assert_eq!(parse_color("#00ff00"), Some(0x00ff00));
+        assert_eq!(parse_color("abc"), None);
+
+        let overrides = HashMap::from([("player_role_color".to_string(), "#123456".to_string())]);
+        assert_eq!(setup_role_options(&overrides, "player_role_name").color, Some(0x123456));
+        let admin = setup_role_options(&overrides, "admin_role_name");
+        assert_eq!(admin.color, Some(0xe74c3c));
+        assert!(admin.hoist);
+        assert!(!setup_role_options(&overrides, "player_role_name").hoist);
+    }
+}
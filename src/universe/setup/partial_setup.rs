@@ -1,10 +1,11 @@
-use serenity::all::{ChannelType, Role, RoleId};
+use serenity::all::{ChannelType, Role};
 use crate::database::server::{Id, IdType, Server};
 use crate::database::server::IdType::Category;
 use crate::discord::channels::{create_channel, get_road_category_permission_set};
 use crate::discord::poise_structs::{Context, Error};
-use crate::discord::roles::{create_role, edit_role_positions, AdminRolePermissions, ModeratorRolePermissions, PlayerRolePermissions, SpectatorRolePermissions};
-use crate::tr;
+use crate::discord::roles::{edit_role_positions, ensure_role, managed_role_positions};
+use crate::universe::setup::names::{get_name_overrides, setup_name, setup_role_options};
+use crate::universe::setup::progress::{SetupPhase, SetupProgress};
 
 /// Performs a partial setup for a Discord server using the provided context and server configuration.
 ///
@@ -21,6 +22,7 @@ use crate::tr;
 ///   server. It is updated during the setup process to reflect the new configuration.
 /// * `snapshot` - A snapshot of the server's state before any changes have been made, used for
 ///   rollback if an error occurs.
+/// * `progress` - The progress message, updated when the roles and the road category are ready.
 ///
 /// # Returns
 ///
@@ -50,7 +52,7 @@ use crate::tr;
 ///
 /// ```rust
 /// // Assuming `ctx`, `server`, and `snapshot` are available.
-/// match partial_setup(&ctx, &mut server, snapshot).await {
+/// match partial_setup(&ctx, &mut server, snapshot, &mut progress).await {
 ///     Ok(success_message) => println!("{}", success_message),
 ///     Err(e) => eprintln!("Setup failed: {}", e),
 /// }
@@ -59,149 +61,79 @@ use crate::tr;
 /// # Notes
 ///
 /// * This function is designed to operate within an asynchronous context.
-/// * It relies on helper functions such as `create_role`, `edit_role_positions`, and
+/// * Resources are named after the universe's name overrides, falling back to the translations.
+/// * It relies on helper functions such as `ensure_role`, `edit_role_positions`, and
 ///   `create_channel` to manage server resources.
-pub async fn partial_setup<'a>(ctx: &Context<'_>, server: &mut Server, snapshot: Server) -> Result<&'a str, Error> {
+pub async fn partial_setup<'a>(ctx: &Context<'_>, server: &mut Server, snapshot: Server, progress: &mut SetupProgress<'_>) -> Result<&'a str, Error> {
+    progress.begin(SetupPhase::Roles).await;
     //everyone role
     let Ok(guild_id) = ctx.guild_id().ok_or("guild_only") else { return Err("guild_only".into()) };
     let everyone_role = guild_id.everyone_role();
+    let overrides = get_name_overrides(server.universe_id).await;
     
     let Ok(existing_roles) = ctx.http().get_guild_roles(ctx.guild_id().unwrap()).await else {return Err("partial_setup__get_guild_roles_error".into())};
 
     let mut roles_created: Vec<Role> = vec![];
-    let mut errors: Vec<&str> = vec![];
 
-    let admin_role = async {
-        if let Some(role_id) = server.clone().admin_role_id {
-            if let Ok(role) = guild_id.role(ctx, role_id.id.into()).await {
-                return Ok(role);
-            }
-        }
-
-        match create_role(ctx, tr!(*ctx, "admin_role_name"), *AdminRolePermissions).await {
-            Ok(role) => {
-                server.admin_role_id((role.id.get(), IdType::Role));
-                roles_created.push(role.clone());
-                Ok(role)
-            }
-            Err(e) => {
-                errors.push("setup__admin_role_not_created");
-                Err(e)
-            }
-        }
-    }.await;
-
-
-    let moderator_role = async {
-        if let Some(role_id) = server.clone().moderator_role_id {
-            if let Ok(role) = guild_id.role(ctx, role_id.id.into()).await {
-                return Ok(role);
-            }
-        }
-
-        match create_role(ctx, tr!(*ctx, "moderator_role_name"), *ModeratorRolePermissions).await {
-            Ok(role) => {
-                server.moderator_role_id((role.id.get(), IdType::Role));
-                roles_created.push(role.clone());
-                Ok(role)
-            }
-            Err(e) => {
-                errors.push("setup__moderator_role_not_created");
-                Err(e)
-            }
-        }
-    }.await;
-
-
-    let spectator_role = async {
-        if let Some(role_id) = server.clone().spectator_role_id {
-            if let Ok(role) = guild_id.role(ctx, role_id.id.into()).await {
-                return Ok(role);
-            }
-        }
-
-        match create_role(ctx, tr!(*ctx, "spectator_role_name"), *SpectatorRolePermissions).await {
-            Ok(role) => {
-                server.spectator_role_id((role.id.get(), IdType::Role));
-                roles_created.push(role.clone());
-                Ok(role)
-            }
-            Err(e) => {
-                errors.push("setup__spectator_role_not_created");
-                Err(e)
-            }
-        }
-    }.await;
+    let admin_role = ensure_role(ctx, &existing_roles, server.admin_role_id, setup_name(ctx, &overrides, "admin_role_name"), setup_role_options(&overrides, "admin_role_name"), &mut roles_created, "setup__admin_role_not_created").await;
+    let moderator_role = ensure_role(ctx, &existing_roles, server.moderator_role_id, setup_name(ctx, &overrides, "moderator_role_name"), setup_role_options(&overrides, "moderator_role_name"), &mut roles_created, "setup__moderator_role_not_created").await;
+    let spectator_role = ensure_role(ctx, &existing_roles, server.spectator_role_id, setup_name(ctx, &overrides, "spectator_role_name"), setup_role_options(&overrides, "spectator_role_name"), &mut roles_created, "setup__spectator_role_not_created").await;
+    let player_role = ensure_role(ctx, &existing_roles, server.player_role_id, setup_name(ctx, &overrides, "player_role_name"), setup_role_options(&overrides, "player_role_name"), &mut roles_created, "setup__player_role_not_created").await;
 
+    // Store the resolved roles right away so that a rollback deletes the ones created by this run
+    if let Ok(role) = &admin_role { server.admin_role_id((role.id.get(), IdType::Role)); }
+    if let Ok(role) = &moderator_role { server.moderator_role_id((role.id.get(), IdType::Role)); }
+    if let Ok(role) = &spectator_role { server.spectator_role_id((role.id.get(), IdType::Role)); }
+    if let Ok(role) = &player_role { server.player_role_id((role.id.get(), IdType::Role)); }
 
-    let player_role = async {
-        if let Some(role_id) = server.clone().player_role_id {
-            if let Ok(role) = guild_id.role(ctx, role_id.id.into()).await {
-                return Ok(role);
-            }
-        }
-
-        match create_role(ctx, tr!(*ctx, "player_role_name"), *PlayerRolePermissions).await {
-            Ok(role) => {
-                server.player_role_id((role.id.get(), IdType::Role));
-                roles_created.push(role.clone());
-                Ok(role)
-            }
-            Err(e) => {
-                errors.push("setup__player_role_not_created");
-                Err(e)
-            }
-        }
-    }.await;
-
-    if !errors.is_empty() {
+    let (Ok(admin_role), Ok(moderator_role), Ok(spectator_role), Ok(player_role)) = (admin_role, moderator_role, spectator_role, player_role) else {
         server.rollback(ctx, snapshot).await;
         return Err("setup__error_during_role_creation".into())
-    }
-
-    let Ok(admin_role) = admin_role else { return Err("setup__admin_role_not_created".into()) };
-    let Ok(moderator_role) = moderator_role else { return Err("setup__moderator_role_not_created".into()) };
-    let Ok(spectator_role) = spectator_role else { return Err("setup__spectator_role_not_created".into()) };
-    let Ok(player_role) = player_role else { return Err("setup__player_role_not_created".into()) };
-    let everyone_role = everyone_role;
+    };
 
     let guild_id = ctx.guild_id().unwrap();
     let bot_id = ctx.cache().current_user().id;
-    let bot_member = guild_id
-        .member(ctx.http(), bot_id)
-        .await.unwrap();
-    let bot_role = bot_member.roles.clone()[0];
-
-    let mut roles_pos: Vec<(RoleId, Option<u64>)> = vec![(admin_role.id, Some(4)), (moderator_role.id, Some(3)), (spectator_role.id, Some(2)), (player_role.id, Some(1)), (bot_role, Some(5))];
+    let Ok(bot_member) = guild_id.member(ctx.http(), bot_id).await else {
+        server.rollback(ctx, snapshot).await;
+        return Err("setup__reorder_went_wrong".into())
+    };
+    // Fetched again so that the roles created above are part of the computation
+    let Ok(guild_roles) = ctx.http().get_guild_roles(guild_id).await else {
+        server.rollback(ctx, snapshot).await;
+        return Err("partial_setup__get_guild_roles_error".into())
+    };
 
-    for role in &existing_roles {
-        if role.id != everyone_role {
-            roles_pos.push((role.id, Some((role.position + existing_roles.len() as u16).into())));
+    let managed = [admin_role.id, moderator_role.id, spectator_role.id, player_role.id];
+    let roles_pos = match managed_role_positions(&guild_roles, &bot_member.roles, &managed) {
+        Ok(roles_pos) => roles_pos,
+        Err(e) => {
+            server.rollback(ctx, snapshot).await;
+            return Err(e.into())
         }
-    }
-    
-    let res = edit_role_positions(ctx, ctx.guild_id().unwrap(), roles_pos).await;
+    };
 
-    match res {
-        Ok(_) => {}
-        Err(_) => {
-            server.rollback(ctx, snapshot).await;
-            return Err("setup__reorder_went_wrong".into())}
+    if edit_role_positions(ctx, guild_id, roles_pos).await.is_err() {
+        server.rollback(ctx, snapshot).await;
+        return Err("setup__reorder_went_wrong".into())
     }
+    progress.done(roles_created.len(), 4).await;
+    progress.begin(SetupPhase::RoadCategory).await;
 
     let permissions = get_road_category_permission_set(everyone_role, player_role.id, spectator_role.id, moderator_role.id);
 
     let result_road_category = match server.clone().road_category_id {
-        None => { Err(create_channel(ctx, tr!(*ctx, "road_channel_name"), ChannelType::Category, 0, permissions, None).await) }
+        None => { Err(create_channel(ctx, setup_name(ctx, &overrides, "road_channel_name"), ChannelType::Category, 0, permissions, None).await) }
         Some(channel_id) => {
             match ctx.http().get_channel(channel_id.id.into()).await {
                 Ok(channel) => { Ok(channel) }
                 Err(_) => {
-                    Err(create_channel(ctx, tr!(*ctx, "road_channel_name"), ChannelType::Category, 0, permissions, None).await)}
+                    Err(create_channel(ctx, setup_name(ctx, &overrides, "road_channel_name"), ChannelType::Category, 0, permissions, None).await)}
             }
         }
     };
 
+    // Only a missing road category is created, an existing one is returned as is
+    let road_category_created = usize::from(result_road_category.is_err());
     let road_category = match result_road_category {
         Ok(channel) => {channel.guild().unwrap()}
         Err(new_channel_result) => {
@@ -213,6 +145,7 @@ pub async fn partial_setup<'a>(ctx: &Context<'_>, server: &mut Server, snapshot:
             }
         }
     };
+    progress.done(road_category_created, 1).await;
     
     server.admin_role_id(Id{ id: admin_role.id.get(), id_type: IdType::Role })
         .moderator_role_id(Id{ id: moderator_role.id.get(), id_type: IdType::Role })
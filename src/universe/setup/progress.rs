@@ -0,0 +1,107 @@
+use poise::{CreateReply, ReplyHandle};
+use serenity::all::{Color, CreateEmbed};
+use crate::discord::poise_structs::Context;
+use crate::tr;
+
+/// A step of the setup reported to the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SetupPhase {
+    Roles,
+    RoadCategory,
+    Categories,
+    Channels,
+}
+
+impl SetupPhase {
+    fn translation_attr(&self) -> &'static str {
+        match self {
+            SetupPhase::Roles => "roles",
+            SetupPhase::RoadCategory => "road_category",
+            SetupPhase::Categories => "categories",
+            SetupPhase::Channels => "channels",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PhaseState {
+    Running,
+    /// How many resources of the phase this run created, out of the ones it needs.
+    Done { created: usize, expected: usize },
+    Failed,
+}
+
+/// Progress message of a running setup.
+///
+/// The message is sent when the setup starts and edited at the beginning and end of each phase,
+/// so the user sees what is happening instead of the deferred "thinking" state. Reporting is
+/// best effort: if the message cannot be sent or edited, the setup carries on.
+pub struct SetupProgress<'a> {
+    ctx: Context<'a>,
+    handle: Option<ReplyHandle<'a>>,
+    phases: Vec<(SetupPhase, PhaseState)>,
+}
+
+impl<'a> SetupProgress<'a> {
+    /// Sends the initial progress message.
+    pub async fn start(ctx: Context<'a>) -> Self {
+        let mut progress = SetupProgress { ctx, handle: None, phases: vec![] };
+        progress.handle = ctx.send(CreateReply::default().embed(progress.embed())).await.ok();
+        progress
+    }
+
+    /// Marks `phase` as running.
+    pub async fn begin(&mut self, phase: SetupPhase) {
+        self.phases.push((phase, PhaseState::Running));
+        self.refresh().await;
+    }
+
+    /// Marks the running phase as done, `expected` resources being ready of which `created` were
+    /// created by this run, the other ones being reused.
+    pub async fn done(&mut self, created: usize, expected: usize) {
+        if let Some((_, state)) = self.phases.last_mut() && *state == PhaseState::Running {
+            *state = PhaseState::Done { created, expected };
+        }
+        self.refresh().await;
+    }
+
+    /// Marks the running phase, if any, as failed.
+    pub async fn fail(&mut self) {
+        if let Some((_, state)) = self.phases.last_mut() && *state == PhaseState::Running {
+            *state = PhaseState::Failed;
+        }
+        self.refresh().await;
+    }
+
+    fn embed(&self) -> CreateEmbed {
+        let lines = self.phases.iter()
+            .map(|(phase, state)| {
+                let label = tr!(self.ctx, "setup__progress", @(phase.translation_attr()));
+                match state {
+                    PhaseState::Running => format!("⏳ {label}"),
+                    PhaseState::Done { created, expected } => {
+                        format!("✅ {label} ({})", tr!(self.ctx, "setup__progress", @created, created: *created, expected: *expected))
+                    }
+                    PhaseState::Failed => format!("❌ {label}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let color = if self.phases.iter().any(|(_, state)| *state == PhaseState::Failed) {
+            Color::from_rgb(255, 0, 0)
+        } else {
+            Color::from_rgb(0x6f, 0x00, 0xff)
+        };
+
+        CreateEmbed::new()
+            .title(tr!(self.ctx, "setup__progress", @title))
+            .description(lines)
+            .color(color)
+    }
+
+    async fn refresh(&self) {
+        if let Some(handle) = &self.handle {
+            let _ = handle.edit(self.ctx, CreateReply::default().embed(self.embed())).await;
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::{HashMap, HashSet};
+use fluent::FluentArgs;
+use serenity::all::{AutoArchiveDuration, ChannelType, CreateForumTag, EditChannel, RoleId};
+use crate::database::server::{get_server_by_id, Id, IdType, Server};
+use crate::discord::channels::{create_channel, create_channel_from_spec, ChannelSpec, get_admin_category_permission_set, get_road_category_permission_set, get_rp_character_permission_set, get_universal_time_permission_set, ITEM_TAG, PLACE_TAG, SPACE_TAG};
+use crate::discord::poise_structs::{Context, Error};
+use crate::discord::roles::{create_role, edit_role_positions, managed_role_positions};
+use crate::{tr, trn};
+use crate::universe::setup::names::{get_name_overrides, setup_name, setup_role_options};
+use crate::universe::status_sub_command::{resource_statuses, ResourceStatus};
+
+/// Fields the repair never recreates: the everyone role cannot be deleted and the README
+/// channel is not part of the setup.
+static NOT_REPAIRABLE: [&str; 2] = ["everyone_role_id", "readme_channel_id"];
+
+/// Roles the setup places beneath the highest role of the bot, from the highest.
+static MANAGED_ROLES: [&str; 4] = ["admin_role_id", "moderator_role_id", "spectator_role_id", "player_role_id"];
+
+/// Outcome of a repair, listing managed fields by what happened to them.
+#[derive(Debug, Default, PartialEq)]
+pub struct RepairSummary {
+    pub recreated: Vec<&'static str>,
+    pub untouched: Vec<&'static str>,
+    pub failed: Vec<&'static str>,
+}
+
+impl RepairSummary {
+    /// Sets the arguments of the `setup__repair_success` message on `args`: each list of localized
+    /// field labels under a heading counting them.
+    pub fn set_args(&self, ctx: &Context<'_>, args: &mut FluentArgs<'_>) {
+        let format = |heading: &str, fields: &[&'static str]| {
+            let mut lines = vec![trn!(*ctx, heading, fields.len())];
+            lines.extend(fields.iter().map(|field| format!("- {}", tr!(*ctx, "universe_status__row", @(field)))));
+            lines.join("\n")
+        };
+        args.set("recreated", format("setup__repair_recreated", &self.recreated));
+        args.set("untouched", format("setup__repair_untouched", &self.untouched));
+        args.set("failed", format("setup__repair_failed", &self.failed));
+    }
+}
+
+/// Splits the managed fields of `server` between the ones to recreate, stored but missing on
+/// Discord, and the ones left untouched because they still exist. Fields that were never
+/// configured are ignored.
+pub fn repair_plan(server: &Server, roles: &HashSet<u64>, channels: &HashSet<u64>) -> (Vec<&'static str>, Vec<&'static str>) {
+    let mut missing = vec![];
+    let mut untouched = vec![];
+    for (field, status) in resource_statuses(server, roles, channels) {
+        if NOT_REPAIRABLE.contains(&field) {
+            continue;
+        }
+        match status {
+            ResourceStatus::Missing => missing.push(field),
+            ResourceStatus::Present => untouched.push(field),
+            ResourceStatus::NotConfigured => {}
+        }
+    }
+    (missing, untouched)
+}
+
+/// Recreates only the resources of this server whose Discord object no longer exists.
+///
+/// Unlike the full and partial setups, the repair does not ask for confirmation and never
+/// deletes anything: resources that still exist are left as they are, and a resource that
+/// fails to be recreated is reported without rolling back the others. Roles are repaired
+/// before categories and categories before channels, so recreated channels get the
+/// permissions and parent of the current roles and categories. Once a role is recreated, the
+/// roles are moved back beneath the highest role of the bot, as the partial setup does.
+pub async fn repair_setup(ctx: &Context<'_>) -> Result<RepairSummary, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("setup__server_not_found".into()) };
+    let Some(mut server) = server_opt else { return Err("setup__server_not_found".into()) };
+
+    let Ok(roles) = ctx.http().get_guild_roles(guild_id).await else { return Err("partial_setup__get_guild_roles_error".into()) };
+    let Ok(channels) = ctx.http().get_channels(guild_id).await else { return Err("setup__dry_run_get_channels_error".into()) };
+    let roles: HashSet<u64> = roles.iter().map(|r| r.id.get()).collect();
+    let channels: HashSet<u64> = channels.iter().map(|c| c.id.get()).collect();
+
+    let overrides = get_name_overrides(server.universe_id).await;
+    let (missing, untouched) = repair_plan(&server, &roles, &channels);
+    let mut summary = RepairSummary { untouched, ..Default::default() };
+
+    for field in missing {
+        match recreate(ctx, &mut server, &overrides, field).await {
+            Ok(_) => summary.recreated.push(field),
+            Err(e) => {
+                tracing::warn!(guild_id = guild_id.get(), field, "failed to recreate resource: {e}");
+                summary.failed.push(field);
+            }
+        }
+    }
+
+    // A server restored from a backup is repaired once every missing resource is back
+    let repaired = server.needs_repair && summary.failed.is_empty();
+    if repaired {
+        server.needs_repair = false;
+    }
+    if !summary.recreated.is_empty() || repaired {
+        let Ok(_) = server.update().await else { return Err("setup__server_update_failed".into()) };
+    }
+    if summary.recreated.iter().any(|field| MANAGED_ROLES.contains(field)) {
+        reorder_roles(ctx, &server, &summary.failed).await?;
+    }
+
+    Ok(summary)
+}
+
+/// Moves the managed roles of `server` beneath the highest role of the bot, leaving out the
+/// `failed` ones which no longer exist, so that recreated roles do not stay at the bottom of
+/// the role list.
+async fn reorder_roles(ctx: &Context<'_>, server: &Server, failed: &[&'static str]) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let ids: HashMap<&str, Option<Id>> = server.managed_ids().into_iter().collect();
+    let managed: Vec<RoleId> = MANAGED_ROLES.iter()
+        .filter(|field| !failed.contains(field))
+        .filter_map(|field| ids.get(field).copied().flatten())
+        .map(|id| RoleId::new(id.id))
+        .collect();
+
+    let bot_id = ctx.cache().current_user().id;
+    let Ok(bot_member) = guild_id.member(ctx.http(), bot_id).await else { return Err("setup__reorder_went_wrong".into()) };
+    let Ok(guild_roles) = ctx.http().get_guild_roles(guild_id).await else { return Err("partial_setup__get_guild_roles_error".into()) };
+    let roles_pos = managed_role_positions(&guild_roles, &bot_member.roles, &managed)?;
+    let Ok(_) = edit_role_positions(ctx, guild_id, roles_pos).await else { return Err("setup__reorder_went_wrong".into()) };
+    Ok(())
+}
+
+/// Recreates the resource stored in `field`, under its current name, and stores its new id on `server`.
+async fn recreate(ctx: &Context<'_>, server: &mut Server, overrides: &HashMap<String, String>, field: &str) -> Result<(), Error> {
+    let everyone_role = ctx.guild_id().unwrap().everyone_role();
+    let role = |id: Option<Id>| id.map(|id| RoleId::new(id.id)).ok_or("setup__repair_missing_dependency");
+    let parent = |id: Option<Id>| id.map(|id| id.id).ok_or("setup__repair_missing_dependency");
+
+    match field {
+        "admin_role_id" => {
+            let role = create_role(ctx, setup_name(ctx, overrides, "admin_role_name"), setup_role_options(overrides, "admin_role_name")).await?;
+            server.admin_role_id((role.id.get(), IdType::Role));
+        }
+        "moderator_role_id" => {
+            let role = create_role(ctx, setup_name(ctx, overrides, "moderator_role_name"), setup_role_options(overrides, "moderator_role_name")).await?;
+            server.moderator_role_id((role.id.get(), IdType::Role));
+        }
+        "spectator_role_id" => {
+            let role = create_role(ctx, setup_name(ctx, overrides, "spectator_role_name"), setup_role_options(overrides, "spectator_role_name")).await?;
+            server.spectator_role_id((role.id.get(), IdType::Role));
+        }
+        "player_role_id" => {
+            let role = create_role(ctx, setup_name(ctx, overrides, "player_role_name"), setup_role_options(overrides, "player_role_name")).await?;
+            server.player_role_id((role.id.get(), IdType::Role));
+        }
+        "admin_category_id" => {
+            let permissions = get_admin_category_permission_set(everyone_role, role(server.spectator_role_id)?, role(server.player_role_id)?, role(server.moderator_role_id)?);
+            let category = create_channel(ctx, setup_name(ctx, overrides, "admin_category_name"), ChannelType::Category, 0, permissions, None).await?;
+            server.admin_category_id((category.id.get(), IdType::Category));
+        }
+        "nrp_category_id" => {
+            let category = create_channel(ctx, setup_name(ctx, overrides, "nrp_category_name"), ChannelType::Category, 1, vec![], None).await?;
+            server.nrp_category_id((category.id.get(), IdType::Category));
+        }
+        "rp_category_id" => {
+            let category = create_channel(ctx, setup_name(ctx, overrides, "rp_category_name"), ChannelType::Category, 1, vec![], None).await?;
+            server.rp_category_id((category.id.get(), IdType::Category));
+        }
+        "road_category_id" => {
+            let permissions = get_road_category_permission_set(everyone_role, role(server.player_role_id)?, role(server.spectator_role_id)?, role(server.moderator_role_id)?);
+            let category = create_channel(ctx, setup_name(ctx, overrides, "road_channel_name"), ChannelType::Category, 0, permissions, None).await?;
+            server.road_category_id((category.id.get(), IdType::Category));
+        }
+        "log_channel_id" => {
+            let channel = create_channel(ctx, setup_name(ctx, overrides, "log_channel_name"), ChannelType::Text, 0, vec![], Some(parent(server.admin_category_id)?)).await?;
+            server.log_channel_id((channel.id.get(), IdType::Channel));
+        }
+        "commands_channel_id" => {
+            let spec = ChannelSpec::new(setup_name(ctx, overrides, "commands_channel_name"), ChannelType::Text)
+                .parent(parent(server.admin_category_id)?)
+                .topic(tr!(*ctx, "commands_channel_topic"));
+            let channel = create_channel_from_spec(ctx, spec).await?;
+            server.commands_channel_id((channel.id.get(), IdType::Channel));
+        }
+        "moderation_channel_id" => {
+            let channel = create_channel(ctx, setup_name(ctx, overrides, "moderation_channel_name"), ChannelType::Text, 0, vec![], Some(parent(server.admin_category_id)?)).await?;
+            server.moderation_channel_id((channel.id.get(), IdType::Channel));
+        }
+        "nrp_general_channel_id" => {
+            let channel = create_channel(ctx, setup_name(ctx, overrides, "nrp_general_channel_name"), ChannelType::Text, 0, vec![], Some(parent(server.nrp_category_id)?)).await?;
+            server.nrp_general_channel_id((channel.id.get(), IdType::Channel));
+        }
+        "rp_character_channel_id" => {
+            let permissions = get_rp_character_permission_set(role(server.player_role_id)?);
+            let channel = create_channel(ctx, setup_name(ctx, overrides, "rp_character_channel_name"), ChannelType::Text, 0, permissions, Some(parent(server.rp_category_id)?)).await?;
+            server.rp_character_channel_id((channel.id.get(), IdType::Channel));
+        }
+        "rp_wiki_channel_id" => {
+            let spec = ChannelSpec::new(setup_name(ctx, overrides, "rp_wiki_channel_name"), ChannelType::Forum)
+                .parent(parent(server.rp_category_id)?)
+                .default_auto_archive_duration(AutoArchiveDuration::OneWeek);
+            let mut channel = create_channel_from_spec(ctx, spec).await?;
+            let _ = channel.edit(ctx, EditChannel::new().available_tags(vec![
+                CreateForumTag::new(PLACE_TAG),
+                CreateForumTag::new(SPACE_TAG),
+                CreateForumTag::new(ITEM_TAG),
+            ])).await;
+            server.rp_wiki_channel_id((channel.id.get(), IdType::Channel));
+        }
+        "universal_time_channel_id" => {
+            let permissions = get_universal_time_permission_set(everyone_role);
+            let channel = create_channel(ctx, setup_name(ctx, overrides, "universal_time_channel_name"), ChannelType::Text, 0, permissions, Some(parent(server.rp_category_id)?)).await?;
+            server.universal_time_channel_id((channel.id.get(), IdType::Channel));
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_repair_plan() {
+        let mut server = Server::default();
+        server.admin_role_id((1, IdType::Role))
+            .moderator_role_id((2, IdType::Role))
+            .everyone_role_id((3, IdType::Role))
+            .road_category_id((10, IdType::Category))
+            .log_channel_id((11, IdType::Channel));
+        let roles: HashSet<u64> = [1].into_iter().collect();
+        let channels: HashSet<u64> = [11].into_iter().collect();
+
+        let (missing, untouched) = repair_plan(&server, &roles, &channels);
+        // The everyone role is never recreated, and fields never configured are left alone
+        assert_eq!(missing, vec!["moderator_role_id", "road_category_id"]);
+        assert_eq!(untouched, vec!["admin_role_id", "log_channel_id"]);
+    }
+}
@@ -1,11 +1,18 @@
-use poise::{CreateReply};
-use serenity::all::{ButtonStyle, Color, ComponentInteractionCollector, CreateActionRow, CreateButton, CreateEmbed};
+use std::time::Duration;
+use fluent::FluentArgs;
 use crate::database::server::{get_server_by_id};
+use crate::database::universe::get_universe_by_server_id;
+use crate::discord::audit::log_action;
 use crate::discord::poise_structs::{Context, Error};
-use crate::tr;
+use crate::discord::roles::preflight;
+use crate::universe::authorization::require_universe_creator;
+use crate::universe::setup::dry_run::_setup_dry_run;
 use crate::universe::setup::full_setup::full_setup;
 use crate::universe::setup::partial_setup::partial_setup;
-use crate::utility::reply::reply;
+use crate::universe::setup::progress::SetupProgress;
+use crate::universe::setup::repair_setup::repair_setup;
+use crate::utility::confirm::{confirm, ConfirmOutcome};
+use crate::utility::reply::{reply, reply_with_args};
 
 ///  * Enum representing the type of setup to be performed.
 ///  *
@@ -16,11 +23,13 @@ use crate::utility::reply::reply;
 ///  * Variants:
 ///  * - `FullSetup`: Represents a complete setup process.
 ///  * - `PartialSetup`: Represents a partial or incomplete setup process.
+///  * - `RepairSetup`: Recreates only the resources deleted from Discord, without confirmation.
 
-#[derive(Debug, poise::ChoiceParameter, Clone, Copy)]
+#[derive(Debug, poise::ChoiceParameter, Clone, Copy, PartialEq, Eq)]
 pub enum SetupType {
     FullSetup,
-    PartialSetup
+    PartialSetup,
+    RepairSetup
 }
 
 /// Sets up the bot or configuration based on the provided setup type.
@@ -28,19 +37,23 @@ pub enum SetupType {
 /// # Parameters
 /// - `ctx`: The command context, providing access to interaction details, bot state, and more.
 /// - `setup_type`: The type of setup to perform, specified by the `SetupType` enum.
+/// - `dry_run`: When `true`, only reports which resources would be created or reused (defaults to `false`).
 ///
 /// # Returns
 /// - `Result<(), Error>`: Returns `Ok(())` if the setup process completes successfully, or an `Error` if it fails.
 ///
 /// # Command Attributes
 /// - `slash_command`: This function is executable as a slash command.
-/// - `required_permissions = "ADMINISTRATOR"`: Only users with administrator permissions in the guild can use this command.
+/// - `required_permissions = "ADMINISTRATOR"`: Only users with administrator permissions in the guild can use this command,
+///   and only the creator of the universe gets past the check of `authorize`.
 /// - `guild_only`: The command can only be invoked in a guild context, not in direct messages.
 ///
 /// # Behavior
 /// 1. Defers the response to provide more time for the execution.
-/// 2. Delegates the main setup logic to a helper function `_setup`, passing in the context and the setup type.
-/// 3. Replies to the user with the result of the setup process.
+/// 2. Delegates the main setup logic to a helper function `_setup`, passing in the context and the setup type,
+///    or to `_setup_dry_run` in dry-run mode, which skips the confirmation buttons and writes nothing.
+/// 3. Replies to the user with the result of the setup process, or with the list of recreated and
+///    untouched resources for a `RepairSetup`.
 ///
 /// # Errors
 /// This function may return an error if:
@@ -54,24 +67,59 @@ pub enum SetupType {
 pub async fn setup(
     ctx: Context<'_>,
     #[description = "universe_setup.setup_type"]
-    setup_type: SetupType
+    setup_type: SetupType,
+    #[description = "universe_setup.dry_run"]
+    dry_run: Option<bool>
 ) -> Result<(), Error> {
     let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
-    let result = _setup(&ctx, setup_type).await;
-    let Ok(_) = reply(ctx, result).await else { return Err("reply__reply_failed".into()) };
+    if let Err(e) = authorize(&ctx).await {
+        let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+        return Ok(());
+    }
+    if dry_run.unwrap_or(false) {
+        if let Err(e) = _setup_dry_run(&ctx, setup_type).await {
+            let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+        }
+        return Ok(());
+    }
+    let mut args = FluentArgs::new();
+    let result = _setup(&ctx, setup_type, &mut args).await;
+    let Ok(_) = reply_with_args(ctx, result, Some(args)).await else { return Err("reply__reply_failed".into()) };
     Ok(())
 }
 
+/// Checks that the author created the universe of the server: the setup can delete and
+/// recreate every resource of the server, which the administrator permission alone does not allow.
+///
+/// # Errors
+/// - `"setup__server_not_found"`: the server is not part of a universe.
+/// - `"universe__not_universe_creator"`: the author did not create the universe.
+/// - `"verify__database_error"`: a query failed.
+pub async fn authorize(ctx: &Context<'_>) -> Result<(), Error> {
+    let universe = match get_universe_by_server_id(ctx.guild_id().unwrap().get()).await {
+        Ok(Some(universe)) => universe,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+    require_universe_creator(ctx, &universe).await
+}
+
 /// Asynchronously initializes or reconfigures the server setup process based on the provided setup type.
 ///
 /// # Arguments
 /// * `ctx` - The context of the command, which includes the guild and channel information where the command was triggered.
-/// * `setup_type` - An enum representing the type of setup to perform. Can be either `FullSetup` or `PartialSetup`.
+/// * `setup_type` - An enum representing the type of setup to perform. Can be `FullSetup`, `PartialSetup` or `RepairSetup`.
+/// * `args` - Receives what the bot is missing when it cannot run the setup, or the recreated and
+///   untouched resources of a `RepairSetup`, for `setup__repair_success`.
 ///
 /// # Returns
 /// `Result<&'static str, Error>` - Returns a success message if the setup process completes successfully, or an error message if the operation fails.
 ///
 /// # Workflow
+/// Every setup type first checks with `preflight` that the bot can create and give roles and
+/// channels, so that it never stops halfway for lack of permissions.
+/// A `RepairSetup` only recreates the resources deleted from Discord (see `repair_setup`), without
+/// confirmation since it never deletes anything. The other setup types go through `rebuild`:
 /// 1. Retrieves the `guild_id` from the context.
 /// 2. Fetches the server from the database by its `guild_id`. If the server is not found, an error is returned.
 /// 3. Checks if the server has any existing setup configuration (roles, categories, or channels):
@@ -82,6 +130,9 @@ pub async fn setup(
 /// 4. Executes either a full or partial setup based on the `setup_type` provided:
 ///    - `FullSetup`: Performs a comprehensive setup with all components of the server.
 ///    - `PartialSetup`: Configures only a subset of the server based on specific criteria.
+///
+///    A progress message is sent beforehand and edited after each phase (roles, categories, channels),
+///    showing the phase that failed if the setup does not complete.
 /// 5. Updates the server configuration in the database.
 /// 6. Returns a success message if the setup completes successfully, or an error message if an error occurs.
 ///
@@ -95,94 +146,72 @@ pub async fn setup(
 ///   and the process is aborted, returning a timeout error.
 ///
 /// # Errors
+/// - `"preflight__missing_permissions"`: The bot cannot manage roles or channels, `args` lists why.
+/// - `"setup__already_in_progress"`: A setup, place or road creation is already running on this guild.
 /// - `"setup__server_not_found"`: The server was not found in the database.
 /// - `"setup__server_already_setup_timeout"`: The user did not respond to the interactive buttons within the timeout period.
 /// - `"setup_server__cancelled"`: The user chose to cancel the setup process.
 /// - `"setup_server__failed"`: A generic error indicating that the setup process encountered an issue.
+/// - The errors of `repair_setup` for a `RepairSetup`.
 ///
 /// # Example Usage
 /// ```rust
-/// let result = _setup(ctx, SetupType::FullSetup).await;
+/// let result = _setup(ctx, SetupType::FullSetup, &mut FluentArgs::new()).await;
 /// match result {
 ///     Ok(message) => println!("{}", message), // Prints "setup_server__success" on success.
 ///     Err(error) => eprintln!("{}", error),    // Prints error messages like "setup__server_not_found".
 /// }
 /// ```
-pub async fn _setup(ctx: &Context<'_>, setup_type: SetupType) -> Result<&'static str, Error> {
+pub async fn _setup(ctx: &Context<'_>, setup_type: SetupType, args: &mut FluentArgs<'_>) -> Result<&'static str, Error> {
+    preflight(ctx, args).await?;
+    match setup_type {
+        // The repair never deletes anything, so it does not need the confirmation prompt
+        SetupType::RepairSetup => {
+            repair_setup(ctx).await?.set_args(ctx, args);
+            Ok("setup__repair_success")
+        }
+        SetupType::FullSetup => rebuild(ctx, true).await,
+        SetupType::PartialSetup => rebuild(ctx, false).await,
+    }
+}
+
+/// Runs a full setup when `full`, else a partial one, after asking before replacing an existing
+/// configuration. See [`_setup`].
+async fn rebuild(ctx: &Context<'_>, full: bool) -> Result<&'static str, Error> {
     let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
 
     let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("setup__server_not_found".into()) };
     let Some(mut server) = server_opt else { return Err("setup__server_not_found".into()) };
-    let server_snapshot = server.clone().snaphot(ctx).await;
-
-    if server.admin_role_id.is_some()
-        || server.moderator_role_id.is_some()
-        || server.spectator_role_id.is_some()
-        || server.player_role_id.is_some()
-        || server.road_category_id.is_some()
-        || server.rp_wiki_channel_id.is_some()
-        || server.admin_category_id.is_some()
-        || server.nrp_category_id.is_some()
-        || server.rp_category_id.is_some()
-        || server.rp_character_channel_id.is_some() {
-
-        let reply = {
-            let components = vec![CreateActionRow::Buttons(vec![
-                CreateButton::new("cancel")
-                    .style(ButtonStyle::Primary)
-                    .label(tr!(*ctx, "cancel_setup")),
-                CreateButton::new("continue")
-                    .style(ButtonStyle::Danger)
-                    .label(tr!(*ctx, "continue_setup")),
-            ])];
+    let server_snapshot = server.clone().snapshot(ctx).await;
 
-            CreateReply::default()
-                .embed(
-                    CreateEmbed::new()
-                        .color(Color::from_rgb(0xff, 0x98, 0))
-                        .title(crate::translation::get(*ctx, &"setup__continue_setup_message", Some("title"), None))
-                        .description(crate::translation::get(*ctx, &"setup__continue_setup_message", Some("message"), None))
-                )
-                .components(components)
-        };
+    if server.is_setup() {
 
-        let message = ctx.send(reply.clone()).await.unwrap();
-
-        let serenity_context = ctx.serenity_context();
-
-        let interaction = ComponentInteractionCollector::new(&serenity_context)
-            .author_id(ctx.author().id)
-            .channel_id(ctx.channel_id())
-            .timeout(std::time::Duration::from_secs(60))
-            .await;
-        match interaction {
-            None => {
-                let Ok(_) = message.delete(*ctx).await else { return Err("setup_server__failed".into()) };
-                return Err("setup__server_already_setup_timeout".into());
-            }
-            Some(mci) => {
-                let Ok(_) = mci.defer(ctx).await else { return Err("setup_server__failed".into()) };
-                let Ok(_) = message.edit(*ctx, reply.components(vec![])).await else { return Err("setup_server__failed".into()) };
-                match mci.data.custom_id.as_str() {
-                    "cancel" => {
-                        let Ok(_) = message.delete(*ctx).await else { return Err("setup_server__failed".into()) };
-                        return Ok("setup_server__cancelled");
-                    }
-                    _ => {}
-                };
-            }
-        };
+        match confirm(*ctx, "setup__continue_setup_message", None, Duration::from_secs(60)).await? {
+            ConfirmOutcome::Confirmed => {}
+            ConfirmOutcome::Cancelled => return Ok("setup_server__cancelled"),
+            ConfirmOutcome::TimedOut => return Err("setup__server_already_setup_timeout".into()),
+        }
     }
 
-    let result = match setup_type {
-        SetupType::FullSetup => { full_setup(ctx, &mut server, server_snapshot).await }
-        SetupType::PartialSetup => { partial_setup(ctx, &mut server, server_snapshot).await }
+    let mut progress = SetupProgress::start(*ctx).await;
+    let result = if full {
+        full_setup(ctx, &mut server, server_snapshot, &mut progress).await
+    } else {
+        partial_setup(ctx, &mut server, server_snapshot, &mut progress).await
     };
 
+    if result.is_err() {
+        progress.fail().await;
+    }
+
     let Ok(_) = server.update().await else { return Err("setup__server_update_failed".into()) };
 
     match result {
-        Ok(_) => { Ok("setup_server__success") }
+        Ok(_) => {
+            log_action(ctx, &server, "setup__log", FluentArgs::new()).await;
+            Ok("setup_server__success")
+        }
         Err(_) => { Err("setup_server__failed".into()) }
     }
 }
\ No newline at end of file
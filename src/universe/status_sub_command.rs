@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+use poise::CreateReply;
+use serenity::all::{Color, CreateEmbed};
+use crate::database::server::{get_server_by_id, IdType, Server};
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::utility::reply::reply;
+
+/// State of a resource managed by the setup.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResourceStatus {
+    /// The id is stored and still resolves on Discord.
+    Present,
+    /// The id is stored but the resource no longer exists on Discord.
+    Missing,
+    /// The setup never stored an id for this resource.
+    NotConfigured,
+}
+
+impl ResourceStatus {
+    fn marker(&self) -> &'static str {
+        match self {
+            ResourceStatus::Present => "✅",
+            ResourceStatus::Missing => "❌",
+            ResourceStatus::NotConfigured => "➖",
+        }
+    }
+
+    fn translation_attr(&self) -> &'static str {
+        match self {
+            ResourceStatus::Present => "present",
+            ResourceStatus::Missing => "missing",
+            ResourceStatus::NotConfigured => "not_configured",
+        }
+    }
+}
+
+/// Resolves every managed field of `server` against the roles and channels present on Discord.
+pub fn resource_statuses(server: &Server, roles: &HashSet<u64>, channels: &HashSet<u64>) -> Vec<(&'static str, ResourceStatus)> {
+    server.managed_ids()
+        .into_iter()
+        .map(|(name, id)| {
+            let status = match id {
+                None => ResourceStatus::NotConfigured,
+                Some(id) => {
+                    let exists = match id.id_type {
+                        IdType::Role => roles.contains(&id.id),
+                        IdType::Channel | IdType::Category => channels.contains(&id.id),
+                    };
+                    if exists { ResourceStatus::Present } else { ResourceStatus::Missing }
+                }
+            };
+            (name, status)
+        })
+        .collect()
+}
+
+/// Shows which resources managed by the setup exist on this server.
+///
+/// Read-only: every stored role, category and channel is looked up on Discord and listed as
+/// present, missing or not configured. Available to moderators as well as administrators.
+#[poise::command(slash_command, required_permissions = "MANAGE_MESSAGES", guild_only, rename = "universe_status")]
+pub async fn status(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer_ephemeral().await else { return Err("reply__reply_failed".into()) };
+    if let Err(e) = _status(&ctx).await {
+        let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+    }
+    Ok(())
+}
+
+async fn _status(ctx: &Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let server = match get_server_by_id(guild_id.get()).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    };
+
+    let Ok(roles) = ctx.http().get_guild_roles(guild_id).await else { return Err("verify__discord_error".into()) };
+    let Ok(channels) = ctx.http().get_channels(guild_id).await else { return Err("verify__discord_error".into()) };
+    let roles: HashSet<u64> = roles.iter().map(|r| r.id.get()).collect();
+    let channels: HashSet<u64> = channels.iter().map(|c| c.id.get()).collect();
+
+    let lines = resource_statuses(&server, &roles, &channels)
+        .into_iter()
+        .map(|(name, status)| format!(
+            "{} **{}** — {}",
+            status.marker(),
+            tr!(*ctx, "universe_status__row", @(name)),
+            tr!(*ctx, "universe_status__state", @(status.translation_attr())),
+        ))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let embed = CreateEmbed::new()
+        .title(tr!(*ctx, "universe_status__report", @title))
+        .description(lines)
+        .color(Color::from_rgb(0x6f, 0x00, 0xff));
+
+    let Ok(_) = ctx.send(CreateReply::default().embed(embed).ephemeral(true)).await else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resource_statuses() {
+        let mut server = Server::default();
+        server.admin_role_id((1, IdType::Role))
+            .moderator_role_id((2, IdType::Role))
+            .road_category_id((10, IdType::Category));
+        let roles: HashSet<u64> = [1].into_iter().collect();
+        let channels: HashSet<u64> = [10].into_iter().collect();
+
+        let statuses = resource_statuses(&server, &roles, &channels);
+        let status_of = |field| statuses.iter().find(|(name, _)| *name == field).unwrap().1;
+        assert_eq!(statuses.len(), server.managed_ids().len());
+        assert_eq!(status_of("admin_role_id"), ResourceStatus::Present);
+        assert_eq!(status_of("moderator_role_id"), ResourceStatus::Missing);
+        assert_eq!(status_of("road_category_id"), ResourceStatus::Present);
+        assert_eq!(status_of("log_channel_id"), ResourceStatus::NotConfigured);
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+use std::time::Duration;
+use fluent::FluentArgs;
+use crate::database::places::{delete_place_by_category_id, get_places_by_server_id, Place};
+use crate::database::road::{delete_roads_by_discord_id, get_roads_by_server_id, Road};
+use crate::database::server::{get_server_by_id, Id, IdExt, IdType, Server};
+use crate::database::universe::get_universe_by_id;
+use crate::discord::audit::{log_action, notify_moderation};
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::authorization::require_universe_creator;
+use crate::utility::confirm::{confirm, ConfirmOutcome};
+use crate::utility::reply::{reply, reply_with_args};
+
+/// Delay between two deletions, to stay below Discord's rate limits on large servers.
+static TEARDOWN_DELAY: Duration = Duration::from_millis(500);
+
+/// Counts of what happened to the resources targeted by a teardown.
+#[derive(Debug, Default, PartialEq)]
+pub struct TeardownSummary {
+    pub deleted: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+/// Lists every role, category and channel managed by the bot on this server: the ones stored on
+/// the `Server` document and the ones of its places and roads. The everyone role is left out,
+/// and an id referenced twice is only listed once.
+pub fn teardown_targets(server: &Server, places: &[Place], roads: &[Road]) -> Vec<Id> {
+    let mut seen = HashSet::new();
+    let server_ids = server.stored_ids()
+        .into_iter()
+        .filter(|(name, _)| *name != "everyone_role_id")
+        .map(|(_, id)| id);
+    let place_ids = places.iter().flat_map(|place| {
+        let children = [place.text_channel_id, place.voice_channel_id].into_iter()
+            .flatten()
+            .map(|id| Id { id, id_type: IdType::Channel });
+        children.chain([
+            Id { id: place.category_id, id_type: IdType::Category },
+            Id { id: place.role, id_type: IdType::Role },
+        ])
+    });
+    let road_ids = roads.iter().flat_map(|road| [
+        Id { id: road.channel_id, id_type: IdType::Channel },
+        Id { id: road.role_id, id_type: IdType::Role },
+    ]);
+
+    server_ids.chain(place_ids).chain(road_ids)
+        .filter(|id| seen.insert(id.id))
+        .collect()
+}
+
+/// Deletes every role, category and channel managed by the bot on this server.
+///
+/// The deletion is destructive, so only the creator of the universe can run it, and it goes
+/// through the same button confirmation as the setup.
+/// Resources that no longer exist on Discord are skipped instead of failing the teardown,
+/// and the ids of deleted or skipped resources are cleared from the database.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_teardown")]
+pub async fn teardown(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+    let sent = match _teardown(&ctx).await {
+        Ok(Some(summary)) => {
+            let mut args = FluentArgs::new();
+            args.set("deleted", summary.deleted);
+            args.set("failed", summary.failed);
+            args.set("skipped", summary.skipped);
+            reply_with_args(ctx, Ok("universe_teardown__success"), Some(args)).await
+        }
+        Ok(None) => reply(ctx, Ok("universe_teardown__cancelled")).await,
+        Err(e) => reply(ctx, Err(e)).await,
+    };
+    let Ok(_) = sent else { return Err("reply__reply_failed".into()) };
+    Ok(())
+}
+
+/// Checks that the author created the universe, asks for confirmation, then tears the server
+/// down. Returns `None` when the user cancels.
+async fn _teardown(ctx: &Context<'_>) -> Result<Option<TeardownSummary>, Error> {
+    let guild_id = ctx.guild_id().unwrap();
+    let Some(_guild_lock) = ctx.data().guild_locks.try_lock(guild_id.get()) else { return Err("setup__already_in_progress".into()) };
+
+    let Ok(server_opt) = get_server_by_id(guild_id.get()).await else { return Err("setup__server_not_found".into()) };
+    let Some(mut server) = server_opt else { return Err("setup__server_not_found".into()) };
+    match get_universe_by_id(server.universe_id).await {
+        Ok(Some(universe)) => require_universe_creator(ctx, &universe).await?,
+        Ok(None) => return Err("setup__server_not_found".into()),
+        Err(_) => return Err("verify__database_error".into()),
+    }
+
+    match confirm(*ctx, "universe_teardown__confirm", None, Duration::from_secs(60)).await? {
+        ConfirmOutcome::Confirmed => {}
+        ConfirmOutcome::Cancelled => return Ok(None),
+        ConfirmOutcome::TimedOut => return Err("universe_teardown__timeout".into()),
+    }
+
+    let Ok(places) = get_places_by_server_id(guild_id.get()).await else { return Err("verify__database_error".into()) };
+    let Ok(roads) = get_roads_by_server_id(guild_id.get()).await else { return Err("verify__database_error".into()) };
+    let Ok(roles) = ctx.http().get_guild_roles(guild_id).await else { return Err("verify__discord_error".into()) };
+    let Ok(channels) = ctx.http().get_channels(guild_id).await else { return Err("verify__discord_error".into()) };
+    let roles: HashSet<u64> = roles.iter().map(|r| r.id.get()).collect();
+    let channels: HashSet<u64> = channels.iter().map(|c| c.id.get()).collect();
+
+    let mut summary = TeardownSummary::default();
+    let mut gone = vec![];
+    for id in teardown_targets(&server, &places, &roads) {
+        let exists = match id.id_type {
+            IdType::Role => roles.contains(&id.id),
+            IdType::Channel | IdType::Category => channels.contains(&id.id),
+        };
+        if !exists {
+            summary.skipped += 1;
+            gone.push(id.id);
+            continue;
+        }
+
+        match Some(id).delete(ctx).await {
+            Ok(_) => {
+                summary.deleted += 1;
+                gone.push(id.id);
+            }
+            Err(e) => {
+                tracing::warn!(guild_id = guild_id.get(), id = id.id, "teardown failed to delete resource: {e}");
+                summary.failed += 1;
+            }
+        }
+        tokio::time::sleep(TEARDOWN_DELAY).await;
+    }
+
+    for id in gone {
+        server.clear_id(id);
+        if let Err(e) = delete_place_by_category_id(server.universe_id, id).await {
+            tracing::error!(guild_id = guild_id.get(), "failed to delete place {id}: {e:?}");
+        }
+        if let Err(e) = delete_roads_by_discord_id(server.universe_id, id).await {
+            tracing::error!(guild_id = guild_id.get(), "failed to delete roads of {id}: {e:?}");
+        }
+    }
+    let Ok(_) = server.update().await else { return Err("setup__server_update_failed".into()) };
+
+    // Only reach the log and moderation channels when their deletion failed
+    let audit_args = || FluentArgs::from_iter([("deleted", summary.deleted), ("failed", summary.failed)]);
+    notify_moderation(ctx, &server, "universe_teardown__notice", audit_args()).await;
+    log_action(ctx, &server, "universe_teardown__log", audit_args()).await;
+    Ok(Some(summary))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_teardown_targets() {
+        let mut server = Server::default();
+        server.admin_role_id((1, IdType::Role))
+            .everyone_role_id((2, IdType::Role))
+            .road_category_id((10, IdType::Category));
+        let places = vec![Place { role: 21, text_channel_id: Some(22), ..Place::named("Town", 20) }];
+
+        let ids: Vec<u64> = teardown_targets(&server, &places, &[]).iter().map(|id| id.id).collect();
+        // The channels of a place are deleted before its category
+        assert_eq!(ids, vec![1, 10, 22, 20, 21]);
+    }
+}
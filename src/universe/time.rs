@@ -8,7 +8,9 @@ use std::sync::Arc;
 use mongodb::bson::oid::ObjectId;
 use crate::database::universe::Universe;
 use crate::tr_locale;
+use crate::translation::guild_locale_or_main;
 use crate::travel::logic::HTTP_CLIENT;
+use crate::utility::tasks::TASKS;
 
 pub static TIME_SLEEPER: Lazy<Arc<Mutex<Option<JoinHandle<()>>>>> = Lazy::new(|| Arc::new(Mutex::new(None)));
 pub static PENDING_TIME_EVENTS: Lazy<Arc<Mutex<Vec<TimeEvent>>>> = Lazy::new(|| Arc::new(Mutex::new(vec![])));
@@ -52,7 +54,7 @@ pub async fn setup_universal_time() {
     let universes = match Universe::get_all_universes().await {
         Ok(u) => u,
         Err(e) => {
-            eprintln!("Failed to get universes for universal time setup: {:?}", e);
+            tracing::error!("failed to get the universes for the universal time: {e}");
             return;
         }
     };
@@ -83,7 +85,7 @@ pub async fn setup_universal_time() {
     }
 
     if events.is_empty() {
-        println!("Universal Time system initialized: 0 active universes.");
+        tracing::info!("universal time initialized: 0 active universes");
         return;
     }
 
@@ -105,13 +107,40 @@ pub async fn setup_universal_time() {
         let delay = event.trigger_timestamp.saturating_sub(now);
         let mut sleeper = TIME_SLEEPER.lock().await;
         *sleeper = Some(time_process(delay));
-        println!("Universal Time system initialized: {} universes. Next event in {}s", PENDING_TIME_EVENTS.lock().await.len(), delay);
+        tracing::info!("universal time initialized: {} universes, next event in {}s", PENDING_TIME_EVENTS.lock().await.len(), delay);
     }
 }
 
+/// Length of a day of a universe, in milliseconds of the time of the universe.
+static UNIVERSE_DAY_MS: u128 = 86_400_000;
+
+/// Origin, in milliseconds, from which the time of a universe running at `new_modifier` percent
+/// shows at `now_ms` the same time of day as from `origin_ms` at `old_modifier` percent.
+pub fn rebased_time_origin(now_ms: u128, origin_ms: u128, old_modifier: u32, new_modifier: u32) -> u128 {
+    if new_modifier == 0 {
+        return origin_ms;
+    }
+    let time_of_day_ms = now_ms.saturating_sub(origin_ms) * old_modifier as u128 / 100 % UNIVERSE_DAY_MS;
+    now_ms.saturating_sub(time_of_day_ms * 100 / new_modifier as u128)
+}
+
+/// Schedules the phase announcements of every universe again, after the time of one changed.
+pub async fn reschedule_universal_time() {
+    if let Some(handle) = TIME_SLEEPER.lock().await.take() {
+        handle.abort();
+    }
+    PENDING_TIME_EVENTS.lock().await.clear();
+    setup_universal_time().await;
+}
+
 fn time_process(delay: u64) -> JoinHandle<()> {
+    let mut shutdown = TASKS.shutdown_signal();
     tokio::spawn(async move {
-        tokio::time::sleep(Duration::from_secs(delay)).await;
+        // Nothing is lost by stopping while waiting: the events are scheduled again at startup
+        if !shutdown.sleep(Duration::from_secs(delay)).await {
+            return;
+        }
+        let _in_flight = TASKS.track();
 
         let mut next_delay: Option<u64> = None;
 
@@ -207,10 +236,7 @@ async fn process_universe_phase_change(universe_id: mongodb::bson::oid::ObjectId
         match cursor.try_next().await {
             Ok(Some(server)) => {
                 if let Some(channel_id) = server.universal_time_channel_id {
-                    let locale = match http.get_guild(server.server_id.into()).await {
-                        Ok(g) => g.preferred_locale,
-                        Err(_) => "fr".to_string()
-                    };
+                    let locale = guild_locale_or_main(server.server_id).await;
                     let msg = tr_locale!(locale.as_str(), phase.get_message_key());
                     let http_clone = http.clone();
                     let channel = ChannelId::new(channel_id.id);
@@ -246,3 +272,19 @@ async fn process_universe_phase_change(universe_id: mongodb::bson::oid::ObjectId
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rebased_time_origin() {
+        // Ten thousand seconds into the universe at normal speed, then twice as fast
+        let origin = rebased_time_origin(10_000_000, 0, 100, 200);
+        assert_eq!(origin, 5_000_000);
+        assert_eq!((10_000_000 - origin) * 200 / 100, 10_000_000);
+        // Only the time of day is kept, not the number of days elapsed
+        assert_eq!(rebased_time_origin(UNIVERSE_DAY_MS * 3 + 1000, 0, 100, 100), UNIVERSE_DAY_MS * 3);
+        assert_eq!(rebased_time_origin(1000, 0, 100, 0), 0);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
 use crate::database::universe::{get_universe_by_server_id};
 use crate::universe::time::TimePhase;
 use chrono::Utc;
@@ -38,7 +39,7 @@ pub async fn time(ctx: Context<'_>) -> Result<(), Error> {
     let rp_minutes = ((rp_total_seconds % 3600.0) / 60.0) as u32;
     
     let rp_time_str = format!("{:02}:{:02}", rp_hours, rp_minutes);
-    let phase_name = crate::translation::get(ctx, phase.get_message_key(), None, None);
+    let phase_name = tr!(ctx, phase.get_message_key());
 
     let mut args = FluentArgs::new();
     args.set("time", rp_time_str);
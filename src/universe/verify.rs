@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::env;
+use std::time::Duration;
+use fluent::FluentArgs;
+use serenity::all::{ChannelId, Color, CreateEmbed, CreateMessage, GuildId, Http};
+use crate::database::places::{get_places_by_server_id, Place};
+use crate::database::road::{get_roads_by_server_id, Road};
+use crate::database::server::{get_all_servers, IdType, Server};
+use crate::discord::poise_structs::Error;
+use crate::translation::get_by_locale;
+use crate::travel::logic::HTTP_CLIENT;
+use crate::utility::tasks::TASKS;
+
+/// Environment variable overriding the delay between two consistency checks, in seconds.
+static VERIFY_INTERVAL_VAR: &str = "CONSISTENCY_CHECK_INTERVAL_SECS";
+static DEFAULT_VERIFY_INTERVAL_SECS: u64 = 86400;
+/// Maximum number of lines listed per section, to stay below Discord's embed size limit.
+static MAX_REPORT_LINES: usize = 30;
+
+/// A channel as seen on Discord when the check runs.
+#[derive(Debug, Clone)]
+pub struct LiveChannel {
+    pub id: u64,
+    pub name: String,
+    pub parent_id: Option<u64>,
+}
+
+/// Differences between the database and the live Discord state of a guild.
+///
+/// `missing` lists resources referenced in the database that no longer exist on Discord,
+/// `orphaned` lists channels of the road category that no road references.
+#[derive(Debug, Default, PartialEq)]
+pub struct DriftReport {
+    pub missing: Vec<String>,
+    pub orphaned: Vec<String>,
+}
+
+impl DriftReport {
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.orphaned.is_empty()
+    }
+
+    /// Builds the report embed in the given locale.
+    pub fn to_embed(&self, locale: &str) -> CreateEmbed {
+        if self.is_empty() {
+            return CreateEmbed::new()
+                .title(get_by_locale(locale, "verify__no_drift", Some("title"), None))
+                .description(get_by_locale(locale, "verify__no_drift", Some("message"), None))
+                .color(Color::from_rgb(0, 255, 0));
+        }
+
+        let mut args = FluentArgs::new();
+        args.set("missing", format_lines(&self.missing));
+        args.set("orphaned", format_lines(&self.orphaned));
+        CreateEmbed::new()
+            .title(get_by_locale(locale, "verify__report", Some("title"), Some(&args)))
+            .description(get_by_locale(locale, "verify__report", Some("message"), Some(&args)))
+            .color(Color::from_rgb(0xff, 0x98, 0))
+    }
+}
+
+fn format_lines(lines: &[String]) -> String {
+    if lines.is_empty() {
+        return "-".to_string();
+    }
+    let mut text = lines.iter()
+        .take(MAX_REPORT_LINES)
+        .map(|line| format!("- {line}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if lines.len() > MAX_REPORT_LINES {
+        text.push_str(&format!("\n- … (+{})", lines.len() - MAX_REPORT_LINES));
+    }
+    text
+}
+
+/// Compares the stored configuration of a server with the channels and roles present on Discord.
+pub fn compute_drift(server: &Server, places: &[Place], roads: &[Road], channels: &[LiveChannel], roles: &HashSet<u64>) -> DriftReport {
+    let channel_ids: HashSet<u64> = channels.iter().map(|c| c.id).collect();
+    let mut report = DriftReport::default();
+
+    for (name, id) in server.stored_ids() {
+        let exists = match id.id_type {
+            IdType::Role => roles.contains(&id.id),
+            IdType::Channel | IdType::Category => channel_ids.contains(&id.id),
+        };
+        if !exists {
+            report.missing.push(format!("{name} `{}`", id.id));
+        }
+    }
+
+    for place in places {
+        if !channel_ids.contains(&place.category_id) {
+            report.missing.push(format!("{} (category) `{}`", place.name, place.category_id));
+        }
+        if !roles.contains(&place.role) {
+            report.missing.push(format!("{} (role) `{}`", place.name, place.role));
+        }
+    }
+
+    for road in roads {
+        if !channel_ids.contains(&road.channel_id) {
+            report.missing.push(format!("{} (channel) `{}`", road.road_name, road.channel_id));
+        }
+        if !roles.contains(&road.role_id) {
+            report.missing.push(format!("{} (role) `{}`", road.road_name, road.role_id));
+        }
+    }
+
+    if let Some(road_category) = server.road_category_id {
+        let road_channels: HashSet<u64> = roads.iter().map(|r| r.channel_id).collect();
+        for channel in channels.iter().filter(|c| c.parent_id == Some(road_category.id)) {
+            if !road_channels.contains(&channel.id) {
+                report.orphaned.push(format!("#{} `{}`", channel.name, channel.id));
+            }
+        }
+    }
+
+    report
+}
+
+/// Fetches the live state of the server's guild and computes its drift report.
+///
+/// Returns the report along with the guild's preferred locale.
+pub async fn verify_server(http: &Http, server: &Server) -> Result<(DriftReport, String), Error> {
+    let guild_id = GuildId::new(server.server_id);
+    let Ok(guild) = http.get_guild(guild_id).await else { return Err("verify__discord_error".into()) };
+    let Ok(channels) = http.get_channels(guild_id).await else { return Err("verify__discord_error".into()) };
+    let Ok(places) = get_places_by_server_id(server.server_id).await else { return Err("verify__database_error".into()) };
+    let Ok(roads) = get_roads_by_server_id(server.server_id).await else { return Err("verify__database_error".into()) };
+
+    let channels: Vec<LiveChannel> = channels.into_iter()
+        .map(|c| LiveChannel { id: c.id.get(), name: c.name, parent_id: c.parent_id.map(|p| p.get()) })
+        .collect();
+    let roles: HashSet<u64> = guild.roles.keys().map(|r| r.get()).collect();
+
+    Ok((compute_drift(server, &places, &roads, &channels, &roles), guild.preferred_locale))
+}
+
+/// Reads the delay between two consistency checks, one day by default.
+fn verify_interval() -> Duration {
+    let secs = env::var(VERIFY_INTERVAL_VAR).ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_VERIFY_INTERVAL_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Checks every attached server and posts a drift report to its log channel when needed.
+async fn verify_all_servers(http: &Http) {
+    let servers = match get_all_servers().await {
+        Ok(servers) => servers,
+        Err(e) => {
+            tracing::error!("Failed to get servers for consistency check: {e:?}");
+            return;
+        }
+    };
+
+    for server in servers.iter().filter(|s| s.detached_at.is_none()) {
+        match verify_server(http, server).await {
+            Ok((report, locale)) => {
+                if report.is_empty() {
+                    continue;
+                }
+                tracing::info!(guild_id = server.server_id, missing = report.missing.len(), orphaned = report.orphaned.len(), "drift detected");
+                if let Some(log_channel) = server.log_channel_id {
+                    let _ = ChannelId::new(log_channel.id)
+                        .send_message(http, CreateMessage::new().embed(report.to_embed(&locale)))
+                        .await;
+                }
+            }
+            Err(e) => tracing::warn!(guild_id = server.server_id, "consistency check failed: {e}"),
+        }
+    }
+}
+
+/// Starts the periodic consistency checker.
+pub async fn setup_consistency_checker() {
+    let interval = verify_interval();
+    TASKS.spawn("consistency_checker", move |mut shutdown| async move {
+        while shutdown.sleep(interval).await {
+            let http_opt = {
+                let lock = HTTP_CLIENT.lock().await;
+                lock.clone()
+            };
+            match http_opt {
+                Some(http) => verify_all_servers(&http).await,
+                None => tracing::warn!("HTTP client not initialized, skipping consistency check"),
+            }
+        }
+    }).await;
+    tracing::info!("consistency checker initialized: every {}s", interval.as_secs());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::database::server::IdType;
+
+    #[test]
+    fn test_compute_drift() {
+        let mut server = Server::default();
+        server.admin_role_id((1, IdType::Role))
+            .player_role_id((2, IdType::Role))
+            .road_category_id((10, IdType::Category))
+            .log_channel_id((11, IdType::Channel));
+
+        let channels = vec![
+            LiveChannel { id: 10, name: "roads".to_string(), parent_id: None },
+            LiveChannel { id: 20, name: "old-road".to_string(), parent_id: Some(10) },
+        ];
+        let roles: HashSet<u64> = [1].into_iter().collect();
+
+        let report = compute_drift(&server, &[], &[], &channels, &roles);
+        assert_eq!(report.missing, vec!["player_role_id `2`", "log_channel_id `11`"]);
+        assert_eq!(report.orphaned, vec!["#old-road `20`"]);
+        assert!(!report.is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+use poise::CreateReply;
+use crate::database::server::get_server_by_id;
+use crate::discord::poise_structs::{Context, Error};
+use crate::universe::verify::verify_server;
+use crate::utility::reply::reply;
+
+/// Compares the stored configuration of this server with its live Discord state.
+///
+/// This is the on-demand version of the periodic consistency check: the drift report is
+/// sent back as an embed instead of being posted to the log channel.
+#[poise::command(slash_command, required_permissions = "ADMINISTRATOR", guild_only, rename = "universe_verify")]
+pub async fn verify(ctx: Context<'_>) -> Result<(), Error> {
+    let Ok(_) = ctx.defer().await else { return Err("reply__reply_failed".into()) };
+
+    let guild_id = ctx.guild_id().unwrap().get();
+    let server = match get_server_by_id(guild_id).await {
+        Ok(Some(server)) => server,
+        Ok(None) => return reply(ctx, Err("setup__server_not_found".into())).await.map(|_| ()),
+        Err(_) => return reply(ctx, Err("verify__database_error".into())).await.map(|_| ()),
+    };
+
+    match verify_server(ctx.http(), &server).await {
+        Ok((report, _)) => {
+            let locale = ctx.locale().unwrap_or("en-US");
+            let Ok(_) = ctx.send(CreateReply::default().embed(report.to_embed(locale))).await else { return Err("reply__reply_failed".into()) };
+        }
+        Err(e) => {
+            let Ok(_) = reply(ctx, Err(e)).await else { return Err("reply__reply_failed".into()) };
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+use fluent::FluentArgs;
+use poise::CreateReply;
+use serenity::all::{ButtonStyle, Color, ComponentInteractionCollector, CreateActionRow, CreateButton, CreateEmbed};
+use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::utility::custom_id::custom_id;
+
+/// Answer given to a confirmation prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmOutcome {
+    Confirmed,
+    Cancelled,
+    /// No button was pressed before the timeout.
+    TimedOut,
+}
+
+/// Custom ids of the cancel and confirm buttons of the prompt sent by the invocation `ctx_id`,
+/// so that concurrent prompts never react to each other's buttons.
+pub fn button_ids(ctx_id: u64) -> (String, String) {
+    (custom_id("confirm_cancel", ctx_id), custom_id("confirm_continue", ctx_id))
+}
+
+/// Waits for one of the buttons of a prompt to be pressed.
+pub trait ButtonPresses {
+    /// Custom id of the first of `custom_ids` pressed before `timeout`, once the press is
+    /// acknowledged, or `None` when none was.
+    async fn next_press(&self, custom_ids: &[String], timeout: Duration) -> Option<String>;
+}
+
+/// Presses of the author of `ctx` on its channel.
+pub struct AuthorPresses<'a>(pub Context<'a>);
+
+impl ButtonPresses for AuthorPresses<'_> {
+    async fn next_press(&self, custom_ids: &[String], timeout: Duration) -> Option<String> {
+        let ctx = self.0;
+        let custom_ids = custom_ids.to_vec();
+        let press = ComponentInteractionCollector::new(ctx)
+            .author_id(ctx.author().id)
+            .channel_id(ctx.channel_id())
+            .filter(move |press| custom_ids.contains(&press.data.custom_id))
+            .timeout(timeout)
+            .await?;
+        let _ = press.defer(ctx).await;
+        Some(press.data.custom_id.clone())
+    }
+}
+
+/// Outcome of a prompt whose cancel and continue buttons are `ids`, once `presses` reported the
+/// pressed one or the timeout.
+pub async fn wait_outcome(presses: &impl ButtonPresses, ids: &(String, String), timeout: Duration) -> ConfirmOutcome {
+    let (cancel_id, continue_id) = ids;
+    match presses.next_press(&[cancel_id.clone(), continue_id.clone()], timeout).await {
+        Some(pressed) if pressed == *continue_id => ConfirmOutcome::Confirmed,
+        Some(_) => ConfirmOutcome::Cancelled,
+        None => ConfirmOutcome::TimedOut,
+    }
+}
+
+/// Asks the author to confirm an operation: the embed shows the `title` and `message` of
+/// `message_key`, its buttons the `cancel` and `confirm` attributes. The prompt is deleted once
+/// answered or after `timeout`.
+///
+/// # Errors
+/// - `"reply__reply_failed"`: the prompt could not be sent.
+pub async fn confirm(ctx: Context<'_>, message_key: &str, args: Option<FluentArgs<'_>>, timeout: Duration) -> Result<ConfirmOutcome, Error> {
+    let ids = button_ids(ctx.id());
+    let prompt = prompt(ctx, message_key, args, &ids);
+    let Ok(message) = ctx.send(prompt).await else { return Err("reply__reply_failed".into()) };
+
+    let outcome = wait_outcome(&AuthorPresses(ctx), &ids, timeout).await;
+    let _ = message.delete(ctx).await;
+    Ok(outcome)
+}
+
+fn prompt(ctx: Context<'_>, message_key: &str, args: Option<FluentArgs<'_>>, (cancel_id, continue_id): &(String, String)) -> CreateReply {
+    let args = args.as_ref();
+    CreateReply::default()
+        .embed(
+            CreateEmbed::new()
+                .color(Color::from_rgb(0xff, 0x98, 0))
+                .title(tr!(ctx, message_key, @title; args))
+                .description(tr!(ctx, message_key, @message; args))
+        )
+        .components(vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(cancel_id)
+                .style(ButtonStyle::Primary)
+                .label(tr!(ctx, message_key, @cancel; args)),
+            CreateButton::new(continue_id)
+                .style(ButtonStyle::Danger)
+                .label(tr!(ctx, message_key, @confirm; args)),
+        ])])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utility::custom_id::from_invocation;
+
+    /// Presses the button at this index of the ids it is given, or none.
+    struct FakePresses(Option<usize>);
+
+    impl ButtonPresses for FakePresses {
+        async fn next_press(&self, custom_ids: &[String], _timeout: Duration) -> Option<String> {
+            self.0.map(|index| custom_ids[index].clone())
+        }
+    }
+
+    #[test]
+    fn test_button_ids() {
+        let (cancel, confirm) = button_ids(1);
+        assert_ne!(cancel, confirm);
+        let (other_cancel, other_confirm) = button_ids(2);
+        assert!(![other_cancel, other_confirm].contains(&cancel));
+        assert!(from_invocation(&cancel, 1) && from_invocation(&confirm, 1));
+    }
+
+    #[tokio::test]
+    async fn test_wait_outcome() {
+        let ids = button_ids(1);
+        let timeout = Duration::from_secs(1);
+        assert_eq!(wait_outcome(&FakePresses(Some(1)), &ids, timeout).await, ConfirmOutcome::Confirmed);
+        assert_eq!(wait_outcome(&FakePresses(Some(0)), &ids, timeout).await, ConfirmOutcome::Cancelled);
+        assert_eq!(wait_outcome(&FakePresses(None), &ids, timeout).await, ConfirmOutcome::TimedOut);
+    }
+}
@@ -0,0 +1,26 @@
+/// Custom id of the component `name` sent by the command invocation `invocation_id`, so that a
+/// collector only ever sees the components of its own invocation.
+pub fn custom_id(name: &str, invocation_id: u64) -> String {
+    format!("{name}:{invocation_id}")
+}
+
+/// Whether `custom_id` is the id of a component built by [`custom_id`] for `invocation_id`.
+pub fn from_invocation(custom_id: &str, invocation_id: u64) -> bool {
+    custom_id.rsplit_once(':').is_some_and(|(_, id)| id.parse() == Ok(invocation_id))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_custom_id() {
+        assert_eq!(custom_id("selected_universe", 42), "selected_universe:42");
+        assert_ne!(custom_id("cancel", 42), custom_id("cancel", 43));
+        assert!(from_invocation(&custom_id("cancel", 42), 42));
+        assert!(!from_invocation(&custom_id("cancel", 42), 4));
+        assert!(!from_invocation(&custom_id("cancel", 4), 42));
+        assert!(!from_invocation("cancel", 42));
+        assert!(!from_invocation("42", 42));
+    }
+}
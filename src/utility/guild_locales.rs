@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use mongodb::bson::oid::ObjectId;
+use crate::database::server::get_server_by_id;
+use crate::database::universe::get_universe_by_id;
+use crate::discord::poise_structs::Context;
+
+/// How long the locales of a guild are trusted before they are read again.
+static GUILD_LOCALES_TTL: Duration = Duration::from_secs(300);
+
+/// Locales chosen for a guild: its own, set with `/universe set_server_locale`, and the default
+/// one of its universe.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuildLocale {
+    pub server: Option<String>,
+    pub universe: Option<String>,
+}
+
+impl GuildLocale {
+    /// Locale to translate into when the interaction has none: the server's, else the universe's.
+    pub fn preferred(&self) -> Option<&str> {
+        self.server.as_deref().or(self.universe.as_deref())
+    }
+}
+
+/// Locales of a guild and the universe it is part of, as read at `read_at`.
+#[derive(Debug, Clone)]
+struct CachedLocale {
+    universe_id: Option<ObjectId>,
+    locale: GuildLocale,
+    read_at: Instant,
+}
+
+/// Locales of each guild, used to translate the replies to interactions which carry no locale
+/// of their own and the names the setup gives.
+///
+/// Shared through the framework `Data` so that the database is read once per guild every
+/// [`GUILD_LOCALES_TTL`] rather than for every message.
+#[derive(Debug, Default, Clone)]
+pub struct GuildLocales(Arc<Mutex<HashMap<u64, CachedLocale>>>);
+
+/// Locales of every guild, shared with the framework `Data` like the translations so that the
+/// tasks posting without a command, such as the travels, read the same cache.
+pub static GUILD_LOCALES: LazyLock<GuildLocales> = LazyLock::new(GuildLocales::default);
+
+impl GuildLocales {
+    /// Locales of `guild_id` as of `now`, or `None` when they were not read recently.
+    pub fn cached(&self, guild_id: u64, now: Instant) -> Option<GuildLocale> {
+        let cache = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache.get(&guild_id)
+            .filter(|cached| now.duration_since(cached.read_at) < GUILD_LOCALES_TTL)
+            .map(|cached| cached.locale.clone())
+    }
+
+    /// Remembers that the guild `guild_id` is part of `universe_id` and uses `locale`.
+    pub fn insert(&self, guild_id: u64, universe_id: Option<ObjectId>, locale: GuildLocale, now: Instant) {
+        let mut cache = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache.insert(guild_id, CachedLocale { universe_id, locale, read_at: now });
+    }
+
+    /// Forgets the locales of `guild_id`, once its own changed.
+    pub fn invalidate_guild(&self, guild_id: u64) {
+        let mut cache = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache.remove(&guild_id);
+    }
+
+    /// Forgets the locales of all the guilds of `universe_id`, once its default locale changed.
+    pub fn invalidate_universe(&self, universe_id: ObjectId) {
+        let mut cache = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        cache.retain(|_, cached| cached.universe_id != Some(universe_id));
+    }
+
+    /// Locales of `guild_id`, read from the database when they are not cached. `None` when they
+    /// cannot be read.
+    pub async fn load(&self, guild_id: u64) -> Option<GuildLocale> {
+        if let Some(locale) = self.cached(guild_id, Instant::now()) {
+            return Some(locale);
+        }
+        let Some(server) = get_server_by_id(guild_id).await.ok()? else {
+            self.insert(guild_id, None, GuildLocale::default(), Instant::now());
+            return Some(GuildLocale::default());
+        };
+        let universe = get_universe_by_id(server.universe_id).await.ok()?;
+        let locale = GuildLocale {
+            server: server.locale,
+            universe: universe.and_then(|universe| universe.default_locale),
+        };
+        self.insert(guild_id, Some(server.universe_id), locale.clone(), Instant::now());
+        Some(locale)
+    }
+}
+
+/// Locales of the guild `ctx` runs on, read from the database when they are not cached. `None`
+/// outside of guilds, and when they cannot be read.
+pub async fn guild_locale(ctx: Context<'_>) -> Option<GuildLocale> {
+    ctx.data().guild_locales.load(ctx.guild_id()?.get()).await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn locale(server: Option<&str>, universe: Option<&str>) -> GuildLocale {
+        GuildLocale { server: server.map(str::to_string), universe: universe.map(str::to_string) }
+    }
+
+    #[test]
+    fn test_preferred_locale() {
+        assert_eq!(locale(Some("fr"), Some("en-US")).preferred(), Some("fr"));
+        assert_eq!(locale(None, Some("en-US")).preferred(), Some("en-US"));
+        assert_eq!(locale(None, None).preferred(), None);
+    }
+
+    #[test]
+    fn test_guild_locales() {
+        let locales = GuildLocales::default();
+        let now = Instant::now();
+        assert_eq!(locales.cached(1, now), None);
+
+        let universe_id = ObjectId::new();
+        locales.insert(1, Some(universe_id), locale(None, Some("fr")), now);
+        locales.insert(2, Some(universe_id), locale(Some("en-US"), Some("fr")), now);
+        locales.insert(3, None, locale(None, None), now);
+        assert_eq!(locales.cached(1, now), Some(locale(None, Some("fr"))));
+        assert_eq!(locales.cached(3, now), Some(locale(None, None)));
+        assert_eq!(locales.cached(1, now + GUILD_LOCALES_TTL), None);
+
+        locales.invalidate_guild(2);
+        assert_eq!(locales.cached(2, now), None);
+        assert!(locales.cached(1, now).is_some());
+
+        locales.invalidate_universe(universe_id);
+        assert_eq!(locales.cached(1, now), None);
+        assert_eq!(locales.cached(3, now), Some(locale(None, None)));
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+/// Guilds on which a setup-like operation (setup, place or road creation) is running.
+///
+/// Shared through the framework `Data` so that two admins running such commands at the same
+/// time on the same guild cannot create duplicate roles and channels.
+#[derive(Debug, Default, Clone)]
+pub struct GuildLocks(Arc<Mutex<HashSet<u64>>>);
+
+impl GuildLocks {
+    /// Locks `guild_id`, or returns `None` if an operation is already running on it.
+    ///
+    /// The lock is released when the returned guard is dropped, which also happens when the
+    /// command panics.
+    pub fn try_lock(&self, guild_id: u64) -> Option<GuildLockGuard> {
+        let mut locked = self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !locked.insert(guild_id) {
+            return None;
+        }
+        Some(GuildLockGuard { locks: self.clone(), guild_id })
+    }
+}
+
+/// Releases the lock of a guild when dropped.
+#[derive(Debug)]
+pub struct GuildLockGuard {
+    locks: GuildLocks,
+    guild_id: u64,
+}
+
+impl Drop for GuildLockGuard {
+    fn drop(&mut self) {
+        let mut locked = self.locks.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        locked.remove(&self.guild_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_guild_lock() {
+        let locks = GuildLocks::default();
+        let guard = locks.try_lock(1);
+        assert!(guard.is_some());
+        assert!(locks.try_lock(1).is_none());
+        assert!(locks.try_lock(2).is_some());
+
+        drop(guard);
+        assert!(locks.try_lock(1).is_some());
+
+        let result = std::panic::catch_unwind(|| {
+            let _guard = locks.try_lock(3);
+            panic!("command panicked");
+        });
+        assert!(result.is_err());
+        assert!(locks.try_lock(3).is_some());
+    }
+}
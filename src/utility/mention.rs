@@ -0,0 +1,20 @@
+/// Parses a channel given as a raw id or as a `<#id>` mention.
+pub fn parse_channel_id(input: &str) -> Option<u64> {
+    if let Ok(id) = input.parse::<u64>() {
+        return Some(id);
+    }
+    input.strip_prefix("<#")?.strip_suffix('>')?.parse::<u64>().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_channel_id() {
+        assert_eq!(parse_channel_id("123"), Some(123));
+        assert_eq!(parse_channel_id("<#456>"), Some(456));
+        assert_eq!(parse_channel_id("<@456>"), None);
+        assert_eq!(parse_channel_id("town"), None);
+    }
+}
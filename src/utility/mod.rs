@@ -1 +1,10 @@
-pub mod reply;
\ No newline at end of file
+pub mod reply;
+pub mod guild_lock;
+pub mod retry;
+pub mod mention;
+pub mod pagination;
+pub mod confirm;
+pub mod custom_id;
+pub mod tasks;
+
+pub mod guild_locales;
\ No newline at end of file
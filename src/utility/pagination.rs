@@ -0,0 +1,99 @@
+use std::time::Duration;
+use poise::CreateReply;
+use serenity::all::{ButtonStyle, ComponentInteractionCollector, CreateActionRow, CreateButton, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage};
+use crate::discord::poise_structs::{Context, Error};
+use crate::utility::custom_id::{custom_id, from_invocation};
+
+/// Number of rows shown on a page of a list.
+static PAGE_SIZE: usize = 10;
+/// How long the page buttons stay active after the last click.
+static PAGE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Number of pages needed to show `count` rows.
+pub fn page_count(count: usize) -> usize {
+    page_count_by(count, PAGE_SIZE)
+}
+
+/// Number of pages needed to show `count` rows, `size` per page.
+pub fn page_count_by(count: usize, size: usize) -> usize {
+    count.div_ceil(size).max(1)
+}
+
+/// Rows shown on `page`, starting at 0.
+pub fn page_of<T>(rows: &[T], page: usize) -> &[T] {
+    page_of_by(rows, page, PAGE_SIZE)
+}
+
+/// Rows shown on `page`, starting at 0, when pages show `size` rows.
+pub fn page_of_by<T>(rows: &[T], page: usize, size: usize) -> &[T] {
+    let start = (page * size).min(rows.len());
+    let end = (start + size).min(rows.len());
+    &rows[start..end]
+}
+
+/// Buttons moving to the previous and next page, none when everything fits on one page.
+pub fn page_buttons(prev_id: &str, next_id: &str, page: usize, pages: usize) -> Vec<CreateActionRow> {
+    if pages <= 1 {
+        return vec![];
+    }
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(prev_id).style(ButtonStyle::Secondary).label("◀").disabled(page == 0),
+        CreateButton::new(next_id).style(ButtonStyle::Secondary).label("▶").disabled(page + 1 >= pages),
+    ])]
+}
+
+/// Sends the first of `pages` pages rendered by `render`, with buttons to move between pages
+/// until they stay unused for `PAGE_TIMEOUT`.
+pub async fn send_pages(ctx: Context<'_>, pages: usize, render: impl Fn(usize) -> CreateEmbed) -> Result<(), Error> {
+    let mut page = 0;
+    let prev_id = custom_id("page_prev", ctx.id());
+    let next_id = custom_id("page_next", ctx.id());
+    let reply = CreateReply::default()
+        .embed(render(page))
+        .components(page_buttons(&prev_id, &next_id, page, pages));
+    let Ok(handle) = ctx.send(reply).await else { return Err("reply__reply_failed".into()) };
+    if pages == 1 {
+        return Ok(());
+    }
+
+    let ctx_id = ctx.id();
+    while let Some(press) = ComponentInteractionCollector::new(ctx)
+        .author_id(ctx.author().id)
+        .filter(move |press| from_invocation(&press.data.custom_id, ctx_id))
+        .timeout(PAGE_TIMEOUT)
+        .await
+    {
+        if press.data.custom_id == next_id {
+            page = (page + 1).min(pages - 1);
+        } else if press.data.custom_id == prev_id {
+            page = page.saturating_sub(1);
+        }
+        let _ = press.create_response(ctx, CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(render(page))
+                .components(page_buttons(&prev_id, &next_id, page, pages))
+        )).await;
+    }
+
+    // Buttons are removed once they stop responding
+    let _ = handle.edit(ctx, CreateReply::default().embed(render(page)).components(vec![])).await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pages() {
+        let rows: Vec<u64> = (0..23).collect();
+
+        assert_eq!(page_count(rows.len()), 3);
+        assert_eq!(page_count(0), 1);
+        assert_eq!(page_of(&rows, 0).len(), 10);
+        assert_eq!(page_of(&rows, 2), &[20, 21, 22]);
+        assert!(page_of(&rows, 5).is_empty());
+        assert_eq!(page_count_by(rows.len(), 25), 1);
+        assert_eq!(page_of_by(&rows, 1, 20), &[20, 21, 22]);
+    }
+}
@@ -1,6 +1,7 @@
-use log::log;
 use serenity::all::{Color, CreateEmbed, CreateEmbedFooter};
 use crate::discord::poise_structs::{Context, Error};
+use crate::tr;
+use crate::discord::telemetry::mark_failed;
 use poise::CreateReply;
 use fluent::FluentArgs;
 
@@ -24,7 +25,7 @@ use fluent::FluentArgs;
 ///    - A failure case generates a red embed with the error message.
 /// 2. The embed includes:
 ///    - A localized title (`title`) and message (`message`) retrieved using the 
-///      `crate::translation::get` function.
+///      `tr!` macro.
 ///    - A footer that displays the original string message.
 ///    - A color indicating the status (green for success, red for failure).
 /// 3. Attempts to send the constructed embed using the `ctx.send` function. If sending succeeds,
@@ -49,10 +50,11 @@ use fluent::FluentArgs;
 ///   returns an appropriate error message wrapped in `Err`.
 ///
 /// # Notes
-/// - The `crate::translation::get` function is used to fetch localized strings for the embed's
+/// - The `tr!` macro is used to fetch localized strings for the embed's
 ///   title and description based on the message content. Ensure that the translation keys exist
 ///   and are properly configured.
 /// - The embed's color uses RGB values to visually indicate success or failure.
+/// - An error marks the invocation as failed in the command metrics, see `mark_failed`.
 pub async fn reply<'a>(
     ctx: Context<'a>,
     result: Result<&'a str, Error>,
@@ -60,28 +62,40 @@ pub async fn reply<'a>(
     reply_with_args(ctx, result, None).await
 }
 
+/// Embed [`reply_with_args`] sends for `result`: its translated title and message over the
+/// translation key, green for a success and red for a failure.
+pub fn result_embed(ctx: Context<'_>, result: &Result<&str, Error>, args: Option<&FluentArgs<'_>>) -> CreateEmbed {
+    let (color, string) = match result {
+        Ok(string) => (Color::from_rgb(0, 255, 0), string.to_string()),
+        Err(error) => (Color::from_rgb(255, 0, 0), error.to_string()),
+    };
+    CreateEmbed::new()
+        .title(tr!(ctx, &string, @title; args))
+        .description(tr!(ctx, &string, @message; args))
+        .footer(CreateEmbedFooter::new(string.clone()))
+        .color(color)
+}
+
 pub async fn reply_with_args<'a>(
     ctx: Context<'a>,
     result: Result<&'a str, Error>,
     args: Option<FluentArgs<'a>>,
 ) -> Result<&'a str, Error> {
-    let (color, string) = match result {
-        Ok(string) => (Color::from_rgb(0, 255, 0), string.to_string()),
-        Err(ref error) => (Color::from_rgb(255, 0, 0), error.to_string()),
+    let string = match result {
+        Ok(string) => string.to_string(),
+        Err(ref error) => error.to_string(),
     };
+    if result.is_err() {
+        mark_failed(ctx).await;
+    }
 
-    match ctx.send(CreateReply::default().embed(
-            CreateEmbed::new()
-                .title(crate::translation::get(ctx, &string, Some("title"), args.as_ref()))
-                .description(crate::translation::get(ctx, &string, Some("message"), args.as_ref()))
-                .footer(CreateEmbedFooter::new(string.clone()))
-                .color(color),
-        ).ephemeral(result.is_err()),
+    match ctx.send(CreateReply::default().embed(result_embed(ctx, &result, args.as_ref()))
+        .ephemeral(result.is_err()),
     )
         .await {
         Ok(_) => {Ok("reply__reply_success")}
         Err(e) => {
-            log!(log::Level::Error, "failed to reply:\nserver: {:?}\nerror_string: {}\nerror: {:?}", ctx.guild_id(), string, e);
+            tracing::error!(guild_id = ?ctx.guild_id(), key = string, "failed to reply: {e}");
             Err("reply__reply_failed".into())}
     }
 }
\ No newline at end of file
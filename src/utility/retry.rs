@@ -0,0 +1,74 @@
+use std::time::Duration;
+use serenity::all::{HttpError, StatusCode};
+
+/// Number of attempts made for a creation before reporting it as failed.
+static MAX_ATTEMPTS: u32 = 4;
+/// Delay before the first retry, doubled at each new attempt.
+static FIRST_RETRY_DELAY: Duration = Duration::from_secs(1);
+/// Upper bound of the delay between two attempts.
+static MAX_RETRY_DELAY: Duration = Duration::from_secs(8);
+
+/// Returns `true` if Discord refused the request because of a rate limit.
+pub fn is_rate_limited(error: &serenity::Error) -> bool {
+    matches!(error, serenity::Error::Http(HttpError::UnsuccessfulRequest(response)) if response.status_code == StatusCode::TOO_MANY_REQUESTS)
+}
+
+/// Delay to wait before attempt number `attempt` (starting at 1 for the first retry), or `None`
+/// once every attempt has been made.
+///
+/// Serenity does not expose the `Retry-After` header of the failed response, so the delay
+/// doubles at each attempt instead, capped by `MAX_RETRY_DELAY`.
+pub fn retry_delay(attempt: u32) -> Option<Duration> {
+    if attempt >= MAX_ATTEMPTS {
+        return None;
+    }
+    Some(FIRST_RETRY_DELAY.saturating_mul(1 << (attempt - 1)).min(MAX_RETRY_DELAY))
+}
+
+/// Runs `create` until it succeeds, retrying while Discord answers with a rate limit.
+///
+/// Other errors are returned right away. Each retry is logged with `resource`, the name of the
+/// role or channel being created, so throttling shows up in the logs.
+pub async fn retry_on_rate_limit<T, F, Fut>(resource: &str, mut create: F) -> serenity::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = serenity::Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match create().await {
+            Err(e) if is_rate_limited(&e) => {
+                let Some(delay) = retry_delay(attempt) else { return Err(e) };
+                tracing::warn!(resource, attempt, delay_ms = delay.as_millis() as u64, "rate limited by Discord, retrying");
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(1), Some(Duration::from_secs(1)));
+        assert_eq!(retry_delay(2), Some(Duration::from_secs(2)));
+        assert_eq!(retry_delay(3), Some(Duration::from_secs(4)));
+        assert_eq!(retry_delay(MAX_ATTEMPTS), None);
+        assert!(!is_rate_limited(&serenity::Error::Other("not an http error")));
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_on_other_errors() {
+        let mut calls = 0;
+        let result: serenity::Result<()> = retry_on_rate_limit("channel", || {
+            calls += 1;
+            async { Err(serenity::Error::Other("failed")) }
+        }).await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,151 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use once_cell::sync::Lazy;
+use tokio::sync::{watch, Mutex, Notify};
+use tokio::task::JoinHandle;
+
+/// Background tasks of the bot, stopped together when the client shuts down.
+pub static TASKS: Lazy<TaskRegistry> = Lazy::new(TaskRegistry::new);
+
+/// Registry of named background tasks sharing a shutdown signal, and of the work in flight,
+/// such as commands and database writes, which the shutdown waits for.
+pub struct TaskRegistry {
+    shutdown: watch::Sender<bool>,
+    tasks: Mutex<Vec<(&'static str, JoinHandle<()>)>>,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+/// Work in flight, until dropped.
+pub struct InFlight<'a>(&'a TaskRegistry);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+/// Handed to a registered task so it can wait without missing the shutdown.
+pub struct Shutdown(watch::Receiver<bool>);
+
+impl Shutdown {
+    /// Sleeps for `duration`. Returns `false`, possibly early, when the task has to stop.
+    pub async fn sleep(&mut self, duration: Duration) -> bool {
+        if *self.0.borrow() {
+            return false;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(duration) => !*self.0.borrow(),
+            _ = self.0.changed() => false,
+        }
+    }
+}
+
+impl TaskRegistry {
+    fn new() -> Self {
+        TaskRegistry { shutdown: watch::channel(false).0, tasks: Mutex::new(vec![]), in_flight: AtomicUsize::new(0), idle: Notify::new() }
+    }
+
+    /// Shutdown signal for a task which is not registered, such as a scheduler replacing its
+    /// own sleeper.
+    pub fn shutdown_signal(&self) -> Shutdown {
+        Shutdown(self.shutdown.subscribe())
+    }
+
+    /// Marks work that must not be cut by the shutdown until the returned guard is dropped.
+    pub fn track(&self) -> InFlight<'_> {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlight(self)
+    }
+
+    async fn wait_idle(&self) {
+        loop {
+            let mut idle = pin!(self.idle.notified());
+            idle.as_mut().enable();
+            if self.in_flight.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+
+    /// Spawns the task `name`, replacing the running task of the same name.
+    pub async fn spawn<F, Fut>(&self, name: &'static str, task: F)
+    where
+        F: FnOnce(Shutdown) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(task(Shutdown(self.shutdown.subscribe())));
+        let mut tasks = self.tasks.lock().await;
+        if let Some(index) = tasks.iter().position(|(task_name, _)| *task_name == name) {
+            tasks.swap_remove(index).1.abort();
+        }
+        tasks.push((name, handle));
+    }
+
+    /// Asks every task to stop and waits for each of them up to `grace`, aborting the late ones,
+    /// then waits up to `grace` for the work in flight.
+    pub async fn shutdown(&self, grace: Duration) {
+        let _ = self.shutdown.send(true);
+        let tasks: Vec<_> = self.tasks.lock().await.drain(..).collect();
+        for (name, mut handle) in tasks {
+            if tokio::time::timeout(grace, &mut handle).await.is_err() {
+                tracing::warn!(task = name, "background task did not stop in time, aborting it");
+                handle.abort();
+            }
+        }
+        if tokio::time::timeout(grace, self.wait_idle()).await.is_err() {
+            tracing::warn!(in_flight = self.in_flight.load(Ordering::SeqCst), "work still in flight at shutdown");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicU32;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_shutdown_stops_tasks() {
+        let registry = TaskRegistry::new();
+        let ticks = Arc::new(AtomicU32::new(0));
+        let counter = ticks.clone();
+        registry.spawn("ticker", move |mut shutdown| async move {
+            while shutdown.sleep(Duration::from_millis(5)).await {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        }).await;
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        registry.shutdown(Duration::from_secs(1)).await;
+        let stopped_at = ticks.load(Ordering::SeqCst);
+        assert!(stopped_at > 0);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), stopped_at);
+        assert!(registry.tasks.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_work_in_flight() {
+        let registry = TaskRegistry::new();
+        let work = registry.track();
+        let finish = async {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            drop(work);
+        };
+        let started = Instant::now();
+        tokio::join!(registry.shutdown(Duration::from_secs(1)), finish);
+        assert!(started.elapsed() >= Duration::from_millis(30));
+
+        let _stuck = registry.track();
+        let started = Instant::now();
+        registry.shutdown(Duration::from_millis(20)).await;
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
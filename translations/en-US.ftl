@@ -18,23 +18,336 @@ start_message = Start Message
             In a partial setup, only the road category and roles will be created.
             In a full setup, the Admin, out of rp, rp categories and their channels are also created.
 
+start__onboarding = Getting started
+    .title = Thank you for using {botname}
+    .message = Set this server up in three steps, the buttons below run them or tell you how.
+start__step = Step
+    .create_universe = Create a universe with `/{universe} {universe_create_universe}`.
+    .link_server = Link this server to one of your universes with `/{universe} {universe_add_server}`.
+    .run_setup = Create the roles, categories and channels of the universe with `/{universe} {universe_setup}`.
+start__button = Button
+    .create_universe = Create a universe
+    .link_server = Link to an existing universe
+    .run_setup = Run setup
+start__state = State
+    .unlinked = This server is not part of a universe yet.
+    .linked = This server is part of a universe and only needs to be set up.
+    .set_up = This server is set up. Running the setup again recreates the roles and channels which were deleted.
+start__create_universe = Create a universe
+    .title = Create a universe
+    .message = A universe needs a name: run `/{universe} {universe_create_universe}` with the name and the setup type of your universe, then come back to `/{start}`.
+start__database_error = Database error
+    .title = Database error
+    .message = The state of this server could not be read. Please try again later.
+
 #Stats
 stat_insert__failed = Failed to insert statistics
     .title = Failed to add stat
-    .description = The stat could not be added.
+    .message = The stat could not be added.
+stat_insert__duplicate_name = Name taken
+    .title = Name taken
+    .message = The universe already has a stat named {$name}.
 resolve_stat__character_not_found = Character not found during stat resolution
     .title = Statistics error
     .message = Unable to find the character to calculate their statistics.
 resolve_stat__database_error = Database error during stat resolution
     .title = Statistics error
     .message = A database error occurred while retrieving statistics.
+stat = stat
+    .description = Defines the stats of the universe.
+stat_create = create
+    .description = Defines a new stat of the universe.
+    .name = name
+    .name-description = Name of the stat.
+    .kind = type
+    .kind-description = Type of the values of the stat.
+    .base_value = base_value
+    .base_value-description = Value new characters start with.
+    .min = min
+    .min-description = Lowest value of the stat, for numbers only.
+    .max = max
+    .max-description = Highest value of the stat, for numbers only.
+    .formula = formula
+    .formula-description = Formula computing the value of the stat.
+stat_list = list
+    .description = Lists the stats of the universe.
+stat_edit = edit
+    .description = Changes a stat. Give - to remove a bound or the formula.
+    .stat = stat
+    .stat-description = Stat to change.
+    .name = name
+    .name-description = New name of the stat.
+    .base_value = base_value
+    .base_value-description = New value new characters start with.
+    .min = min
+    .min-description = New lowest value, or - to remove it.
+    .max = max
+    .max-description = New highest value, or - to remove it.
+    .formula = formula
+    .formula-description = New formula, or - to remove it.
+stat_delete = delete
+    .description = Deletes a stat that no character or modifier uses.
+    .stat = stat
+    .stat-description = Stat to delete.
+stat_type_int = Integer
+stat_type_float = Decimal
+stat_type_text = Text
+stat_type_bool = Yes/No
+stat_type = Type
+    .int = integer
+    .float = decimal
+    .text = text
+    .bool = yes/no
+stat__no_universe = No universe
+    .title = No universe
+    .message = This server is not linked to a universe.
+stat__database_error = Database error
+    .title = Database error
+    .message = The stats could not be read or saved. Please try again.
+stat__not_found = Stat not found
+    .title = Stat not found
+    .message = The universe has no stat named {$name}.
+stat__empty_name = Empty name
+    .title = Empty name
+    .message = The name of a stat cannot be empty.
+stat__invalid_value = Invalid value
+    .title = Invalid value
+    .message = "{$value}" is not a value of the type of the stat.
+stat__bounds_not_numeric = Bounds not allowed
+    .title = Bounds not allowed
+    .message = Only integer and decimal stats can have a min and a max.
+stat__invalid_bounds = Invalid bounds
+    .title = Invalid bounds
+    .message = The min of {$name} is greater than its max.
+stat__out_of_bounds = Base value out of bounds
+    .title = Base value out of bounds
+    .message = The base value of {$name} must be between its min and its max.
+stat_create__success = Stat created
+    .title = Stat created
+    .message = The stat {$name} has been created.
+stat_edit__nothing_to_change = Nothing to change
+    .title = Nothing to change
+    .message = Give at least one field to change.
+stat_edit__success = Stat updated
+    .title = Stat updated
+    .message = The stat {$name} has been updated.
+stat_delete__in_use = Stat in use
+    .title = Stat in use
+    .message = {$name} is still used by a character or a modifier.
+stat_delete__success = Stat deleted
+    .title = Stat deleted
+    .message = The stat {$name} has been deleted.
+stat_list__empty = No stats
+    .title = No stats
+    .message = This universe has no stats yet. Define one with /stat create.
+stat_list__page = Stats
+    .title = { $count ->
+        [one] {$count} stat
+       *[other] {$count} stats
+    } - page {$page}/{$pages}
+stat_list__row = Stat
+    .message = **{$name}** - {$kind} - base {$base_value} - bounds {$bounds} - formula {$formula}
+stat_formula__invalid = Invalid formula
+    .title = Invalid formula
+    .message = The formula does not compile: {$error}
+stat_formula__too_expensive = Formula too expensive
+    .title = Formula too expensive
+    .message = The formula is too complex or takes too long to compute. Simplify it.
+stat_formula__arithmetic = Calculation error
+    .title = Calculation error
+    .message = The formula divides by zero or gives a number that is too large.
+stat_formula__type_mismatch = Wrong type
+    .title = Wrong type
+    .message = The formula gives a value that does not match the type of the stat.
+stat_formula__failed = Formula error
+    .title = Formula error
+    .message = The formula could not be computed. Check the names of the stats it uses.
+#Dice
+roll = roll
+    .description = Rolls dice, such as 2d6+3 or 4d6kh3, optionally adding a stat of your character.
+    .expression = expression
+    .expression-description = Dice to roll: 2d6+3, d20, 4d6kh3 (keep the 3 highest), 2d20kl1 (keep the lowest)...
+    .stat = stat
+    .stat-description = Stat of the character you are playing to add to the total.
+contest = contest
+    .description = Opposes a stat of your character to a stat of the character of another player.
+    .stat_a = stat
+    .stat_a-description = Stat of the character you are playing.
+    .user_b = opponent
+    .user_b-description = Player whose character you oppose.
+    .stat_b = opponent_stat
+    .stat_b-description = Stat of the character of the opponent.
+contest__result = Contest
+    .title = ⚔️ {$first} vs {$second}
+    .winner = **{$winner}** wins by {$margin}.
+    .draw = Draw!
+contest__side = Contest side
+    .message = {$stat}: {$value} - total **{$total}**
+contest__missing_stat = Missing stat
+    .title = Missing stat
+    .message = {$character} has no value for the stat {$stat}.
+contest__opponent_no_active = No opponent character
+    .title = No opponent character
+    .message = {$user} is not playing any character.
+contest__opponent_not_playable = Opponent not approved
+    .title = Opponent not approved
+    .message = {$name}, the character of {$user}, has not been approved and cannot take part in a contest.
+contest__invalid_die = Invalid contest die
+    .title = Invalid contest die
+    .message = The contest die of the universe, `{$expression}`, is invalid. Change it with /universe settings contest_die.
+roll__result = Roll
+    .title = 🎲 {$expression}
+roll__fields = Roll
+    .dice = Dice
+    .stat = Stat bonus
+    .total = Total
+roll__stat_bonus = Stat bonus
+    .message = {$character} - {$stat}: {$value}
+roll__stat_not_numeric = Not a number
+    .title = Stat not numeric
+    .message = The stat {$stat} is not a number, it cannot be added to a roll.
+dice__invalid_expression = Invalid dice
+    .title = Invalid dice expression
+    .message = `{$expression}` is not a valid dice expression. Write dice as NdM, such as 2d6+3, d20 or 4d6kh3.
+dice__too_many_dice = Too many dice
+    .title = Too many dice
+    .message = A roll can use at most 100 dice.
+dice__too_many_sides = Too many sides
+    .title = Too many sides
+    .message = A die can have at most 999 sides.
+dice__invalid_keep = Invalid keep
+    .title = Invalid number of kept dice
+    .message = The number of kept dice must be between 1 and the number of dice rolled.
+#Membership
+join = join
+    .description = Joins the game: gives you the player role and the role of the starting place.
+join__success = Welcome
+    .title = Welcome
+    .message = You are now a player.
+join__success_with_place = Welcome
+    .title = Welcome
+    .message = You are now a player, starting in {$place}.
+join__already_player = Already a player
+    .title = Already a player
+    .message = You already have the player role.
+join__log = Player joined
+    .title = Player joined
+    .message = {$user} joined the game.
+spectate = spectate
+    .description = Watches the game: gives you the spectator role, which can read but not play.
+spectate__success = Spectating
+    .title = Spectating
+    .message = You are now a spectator.
+spectate__already = Already a spectator
+    .title = Already a spectator
+    .message = You already have the spectator role.
+spectate__is_player = Already a player
+    .title = Already a player
+    .message = You are a player. Use /leave before spectating.
+spectate__log = Spectator joined
+    .title = Spectator joined
+    .message = {$user} is now spectating.
+leave = leave
+    .description = Leaves the game: takes back your player, spectator, place and road roles.
+leave__success = Left the game
+    .title = Left the game
+    .message = Your roles were taken back and you no longer play any character.
+leave__not_in_game = Not in the game
+    .title = Not in the game
+    .message = You are neither a player nor a spectator.
+leave__log = Member left
+    .title = Member left
+    .message = {$user} left the game.
+membership__bot_role_too_low = Bot role too low
+    .title = Bot role too low
+    .message = The role of the bot is below {$role}. Ask an administrator to move it higher.
+membership__setup_required = Setup required
+    .title = Setup required
+    .message = The roles of this server are not set up yet. Ask an administrator to run /universe setup.
+membership__role_failed = Roles not updated
+    .title = Roles not updated
+    .message = Your roles could not be changed. The role of the bot may be below the roles to give.
+role_menu__menu = Choose your role
+    .title = Choose your role
+    .message = Press a button to join the game as a player or to watch it as a spectator. Press it again to give the role back.
+role_menu__button = Roles
+    .player = Become a player
+    .spectator = Become a spectator
+role_menu__spectator_added = Spectating
+    .title = Spectating
+    .message = You are now a spectator.
+role_menu__spectator_removed = Spectator role removed
+    .title = Spectator role removed
+    .message = You are no longer a spectator.
+role_menu__approval_required = Approval required
+    .title = Approval required
+    .message = The characters of this universe must be approved before playing. Use /join once yours is.
+role_menu__unknown_button = Unknown button
+    .title = Unknown button
+    .message = This button no longer gives any role.
+role_menu__server_not_found = No universe
+    .title = No universe
+    .message = This server is no longer part of a universe, so its role menu gives no role.
+role_menu__database_error = Database error
+    .title = Database error
+    .message = Your roles could not be changed. Please try again later.
+role_menu__no_channel = No NRP general channel
+role_menu__post_failed = Role menu not posted
 #Reply
 reply__reply_success = Success
     .title = Success
     .message = The operation was successful.
 reply__reply_failed = Failed to send reply
     .title = Reply failed
-    .description = The reply failed
+    .message = The reply failed
+#Errors
+error__argument_parse = Invalid argument
+    .title = Invalid argument
+    .message = One of the provided arguments could not be understood. Please check your input and try again.
+error__cooldown_hit = Command on cooldown
+    .title = Please wait
+    .message = This command is on cooldown. You can use it again in {$seconds} second(s).
+error__not_an_owner = Owners only
+    .title = Owners only
+    .message = Only the owners of the bot can run this command.
+ping__report = Pong!
+    .title = Pong!
+    .interaction = Interaction
+    .gateway = Gateway (shard {$shard})
+    .database = Database
+    .unavailable = unavailable
+ping__latency = {$ms} ms
+botstats__report = Bot statistics
+    .title = Bot statistics
+    .message = {$runs} commands run since the bot started, {$failures} failed ({$error_rate}%).
+botstats__command = `/{$command}`: {$runs} runs, {$error_rate}% failed, p95 {$p95}
+botstats__latency = ≤ {$ms} ms
+botstats__latency_over = > {$ms} ms
+grant_limits__success = Limits granted
+    .title = Limits granted
+    .message = {$user} can now create {$max_universes} universes of {$max_servers_per_universe} servers each.
+grant_limits__success_until = Limits granted
+    .title = Limits granted
+    .message = {$user} can now create {$max_universes} universes of {$max_servers_per_universe} servers each, until {$expires_at}.
+grant_limits__failed = Limits not granted
+    .title = Limits not granted
+    .message = The limits could not be saved
+            Please try again or contact support if the problem persists: {support}
+reload_translations__success = Translations reloaded
+    .title = Translations reloaded
+    .message = {$locales} locales and {$messages} messages are now in use.{ $conflicts ->
+        [0] {""}
+        [one] {" "}{$conflicts} message defined by two files kept its first definition.
+       *[other] {" "}{$conflicts} messages defined by two files kept their first definition.
+    }
+reload_translations__failed = Translations not reloaded
+    .title = Translations not reloaded
+    .message = The translations in use were kept, as these files could not be loaded:
+            {$errors}
+error__internal_error = Internal error
+    .title = Internal error
+    .message = An unexpected error occurred while executing the command.
+            Please try again or contact support if the problem persists: {support}
 #Universe
 universe = universe
     .description = Universe management commands.
@@ -43,17 +356,339 @@ universe_create_universe = new_universe
     .universe_name = name
     .universe_name-description = Name of the new Universe
     .setup_type = setup_type
-    .setup_type-description = Configuration type for this server
+    .setup_type-description = Configuration type for this server, or empty to choose after the creation
 universe_add_server = add
     .description = Adds this server to an existing universe.
     .setup_type = setup_type
     .setup_type-description = Configuration type for this server
+universe_add_admin = add_admin
+    .description = Makes a user an admin of the universe, able to change its settings and add servers to it.
+    .user = user
+    .user-description = User to make an admin.
+universe_remove_admin = remove_admin
+    .description = Removes a user from the admins of the universe.
+    .user = user
+    .user-description = Admin to remove.
 universe_setup = setup
     .description = Configure or reconfigure the current server for the universe it is linked to.
     .setup_type = setup_type
-    .setup_type-description = Type of setup to perform (Full or Partial).
+    .setup_type-description = Type of setup to perform (Full, Partial or Repair).
+    .dry_run = dry_run
+    .dry_run-description = Only shows what would be created, without changing anything.
+universe_settings = settings
+    .description = Universe-wide settings.
+universe_settings_set_name = set_name
+    .description = Sets the name the setup gives to a role or channel.
+    .key = key
+    .key-description = Name or role color to customize (e.g. admin_role_name, admin_role_color).
+    .value = value
+    .value-description = New name, or #rrggbb color.
+universe_settings_apply_names = apply_names
+    .description = Renames the existing roles and channels of this server after the current names.
+universe_settings_road_slowmode = road_slowmode
+    .description = Sets the seconds of slowmode road channels get per kilometer, 0 for a fixed slowmode.
+    .seconds_per_km = seconds_per_km
+    .seconds_per_km-description = Seconds of slowmode per kilometer of road. 0 gives every road the same slowmode.
+    .apply_to_existing = apply_to_existing
+    .apply_to_existing-description = Also update the slowmode of the existing road channels (default: no).
+universe_settings_contest_die = contest_die
+    .description = Sets the dice each side of a contest rolls, 1d20 by default.
+    .expression = expression
+    .expression-description = Dice expression, such as 1d20 or 2d10kh1.
+universe_settings_join = join
+    .description = Sets the place new players start in and whether joining needs an approved character.
+    .starting_place = starting_place
+    .starting_place-description = Place whose role new players get, or - for none.
+    .requires_approval = requires_approval
+    .requires_approval-description = Only players with an approved character can join.
+universe_settings_general = general
+    .description = Sets the default language and the time speed of the universe in a form.
+universe_export = export
+    .description = Sends a JSON backup of the universe, its servers, places, roads, stats and characters.
+universe_import = import
+    .description = Restores a universe from a backup sent by the export command.
+    .file = file
+    .file-description = JSON file sent by the export command, gzipped or not.
+universe_set_server_locale = set_server_locale
+    .description = Sets the language of this server, used over the default language of the universe.
+    .locale = locale
+    .locale-description = Language of the server (en-US, fr), or - to use the one of the universe.
+universe_status = status
+    .description = Shows which channels and roles managed by the setup exist on this server.
+universe_teardown = teardown
+    .description = Deletes every channel, category and role managed by the bot on this server.
 universe_time = time
     .description = Displays the current time of the universe.
+universe_verify = verify
+    .description = Compares the server configuration stored by the bot with the channels and roles present on Discord.
+
+add_server__confirm = Join {$universe}?
+    .title = Add this server to {$universe}?
+    .message = The setup will then create the roles and channels of {$universe} on this server, and its members will be able to play in it. A server belongs to one universe at a time.
+    .cancel = Cancel
+    .confirm = Confirm
+add_server__confirmation_timeout = Not confirmed
+    .title = Not confirmed
+    .message = The choice was not confirmed in time. The server was not added to any universe.
+add_server__log = Server linked
+    .title = Server linked
+    .message = {$actor} linked the server to the universe {$universe}.
+add_server__cancelled = Cancelled
+    .title = Cancelled
+    .message = The server was not added to any universe.
+add_server__selection_timeout = No universe chosen
+    .title = No universe chosen
+    .message = No universe was chosen in time. Run the command again to add this server to a universe.
+add_server__universe_not_found = Universe not found
+    .title = Universe not found
+    .message = The chosen universe no longer exists. Run the command again to choose another one.
+add_server__database_error = Database error
+    .title = Database error
+    .message = The chosen universe could not be read. Please try again later.
+add_server_to_universe__guild_reattached = Server reattached
+    .title = Server reattached
+    .message = This server was already part of the universe. It has been reattached and its setup has been refreshed.
+add_server_to_universe__already_bind = Server already in a universe
+    .title = Server already in a universe
+    .message = This server is already part of a universe.
+add_server_to_universe__universes_unavailable = No universe available
+    .title = No universe available
+    .message = You neither created nor administrate a universe this server could join. Create one first.
+add_server_to_universe__guild_linked = Server added
+    .title = Server added
+    .message = This server has been added to the universe and set up.
+choose_universe = Choose the universe to add this server to.
+guild_delete__server_detached = Server detached
+    .title = Server removed from {$universe}
+    .message = The bot has been removed from the server {$server}. It stays registered in the universe {$universe} and can be reattached with `/universe add`.
+universe_status__report = Setup status
+    .title = Setup status
+universe_status__row = Resource
+    .admin_role_id = Admin role
+    .moderator_role_id = Moderator role
+    .spectator_role_id = Spectator role
+    .player_role_id = Player role
+    .everyone_role_id = Everyone role
+    .admin_category_id = Admin category
+    .nrp_category_id = Non-RP category
+    .rp_category_id = RP category
+    .road_category_id = Roads category
+    .rp_wiki_channel_id = Wiki channel
+    .log_channel_id = Log channel
+    .moderation_channel_id = Moderation channel
+    .commands_channel_id = Commands channel
+    .nrp_general_channel_id = Non-RP general channel
+    .rp_character_channel_id = Character channel
+    .universal_time_channel_id = Universal time channel
+    .readme_channel_id = README channel
+universe_status__state = Status
+    .present = present
+    .missing = missing on Discord
+    .not_configured = not configured
+universe_teardown__confirm = Confirm teardown?
+    .title = Confirm teardown
+    .message = Every channel, category and role created by the bot on this server, including places and roads, will be deleted. This cannot be undone.
+    .cancel = Cancel
+    .confirm = Continue
+universe_add_admin__success = Admin added
+    .title = Admin added
+    .message = {$user} is now an admin of the universe.
+universe_add_admin__already_admin = Already an admin
+    .title = Already an admin
+    .message = {$user} is already an admin of the universe.
+universe_add_admin__creator = Universe creator
+    .title = Universe creator
+    .message = {$user} created the universe and already has every right on it.
+universe_add_admin__bot = Bots cannot be admins
+    .title = Bots cannot be admins
+    .message = {$user} is a bot and cannot administrate the universe.
+universe_remove_admin__success = Admin removed
+    .title = Admin removed
+    .message = {$user} is no longer an admin of the universe.
+universe_remove_admin__not_admin = Not an admin
+    .title = Not an admin
+    .message = {$user} is not an admin of the universe.
+create_universe__universe_successfully_created = Universe created
+    .title = Universe created
+    .message = The universe has been created and this server attached to it.
+create_universe__run_full_setup = Run full setup
+create_universe__run_partial_setup = Run partial setup
+create_universe__check_universe_limit_failed = Universe creation failed
+    .title = Universe creation failed
+    .message = Your universes could not be counted. Please try again later.
+create_universe__already_exist_for_this_server = Server already in a universe
+    .title = Server already in a universe
+    .message = This server is already part of a universe, so no other one can be created from it.
+universe__check_server_limit_failed = Server limit check failed
+    .title = Server limit check failed
+    .message = The servers of the universe could not be counted.
+            Please try again or contact support if the problem persists: {support}
+create_universe__universe_limit_reached = Universe limit reached
+    .title = Universe limit reached
+    .message = You already created as many universes as you can.
+create_universe__get_server_failed = Universe creation failed
+    .title = Universe creation failed
+    .message = This server could not be read from the database. Please try again later.
+create_universe__universe_insert_failed = Universe creation failed
+    .title = Universe creation failed
+    .message = The universe could not be saved. Nothing was created, please try again later.
+create_universe__setup_constraints_failed = Universe creation failed
+    .title = Universe creation failed
+    .message = The database indexes of the universe could not be created, so the universe was removed. Please try again later.
+create_universe__server_insert_failed = Universe creation failed
+    .title = Universe creation failed
+    .message = This server could not be attached to the universe, so the universe was removed. Please try again later.
+create_universe__speed_stat_insert_failed = Universe creation failed
+    .title = Universe creation failed
+    .message = The default stats could not be saved, so the universe was removed. Please try again later.
+create_universe__name_length = Invalid universe name
+    .title = Invalid universe name
+    .message = A universe name must be between 2 and 64 characters long.
+create_universe__name_characters = Invalid universe name
+    .title = Invalid universe name
+    .message = A universe name can only contain letters, digits, spaces, and the characters - _ and '.
+create_universe__name_taken = Universe name already used
+    .title = Universe name already used
+    .message = You already created a universe with this name.
+universe__not_universe_creator = Universe creator only
+    .title = Universe creator only
+    .message = Only the creator of the universe can do this. Being an administrator of this server is not enough.
+universe__not_universe_admin = Universe admins only
+    .title = Universe admins only
+    .message = Only the creator of the universe, its admins and the members with its admin role can do this.
+universe_teardown__notice = Server torn down
+    .title = Server torn down
+    .message = {$actor} tore the server down: {$deleted} roles and channels deleted, {$failed} could not be deleted.
+universe_teardown__log = Server torn down
+    .title = Server torn down
+    .message = {$actor} tore the server down; {$failed} resources could not be deleted.
+universe_teardown__success = Teardown completed
+    .title = Teardown completed
+    .message = Deleted: {$deleted}, failed: {$failed}, already missing: {$skipped}
+universe_teardown__cancelled = Teardown cancelled
+    .title = Teardown cancelled
+    .message = Nothing has been deleted
+universe_teardown__timeout = Teardown timeout exceeded
+    .title = Timeout exceeded
+    .message = The time to confirm the teardown has expired
+universe_teardown__failed = Teardown failed
+    .title = Teardown error
+    .message = The teardown could not be completed
+            Please try again or contact support if the problem persists: {support}
+universe_export__success = Universe exported
+    .title = {$universe} exported
+    .message = The attached file holds {$servers} servers, {$places} places, {$roads} roads, {$stats} stats and {$characters} characters. Keep it somewhere safe.
+universe_export__failed = Export failed
+    .title = Export error
+    .message = The backup of the universe could not be written
+            Please try again or contact support if the problem persists: {support}
+universe_import__success = Universe imported
+    .title = {$universe} imported
+    .message = {$servers} servers, {$places} places, {$roads} roads, {$stats} stats and {$characters} characters were restored. Run the setup in repair mode on each server to recreate its channels and roles.
+universe_import__cancelled = Import cancelled
+    .title = Import cancelled
+    .message = Nothing has been imported
+universe_import__timeout = Import timeout exceeded
+    .title = Timeout exceeded
+    .message = The time to confirm the import has expired
+universe_import__confirm_transfer = Take the universe over?
+    .title = Take {$universe} over?
+    .message = This backup was made by {$creator}. Importing it makes you the creator of the restored universe.
+    .cancel = Cancel
+    .confirm = Import
+universe_import__download_failed = Download failed
+    .title = Download error
+    .message = The attached file could not be downloaded. Please try again.
+universe_import__invalid_file = Invalid backup
+    .title = Invalid backup
+    .message = The attached file is not a backup sent by the export command.
+universe_import__unsupported_version = Unsupported backup
+    .title = Unsupported backup
+    .message = This backup was written in another format. Only version {$version} can be imported.
+universe_import__server_conflict = Server already in a universe
+    .title = Server already in a universe
+    .message = The server {$server_id} of the backup is already part of a universe. Remove it from that universe before importing. Nothing has been imported.
+universe_import__server_limit = Too many servers
+    .title = Too many servers
+    .message = The backup has {$servers} servers, but your universes can span at most {$max}. Nothing has been imported.
+universe_import__server_not_administered = Server not administered
+    .title = Server not administered
+    .message = The server {$server_id} of the backup cannot be imported: the bot must be on it and you must be one of its administrators. Nothing has been imported.
+universe_import__failed = Import failed
+    .title = Import error
+    .message = The universe could not be restored and nothing has been kept
+            Please try again or contact support if the problem persists: {support}
+universe_settings_set_name__success = Name updated
+    .title = Name updated
+    .message = `{$key}` is now named "{$value}". Run the apply_names command to rename the existing roles and channels.
+universe_settings_apply_names__success = Names applied
+    .title = Names applied
+    .message = Renamed: {$renamed}, failed: {$failed}
+universe_settings__unknown_name_key = Unknown name
+    .title = Unknown name
+    .message = This name cannot be customized. Pick one of the suggested keys.
+universe_settings__empty_name = Empty name
+    .title = Empty name
+    .message = The new name cannot be empty.
+universe_settings__invalid_color = Invalid color
+    .title = Invalid color
+    .message = Role colors are written as hexadecimal, like #e74c3c.
+universe_settings_road_slowmode__success = Road slowmode updated
+    .title = Road slowmode updated
+    .message = Roads now get {$seconds} second(s) of slowmode per kilometer. Existing road channels keep their slowmode until their road is edited.
+universe_settings_road_slowmode__applied = Road slowmode applied
+    .title = Road slowmode applied
+    .message = Roads now get {$seconds} second(s) of slowmode per kilometer. Updated road channels: {$updated}, failed: {$failed}
+universe_settings_contest_die__success = Contest die updated
+    .title = Contest die updated
+    .message = Each side of a contest now rolls {$expression}.
+universe_settings_join__success = Join settings updated
+    .title = Join settings updated
+    .message = Starting place: {$place}. Approved character required: {$requires_approval}.
+universe_settings_general__title = Universe settings
+universe_settings_general__locale = Default language (en-US, fr or empty)
+universe_settings_general__time_modifier = Time speed in percent (100 = normal)
+universe_settings_general__timed_out = Timed out
+    .title = Form timed out
+    .message = The settings form was not submitted in time.
+universe_settings_general__unknown_locale = Unknown language
+    .title = Unknown language
+    .message = No translation exists for this language. Use en-US, fr or leave the field empty.
+universe_settings_general__invalid_time_modifier = Invalid time speed
+    .title = Invalid time speed
+    .message = The time speed must be a positive whole number of percent.
+universe_settings_general__success = Universe settings updated
+    .title = Universe settings updated
+    .message = Default language: {$locale}. Time speed: {$time_modifier}%.
+universe_settings_general__log = Universe settings changed
+    .title = Settings of {$universe} changed
+    .message = {$actor} set the default language to {$locale} and the time speed to {$time_modifier}%.
+universe_set_server_locale__unknown_locale = Unknown language
+    .title = Unknown language
+    .message = No translation exists for this language. Use en-US, fr or - to use the language of the universe.
+universe_set_server_locale__success = Server language updated
+    .title = Server language updated
+    .message = Language of this server: {$locale}. Run the setup again to rename its channels and roles.
+universe_set_server_locale__log = Server language changed
+    .title = Language of a server of {$universe} changed
+    .message = {$actor} set the language of this server to {$locale}.
+verify__report = Consistency report
+    .title = Configuration drift detected
+    .message = Missing resources:
+            {$missing}
+            Orphaned resources:
+            {$orphaned}
+verify__no_drift = No drift
+    .title = Configuration consistent
+    .message = Every channel and role stored by the bot still exists on Discord.
+verify__discord_error = Failed to read the server
+    .title = Verification error
+    .message = Unable to retrieve the channels and roles of the server
+            Please try again or contact support if the problem persists: {support}
+verify__database_error = Database error during verification
+    .title = Verification error
+    .message = Unable to retrieve the server configuration
+            Please try again or contact support if the problem persists: {support}
 
 #Roads
 road = road
@@ -68,6 +703,28 @@ road_create_road = create_road
     .distance-description = Distance between the two places in kilometers.
     .secret_channel = secret
     .secret_channel-description = If true, the road will not be displayed on public maps.
+    .name = name
+    .name-description = Name of the road. Defaults to the names of both places.
+road_delete_road = delete
+    .description = Deletes a road, given its channel or its two places.
+    .road = road
+    .road-description = Channel of the road to delete.
+    .place_one = place_one
+    .place_one-description = First end of the road, if the channel is not given.
+    .place_two = place_two
+    .place_two-description = Second end of the road, if the channel is not given.
+road_list_roads = list
+    .description = Lists the roads of the universe, or only those of one place.
+    .place = place
+    .place-description = Only show the roads leading to this place.
+road_map = map
+    .description = Exports the roads of the universe as a Graphviz map.
+road_edit_road = edit
+    .description = Changes the distance of a road.
+    .road = road
+    .road-description = Channel of the road to edit.
+    .distance = distance
+    .distance-description = New distance between the two places in kilometers.
 
 #Places
 place = place
@@ -76,14 +733,106 @@ place_create_place = new_place
     .description = Creates a new category corresponding to a city or interaction place.
     .name = name
     .name-description = Name of the place to create.
+    .with_text = with_text
+    .with_text-description = Create a general text channel in the place (default: yes).
+    .with_voice = with_voice
+    .with_voice-description = Create a voice channel in the place (default: no).
+place_delete_place = delete
+    .description = Deletes a place, with its role, category and channels.
+    .place = place
+    .place-description = Place to delete.
+place_list_places = list
+    .description = Lists the places of the universe.
+place_rename_place = rename
+    .description = Renames a place, its role and the roads named after it.
+    .place = place
+    .place-description = Place to rename.
+    .name = name
+    .name-description = New name of the place.
+place_info_place = info
+    .description = Shows a place, the places it leads to and its modifiers.
+    .place = place
+    .place-description = Place to show.
+place_occupants = occupants
+    .description = Lists the characters standing in a place, grouped by player.
+    .place = place
+    .place-description = Place whose occupants are listed.
+place_add_modifier = add_modifier
+    .description = Adds a modifier on a stat to a place.
+    .place = place
+    .place-description = Place to modify.
+    .stat = stat
+    .stat-description = Name of the stat to modify.
+    .kind = kind
+    .kind-description = How the value changes the stat.
+    .value = value
+    .value-description = Value of the modifier.
+    .duration = duration
+    .duration-description = Duration in in-universe hours. Permanent when empty.
+place_remove_modifier = remove_modifier
+    .description = Removes the modifiers of a kind on a stat from a place.
+    .place = place
+    .place-description = Place to modify.
+    .stat = stat
+    .stat-description = Name of the modified stat.
+    .kind = kind
+    .kind-description = Kind of the modifiers to remove.
+modifier_type_base = Base
+modifier_type_multiplier = Multiplier
+modifier_type_flats = Flat
+modifier_expiry__notice = Modifiers expired
+    .title = Modifiers expired: {$holder}
+    .line = The {$kind} modifier on {$stat} has ended.
+place_general_channel_name = {$place_name}-general
+place_voice_channel_name = {$place_name} voice
 create_place__new_place_title = Place: {$place_name}
+create_place__wiki_template = ## {$place_name}
+    _Describe the place here._
+    ### Roads
+    No road leads here yet.
 create_place__channel_id = Place Id
 
 #Characters
 character = character
     .description = Character management commands.
 character_create_character = new_character
-    .description = Allows you to create your character in the universe. Only one character per player.
+    .description = Allows you to create a character in the universe. Players can have several and choose the one they play with /character switch.
+character_list = list
+    .description = Lists your characters, or those of another player for moderators.
+    .user = user
+    .user-description = Player whose characters to list, yourself by default.
+character_show = show
+    .description = Shows the sheet of a character, with its stats once modifiers are applied.
+    .name = name
+    .name-description = Name of the character.
+character_stat = stat
+    .description = Character statistics commands.
+character_stat_set = set
+    .description = Changes the value of a stat of a character, the one you play by default.
+    .stat = stat
+    .stat-description = Stat to change.
+    .value = value
+    .value-description = New value of the stat.
+    .character = character
+    .character-description = Name of the character, the one you play by default.
+character_switch = switch
+    .description = Chooses which of your characters you are playing.
+    .name = name
+    .name-description = Name of the character to play.
+character_delete = delete
+    .description = Deletes a character, its sheet and the roles its travels gave.
+    .name = name
+    .name-description = Name of the character to delete.
+character_teleport = teleport
+    .description = Moves the character of a player to a place at once, without travelling.
+    .user = user
+    .user-description = Player whose character is moved.
+    .place = place
+    .place-description = Place where the character is moved.
+whereis = whereis
+    .description = Shows the character a player is playing and where it is.
+    .user = user
+    .user-description = Player to look for, yourself by default.
 
 #Travels
 travel = travel
@@ -94,6 +843,14 @@ travel_start = start
     .destination-description = The place where you want to go (ID or mention).
 travel_stop = stop
     .description = Stops your current journey on the road you are currently on.
+travel_status = status
+    .description = Shows where your character is and how long its journey still lasts.
+travel_cancel = cancel
+    .description = Gives up your journey and goes back to the place you left.
+navigate = navigate
+    .description = Shows the shortest way from the place of your character to a destination.
+    .destination = destination
+    .destination-description = The place you want to reach.
 
 #Misc
 ping = ping
@@ -101,7 +858,21 @@ ping = ping
 support_command = support
     .description = Displays information to support the project.
 start = start
-    .description = Displays startup instructions.
+    .description = Guides you through setting the bot up on this server.
+botstats = botstats
+    .description = Shows how many commands ran, failed and how long they took (bot owners only).
+reload_translations = reload_translations
+    .description = Reads the translation files again and uses them right away (bot owners only).
+grant_limits = grant_limits
+    .description = Sets how many universes a user can create and servers they can span (bot owners only).
+    .user = user
+    .user-description = User whose limits are set.
+    .max_universes = max_universes
+    .max_universes-description = Most universes the user can create.
+    .max_servers_per_universe = max_servers_per_universe
+    .max_servers_per_universe-description = Most servers each universe of the user can span.
+    .days = days
+    .days-description = Number of days before the user is back to the free limits. Forever when omitted.
 
 #Server
 id__nothing_to_delete = Nothing to delete
@@ -123,8 +894,15 @@ id__channel_delete_failed = Failed to delete channel
     .title = Deletion error
     .message = Unable to delete the channel
             Please try again or contact support if the problem persists: {support}
+reconcile__deleted_resource = Deleted resource forgotten
+    .title = Resource deleted outside of the bot
+    .message = The resource `{$id}` has been deleted from Discord.
+            Cleared configuration: {$fields}
+            Places removed: {$places}
+            Roads removed: {$roads}
 
 #Setup
+readme_channel_name = readme
 SetupType = SetupType
     .FullSetup = Full
     .PartialSetup = Partial
@@ -133,6 +911,55 @@ continue_setup = Continue
 setup__continue_setup_message = Continue setup?
     .title = Continue setup
     .message = Do you want to continue the setup despite a previous setup? Missing channels and roles will be created.
+    .cancel = Cancel
+    .confirm = Continue
+setup__dry_run = Setup preview
+    .title = Setup preview
+    .message = Nothing has been changed. ✅ resources already exist and would be reused, ➕ resources would be created.
+setup__dry_run_get_channels_error = Failed to retrieve guild channels
+    .title = Setup error
+    .message = Unable to retrieve the server channels
+            Please try again or contact support if the problem persists: {support}
+setup__repair_success = Repair completed
+    .title = Repair completed
+    .message = {$recreated}
+            {$untouched}
+            {$failed}
+setup__repair_recreated = { $count ->
+        [one] {$count} resource recreated:
+       *[other] {$count} resources recreated:
+    }
+setup__repair_untouched = { $count ->
+        [one] {$count} resource left untouched:
+       *[other] {$count} resources left untouched:
+    }
+setup__repair_failed = { $count ->
+        [one] {$count} resource could not be recreated:
+       *[other] {$count} resources could not be recreated:
+    }
+setup__repair_missing_dependency = Missing dependency
+    .title = Repair error
+    .message = A role or category needed to recreate this resource is not configured
+setup__progress = Setup in progress
+    .title = Setup in progress
+    .roles = Roles
+    .road_category = Roads category
+    .categories = Categories
+    .channels = Channels
+    .created = {$created}/{$expected} created
+setup__already_in_progress = Operation already in progress
+    .title = Operation in progress
+    .message = A setup, place or road creation is already running on this server. Please wait for it to finish.
+preflight__missing_permissions = Missing bot permissions
+    .title = Missing bot permissions
+    .message = The bot cannot do this on this server yet. Fix the following in the server settings, then try again:
+        {$permissions}
+preflight__missing_manage_roles = The Manage Roles permission
+preflight__missing_manage_channels = The Manage Channels permission
+preflight__role_too_low = A role of the bot above @everyone
+setup__bot_role_too_low = Bot role too low
+    .title = Bot role too low
+    .message = The bot's highest role must be above the roles it manages. Move the bot's role higher in the server settings, then run the setup again.
 setup__server_already_setup_timeout = Setup timeout exceeded
     .title = Timeout exceeded
     .message = The time to continue the setup has expired
@@ -140,6 +967,14 @@ partial_setup__get_guild_roles_error = Failed to retrieve guild roles
     .title = Setup error
     .message = Unable to retrieve roles from the server.
             Please try again or contact support if the problem persists: {support}
+item__server_not_found = Server not found
+    .title = Server not found
+    .message = This server is not registered in our database.
+            Please try again or contact support if the problem persists: {support}
+create_item__db_error = Item creation failed
+    .title = Item creation failed
+    .message = The item could not be saved.
+            Please try again or contact support if the problem persists: {support}
 setup__server_not_found = Server not found
     .title = Server not found
     .message = This server is not registered in our database.
@@ -147,6 +982,9 @@ setup__server_not_found = Server not found
 setup_server__cancelled = Setup cancelled
     .title = Setup cancelled
     .message = Server setup has been cancelled
+setup__log = Server set up
+    .title = Server set up
+    .message = {$actor} ran the setup of the server.
 setup_server__success = Setup successful
     .title = Success
     .message = The server has been successfully configured
@@ -186,6 +1024,7 @@ setup__log_channel_not_created = Logs channel not created
     .message = Unable to create the log channel
             Please try again or contact support if the problem persists: {support}
 commands_channel_name = Commands
+commands_channel_topic = Use the bot commands here
 setup__commands_channel_not_created = Commands channel not created
     .title = Creation error
     .message = Unable to create the commands channel
@@ -275,6 +1114,12 @@ create_place__database_not_found = Database not found
     .title = Connection failed
     .message = The database connection failed.
             Please try again or contact support if the problem persists: {support}
+create_place__setup_required = Setup required
+    .title = Setup required
+    .message = This server is not set up yet. Run /universe setup PartialSetup first.
+create_place__name_already_exists = Place already exists
+    .title = Place already exists
+    .message = The universe already has a place with this name. Pick another name.
 create_place__role_not_created = Role creation failed
     .title = Role creation failed
     .message = The place role could not be created correctly.
@@ -286,9 +1131,131 @@ create_role__rollback_failed = Rollback failed
     .title = Rollback failed
     .message = Something went wrong during place creation and the rollback failed.
             Please contact support: {support}
+create_place__log = Place created
+    .title = Place created
+    .message = {$actor} created the place {$place}.
 create_place__success = Place created
     .title = Place created
     .message = The place has been successfully created.
+create_place__success_with_wiki = Place created
+    .title = Place created
+    .message = {$place} has been successfully created. Its wiki page: {$wiki_post}
+create_place__success_wiki_failed = Place created
+    .title = Place created without wiki page
+    .message = {$place} has been successfully created, but its wiki page could not be posted. Check the bot's permissions in the wiki forum.
+delete_place__invalid_place = Invalid place
+    .title = Invalid place
+    .message = Pick a place from the suggestions, or give the ID or mention of its category.
+delete_place__not_found = Place not found
+    .title = Place not found
+    .message = This category is not a place of the universe.
+delete_place__other_server = Place on another server
+    .title = Place on another server
+    .message = {$place} belongs to another server of the universe. Delete it from that server.
+delete_place__roads_remaining = Roads remaining
+    .title = Roads remaining
+    .message = {$place} is still connected by these roads, delete them first:
+            {$roads}
+delete_place__discord_delete_failed = Deletion failed
+    .title = Deletion failed
+    .message = The role or a channel of {$place} could not be deleted. Check the bot's permissions.
+            Please try again or contact support if the problem persists: {support}
+delete_place__database_delete_failed = Place partially deleted
+    .title = Place partially deleted
+    .message = The role and channels of {$place} were deleted but its record could not be removed from the database.
+            Please try again or contact support if the problem persists: {support}
+delete_place__notice = Place deleted
+    .title = Place deleted
+    .message = {$actor} deleted the place {$place}, with its role, its channels and its wiki posts.
+delete_place__log = Place deleted
+    .title = Place deleted
+    .message = {$actor} deleted the place {$place}.
+delete_place__success = Place deleted
+    .title = Place deleted
+    .message = {$place} has been deleted.
+place_list__empty = No places
+    .title = No places
+    .message = This universe has no places yet.
+place_list__page = Places
+    .title = { $count ->
+        [one] {$count} place
+       *[other] {$count} places
+    } - page {$page}/{$pages}
+place_list__row = Place
+    .message = **{$name}** - {$category} - {$role} - { $modifiers ->
+        [one] {$modifiers} modifier
+       *[other] {$modifiers} modifiers
+    }
+place_occupants__empty = No occupants
+    .title = No occupants
+    .message = No character is in this place.
+place_occupants__not_here = Place out of sight
+    .title = Place out of sight
+    .message = You can only see the occupants of the place your character is in.
+place_occupants__page = Occupants
+    .title = { $count ->
+        [one] {$count} occupant
+       *[other] {$count} occupants
+    } of {$place} - page {$page}/{$pages}
+place_occupants__row = Occupant
+    .message = {$user}: {$names}
+rename_place__empty_name = Empty name
+    .title = Empty name
+    .message = The new name of the place cannot be empty.
+rename_place__other_server = Place on another server
+    .title = Place on another server
+    .message = {$old} belongs to another server of the universe. Rename it from that server.
+rename_place__discord_edit_failed = Rename failed
+    .title = Rename failed
+    .message = A channel or role of {$old} could not be renamed. The other renames were reverted.
+            Please try again or contact support if the problem persists: {support}
+rename_place__revert_failed = Rename partially applied
+    .title = Rename partially applied
+    .message = A channel or role of {$old} could not be renamed, and some renames could not be reverted. Check the names of the place and its roads.
+            Please contact support: {support}
+rename_place__database_failed = Rename not saved
+    .title = Rename not saved
+    .message = The new name of {$old} could not be saved in the database. The Discord renames were reverted.
+            Please try again or contact support if the problem persists: {support}
+rename_place__success = Place renamed
+    .title = Place renamed
+    .message = {$old} is now named {$new}. Roads renamed: {$roads}
+place_info__fields = Place
+    .role = Role
+    .category = Category
+    .roads = Roads
+    .modifiers = Modifiers
+place_info__road = Road
+    .message = - {$place}: {$distance} km ({$channel})
+place_info__no_roads = No roads
+    .message = No roads lead to this place yet.
+place_info__modifier = Modifier
+    .message = - {$stat}: {$kind} {$value}, formula {$formula}, {$expiry}
+place_info__modifier_kind = Modifier kind
+    .base = base
+    .multiplier = multiplier
+    .flat = flat
+place_info__no_modifiers = No modifiers
+    .message = No active modifiers.
+place_info__permanent = Permanent
+    .message = permanent
+place_modifier__stat_not_found = Stat not found
+    .title = Stat not found
+    .message = The universe has no stat named {$stat}.
+place_modifier__time_stopped = Time stopped
+    .title = Time stopped
+    .message = The time of the universe is stopped, a modifier with a duration would never end. Leave the duration empty.
+place_modifier__not_found = Modifier not found
+    .title = Modifier not found
+    .message = {$place} has no modifier of this kind on {$stat}.
+place_modifier__added = Modifier added
+    .title = Modifier added
+    .message = Modifiers of {$place}:
+            {$modifiers}
+place_modifier__removed = Modifier removed
+    .title = Modifier removed
+    .message = {$removed} modifier(s) on {$stat} removed. Modifiers of {$place}:
+            {$modifiers}
 
 #Create road
 create_road = create_road
@@ -299,6 +1266,9 @@ create_road__database_error = Database error
     .title = Database error
     .message = An error occurred while accessing the database.
                         Please try again or contact support if the problem persists: {support}
+create_road__setup_required = Setup required
+    .title = Setup required
+    .message = This server has no road category yet. Run /universe setup PartialSetup first.
 create_place__place_one_not_found = First place not found
     .title = First place not found
     .message = The first specified place was not found in the universe.
@@ -331,15 +1301,21 @@ create_road__insert_road_failed_rollback_role_failed = Critical error
     .title = Critical error
     .message = The road registration failed and the role rollback failed.
                         Please contact support: {support}
+create_road__log = Road created
+    .title = Road created
+    .message = {$actor} created the road {$road}: {$channel}
 create_road__success = Road created
     .title = Road created
-    .message = The road has been successfully created
+    .message = The road {$road} has been successfully created: {$channel}
 create_road__limit_reached = Road limit reached
     .title = Limit reached
     .message = One of the places has already reached the maximum of 25 roads (excluding secret roads).
 create_road__already_exists = Road already exists
     .title = Existing road
-    .message = A road already exists between these two places.
+    .message = A road already exists between these two places: {$channel}
+create_road__same_place = Same place
+    .title = Same place
+    .message = A road must connect two different places.
 create_road__universe_mismatch = Different universe
     .title = Different universe
     .message = Both places must belong to the same universe.
@@ -349,6 +1325,67 @@ create_road__invalid_place_one = Invalid first place ID
 create_road__invalid_place_two = Invalid second place ID
     .title = Invalid second place
     .message = The ID or mention of the second place is invalid. Use an ID or a mention <#id>.
+delete_road__missing_road = Road missing
+    .title = Road missing
+    .message = Give the channel of the road, or both of its places.
+delete_road__not_found = Road not found
+    .title = Road not found
+    .message = No road of the universe matches.
+delete_road__other_server = Road on another server
+    .title = Road on another server
+    .message = The road {$road} belongs to another server of the universe. Delete it from that server.
+delete_road__channel_delete_failed = Channel deletion failed
+    .title = Channel deletion failed
+    .message = The channel of the road {$road} could not be deleted, nothing was deleted. Check the bot's permissions.
+            Please try again or contact support if the problem persists: {support}
+delete_road__role_delete_failed = Role deletion failed
+    .title = Road partially deleted
+    .message = The channel of the road {$road} was deleted but not its role, and the road is still saved.
+            Please try again or contact support if the problem persists: {support}
+delete_road__database_delete_failed = Road partially deleted
+    .title = Road partially deleted
+    .message = The channel and role of the road {$road} were deleted but its record could not be removed from the database.
+            Please try again or contact support if the problem persists: {support}
+delete_road__notice = Road deleted
+    .title = Road deleted
+    .message = {$actor} deleted the road {$road}, with its role and its channel.
+delete_road__log = Road deleted
+    .title = Road deleted
+    .message = {$actor} deleted the road {$road}.
+delete_road__success = Road deleted
+    .title = Road deleted
+    .message = The road between {$place_one} and {$place_two} has been deleted.
+road_list__empty = No roads
+    .title = No roads
+    .message = No road matches yet. Connect two places with /road create_road.
+road_map__success = Road map
+    .title = Road map
+    .message = Map of { $places ->
+        [one] {$places} place
+       *[other] {$places} places
+    } and { $roads ->
+        [one] {$roads} road
+       *[other] {$roads} roads
+    }, in the Graphviz format. Dashed roads are secret, orange roads have active modifiers.
+road_map__export_failed = Export failed
+    .title = Export failed
+    .message = The map could not be compressed.
+road_list__page = Roads
+    .title = { $count ->
+        [one] {$count} road
+       *[other] {$count} roads
+    } - page {$page}/{$pages}
+road_list__row = Road
+    .message = **{$place_one}** ⟷ **{$place_two}** — distance {$distance} km — {$channel}
+edit_road__invalid_distance = Invalid distance
+    .title = Invalid distance
+    .message = The distance of a road must be greater than 0.
+edit_road__success = Road edited
+    .title = Road edited
+    .message = Distance of {$road}: {$old_distance} km → {$new_distance} km
+edit_road__slowmode_not_applied = Road edited
+    .title = Road edited, slowmode not updated
+    .message = Distance of {$road}: {$old_distance} km → {$new_distance} km. The slowmode of its channel could not be updated, check the bot's permissions.
 
 #Create character
 create_character = new_character
@@ -377,9 +1414,6 @@ create_character__database_error = Database error
 create_character__wrong_channel = Wrong channel
     .title = Wrong channel
     .message = This command must be used in the character sheet channel.
-create_character__character_already_existing = Character already exists
-    .title = Character already exists
-    .message = You already have a character. You can't create another one.
 CharacterModal = character_modal
     .character_name = Name
     .character_description = Character's description
@@ -448,6 +1482,116 @@ accept_character__no_player_role_id = Server not configured
 accept_character__member_not_found = Member not found during acceptance.
     .title = Acceptance error
     .message = Unable to find the user on the server.
+character_create__name_taken = Name already taken
+    .title = Name already taken
+    .message = A character of this universe is already named {$name}.
+character_sheet__fields = Character sheet
+    .owner = Player
+    .description = Description
+    .stats = Statistics
+    .status = Status
+    .place = Place
+character_sheet__no_description = No description
+    .message = _No description yet._
+character_sheet__no_stats = No statistics
+    .message = _This universe has no statistics._
+character_sheet__update_failed = Sheet not updated
+    .message = The character sheet message could not be updated.
+character_status = Status
+    .pending = Awaiting approval
+    .approved = Approved
+    .rejected = Rejected
+character_list__empty = No characters
+    .title = No characters
+    .message = There is no character to list yet. Create one with /character new_character.
+character_list__page = Characters
+    .title = { $count ->
+        [one] {$count} character
+       *[other] {$count} characters
+    } of {$user} - page {$page}/{$pages}
+character_list__row = Character
+    .message = **{$name}** — {$status} — {$place}
+character_list__no_place = No place
+    .message = nowhere yet
+character_approval__approved = {$name} has been approved by {$moderator}.
+character_approval__rejected = {$name} has been rejected by {$moderator}.
+character_approval__rejected_notice = Character rejected
+    .title = Character rejected
+    .message = {$actor} rejected {$name}, the character of {$user}.
+character_approval__not_found = This character no longer exists.
+character_approval__already_decided = A moderator has already decided on this character.
+character__pending_approval = Character awaiting approval
+    .title = Character awaiting approval
+    .message = Your character has not been approved by a moderator yet.
+character__rejected = Character rejected
+    .title = Character rejected
+    .message = Your character has been rejected by a moderator and cannot play.
+character__not_found = Character not found
+    .title = Character not found
+    .message = No character of this universe is named {$name}.
+character__no_active = No character played
+    .title = No character played
+    .message = You are not playing any character. Create one with /character new_character, or choose one of yours with /character switch.
+character_switch__success = Character switched
+    .title = Character switched
+    .message = You are now playing {$name}.
+character_switch__already_active = Character already played
+    .title = Character already played
+    .message = You are already playing {$name}.
+character_switch__travelling = Journey in progress
+    .title = Journey in progress
+    .message = You cannot switch characters while travelling. Wait for the end of the journey first.
+character_delete__confirm = Delete the character?
+    .title = Delete {$name}?
+    .message = The character, their sheet and the roles of their journey will be removed. This cannot be undone.
+    .cancel = Cancel
+    .confirm = Delete
+character_delete__cancelled = Deletion cancelled
+    .title = Deletion cancelled
+    .message = {$name} has not been deleted.
+character_delete__timeout = Deletion cancelled
+    .title = Deletion cancelled
+    .message = The deletion of {$name} was not confirmed in time.
+character_delete__success = Character deleted
+    .title = Character deleted
+    .message = {$name} has been deleted.
+character_delete__notice = Approved character deleted
+    .title = Approved character deleted
+    .message = {$actor} deleted their approved character {$name}.
+character_teleport__success = Character teleported
+    .title = Character teleported
+    .message = {$name}, played by {$user}, is now in {$destination}.
+character_teleport__no_character = No approved character
+    .title = No approved character
+    .message = {$user} is not playing any approved character.
+character_teleport__log = {$moderator} teleported {$name}, played by {$user}, to {$destination}.
+whereis__in_place = Whereabouts
+    .title = Whereabouts
+    .message = {$user} plays {$name}, who is in {$place}.
+whereis__travelling = Whereabouts
+    .title = Whereabouts
+    .message = {$user} plays {$name}, who is travelling on {$road} towards {$destination}.
+whereis__nowhere = Whereabouts
+    .title = Whereabouts
+    .message = {$user} plays {$name}, who is not in any place yet.
+whereis__no_character = No character played
+    .title = No character played
+    .message = {$user} is not playing any character.
+character_stat__stat_not_found = Stat not found
+    .title = Stat not found
+    .message = This universe has no stat named {$stat}.
+character_stat__invalid_value = Invalid value
+    .title = Invalid value
+    .message = {$value} is not a valid value for {$stat}.
+character_stat__out_of_bounds = Value out of bounds
+    .title = Value out of bounds
+    .message = {$stat} must be between {$min} and {$max}.
+character_stat__set = Stat changed
+    .title = Stat changed
+    .message = {$stat} of {$character}: {$old_value} → {$value}
+character_stat__set_sheet_not_updated = Stat changed
+    .title = Stat changed, sheet not updated
+    .message = {$stat} of {$character}: {$old_value} → {$value}. The character sheet could not be updated.
 
 travel__server_not_found = Server not found
     .title = Server not found
@@ -481,6 +1625,43 @@ travel__already_moving_to_destination = Already on the way
 travel__invalid_road_destination = Invalid destination
     .title = Invalid destination
     .message = You cannot go to this place from your current position on the road.
+travel_status__in_place = Not traveling
+    .title = Not traveling
+    .message = Your character is in {$place}.
+travel_status__moving = Journey under way
+    .title = Journey under way
+    .message = On the road {$road} toward {$destination}, left {$departure}. {$distance} km to go, arrival {$arrival}.
+travel_status__stopped = Journey paused
+    .title = Journey paused
+    .message = Stopped on the road {$road}, {$distance} km from {$destination}. Use /travel start to set off again.
+travel_cancel__not_traveling = Not traveling
+    .title = Not traveling
+    .message = Your character is not on a road.
+travel_cancel__success = Journey cancelled
+    .title = Journey cancelled
+    .message = You gave up your journey and went back to {$place}.
+navigate__route = Route
+    .title = 🧭 Route to {$destination}
+    .distance = Distance
+    .duration = Travel time
+navigate__leg = Leg
+    .message = {$index}. {$place} by {$road} - {$distance} km
+navigate__already_there = Already there
+    .message = Your character is already there.
+navigate__unknown_duration = Unknown
+    .message = Unknown
+navigate__no_place = No current place
+    .title = No current place
+    .message = Your character is not in any place, so no route can be computed.
+navigate__unreachable = Unreachable
+    .title = Unreachable destination
+    .message = No road leads from the place of your character to {$destination}.
+navigate__too_many_roads = Too many roads
+    .title = Too many roads
+    .message = This universe has more than {$max} roads, too many to compute a route.
+travel__not_in_place = Not in this place
+    .title = Not in this place
+    .message = Your character is not in this place. Start your journey from the place where your character stands.
 move_from_place__road_not_found = No road found
     .title = No road found
     .message = There is no direct road between your current position and {$destination}.
@@ -18,23 +18,336 @@ start_message = Start Message
     Dans un setup partiel, seule la catégorie route et les rôles seront créés.
     Dans un setup complet, les catégories Admin, hors rp, rp et leurs selons sont également créés en plus.
 
+start__onboarding = Premiers pas
+    .title = Merci d'utiliser {botname}
+    .message = Configurez ce serveur en trois étapes, les boutons ci-dessous les lancent ou vous indiquent comment faire.
+start__step = Étape
+    .create_universe = Créez un univers avec `/{universe} {universe_create_universe}`.
+    .link_server = Liez ce serveur à l'un de vos univers avec `/{universe} {universe_add_server}`.
+    .run_setup = Créez les rôles, catégories et salons de l'univers avec `/{universe} {universe_setup}`.
+start__button = Bouton
+    .create_universe = Créer un univers
+    .link_server = Lier à un univers existant
+    .run_setup = Lancer le setup
+start__state = État
+    .unlinked = Ce serveur ne fait encore partie d'aucun univers.
+    .linked = Ce serveur fait partie d'un univers et n'attend plus que son setup.
+    .set_up = Ce serveur est configuré. Relancer le setup recrée les rôles et salons supprimés.
+start__create_universe = Créer un univers
+    .title = Créer un univers
+    .message = Un univers a besoin d'un nom : lancez `/{universe} {universe_create_universe}` avec le nom et le type de setup de votre univers, puis revenez à `/{start}`.
+start__database_error = Erreur de base de données
+    .title = Erreur de base de données
+    .message = L'état de ce serveur n'a pas pu être lu. Veuillez réessayer plus tard.
+
 #Stats
 stat_insert__failed = Échec de l'insertion des statistiques
     .title = Ajout de la stat échouée
-    .description = La stat n'as pas pu être ajouté.
+    .message = La stat n'a pas pu être ajoutée.
+stat_insert__duplicate_name = Nom déjà pris
+    .title = Nom déjà pris
+    .message = L'univers a déjà une stat nommée {$name}.
 resolve_stat__character_not_found = Personnage non trouvé lors de la résolution de la stat
     .title = Erreur de statistique
     .message = Impossible de trouver le personnage pour calculer ses statistiques.
 resolve_stat__database_error = Erreur de base de données lors de la résolution de la stat
     .title = Erreur de statistique
     .message = Une erreur de base de données s'est produite lors de la récupération des statistiques.
+stat = stat
+    .description = Définit les stats de l'univers.
+stat_create = créer
+    .description = Définit une nouvelle stat de l'univers.
+    .name = nom
+    .name-description = Nom de la stat.
+    .kind = type
+    .kind-description = Type des valeurs de la stat.
+    .base_value = valeur_de_base
+    .base_value-description = Valeur de départ des nouveaux personnages.
+    .min = min
+    .min-description = Valeur la plus basse de la stat, pour les nombres uniquement.
+    .max = max
+    .max-description = Valeur la plus haute de la stat, pour les nombres uniquement.
+    .formula = formule
+    .formula-description = Formule calculant la valeur de la stat.
+stat_list = liste
+    .description = Liste les stats de l'univers.
+stat_edit = modifier
+    .description = Modifie une stat. Donnez - pour retirer une borne ou la formule.
+    .stat = stat
+    .stat-description = Stat à modifier.
+    .name = nom
+    .name-description = Nouveau nom de la stat.
+    .base_value = valeur_de_base
+    .base_value-description = Nouvelle valeur de départ des nouveaux personnages.
+    .min = min
+    .min-description = Nouvelle valeur la plus basse, ou - pour la retirer.
+    .max = max
+    .max-description = Nouvelle valeur la plus haute, ou - pour la retirer.
+    .formula = formule
+    .formula-description = Nouvelle formule, ou - pour la retirer.
+stat_delete = supprimer
+    .description = Supprime une stat qu'aucun personnage ni modificateur n'utilise.
+    .stat = stat
+    .stat-description = Stat à supprimer.
+stat_type_int = Entier
+stat_type_float = Décimal
+stat_type_text = Texte
+stat_type_bool = Oui/Non
+stat_type = Type
+    .int = entier
+    .float = décimal
+    .text = texte
+    .bool = oui/non
+stat__no_universe = Aucun univers
+    .title = Aucun univers
+    .message = Ce serveur n'est lié à aucun univers.
+stat__database_error = Erreur de base de données
+    .title = Erreur de base de données
+    .message = Les stats n'ont pas pu être lues ou enregistrées. Veuillez réessayer.
+stat__not_found = Stat introuvable
+    .title = Stat introuvable
+    .message = L'univers n'a pas de stat nommée {$name}.
+stat__empty_name = Nom vide
+    .title = Nom vide
+    .message = Le nom d'une stat ne peut pas être vide.
+stat__invalid_value = Valeur invalide
+    .title = Valeur invalide
+    .message = « {$value} » n'est pas une valeur du type de la stat.
+stat__bounds_not_numeric = Bornes interdites
+    .title = Bornes interdites
+    .message = Seules les stats entières et décimales peuvent avoir un min et un max.
+stat__invalid_bounds = Bornes invalides
+    .title = Bornes invalides
+    .message = Le min de {$name} est plus grand que son max.
+stat__out_of_bounds = Valeur de base hors limites
+    .title = Valeur de base hors limites
+    .message = La valeur de base de {$name} doit être comprise entre son min et son max.
+stat_create__success = Stat créée
+    .title = Stat créée
+    .message = La stat {$name} a été créée.
+stat_edit__nothing_to_change = Rien à modifier
+    .title = Rien à modifier
+    .message = Donnez au moins un champ à modifier.
+stat_edit__success = Stat modifiée
+    .title = Stat modifiée
+    .message = La stat {$name} a été modifiée.
+stat_delete__in_use = Stat utilisée
+    .title = Stat utilisée
+    .message = {$name} est encore utilisée par un personnage ou un modificateur.
+stat_delete__success = Stat supprimée
+    .title = Stat supprimée
+    .message = La stat {$name} a été supprimée.
+stat_list__empty = Aucune stat
+    .title = Aucune stat
+    .message = Cet univers n'a pas encore de stat. Définissez-en une avec /stat créer.
+stat_list__page = Stats
+    .title = { $count ->
+        [one] {$count} stat
+       *[other] {$count} stats
+    } - page {$page}/{$pages}
+stat_list__row = Stat
+    .message = **{$name}** - {$kind} - base {$base_value} - bornes {$bounds} - formule {$formula}
+stat_formula__invalid = Formule invalide
+    .title = Formule invalide
+    .message = La formule ne compile pas : {$error}
+stat_formula__too_expensive = Formule trop coûteuse
+    .title = Formule trop coûteuse
+    .message = La formule est trop complexe ou trop longue à calculer. Simplifiez-la.
+stat_formula__arithmetic = Erreur de calcul
+    .title = Erreur de calcul
+    .message = La formule divise par zéro ou donne un nombre trop grand.
+stat_formula__type_mismatch = Mauvais type
+    .title = Mauvais type
+    .message = La formule donne une valeur qui ne correspond pas au type de la stat.
+stat_formula__failed = Erreur de formule
+    .title = Erreur de formule
+    .message = La formule n'a pas pu être calculée. Vérifiez les noms des stats qu'elle utilise.
+#Dice
+roll = lancer
+    .description = Lance des dés, comme 2d6+3 ou 4d6kh3, en ajoutant éventuellement une stat de votre personnage.
+    .expression = expression
+    .expression-description = Dés à lancer : 2d6+3, d20, 4d6kh3 (garde les 3 meilleurs), 2d20kl1 (garde le plus bas)...
+    .stat = stat
+    .stat-description = Stat du personnage que vous jouez à ajouter au total.
+contest = opposition
+    .description = Oppose une stat de votre personnage à une stat du personnage d'un autre joueur.
+    .stat_a = stat
+    .stat_a-description = Stat du personnage que vous jouez.
+    .user_b = adversaire
+    .user_b-description = Joueur dont vous affrontez le personnage.
+    .stat_b = stat_adversaire
+    .stat_b-description = Stat du personnage de l'adversaire.
+contest__result = Opposition
+    .title = ⚔️ {$first} contre {$second}
+    .winner = **{$winner}** l'emporte de {$margin}.
+    .draw = Égalité !
+contest__side = Camp
+    .message = {$stat} : {$value} - total **{$total}**
+contest__missing_stat = Stat manquante
+    .title = Stat manquante
+    .message = {$character} n'a pas de valeur pour la stat {$stat}.
+contest__opponent_no_active = Aucun personnage adverse
+    .title = Aucun personnage adverse
+    .message = {$user} ne joue aucun personnage.
+contest__opponent_not_playable = Adversaire non approuvé
+    .title = Adversaire non approuvé
+    .message = {$name}, le personnage de {$user}, n'a pas été approuvé et ne peut pas participer à une opposition.
+contest__invalid_die = Dé d'opposition invalide
+    .title = Dé d'opposition invalide
+    .message = Le dé d'opposition de l'univers, `{$expression}`, est invalide. Changez-le avec /univers parametres de_opposition.
+roll__result = Lancer
+    .title = 🎲 {$expression}
+roll__fields = Lancer
+    .dice = Dés
+    .stat = Bonus de stat
+    .total = Total
+roll__stat_bonus = Bonus de stat
+    .message = {$character} - {$stat} : {$value}
+roll__stat_not_numeric = Pas un nombre
+    .title = Stat non numérique
+    .message = La stat {$stat} n'est pas un nombre, elle ne peut pas être ajoutée à un lancer.
+dice__invalid_expression = Dés invalides
+    .title = Expression de dés invalide
+    .message = `{$expression}` n'est pas une expression de dés valide. Écrivez les dés sous la forme NdM, comme 2d6+3, d20 ou 4d6kh3.
+dice__too_many_dice = Trop de dés
+    .title = Trop de dés
+    .message = Un lancer peut utiliser au plus 100 dés.
+dice__too_many_sides = Trop de faces
+    .title = Trop de faces
+    .message = Un dé peut avoir au plus 999 faces.
+dice__invalid_keep = Nombre de dés gardés invalide
+    .title = Nombre de dés gardés invalide
+    .message = Le nombre de dés gardés doit être compris entre 1 et le nombre de dés lancés.
+#Membership
+join = rejoindre
+    .description = Rejoint la partie : vous donne le rôle de joueur et celui du lieu de départ.
+join__success = Bienvenue
+    .title = Bienvenue
+    .message = Vous êtes maintenant joueur.
+join__success_with_place = Bienvenue
+    .title = Bienvenue
+    .message = Vous êtes maintenant joueur, et commencez à {$place}.
+join__already_player = Déjà joueur
+    .title = Déjà joueur
+    .message = Vous avez déjà le rôle de joueur.
+join__log = Nouveau joueur
+    .title = Nouveau joueur
+    .message = {$user} a rejoint la partie.
+spectate = observer
+    .description = Observe la partie : vous donne le rôle de spectateur, qui peut lire sans jouer.
+spectate__success = Spectateur
+    .title = Spectateur
+    .message = Vous êtes maintenant spectateur.
+spectate__already = Déjà spectateur
+    .title = Déjà spectateur
+    .message = Vous avez déjà le rôle de spectateur.
+spectate__is_player = Déjà joueur
+    .title = Déjà joueur
+    .message = Vous êtes joueur. Utilisez /quitter avant d'observer.
+spectate__log = Nouveau spectateur
+    .title = Nouveau spectateur
+    .message = {$user} observe maintenant la partie.
+leave = quitter
+    .description = Quitte la partie : retire vos rôles de joueur, de spectateur, de lieu et de route.
+leave__success = Partie quittée
+    .title = Partie quittée
+    .message = Vos rôles ont été retirés et vous ne jouez plus aucun personnage.
+leave__not_in_game = Hors de la partie
+    .title = Hors de la partie
+    .message = Vous n'êtes ni joueur ni spectateur.
+leave__log = Départ d'un membre
+    .title = Départ d'un membre
+    .message = {$user} a quitté la partie.
+membership__bot_role_too_low = Rôle du bot trop bas
+    .title = Rôle du bot trop bas
+    .message = Le rôle du bot est en dessous de {$role}. Demandez à un administrateur de le monter.
+membership__setup_required = Configuration requise
+    .title = Configuration requise
+    .message = Les rôles de ce serveur ne sont pas encore configurés. Demandez à un administrateur de lancer /univers configuration.
+membership__role_failed = Rôles non modifiés
+    .title = Rôles non modifiés
+    .message = Vos rôles n'ont pas pu être modifiés. Le rôle du bot est peut-être en dessous des rôles à donner.
+role_menu__menu = Choisissez votre rôle
+    .title = Choisissez votre rôle
+    .message = Appuyez sur un bouton pour rejoindre la partie en tant que joueur ou pour la suivre en tant que spectateur. Appuyez à nouveau pour rendre le rôle.
+role_menu__button = Rôles
+    .player = Devenir joueur
+    .spectator = Devenir spectateur
+role_menu__spectator_added = Spectateur
+    .title = Spectateur
+    .message = Vous êtes maintenant spectateur.
+role_menu__spectator_removed = Rôle de spectateur retiré
+    .title = Rôle de spectateur retiré
+    .message = Vous n'êtes plus spectateur.
+role_menu__approval_required = Validation requise
+    .title = Validation requise
+    .message = Les personnages de cet univers doivent être validés avant de jouer. Utilisez /rejoindre une fois le vôtre validé.
+role_menu__unknown_button = Bouton inconnu
+    .title = Bouton inconnu
+    .message = Ce bouton ne donne plus aucun rôle.
+role_menu__server_not_found = Aucun univers
+    .title = Aucun univers
+    .message = Ce serveur ne fait plus partie d'un univers, son menu des rôles ne donne donc aucun rôle.
+role_menu__database_error = Erreur de base de données
+    .title = Erreur de base de données
+    .message = Vos rôles n'ont pas pu être modifiés. Veuillez réessayer plus tard.
+role_menu__no_channel = Pas de salon général HRP
+role_menu__post_failed = Menu des rôles non publié
 #Reply
 reply__reply_success = Succès
     .title = Succès
     .message = L'opération a été effectuée avec succès.
 reply__reply_failed = Échec de l'envoi de la réponse
     .title = Réponse échouée
-    .description = La réponse à échouée
+    .message = La réponse a échoué
+#Errors
+error__argument_parse = Argument invalide
+    .title = Argument invalide
+    .message = L'un des arguments fournis n'a pas pu être compris. Vérifiez votre saisie et réessayez.
+error__cooldown_hit = Commande en recharge
+    .title = Veuillez patienter
+    .message = Cette commande est en recharge. Vous pourrez la réutiliser dans {$seconds} seconde(s).
+error__not_an_owner = Réservé aux propriétaires
+    .title = Réservé aux propriétaires
+    .message = Seuls les propriétaires du bot peuvent lancer cette commande.
+ping__report = Pong !
+    .title = Pong !
+    .interaction = Interaction
+    .gateway = Passerelle (shard {$shard})
+    .database = Base de données
+    .unavailable = indisponible
+ping__latency = {$ms} ms
+botstats__report = Statistiques du bot
+    .title = Statistiques du bot
+    .message = {$runs} commandes lancées depuis le démarrage du bot, {$failures} en échec ({$error_rate} %).
+botstats__command = `/{$command}` : {$runs} lancements, {$error_rate} % d'échecs, p95 {$p95}
+botstats__latency = ≤ {$ms} ms
+botstats__latency_over = > {$ms} ms
+grant_limits__success = Limites accordées
+    .title = Limites accordées
+    .message = {$user} peut désormais créer {$max_universes} univers de {$max_servers_per_universe} serveurs chacun.
+grant_limits__success_until = Limites accordées
+    .title = Limites accordées
+    .message = {$user} peut désormais créer {$max_universes} univers de {$max_servers_per_universe} serveurs chacun, jusqu'au {$expires_at}.
+grant_limits__failed = Limites non accordées
+    .title = Limites non accordées
+    .message = Les limites n'ont pas pu être enregistrées
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+reload_translations__success = Traductions rechargées
+    .title = Traductions rechargées
+    .message = {$locales} langues et {$messages} messages sont désormais utilisés.{ $conflicts ->
+        [0] {""}
+        [one] {" "}{$conflicts} message défini par deux fichiers a gardé sa première définition.
+       *[other] {" "}{$conflicts} messages définis par deux fichiers ont gardé leur première définition.
+    }
+reload_translations__failed = Traductions non rechargées
+    .title = Traductions non rechargées
+    .message = Les traductions utilisées ont été conservées, car ces fichiers n'ont pas pu être chargés :
+            {$errors}
+error__internal_error = Erreur interne
+    .title = Erreur interne
+    .message = Une erreur inattendue s'est produite lors de l'exécution de la commande.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
 #Universe
 universe = univers
     .description = Commandes de gestion de l'univers.
@@ -43,17 +356,339 @@ universe_create_universe = nouvel_univers
     .universe_name = nom
     .universe_name-description = Nom du nouvel Univers
     .setup_type = type_de_setup
-    .setup_type-description = Type de configuration pour ce serveur
+    .setup_type-description = Type de configuration pour ce serveur, ou vide pour choisir après la création
 universe_add_server = ajouter
     .description = Ajoute ce serveur à un univers existant.
     .setup_type = type_de_setup
     .setup_type-description = Type de configuration pour ce serveur
+universe_add_admin = ajouter_admin
+    .description = Nomme un utilisateur administrateur de l'univers, qui peut modifier ses paramètres.
+    .user = utilisateur
+    .user-description = Utilisateur à nommer administrateur.
+universe_remove_admin = retirer_admin
+    .description = Retire un utilisateur des administrateurs de l'univers.
+    .user = utilisateur
+    .user-description = Administrateur à retirer.
 universe_setup = configuration
     .description = Configure ou reconfigure le serveur actuel pour l'univers auquel il est lié.
     .setup_type = type_de_setup
-    .setup_type-description = Type de configuration à effectuer (Complet ou Partiel).
+    .setup_type-description = Type de configuration à effectuer (Complet, Partiel ou Réparation).
+    .dry_run = simulation
+    .dry_run-description = Affiche seulement ce qui serait créé, sans rien modifier.
+universe_settings = parametres
+    .description = Paramètres de l'univers.
+universe_settings_set_name = definir_nom
+    .description = Définit le nom donné par la configuration à un rôle ou un salon.
+    .key = cle
+    .key-description = Nom ou couleur de rôle à personnaliser (ex. admin_role_name, admin_role_color).
+    .value = valeur
+    .value-description = Nouveau nom, ou couleur #rrggbb.
+universe_settings_apply_names = appliquer_noms
+    .description = Renomme les rôles et salons existants de ce serveur selon les noms actuels.
+universe_settings_road_slowmode = mode_lent_routes
+    .description = Définit les secondes de mode lent des salons de route par kilomètre, 0 pour un mode lent fixe.
+    .seconds_per_km = secondes_par_km
+    .seconds_per_km-description = Secondes de mode lent par kilomètre de route. 0 donne le même mode lent à toutes les routes.
+    .apply_to_existing = appliquer_aux_existantes
+    .apply_to_existing-description = Met aussi à jour le mode lent des salons de route existants (par défaut : non).
+universe_settings_contest_die = de_opposition
+    .description = Définit les dés lancés par chaque camp d'une opposition, 1d20 par défaut.
+    .expression = expression
+    .expression-description = Expression de dés, comme 1d20 ou 2d10kh1.
+universe_settings_join = rejoindre
+    .description = Définit le lieu de départ des nouveaux joueurs et si rejoindre exige un personnage approuvé.
+    .starting_place = lieu_de_depart
+    .starting_place-description = Lieu dont les nouveaux joueurs reçoivent le rôle, ou - pour aucun.
+    .requires_approval = approbation_requise
+    .requires_approval-description = Seuls les joueurs ayant un personnage approuvé peuvent rejoindre.
+universe_settings_general = general
+    .description = Définit la langue par défaut et la vitesse du temps de l'univers dans un formulaire.
+universe_status = statut
+    .description = Affiche quels salons et rôles gérés par la configuration existent sur ce serveur.
+universe_export = exporter
+    .description = Envoie une sauvegarde JSON de l'univers, ses serveurs, lieux, routes, stats et personnages.
+universe_import = importer
+    .description = Restaure un univers depuis une sauvegarde envoyée par la commande d'export.
+    .file = fichier
+    .file-description = Fichier JSON envoyé par la commande d'export, compressé ou non.
+universe_set_server_locale = langue_serveur
+    .description = Définit la langue de ce serveur, utilisée à la place de la langue par défaut de l'univers.
+    .locale = langue
+    .locale-description = Langue du serveur (en-US, fr), ou - pour utiliser celle de l'univers.
+universe_teardown = demanteler
+    .description = Supprime tous les salons, catégories et rôles gérés par le bot sur ce serveur.
 universe_time = temps
     .description = Affiche l'heure actuelle de l'univers.
+universe_verify = verifier
+    .description = Compare la configuration du serveur enregistrée par le bot avec les salons et rôles présents sur Discord.
+
+add_server__confirm = Rejoindre {$universe} ?
+    .title = Ajouter ce serveur à {$universe} ?
+    .message = La configuration créera ensuite les rôles et salons de {$universe} sur ce serveur, et ses membres pourront y jouer. Un serveur n'appartient qu'à un univers à la fois.
+    .cancel = Annuler
+    .confirm = Confirmer
+add_server__confirmation_timeout = Non confirmé
+    .title = Non confirmé
+    .message = Le choix n'a pas été confirmé à temps. Le serveur n'a été ajouté à aucun univers.
+add_server__log = Serveur lié
+    .title = Serveur lié
+    .message = {$actor} a lié le serveur à l'univers {$universe}.
+add_server__cancelled = Annulé
+    .title = Annulé
+    .message = Le serveur n'a été ajouté à aucun univers.
+add_server__selection_timeout = Aucun univers choisi
+    .title = Aucun univers choisi
+    .message = Aucun univers n'a été choisi à temps. Relancez la commande pour ajouter ce serveur à un univers.
+add_server__universe_not_found = Univers introuvable
+    .title = Univers introuvable
+    .message = L'univers choisi n'existe plus. Relancez la commande pour en choisir un autre.
+add_server__database_error = Erreur de base de données
+    .title = Erreur de base de données
+    .message = L'univers choisi n'a pas pu être lu. Veuillez réessayer plus tard.
+add_server_to_universe__guild_reattached = Serveur rattaché
+    .title = Serveur rattaché
+    .message = Ce serveur faisait déjà partie de l'univers. Il a été rattaché et sa configuration a été mise à jour.
+add_server_to_universe__already_bind = Serveur déjà dans un univers
+    .title = Serveur déjà dans un univers
+    .message = Ce serveur fait déjà partie d'un univers.
+add_server_to_universe__universes_unavailable = Aucun univers disponible
+    .title = Aucun univers disponible
+    .message = Vous n'avez créé ni n'administrez aucun univers que ce serveur pourrait rejoindre. Créez-en un d'abord.
+add_server_to_universe__guild_linked = Serveur ajouté
+    .title = Serveur ajouté
+    .message = Ce serveur a été ajouté à l'univers et configuré.
+choose_universe = Choisissez l'univers auquel ajouter ce serveur.
+guild_delete__server_detached = Serveur détaché
+    .title = Serveur retiré de {$universe}
+    .message = Le bot a été retiré du serveur {$server}. Il reste enregistré dans l'univers {$universe} et peut être rattaché avec `/univers ajouter`.
+universe_status__report = État de la configuration
+    .title = État de la configuration
+universe_status__row = Ressource
+    .admin_role_id = Rôle administrateur
+    .moderator_role_id = Rôle modérateur
+    .spectator_role_id = Rôle spectateur
+    .player_role_id = Rôle joueur
+    .everyone_role_id = Rôle everyone
+    .admin_category_id = Catégorie administration
+    .nrp_category_id = Catégorie hors-RP
+    .rp_category_id = Catégorie RP
+    .road_category_id = Catégorie des routes
+    .rp_wiki_channel_id = Salon wiki
+    .log_channel_id = Salon de logs
+    .moderation_channel_id = Salon de modération
+    .commands_channel_id = Salon des commandes
+    .nrp_general_channel_id = Salon général hors-RP
+    .rp_character_channel_id = Salon des personnages
+    .universal_time_channel_id = Salon de l'heure universelle
+    .readme_channel_id = Salon README
+universe_status__state = État
+    .present = présent
+    .missing = absent de Discord
+    .not_configured = non configuré
+universe_teardown__confirm = Confirmer le démantèlement ?
+    .title = Confirmer le démantèlement
+    .message = Tous les salons, catégories et rôles créés par le bot sur ce serveur, y compris les lieux et les routes, seront supprimés. Cette action est irréversible.
+    .cancel = Annuler
+    .confirm = Continuer
+universe_add_admin__success = Administrateur ajouté
+    .title = Administrateur ajouté
+    .message = {$user} est maintenant administrateur de l'univers.
+universe_add_admin__already_admin = Déjà administrateur
+    .title = Déjà administrateur
+    .message = {$user} est déjà administrateur de l'univers.
+universe_add_admin__creator = Créateur de l'univers
+    .title = Créateur de l'univers
+    .message = {$user} a créé l'univers et a déjà tous les droits dessus.
+universe_add_admin__bot = Les bots ne peuvent pas être administrateurs
+    .title = Les bots ne peuvent pas être administrateurs
+    .message = {$user} est un bot et ne peut pas administrer l'univers.
+universe_remove_admin__success = Administrateur retiré
+    .title = Administrateur retiré
+    .message = {$user} n'est plus administrateur de l'univers.
+universe_remove_admin__not_admin = Pas administrateur
+    .title = Pas administrateur
+    .message = {$user} n'est pas administrateur de l'univers.
+create_universe__universe_successfully_created = Univers créé
+    .title = Univers créé
+    .message = L'univers a été créé et ce serveur y a été rattaché.
+create_universe__run_full_setup = Configuration complète
+create_universe__run_partial_setup = Configuration partielle
+create_universe__check_universe_limit_failed = Échec de la création de l'univers
+    .title = Échec de la création de l'univers
+    .message = Vos univers n'ont pas pu être comptés. Veuillez réessayer plus tard.
+create_universe__already_exist_for_this_server = Serveur déjà dans un univers
+    .title = Serveur déjà dans un univers
+    .message = Ce serveur fait déjà partie d'un univers, aucun autre ne peut donc être créé depuis celui-ci.
+universe__check_server_limit_failed = Échec du comptage des serveurs
+    .title = Échec du comptage des serveurs
+    .message = Les serveurs de l'univers n'ont pas pu être comptés.
+            Veuillez réessayer ou contacter le support si le problème persiste : {support}
+create_universe__universe_limit_reached = Limite d'univers atteinte
+    .title = Limite d'univers atteinte
+    .message = Vous avez déjà créé autant d'univers que possible.
+create_universe__get_server_failed = Échec de la création de l'univers
+    .title = Échec de la création de l'univers
+    .message = Ce serveur n'a pas pu être lu dans la base de données. Veuillez réessayer plus tard.
+create_universe__universe_insert_failed = Échec de la création de l'univers
+    .title = Échec de la création de l'univers
+    .message = L'univers n'a pas pu être enregistré. Rien n'a été créé, veuillez réessayer plus tard.
+create_universe__setup_constraints_failed = Échec de la création de l'univers
+    .title = Échec de la création de l'univers
+    .message = Les index de l'univers n'ont pas pu être créés, l'univers a donc été supprimé. Veuillez réessayer plus tard.
+create_universe__server_insert_failed = Échec de la création de l'univers
+    .title = Échec de la création de l'univers
+    .message = Ce serveur n'a pas pu être rattaché à l'univers, l'univers a donc été supprimé. Veuillez réessayer plus tard.
+create_universe__speed_stat_insert_failed = Échec de la création de l'univers
+    .title = Échec de la création de l'univers
+    .message = Les statistiques par défaut n'ont pas pu être enregistrées, l'univers a donc été supprimé. Veuillez réessayer plus tard.
+create_universe__name_length = Nom d'univers invalide
+    .title = Nom d'univers invalide
+    .message = Le nom d'un univers doit faire entre 2 et 64 caractères.
+create_universe__name_characters = Nom d'univers invalide
+    .title = Nom d'univers invalide
+    .message = Le nom d'un univers ne peut contenir que des lettres, des chiffres, des espaces et les caractères - _ et '.
+create_universe__name_taken = Nom d'univers déjà utilisé
+    .title = Nom d'univers déjà utilisé
+    .message = Vous avez déjà créé un univers portant ce nom.
+universe__not_universe_creator = Réservé au créateur de l'univers
+    .title = Réservé au créateur de l'univers
+    .message = Seul le créateur de l'univers peut faire cela. Être administrateur de ce serveur ne suffit pas.
+universe__not_universe_admin = Réservé aux administrateurs de l'univers
+    .title = Réservé aux administrateurs de l'univers
+    .message = Seuls le créateur de l'univers, ses administrateurs et les membres ayant son rôle d'administrateur peuvent faire cela.
+universe_teardown__notice = Serveur démantelé
+    .title = Serveur démantelé
+    .message = {$actor} a démantelé le serveur : {$deleted} rôles et salons supprimés, {$failed} n'ont pas pu l'être.
+universe_teardown__log = Serveur démantelé
+    .title = Serveur démantelé
+    .message = {$actor} a démantelé le serveur ; {$failed} ressources n'ont pas pu être supprimées.
+universe_teardown__success = Démantèlement terminé
+    .title = Démantèlement terminé
+    .message = Supprimés : {$deleted}, échecs : {$failed}, déjà absents : {$skipped}
+universe_teardown__cancelled = Démantèlement annulé
+    .title = Démantèlement annulé
+    .message = Rien n'a été supprimé
+universe_teardown__timeout = Délai de démantèlement dépassé
+    .title = Délai dépassé
+    .message = Le délai pour confirmer le démantèlement a expiré
+universe_teardown__failed = Échec du démantèlement
+    .title = Erreur de démantèlement
+    .message = Le démantèlement n'a pas pu être effectué
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+universe_export__success = Univers exporté
+    .title = {$universe} exporté
+    .message = Le fichier joint contient {$servers} serveurs, {$places} lieux, {$roads} routes, {$stats} stats et {$characters} personnages. Conservez-le en lieu sûr.
+universe_export__failed = Échec de l'export
+    .title = Erreur d'export
+    .message = La sauvegarde de l'univers n'a pas pu être écrite
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+universe_import__success = Univers importé
+    .title = {$universe} importé
+    .message = {$servers} serveurs, {$places} lieux, {$roads} routes, {$stats} stats et {$characters} personnages ont été restaurés. Lancez la configuration en mode réparation sur chaque serveur pour recréer ses salons et rôles.
+universe_import__cancelled = Import annulé
+    .title = Import annulé
+    .message = Rien n'a été importé
+universe_import__timeout = Délai d'import dépassé
+    .title = Délai dépassé
+    .message = Le délai pour confirmer l'import a expiré
+universe_import__confirm_transfer = Reprendre l'univers ?
+    .title = Reprendre {$universe} ?
+    .message = Cette sauvegarde a été faite par {$creator}. L'importer fait de vous le créateur de l'univers restauré.
+    .cancel = Annuler
+    .confirm = Importer
+universe_import__download_failed = Échec du téléchargement
+    .title = Erreur de téléchargement
+    .message = Le fichier joint n'a pas pu être téléchargé. Veuillez ressayer.
+universe_import__invalid_file = Sauvegarde invalide
+    .title = Sauvegarde invalide
+    .message = Le fichier joint n'est pas une sauvegarde envoyée par la commande d'export.
+universe_import__unsupported_version = Sauvegarde non prise en charge
+    .title = Sauvegarde non prise en charge
+    .message = Cette sauvegarde a été écrite dans un autre format. Seule la version {$version} peut être importée.
+universe_import__server_conflict = Serveur déjà dans un univers
+    .title = Serveur déjà dans un univers
+    .message = Le serveur {$server_id} de la sauvegarde fait déjà partie d'un univers. Retirez-le de cet univers avant d'importer. Rien n'a été importé.
+universe_import__server_limit = Trop de serveurs
+    .title = Trop de serveurs
+    .message = La sauvegarde compte {$servers} serveurs, mais vos univers ne peuvent en couvrir que {$max}. Rien n'a été importé.
+universe_import__server_not_administered = Serveur non administré
+    .title = Serveur non administré
+    .message = Le serveur {$server_id} de la sauvegarde ne peut pas être importé : le bot doit y être présent et vous devez en être administrateur. Rien n'a été importé.
+universe_import__failed = Échec de l'import
+    .title = Erreur d'import
+    .message = L'univers n'a pas pu être restauré et rien n'a été conservé
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+universe_settings_set_name__success = Nom mis à jour
+    .title = Nom mis à jour
+    .message = `{$key}` s'appelle désormais « {$value} ». Lancez la commande appliquer_noms pour renommer les rôles et salons existants.
+universe_settings_apply_names__success = Noms appliqués
+    .title = Noms appliqués
+    .message = Renommés : {$renamed}, échecs : {$failed}
+universe_settings__unknown_name_key = Nom inconnu
+    .title = Nom inconnu
+    .message = Ce nom ne peut pas être personnalisé. Choisissez l'une des clés proposées.
+universe_settings__empty_name = Nom vide
+    .title = Nom vide
+    .message = Le nouveau nom ne peut pas être vide.
+universe_settings__invalid_color = Couleur invalide
+    .title = Couleur invalide
+    .message = Les couleurs de rôle s'écrivent en hexadécimal, comme #e74c3c.
+universe_settings_road_slowmode__success = Mode lent des routes mis à jour
+    .title = Mode lent des routes mis à jour
+    .message = Les routes ont désormais {$seconds} seconde(s) de mode lent par kilomètre. Les salons de route existants gardent leur mode lent jusqu'à la modification de leur route.
+universe_settings_road_slowmode__applied = Mode lent des routes appliqué
+    .title = Mode lent des routes appliqué
+    .message = Les routes ont désormais {$seconds} seconde(s) de mode lent par kilomètre. Salons de route mis à jour : {$updated}, échecs : {$failed}
+universe_settings_contest_die__success = Dé d'opposition mis à jour
+    .title = Dé d'opposition mis à jour
+    .message = Chaque camp d'une opposition lance désormais {$expression}.
+universe_settings_join__success = Paramètres d'arrivée mis à jour
+    .title = Paramètres d'arrivée mis à jour
+    .message = Lieu de départ : {$place}. Personnage approuvé requis : {$requires_approval}.
+universe_settings_general__title = Paramètres de l'univers
+universe_settings_general__locale = Langue par défaut (en-US, fr ou vide)
+universe_settings_general__time_modifier = Vitesse du temps en pourcent (100 = normale)
+universe_settings_general__timed_out = Délai dépassé
+    .title = Formulaire expiré
+    .message = Le formulaire des paramètres n'a pas été envoyé à temps.
+universe_settings_general__unknown_locale = Langue inconnue
+    .title = Langue inconnue
+    .message = Aucune traduction n'existe pour cette langue. Utilisez en-US, fr ou laissez le champ vide.
+universe_settings_general__invalid_time_modifier = Vitesse du temps invalide
+    .title = Vitesse du temps invalide
+    .message = La vitesse du temps doit être un nombre entier positif de pourcents.
+universe_settings_general__success = Paramètres de l'univers mis à jour
+    .title = Paramètres de l'univers mis à jour
+    .message = Langue par défaut : {$locale}. Vitesse du temps : {$time_modifier} %.
+universe_settings_general__log = Paramètres de l'univers modifiés
+    .title = Paramètres de {$universe} modifiés
+    .message = {$actor} a réglé la langue par défaut sur {$locale} et la vitesse du temps sur {$time_modifier} %.
+universe_set_server_locale__unknown_locale = Langue inconnue
+    .title = Langue inconnue
+    .message = Aucune traduction n'existe pour cette langue. Utilisez en-US, fr ou - pour utiliser la langue de l'univers.
+universe_set_server_locale__success = Langue du serveur mise à jour
+    .title = Langue du serveur mise à jour
+    .message = Langue de ce serveur : {$locale}. Relancez la configuration pour renommer ses salons et rôles.
+universe_set_server_locale__log = Langue du serveur modifiée
+    .title = Langue d'un serveur de {$universe} modifiée
+    .message = {$actor} a réglé la langue de ce serveur sur {$locale}.
+verify__report = Rapport de cohérence
+    .title = Écarts de configuration détectés
+    .message = Ressources manquantes:
+            {$missing}
+            Ressources orphelines:
+            {$orphaned}
+verify__no_drift = Aucun écart
+    .title = Configuration cohérente
+    .message = Tous les salons et rôles enregistrés par le bot existent encore sur Discord.
+verify__discord_error = Impossible de lire le serveur
+    .title = Erreur de vérification
+    .message = Impossible de récupérer les salons et rôles du serveur
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+verify__database_error = Erreur de base de données pendant la vérification
+    .title = Erreur de vérification
+    .message = Impossible de récupérer la configuration du serveur
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
 
 #Roads
 road = route
@@ -68,6 +703,28 @@ road_create_road = nouvelle_route
     .distance-description = Distance entre les deux lieux en kilomètres.
     .secret_channel = secret
     .secret_channel-description = Si vrai, la route ne sera pas affichée sur les cartes publiques.
+    .name = nom
+    .name-description = Nom de la route. Par défaut, les noms des deux lieux.
+road_delete_road = supprimer
+    .description = Supprime une route, à partir de son salon ou de ses deux lieux.
+    .road = route
+    .road-description = Salon de la route à supprimer.
+    .place_one = lieu_un
+    .place_one-description = Première extrémité de la route, si le salon n'est pas donné.
+    .place_two = lieu_deux
+    .place_two-description = Seconde extrémité de la route, si le salon n'est pas donné.
+road_list_roads = liste
+    .description = Liste les routes de l'univers, ou seulement celles d'un lieu.
+    .place = lieu
+    .place-description = N'afficher que les routes menant à ce lieu.
+road_map = carte
+    .description = Exporte les routes de l'univers sous forme de carte Graphviz.
+road_edit_road = modifier
+    .description = Modifie la distance d'une route.
+    .road = route
+    .road-description = Salon de la route à modifier.
+    .distance = distance
+    .distance-description = Nouvelle distance entre les deux lieux en kilomètres.
 
 #Places
 place = lieu
@@ -76,14 +733,106 @@ place_create_place = nouvel_endroit
     .description = Crée une nouvelle catégorie correspondant à une ville ou un lieu d'interaction.
     .name = nom
     .name-description = Nom du lieu à créer.
+    .with_text = avec_texte
+    .with_text-description = Crée un salon textuel général dans le lieu (par défaut : oui).
+    .with_voice = avec_vocal
+    .with_voice-description = Crée un salon vocal dans le lieu (par défaut : non).
+place_delete_place = supprimer
+    .description = Supprime un lieu, avec son rôle, sa catégorie et ses salons.
+    .place = lieu
+    .place-description = Lieu à supprimer.
+place_list_places = liste
+    .description = Liste les lieux de l'univers.
+place_rename_place = renommer
+    .description = Renomme un lieu, son rôle et les routes nommées d'après lui.
+    .place = lieu
+    .place-description = Lieu à renommer.
+    .name = nom
+    .name-description = Nouveau nom du lieu.
+place_info_place = info
+    .description = Affiche un lieu, les lieux auxquels il mène et ses modificateurs.
+    .place = lieu
+    .place-description = Lieu à afficher.
+place_occupants = occupants
+    .description = Liste les personnages présents dans un lieu, regroupés par joueur.
+    .place = lieu
+    .place-description = Lieu dont les occupants sont listés.
+place_add_modifier = ajouter_modificateur
+    .description = Ajoute un modificateur sur une statistique à un lieu.
+    .place = lieu
+    .place-description = Lieu à modifier.
+    .stat = statistique
+    .stat-description = Nom de la statistique à modifier.
+    .kind = type
+    .kind-description = Façon dont la valeur modifie la statistique.
+    .value = valeur
+    .value-description = Valeur du modificateur.
+    .duration = durée
+    .duration-description = Durée en heures de l'univers. Permanent si vide.
+place_remove_modifier = retirer_modificateur
+    .description = Retire d'un lieu les modificateurs d'un type sur une statistique.
+    .place = lieu
+    .place-description = Lieu à modifier.
+    .stat = statistique
+    .stat-description = Nom de la statistique modifiée.
+    .kind = type
+    .kind-description = Type des modificateurs à retirer.
+modifier_type_base = Base
+modifier_type_multiplier = Multiplicateur
+modifier_type_flats = Fixe
+modifier_expiry__notice = Modificateurs expirés
+    .title = Modificateurs expirés : {$holder}
+    .line = Le modificateur {$kind} sur {$stat} a pris fin.
+place_general_channel_name = {$place_name}-général
+place_voice_channel_name = {$place_name} vocal
 create_place__new_place_title = Lieu: {$place_name}
+create_place__wiki_template = ## {$place_name}
+    _Décrivez le lieu ici._
+    ### Routes
+    Aucune route ne mène encore ici.
 create_place__channel_id = Id du lieu
 
 #Characters
 character = personnage
     .description = Commandes de gestion des personnages.
 character_create_character = nouveau_personnage
-    .description = Permet de créer votre personnage dans l'univers. Un seul personnage par joueur.
+    .description = Permet de créer un personnage dans l'univers. Les joueurs peuvent en avoir plusieurs et choisir celui qu'ils jouent avec /personnage changer.
+character_list = liste
+    .description = Liste vos personnages, ou ceux d'un autre joueur pour les modérateurs.
+    .user = utilisateur
+    .user-description = Joueur dont lister les personnages, vous par défaut.
+character_show = afficher
+    .description = Affiche la fiche d'un personnage, avec ses statistiques une fois les modificateurs appliqués.
+    .name = nom
+    .name-description = Nom du personnage.
+character_stat = stat
+    .description = Commandes des statistiques des personnages.
+character_stat_set = modifier
+    .description = Change la valeur d'une statistique d'un personnage, celui que vous jouez par défaut.
+    .stat = stat
+    .stat-description = Statistique à changer.
+    .value = valeur
+    .value-description = Nouvelle valeur de la statistique.
+    .character = personnage
+    .character-description = Nom du personnage, celui que vous jouez par défaut.
+character_switch = changer
+    .description = Choisit lequel de vos personnages vous jouez.
+    .name = nom
+    .name-description = Nom du personnage à jouer.
+character_delete = supprimer
+    .description = Supprime un personnage, sa fiche et les rôles donnés par ses voyages.
+    .name = nom
+    .name-description = Nom du personnage à supprimer.
+character_teleport = téléporter
+    .description = Déplace aussitôt le personnage d'un joueur dans un lieu, sans voyager.
+    .user = utilisateur
+    .user-description = Joueur dont le personnage est déplacé.
+    .place = lieu
+    .place-description = Lieu où le personnage est déplacé.
+whereis = ou_est
+    .description = Montre le personnage joué par un joueur et où il se trouve.
+    .user = utilisateur
+    .user-description = Joueur à chercher, vous-même par défaut.
 
 #Travels
 travel = voyage
@@ -94,6 +843,14 @@ travel_start = départ
     .destination-description = Le lieu où vous souhaitez vous rendre (ID ou mention).
 travel_stop = stop
     .description = Arrête votre voyage actuel sur la route où vous vous trouvez.
+travel_status = statut
+    .description = Affiche où se trouve votre personnage et combien de temps dure encore son voyage.
+travel_cancel = annuler
+    .description = Abandonne votre voyage et retourne au lieu que vous avez quitté.
+navigate = itineraire
+    .description = Affiche le plus court chemin du lieu de votre personnage jusqu'à une destination.
+    .destination = destination
+    .destination-description = Le lieu que vous voulez atteindre.
 
 #Misc
 ping = ping
@@ -101,7 +858,21 @@ ping = ping
 support_command = supporter
     .description = Affiche les informations pour soutenir le projet.
 start = start
-    .description = Affiche les instructions de démarrage.
+    .description = Vous guide dans la configuration du bot sur ce serveur.
+botstats = botstats
+    .description = Montre combien de commandes ont tourné, échoué et leur durée (propriétaires du bot).
+reload_translations = recharger_traductions
+    .description = Relit les fichiers de traduction et les utilise aussitôt (propriétaires du bot).
+grant_limits = accorder_limites
+    .description = Définit le nombre d'univers et de serveurs par univers d'un utilisateur (propriétaires du bot).
+    .user = utilisateur
+    .user-description = Utilisateur dont les limites sont définies.
+    .max_universes = univers_max
+    .max_universes-description = Nombre maximal d'univers que l'utilisateur peut créer.
+    .max_servers_per_universe = serveurs_max_par_univers
+    .max_servers_per_universe-description = Nombre maximal de serveurs de chaque univers de l'utilisateur.
+    .days = jours
+    .days-description = Nombre de jours avant le retour aux limites gratuites. Pour toujours si omis.
 
 #Server
 id__nothing_to_delete = Rien à supprimer
@@ -123,8 +894,15 @@ id__channel_delete_failed = Échec de la suppression du salon
     .title = Erreur de suppression
     .message = Impossible de supprimer le salon
             Veuillez ressayer ou contacter le support si le problème persiste: {support}
+reconcile__deleted_resource = Ressource supprimée oubliée
+    .title = Ressource supprimée hors du bot
+    .message = La ressource `{$id}` a été supprimée de Discord.
+            Configuration effacée : {$fields}
+            Lieux supprimés : {$places}
+            Routes supprimées : {$roads}
 
 #Setup
+readme_channel_name = lisez-moi
 SetupType = SetupType
     .FullSetup = Complet
     .PartialSetup = Partiel
@@ -133,6 +911,55 @@ continue_setup = Continuer
 setup__continue_setup_message = Continuer la configuration ?
     .title = Continuer la configuration
     .message = Voulez-vous continuer la configuration malgré un précédent setup ?  Les salon et rôles inexistants seront créés.
+    .cancel = Annuler
+    .confirm = Continuer
+setup__dry_run = Aperçu de la configuration
+    .title = Aperçu de la configuration
+    .message = Rien n'a été modifié. Les ressources ✅ existent déjà et seraient réutilisées, les ressources ➕ seraient créées.
+setup__dry_run_get_channels_error = Échec de la récupération des salons
+    .title = Erreur de configuration
+    .message = Impossible de récupérer les salons du serveur
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+setup__repair_success = Réparation terminée
+    .title = Réparation terminée
+    .message = {$recreated}
+            {$untouched}
+            {$failed}
+setup__repair_recreated = { $count ->
+        [one] {$count} ressource recréée :
+       *[other] {$count} ressources recréées :
+    }
+setup__repair_untouched = { $count ->
+        [one] {$count} ressource inchangée :
+       *[other] {$count} ressources inchangées :
+    }
+setup__repair_failed = { $count ->
+        [one] {$count} ressource n'a pas pu être recréée :
+       *[other] {$count} ressources n'ont pas pu être recréées :
+    }
+setup__repair_missing_dependency = Dépendance manquante
+    .title = Erreur de réparation
+    .message = Un rôle ou une catégorie nécessaire pour recréer cette ressource n'est pas configuré
+setup__progress = Configuration en cours
+    .title = Configuration en cours
+    .roles = Rôles
+    .road_category = Catégorie des routes
+    .categories = Catégories
+    .channels = Salons
+    .created = {$created}/{$expected} créés
+setup__already_in_progress = Opération déjà en cours
+    .title = Opération en cours
+    .message = Une configuration, une création de lieu ou de route est déjà en cours sur ce serveur. Veuillez attendre qu'elle se termine.
+preflight__missing_permissions = Permissions du bot manquantes
+    .title = Permissions du bot manquantes
+    .message = Le bot ne peut pas encore faire cela sur ce serveur. Corrigez les points suivants dans les paramètres du serveur, puis réessayez :
+        {$permissions}
+preflight__missing_manage_roles = La permission Gérer les rôles
+preflight__missing_manage_channels = La permission Gérer les salons
+preflight__role_too_low = Un rôle du bot au-dessus de @everyone
+setup__bot_role_too_low = Rôle du bot trop bas
+    .title = Rôle du bot trop bas
+    .message = Le rôle le plus haut du bot doit être au-dessus des rôles qu'il gère. Montez le rôle du bot dans les paramètres du serveur, puis relancez la configuration.
 setup__server_already_setup_timeout = Délai de configuration dépassé
     .title = Délai dépassé
     .message = Le délai pour continuer la configuration a expiré
@@ -140,6 +967,14 @@ partial_setup__get_guild_roles_error = Échec de la récupération des rôles du
     .title = Erreur de configuration
     .message = Impossible de récupérer les rôles du serveur.
             Veuillez réessayer ou contacter le support si le problème persiste : {support}
+item__server_not_found = Serveur introuvable
+    .title = Serveur introuvable
+    .message = Ce serveur n'est pas enregistré dans notre base de données.
+            Veuillez réessayer ou contacter le support si le problème persiste : {support}
+create_item__db_error = Échec de la création de l'objet
+    .title = Échec de la création de l'objet
+    .message = L'objet n'a pas pu être enregistré.
+            Veuillez réessayer ou contacter le support si le problème persiste : {support}
 setup__server_not_found = Serveur introuvable
     .title = Serveur introuvable
     .message = Ce serveur n'est pas enregistré dans notre base de données.
@@ -147,6 +982,9 @@ setup__server_not_found = Serveur introuvable
 setup_server__cancelled = Configuration annulée
     .title = Configuration annulée
     .message = La configuration du serveur a été annulée
+setup__log = Serveur configuré
+    .title = Serveur configuré
+    .message = {$actor} a lancé la configuration du serveur.
 setup_server__success = Configuration réussie
     .title = Succès
     .message = Le serveur a été configuré avec succès
@@ -186,6 +1024,7 @@ setup__log_channel_not_created = Salon de logs non créé
     .message = Impossible de créer le salon de log
             Veuillez ressayer ou contacter le support si le problème persiste: {support}
 commands_channel_name = Commandes
+commands_channel_topic = Utilisez les commandes du bot ici
 setup__commands_channel_not_created = Salon de commandes non créé
     .title = Erreur de création
     .message = Impossible de créer le salon de commandes
@@ -274,6 +1113,12 @@ create_place__database_not_found = Base de données introuvable
     .title = Connexion échouée
     .message = La connexion à la base de donénes à échouée.
             Veuillez ressayer ou contacter le support si le problème persiste: {support}
+create_place__setup_required = Configuration requise
+    .title = Configuration requise
+    .message = Ce serveur n'est pas encore configuré. Lancez d'abord /univers configuration Partiel.
+create_place__name_already_exists = Lieu déjà existant
+    .title = Lieu déjà existant
+    .message = L'univers a déjà un lieu portant ce nom. Choisissez un autre nom.
 create_place__role_not_created = Création de rôle échouée
     .title = Création de rôle échouée
     .message = Le rôle du lieu n'as pas pu être créé correctement.
@@ -285,9 +1130,131 @@ create_role__rollback_failed = Rollback échoué
     .title = Rollback échoué
     .message = Quelque chose s'est mal passé durant la création du lieu et le rollback à échoué.
             Veuillez contacter le support: {support}
+create_place__log = Lieu créé
+    .title = Lieu créé
+    .message = {$actor} a créé le lieu {$place}.
 create_place__success = Place créée
     .title = Place créée
     .message = La place à été créée avec succès.
+create_place__success_with_wiki = Place créée
+    .title = Place créée
+    .message = {$place} a été créé avec succès. Sa page wiki : {$wiki_post}
+create_place__success_wiki_failed = Place créée
+    .title = Place créée sans page wiki
+    .message = {$place} a été créé avec succès, mais sa page wiki n'a pas pu être publiée. Vérifiez les permissions du bot dans le forum wiki.
+delete_place__invalid_place = Lieu invalide
+    .title = Lieu invalide
+    .message = Choisissez un lieu parmi les suggestions, ou donnez l'ID ou la mention de sa catégorie.
+delete_place__not_found = Lieu introuvable
+    .title = Lieu introuvable
+    .message = Cette catégorie n'est pas un lieu de l'univers.
+delete_place__other_server = Lieu sur un autre serveur
+    .title = Lieu sur un autre serveur
+    .message = {$place} appartient à un autre serveur de l'univers. Supprimez-le depuis ce serveur.
+delete_place__roads_remaining = Routes restantes
+    .title = Routes restantes
+    .message = {$place} est encore relié par ces routes, supprimez-les d'abord :
+            {$roads}
+delete_place__discord_delete_failed = Suppression échouée
+    .title = Suppression échouée
+    .message = Le rôle ou un salon de {$place} n'a pas pu être supprimé. Vérifiez les permissions du bot.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+delete_place__database_delete_failed = Lieu partiellement supprimé
+    .title = Lieu partiellement supprimé
+    .message = Le rôle et les salons de {$place} ont été supprimés mais son enregistrement n'a pas pu être retiré de la base de données.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+delete_place__notice = Lieu supprimé
+    .title = Lieu supprimé
+    .message = {$actor} a supprimé le lieu {$place}, avec son rôle, ses salons et ses pages de wiki.
+delete_place__log = Lieu supprimé
+    .title = Lieu supprimé
+    .message = {$actor} a supprimé le lieu {$place}.
+delete_place__success = Lieu supprimé
+    .title = Lieu supprimé
+    .message = {$place} a été supprimé.
+place_list__empty = Aucun lieu
+    .title = Aucun lieu
+    .message = Cet univers n'a pas encore de lieu.
+place_list__page = Lieux
+    .title = { $count ->
+        [one] {$count} lieu
+       *[other] {$count} lieux
+    } - page {$page}/{$pages}
+place_list__row = Lieu
+    .message = **{$name}** - {$category} - {$role} - { $modifiers ->
+        [one] {$modifiers} modificateur
+       *[other] {$modifiers} modificateurs
+    }
+place_occupants__empty = Aucun occupant
+    .title = Aucun occupant
+    .message = Aucun personnage ne se trouve dans ce lieu.
+place_occupants__not_here = Lieu hors de vue
+    .title = Lieu hors de vue
+    .message = Vous ne pouvez voir que les occupants du lieu où se trouve votre personnage.
+place_occupants__page = Occupants
+    .title = { $count ->
+        [one] {$count} occupant
+       *[other] {$count} occupants
+    } de {$place} - page {$page}/{$pages}
+place_occupants__row = Occupant
+    .message = {$user} : {$names}
+rename_place__empty_name = Nom vide
+    .title = Nom vide
+    .message = Le nouveau nom du lieu ne peut pas être vide.
+rename_place__other_server = Lieu sur un autre serveur
+    .title = Lieu sur un autre serveur
+    .message = {$old} appartient à un autre serveur de l'univers. Renommez-le depuis ce serveur.
+rename_place__discord_edit_failed = Renommage échoué
+    .title = Renommage échoué
+    .message = Un salon ou un rôle de {$old} n'a pas pu être renommé. Les autres renommages ont été annulés.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+rename_place__revert_failed = Renommage partiel
+    .title = Renommage partiel
+    .message = Un salon ou un rôle de {$old} n'a pas pu être renommé, et certains renommages n'ont pas pu être annulés. Vérifiez les noms du lieu et de ses routes.
+            Veuillez contacter le support: {support}
+rename_place__database_failed = Renommage non enregistré
+    .title = Renommage non enregistré
+    .message = Le nouveau nom de {$old} n'a pas pu être enregistré dans la base de données. Les renommages Discord ont été annulés.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+rename_place__success = Lieu renommé
+    .title = Lieu renommé
+    .message = {$old} s'appelle désormais {$new}. Routes renommées : {$roads}
+place_info__fields = Lieu
+    .role = Rôle
+    .category = Catégorie
+    .roads = Routes
+    .modifiers = Modificateurs
+place_info__road = Route
+    .message = - {$place} : {$distance} km ({$channel})
+place_info__no_roads = Aucune route
+    .message = Aucune route ne mène encore à ce lieu.
+place_info__modifier = Modificateur
+    .message = - {$stat} : {$kind} {$value}, formule {$formula}, {$expiry}
+place_info__modifier_kind = Type de modificateur
+    .base = base
+    .multiplier = multiplicateur
+    .flat = fixe
+place_info__no_modifiers = Aucun modificateur
+    .message = Aucun modificateur actif.
+place_info__permanent = Permanent
+    .message = permanent
+place_modifier__stat_not_found = Statistique introuvable
+    .title = Statistique introuvable
+    .message = L'univers n'a pas de statistique nommée {$stat}.
+place_modifier__time_stopped = Temps arrêté
+    .title = Temps arrêté
+    .message = Le temps de l'univers est arrêté, un modificateur avec une durée ne finirait jamais. Laissez la durée vide.
+place_modifier__not_found = Modificateur introuvable
+    .title = Modificateur introuvable
+    .message = {$place} n'a pas de modificateur de ce type sur {$stat}.
+place_modifier__added = Modificateur ajouté
+    .title = Modificateur ajouté
+    .message = Modificateurs de {$place} :
+            {$modifiers}
+place_modifier__removed = Modificateur retiré
+    .title = Modificateur retiré
+    .message = {$removed} modificateur(s) sur {$stat} retiré(s). Modificateurs de {$place} :
+            {$modifiers}
 
 #Create road
 create_road = nouvelle_route
@@ -298,6 +1265,9 @@ create_road__database_error = Erreur de base de données
     .title = Erreur de base de données
     .message = Une erreur s'est produite lors de l'accès à la base de données.
             Veuillez ressayer ou contacter le support si le problème persiste: {support}
+create_road__setup_required = Configuration requise
+    .title = Configuration requise
+    .message = Ce serveur n'a pas encore de catégorie des routes. Lancez d'abord /univers configuration Partiel.
 create_place__place_one_not_found = Premier lieu introuvable
     .title = Premier lieu introuvable
     .message = Le premier lieu spécifié n'a pas été trouvé dans l'univers.
@@ -336,15 +1306,82 @@ create_road__invalid_place_one = Identifiant du premier lieu invalide
 create_road__invalid_place_two = Identifiant du second lieu invalide
     .title = Second lieu invalide
     .message = L'identifiant ou la mention du second lieu est invalide. Utilisez un ID ou une mention <#id>.
+delete_road__missing_road = Route manquante
+    .title = Route manquante
+    .message = Donnez le salon de la route, ou ses deux lieux.
+delete_road__not_found = Route introuvable
+    .title = Route introuvable
+    .message = Aucune route de l'univers ne correspond.
+delete_road__other_server = Route sur un autre serveur
+    .title = Route sur un autre serveur
+    .message = La route {$road} appartient à un autre serveur de l'univers. Supprimez-la depuis ce serveur.
+delete_road__channel_delete_failed = Suppression du salon échouée
+    .title = Suppression du salon échouée
+    .message = Le salon de la route {$road} n'a pas pu être supprimé, rien n'a été supprimé. Vérifiez les permissions du bot.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+delete_road__role_delete_failed = Suppression du rôle échouée
+    .title = Route partiellement supprimée
+    .message = Le salon de la route {$road} a été supprimé mais pas son rôle, et la route est toujours enregistrée.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+delete_road__database_delete_failed = Route partiellement supprimée
+    .title = Route partiellement supprimée
+    .message = Le salon et le rôle de la route {$road} ont été supprimés mais son enregistrement n'a pas pu être retiré de la base de données.
+            Veuillez ressayer ou contacter le support si le problème persiste: {support}
+delete_road__notice = Route supprimée
+    .title = Route supprimée
+    .message = {$actor} a supprimé la route {$road}, avec son rôle et son salon.
+delete_road__log = Route supprimée
+    .title = Route supprimée
+    .message = {$actor} a supprimé la route {$road}.
+delete_road__success = Route supprimée
+    .title = Route supprimée
+    .message = La route entre {$place_one} et {$place_two} a été supprimée.
+road_list__empty = Aucune route
+    .title = Aucune route
+    .message = Aucune route ne correspond pour l'instant. Reliez deux lieux avec /route nouvelle_route.
+road_map__success = Carte des routes
+    .title = Carte des routes
+    .message = Carte de { $places ->
+        [one] {$places} lieu
+       *[other] {$places} lieux
+    } et { $roads ->
+        [one] {$roads} route
+       *[other] {$roads} routes
+    }, au format Graphviz. Les routes en pointillés sont secrètes, les routes orange ont des modificateurs actifs.
+road_map__export_failed = Échec de l'export
+    .title = Échec de l'export
+    .message = La carte n'a pas pu être compressée.
+road_list__page = Routes
+    .title = { $count ->
+        [one] {$count} route
+       *[other] {$count} routes
+    } - page {$page}/{$pages}
+road_list__row = Route
+    .message = **{$place_one}** ⟷ **{$place_two}** — distance {$distance} km — {$channel}
+edit_road__invalid_distance = Distance invalide
+    .title = Distance invalide
+    .message = La distance d'une route doit être supérieure à 0.
+edit_road__success = Route modifiée
+    .title = Route modifiée
+    .message = Distance de {$road} : {$old_distance} km → {$new_distance} km
+edit_road__slowmode_not_applied = Route modifiée
+    .title = Route modifiée, mode lent non mis à jour
+    .message = Distance de {$road} : {$old_distance} km → {$new_distance} km. Le mode lent de son salon n'a pas pu être mis à jour, vérifiez les permissions du bot.
+create_road__log = Route créée
+    .title = Route créée
+    .message = {$actor} a créé la route {$road} : {$channel}
 create_road__success = Route créée
     .title = Route créée
-    .message = La route a été créée avec succès
+    .message = La route {$road} a été créée avec succès : {$channel}
 create_road__limit_reached = Limite de routes atteinte
     .title = Limite atteinte
     .message = L'un des lieux a déjà atteint le nombre maximum de 25 routes (hors routes secrètes).
 create_road__already_exists = Route déjà existante
     .title = Route existante
-    .message = Une route existe déjà entre ces deux lieux.
+    .message = Une route existe déjà entre ces deux lieux : {$channel}
+create_road__same_place = Même lieu
+    .title = Même lieu
+    .message = Une route doit relier deux lieux différents.
 create_road__universe_mismatch = Univers différent
     .title = Univers différent
     .message = Les deux lieux doivent appartenir au même univers.
@@ -376,9 +1413,6 @@ create_character__database_error = Erreur de base de données
 create_character__wrong_channel = Mauvais salon
     .title = Mauvais salon
     .message = Cette commande doit être utilisée dans le salon des fiches de personnage.
-create_character__character_already_existing = Le personnage existe déjà
-    .title = Le personnage existe déjà
-    .message = Vous avez déjà un personnage. Vous ne pouvez pas en créer un autre.
 CharacterModal = character_modal
     .character_name = Nom
     .character_description = Description du personnage
@@ -447,6 +1481,116 @@ accept_character__no_player_role_id = Serveur non setup
 accept_character__member_not_found = Membre introuvable lors de l'acceptation.
     .title = Erreur d'acceptation
     .message = Impossible de trouver l'utilisateur sur le serveur.
+character_create__name_taken = Nom déjà pris
+    .title = Nom déjà pris
+    .message = Un personnage de cet univers s'appelle déjà {$name}.
+character_sheet__fields = Fiche personnage
+    .owner = Joueur
+    .description = Description
+    .stats = Statistiques
+    .status = Statut
+    .place = Lieu
+character_sheet__no_description = Aucune description
+    .message = _Pas encore de description._
+character_sheet__no_stats = Aucune statistique
+    .message = _Cet univers n'a aucune statistique._
+character_sheet__update_failed = Fiche non mise à jour
+    .message = Le message de la fiche personnage n'a pas pu être mis à jour.
+character_status = Statut
+    .pending = En attente de validation
+    .approved = Validé
+    .rejected = Refusé
+character_list__empty = Aucun personnage
+    .title = Aucun personnage
+    .message = Il n'y a encore aucun personnage à lister. Créez-en un avec /personnage nouveau_personnage.
+character_list__page = Personnages
+    .title = { $count ->
+        [one] {$count} personnage
+       *[other] {$count} personnages
+    } de {$user} - page {$page}/{$pages}
+character_list__row = Personnage
+    .message = **{$name}** — {$status} — {$place}
+character_list__no_place = Aucun lieu
+    .message = nulle part pour l'instant
+character_approval__approved = {$name} a été validé par {$moderator}.
+character_approval__rejected = {$name} a été refusé par {$moderator}.
+character_approval__rejected_notice = Personnage refusé
+    .title = Personnage refusé
+    .message = {$actor} a refusé {$name}, le personnage de {$user}.
+character_approval__not_found = Ce personnage n'existe plus.
+character_approval__already_decided = Un modérateur a déjà statué sur ce personnage.
+character__pending_approval = Personnage en attente de validation
+    .title = Personnage en attente de validation
+    .message = Votre personnage n'a pas encore été validé par un modérateur.
+character__rejected = Personnage refusé
+    .title = Personnage refusé
+    .message = Votre personnage a été refusé par un modérateur et ne peut pas jouer.
+character__not_found = Personnage introuvable
+    .title = Personnage introuvable
+    .message = Aucun personnage de cet univers ne s'appelle {$name}.
+character__no_active = Aucun personnage joué
+    .title = Aucun personnage joué
+    .message = Vous ne jouez aucun personnage. Créez-en un avec /personnage nouveau_personnage, ou choisissez l'un des vôtres avec /personnage changer.
+character_switch__success = Personnage changé
+    .title = Personnage changé
+    .message = Vous jouez désormais {$name}.
+character_switch__already_active = Personnage déjà joué
+    .title = Personnage déjà joué
+    .message = Vous jouez déjà {$name}.
+character_switch__travelling = Voyage en cours
+    .title = Voyage en cours
+    .message = Vous ne pouvez pas changer de personnage pendant un voyage. Attendez la fin du trajet.
+character_delete__confirm = Supprimer le personnage ?
+    .title = Supprimer {$name} ?
+    .message = Le personnage, sa fiche et les rôles de son voyage seront retirés. Cette action est irréversible.
+    .cancel = Annuler
+    .confirm = Supprimer
+character_delete__cancelled = Suppression annulée
+    .title = Suppression annulée
+    .message = {$name} n'a pas été supprimé.
+character_delete__timeout = Suppression annulée
+    .title = Suppression annulée
+    .message = La suppression de {$name} n'a pas été confirmée à temps.
+character_delete__success = Personnage supprimé
+    .title = Personnage supprimé
+    .message = {$name} a été supprimé.
+character_delete__notice = Personnage validé supprimé
+    .title = Personnage validé supprimé
+    .message = {$actor} a supprimé son personnage validé {$name}.
+character_teleport__success = Personnage téléporté
+    .title = Personnage téléporté
+    .message = {$name}, joué par {$user}, est maintenant à {$destination}.
+character_teleport__no_character = Aucun personnage validé
+    .title = Aucun personnage validé
+    .message = {$user} ne joue aucun personnage validé.
+character_teleport__log = {$moderator} a téléporté {$name}, joué par {$user}, à {$destination}.
+whereis__in_place = Position
+    .title = Position
+    .message = {$user} joue {$name}, qui se trouve à {$place}.
+whereis__travelling = Position
+    .title = Position
+    .message = {$user} joue {$name}, qui voyage sur {$road} vers {$destination}.
+whereis__nowhere = Position
+    .title = Position
+    .message = {$user} joue {$name}, qui n'est encore dans aucun lieu.
+whereis__no_character = Aucun personnage joué
+    .title = Aucun personnage joué
+    .message = {$user} ne joue aucun personnage.
+character_stat__stat_not_found = Statistique introuvable
+    .title = Statistique introuvable
+    .message = Cet univers n'a pas de statistique nommée {$stat}.
+character_stat__invalid_value = Valeur invalide
+    .title = Valeur invalide
+    .message = {$value} n'est pas une valeur valide pour {$stat}.
+character_stat__out_of_bounds = Valeur hors limites
+    .title = Valeur hors limites
+    .message = {$stat} doit être compris entre {$min} et {$max}.
+character_stat__set = Statistique modifiée
+    .title = Statistique modifiée
+    .message = {$stat} de {$character} : {$old_value} → {$value}
+character_stat__set_sheet_not_updated = Statistique modifiée
+    .title = Statistique modifiée, fiche non mise à jour
+    .message = {$stat} de {$character} : {$old_value} → {$value}. La fiche du personnage n'a pas pu être mise à jour.
 
 
 #Travels
@@ -482,6 +1626,43 @@ travel__already_moving_to_destination = Déjà en route
 travel__invalid_road_destination = Destination invalide
     .title = Destination invalide
     .message = Vous ne pouvez pas aller à cet endroit depuis votre position actuelle sur la route.
+travel_status__in_place = Pas en voyage
+    .title = Pas en voyage
+    .message = Votre personnage se trouve à {$place}.
+travel_status__moving = Voyage en cours
+    .title = Voyage en cours
+    .message = Sur la route {$road} vers {$destination}, parti {$departure}. Encore {$distance} km, arrivée {$arrival}.
+travel_status__stopped = Voyage en pause
+    .title = Voyage en pause
+    .message = Arrêté sur la route {$road}, à {$distance} km de {$destination}. Utilisez /voyage départ pour repartir.
+travel_cancel__not_traveling = Pas en voyage
+    .title = Pas en voyage
+    .message = Votre personnage n'est pas sur une route.
+travel_cancel__success = Voyage annulé
+    .title = Voyage annulé
+    .message = Vous avez abandonné votre voyage et êtes retourné à {$place}.
+navigate__route = Itinéraire
+    .title = 🧭 Itinéraire vers {$destination}
+    .distance = Distance
+    .duration = Temps de trajet
+navigate__leg = Étape
+    .message = {$index}. {$place} par {$road} - {$distance} km
+navigate__already_there = Déjà sur place
+    .message = Votre personnage y est déjà.
+navigate__unknown_duration = Inconnu
+    .message = Inconnu
+navigate__no_place = Aucun lieu actuel
+    .title = Aucun lieu actuel
+    .message = Votre personnage ne se trouve dans aucun lieu, aucun itinéraire ne peut être calculé.
+navigate__unreachable = Inaccessible
+    .title = Destination inaccessible
+    .message = Aucune route ne mène du lieu de votre personnage à {$destination}.
+navigate__too_many_roads = Trop de routes
+    .title = Trop de routes
+    .message = Cet univers a plus de {$max} routes, trop pour calculer un itinéraire.
+travel__not_in_place = Pas dans ce lieu
+    .title = Pas dans ce lieu
+    .message = Votre personnage ne se trouve pas dans ce lieu. Partez du lieu où se trouve votre personnage.
 move_from_place__road_not_found = Aucune route trouvée
     .title = Aucune route trouvée
     .message = Il n'y a pas de route directe entre votre position actuelle et {$destination}.